@@ -0,0 +1,85 @@
+/* =========================================
+   Offline Playground
+   ส่งปุ่ม Run ไปที่ `cargo run --bin playground` แทน play.rust-lang.org
+   (โหลดทุกหน้าผ่าน docs/book.toml → additional-js แต่บนเว็บจริงไม่ทำอะไร)
+   ========================================= */
+
+(function () {
+    const REMOTE = 'https://play.rust-lang.org';
+    const KEY = 'playground-url';
+
+    function isLoopback(hostname) {
+        return hostname === 'localhost' || hostname === '[::1]' ||
+            /^127\.\d+\.\d+\.\d+$/.test(hostname);
+    }
+
+    // รับเฉพาะ playground ในเครื่อง (loopback) หรือ server เดียวกับหนังสือ
+    // ลิงก์จากที่อื่นจะส่ง code ของผู้เรียนไป host แปลกๆ ไม่ได้
+    function trusted(value) {
+        let url;
+        try {
+            url = new URL(value);
+        } catch (e) {
+            return null;
+        }
+        if (url.protocol !== 'http:' && url.protocol !== 'https:') {
+            return null;
+        }
+        if (!isLoopback(url.hostname) && url.origin !== window.location.origin) {
+            return null;
+        }
+        return url.origin;
+    }
+
+    // ?playground=http://localhost:3030 → จำไว้ใน localStorage
+    // ?playground=off → กลับไปใช้ play.rust-lang.org
+    const params = new URLSearchParams(window.location.search);
+    const chosen = params.get('playground');
+    if (chosen === 'off') {
+        localStorage.removeItem(KEY);
+    } else if (chosen) {
+        const url = trusted(chosen);
+        if (url) {
+            localStorage.setItem(KEY, url);
+        } else {
+            console.warn('playground: ignoring ' + chosen + ' (only localhost URLs are allowed)');
+        }
+    }
+
+    const stored = localStorage.getItem(KEY);
+    const local = stored && trusted(stored);
+    if (stored && !local) {
+        localStorage.removeItem(KEY);
+    }
+
+    // ไม่ได้เลือกไว้: ถ้าเปิดหนังสือจาก localhost ให้ดูว่า server นี้เป็น playground หรือไม่
+    // (cargo run --bin playground เสิร์ฟหนังสือเอง, mdbook serve ไม่ใช่)
+    const detect = !local && chosen !== 'off' && isLoopback(window.location.hostname);
+    if (!local && !detect) {
+        return;
+    }
+
+    const remoteFetch = window.fetch.bind(window);
+    let found = null;
+    function sameOrigin() {
+        if (!found) {
+            found = remoteFetch(window.location.origin + '/meta/crates')
+                .then((response) => response.ok && response.json())
+                .then((body) => Boolean(body && Array.isArray(body.crates)))
+                .catch(() => false);
+        }
+        return found;
+    }
+
+    window.fetch = function (resource, options) {
+        if (typeof resource !== 'string' || !resource.startsWith(REMOTE)) {
+            return remoteFetch(resource, options);
+        }
+        const path = resource.slice(REMOTE.length);
+        if (local) {
+            return remoteFetch(local + path, options);
+        }
+        return sameOrigin().then((yes) =>
+            remoteFetch((yes ? window.location.origin : REMOTE) + path, options));
+    };
+})();
@@ -0,0 +1,75 @@
+// ===========================================
+// Calculator - บทที่ 9
+// รัน: cargo run --example calculator
+// ===========================================
+
+// divide() ใน error_handling.rs คืน Err(String) ส่วนตรงนี้ error บอกได้ว่าผิดตรงไหน
+use rust_tutorial::calc::{self, CalcError, Calculator, ErrorKind};
+
+fn main() {
+    println!("🧮 Calculator Demo\n");
+
+    // 1. ลำดับความสำคัญของเครื่องหมาย
+    println!("1️⃣ Precedence:");
+    for source in ["1 + 2 * 3", "(1 + 2) * 3", "-2^2", "2^3^2", "10 % 4 - 1"] {
+        show(source, calc::evaluate(source));
+    }
+
+    // 2. ฟังก์ชันและค่าคงที่
+    println!("\n2️⃣ Functions:");
+    for source in [
+        "sqrt(16) + abs(-2)",
+        "max(3, 7, 5)",
+        "round(pi * 100) / 100",
+    ] {
+        show(source, calc::evaluate(source));
+    }
+
+    // 3. ตัวแปร (ค่าที่กำหนดด้วย = ถูกเก็บไว้ใช้ต่อ)
+    println!("\n3️⃣ Variables:");
+    let mut calculator = Calculator::new();
+    for source in ["price = 120", "qty = 3", "price * qty * (1 - 0.1)"] {
+        show(source, calculator.run(source));
+    }
+
+    // 4. Error พร้อมตำแหน่ง
+    println!("\n4️⃣ Errors:");
+    for source in [
+        "10 / (5 - 5)",
+        "2 * (3 + 4",
+        "1 + * 2",
+        "sqrt(-4)",
+        "price + tax",
+        "10 ^ 400",
+        "3 $ 4",
+    ] {
+        if let Err(e) = calculator.eval(source) {
+            println!("{}\n", e.render(source));
+        }
+    }
+
+    // 5. match กับชนิดของ error
+    println!("5️⃣ Match on ErrorKind:");
+    for source in ["1 / 0", "x + 1", "min()"] {
+        let message = match calc::evaluate(source) {
+            Ok(value) => format!("= {}", value),
+            Err(CalcError {
+                kind: ErrorKind::DivisionByZero,
+                ..
+            }) => "cannot divide by zero".to_string(),
+            Err(CalcError {
+                kind: ErrorKind::UnknownVariable(name),
+                span,
+            }) => format!("what is '{}'? ({})", name, span),
+            Err(e) => format!("error: {}", e),
+        };
+        println!("{:<8} {}", source, message);
+    }
+}
+
+fn show(source: &str, result: Result<f64, CalcError>) {
+    match result {
+        Ok(value) => println!("{:<26} = {}", source, value),
+        Err(e) => println!("{:<26} ❌ {}", source, e),
+    }
+}
@@ -3,7 +3,10 @@
 // รัน: cargo run --example collections
 // ===========================================
 
-use std::collections::HashMap;
+// สร้าง Vec ว่างแล้ว push ทีละตัว ให้ผู้เรียนเห็นก่อนใช้ vec![...]
+#![allow(clippy::vec_init_then_push)]
+
+use std::collections::{BTreeMap, HashMap};
 
 fn main() {
     println!("🦀 Collections Demo\n");
@@ -55,7 +58,8 @@ fn main() {
     scores.insert(String::from("Blue"), 10);
     scores.insert(String::from("Red"), 50);
 
-    println!("Scores: {:?}", scores);
+    // HashMap ไม่เก็บลำดับ (ลำดับเปลี่ยนได้ทุกครั้งที่รัน) จึงแปลงเป็น BTreeMap ให้เรียงตาม key ก่อนแสดง
+    println!("Scores: {:?}", scores.iter().collect::<BTreeMap<_, _>>());
 
     // Access
     let team = String::from("Blue");
@@ -66,11 +70,13 @@ fn main() {
     // Update
     scores.insert(String::from("Blue"), 25); // overwrite
     scores.entry(String::from("Yellow")).or_insert(30); // insert if not exists
-    println!("Updated: {:?}", scores);
+    println!("Updated: {:?}", scores.iter().collect::<BTreeMap<_, _>>());
 
-    // Iterate
+    // Iterate (เรียง key ก่อน)
     println!("All scores:");
-    for (key, value) in &scores {
+    let mut teams: Vec<_> = scores.iter().collect();
+    teams.sort();
+    for (key, value) in teams {
         println!("  {} = {}", key, value);
     }
 
@@ -86,7 +92,7 @@ fn main() {
 
     println!("Text: \"{}\"", text);
     println!("Word counts:");
-    for (word, count) in &word_count {
+    for (word, count) in word_count.iter().collect::<BTreeMap<_, _>>() {
         println!("  {}: {}", word, count);
     }
 }
@@ -65,6 +65,7 @@ fn main() {
 }
 
 // Custom divide function
+// (เครื่องคิดเลขที่ error บอกตำแหน่ง: examples/calculator.rs และ rust_tutorial::calc)
 fn divide(a: f64, b: f64) -> Result<f64, String> {
     if b == 0.0 {
         Err(String::from("Cannot divide by zero"))
@@ -74,6 +75,7 @@ fn divide(a: f64, b: f64) -> Result<f64, String> {
 }
 
 // Using ? operator
+// (fallback แบบนี้ซ่อน error อื่นด้วย เช่นไม่มีสิทธิ์อ่าน - แบบแยกแยะได้: rust_tutorial::config)
 fn read_username() -> Result<String, io::Error> {
     let mut file = match File::open("username.txt") {
         Ok(f) => f,
@@ -94,6 +94,7 @@ impl<T> Point<T> {
 }
 
 // Specific implementation for f64
+// (ระยะระหว่างจุดและรูปทรงต่างๆ: rust_tutorial::geometry)
 impl Point<f64> {
     fn distance_from_origin(&self) -> f64 {
         (self.x.powi(2) + self.y.powi(2)).sqrt()
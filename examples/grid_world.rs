@@ -0,0 +1,86 @@
+// ===========================================
+// Grid World - บทที่ 6-7
+// รัน: cargo run --example grid_world
+// ===========================================
+
+// Direction แบบเดียวกับใน structs_enums.rs แต่ใช้เดินบนแผนที่จริง
+use rust_tutorial::grid::{self, Blocked, Direction, Step, World};
+
+const MAP: &str = "
+    ##########
+    #@...#...#
+    #.##.#.#.#
+    #....#.#.#
+    ####...#.#
+    #......#.#
+    ##########
+";
+
+fn main() {
+    println!("🗺️ Grid World Demo\n");
+
+    // 1. แผนที่จาก ASCII
+    println!("1️⃣ Map:");
+    let mut world = World::parse(MAP).expect("valid map");
+    print!("{}", world.render());
+    println!("Player at {}", world.player());
+
+    // 2. match กับ enum ที่มีข้อมูล (Step, Blocked)
+    println!("\n2️⃣ Step:");
+    for direction in [Direction::East, Direction::North, Direction::South] {
+        match world.step(direction) {
+            Step::Moved(position) => println!("{} {} → {}", direction.arrow(), direction, position),
+            Step::Blocked(blocked) => {
+                println!("{} {} {}", direction.arrow(), direction, describe(&blocked))
+            }
+        }
+    }
+
+    // 3. เดินหลายก้าว (ก้าวที่ชนถูกข้าม)
+    println!("\n3️⃣ Walk:");
+    let moves = Direction::parse_moves("E E S S S W").expect("valid moves");
+    let walk = world.walk(&moves);
+    println!(
+        "Moved {} of {} steps, now at {}",
+        walk.moved,
+        moves.len(),
+        walk.end
+    );
+    for (index, direction, blocked) in &walk.blocked {
+        println!("  step {} ({}) {}", index + 1, direction, describe(blocked));
+    }
+    print!("{}", world.render());
+
+    // 4. หาเส้นทาง (Vec<Direction>)
+    println!("\n4️⃣ Pathfinding:");
+    let start = world.player();
+    let goal = grid::Position::new(8, 5);
+    let path = grid::astar(&world.grid, start, goal).expect("reachable");
+    let letters: String = path.iter().map(|d| d.letter()).collect();
+    println!(
+        "A*  {} → {}: {} steps ({})",
+        start,
+        goal,
+        path.len(),
+        letters
+    );
+    let bfs = grid::bfs(&world.grid, start, goal).expect("reachable");
+    println!("BFS {} → {}: {} steps", start, goal, bfs.len());
+    print!("{}", grid::render_path(&world.grid, start, &path));
+
+    // 5. เดินตามเส้นทางที่หาได้
+    println!("\n5️⃣ Follow:");
+    let walk = world.walk(&path);
+    println!(
+        "Arrived at {} with {} collisions",
+        walk.end,
+        walk.blocked.len()
+    );
+}
+
+fn describe(blocked: &Blocked) -> String {
+    match blocked {
+        Blocked::Wall(at) => format!("blocked by wall at {}", at),
+        Blocked::OutOfBounds(at) => format!("would leave the map at {}", at),
+    }
+}
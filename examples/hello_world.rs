@@ -1,11 +1,18 @@
 // ===========================================
 // Hello World - บทที่ 1
 // รัน: cargo run --example hello_world
+//      cargo run --example hello_world -- --lang en   (ข้อความภาษาอังกฤษ)
 // ===========================================
 
+// t! ดึงข้อความตามภาษาจาก locales/th.json หรือ locales/en.json
+use rust_tutorial::{i18n, t};
+
 fn main() {
+    // เลือกภาษาจาก --lang หรือ $LANG (ค่าเริ่มต้นภาษาไทย)
+    i18n::init();
+
     // พิมพ์ข้อความ
-    println!("🦀 สวัสดี Rust!");
+    println!("{}", t!("hello.greeting"));
     println!("Hello, World!");
 
     // ตัวแปร (บทที่ 2)
@@ -13,19 +20,19 @@ fn main() {
     let age: u32 = 25;
     let is_learning = true;
 
-    println!("\n📝 ตัวอย่างตัวแปร:");
+    println!("\n{}", t!("hello.variables"));
     println!("Name: {}", name);
     println!("Age: {}", age);
     println!("Is Learning: {}", is_learning);
 
     // ฟังก์ชัน (บทที่ 3)
-    println!("\n🔧 ตัวอย่างฟังก์ชัน:");
+    println!("\n{}", t!("hello.functions"));
     greet("World");
     let sum = add(5, 3);
     println!("5 + 3 = {}", sum);
 
     // Control Flow (บทที่ 4)
-    println!("\n🔀 ตัวอย่าง Control Flow:");
+    println!("\n{}", t!("hello.control_flow"));
     check_number(10);
 
     for i in 1..=5 {
@@ -46,10 +53,10 @@ fn add(a: i32, b: i32) -> i32 {
 // ตรวจสอบเลข
 fn check_number(n: i32) {
     if n > 0 {
-        println!("{} is positive", n);
+        println!("{}", t!("hello.positive", n = n));
     } else if n < 0 {
-        println!("{} is negative", n);
+        println!("{}", t!("hello.negative", n = n));
     } else {
-        println!("{} is zero", n);
+        println!("{}", t!("hello.zero", n = n));
     }
 }
@@ -3,6 +3,9 @@
 // รัน: cargo run --example iterators
 // ===========================================
 
+// หัวข้อ fold ตั้งใจรวมผลด้วย fold แทน sum()/product() และใช้ vec! เหมือนบทก่อนๆ
+#![allow(clippy::unnecessary_fold, clippy::useless_vec)]
+
 fn main() {
     println!("🦀 Iterators & Closures Demo\n");
 
@@ -3,6 +3,9 @@
 // รัน: cargo run --example ownership
 // ===========================================
 
+// calculate_length รับ &String ตามบทเรียนเรื่อง borrowing (&str มาทีหลังในหัวข้อ slices)
+#![allow(clippy::ptr_arg)]
+
 fn main() {
     println!("🦀 Ownership Demo\n");
 
@@ -25,6 +28,7 @@ fn main() {
     let y = x; // copy (integers implement Copy)
     println!("x = {}, y = {}", x, y);
 
+    // ANCHOR: borrowing
     // 4. References (Borrowing)
     println!("\n4️⃣ References (Borrowing):");
     let s5 = String::from("rust");
@@ -37,6 +41,7 @@ fn main() {
     println!("Before: {}", s6);
     change(&mut s6);
     println!("After: {}", s6);
+    // ANCHOR_END: borrowing
 
     // 6. Slices
     println!("\n6️⃣ Slices:");
@@ -47,6 +52,7 @@ fn main() {
     println!("Second word: {}", world);
 }
 
+// ANCHOR: borrowing_fns
 fn calculate_length(s: &String) -> usize {
     s.len()
 }
@@ -54,3 +60,4 @@ fn calculate_length(s: &String) -> usize {
 fn change(s: &mut String) {
     s.push_str(", World!");
 }
+// ANCHOR_END: borrowing_fns
@@ -69,6 +69,7 @@ fn main() {
 }
 
 // Struct
+// (แบบตรวจข้อมูลก่อนสร้าง ใช้ใน web_server ด้วย: rust_tutorial::users)
 struct User {
     name: String,
     age: u32,
@@ -76,6 +77,7 @@ struct User {
 }
 
 // Struct with methods
+// (สี่เหลี่ยมที่มีตำแหน่ง วงกลม และรูปหลายเหลี่ยม: rust_tutorial::geometry)
 #[derive(Debug)]
 struct Rectangle {
     width: u32,
@@ -97,9 +99,11 @@ impl Rectangle {
 }
 
 // Tuple Struct
+// (สีแบบใช้งานจริง hex, ชื่อ CSS, HSL: rust_tutorial::color)
 struct Color(u8, u8, u8);
 
 // Enum
+// (เดินบนแผนที่จริง: examples/grid_world.rs และ rust_tutorial::grid)
 #[derive(Clone, Copy)]
 enum Direction {
     North,
@@ -118,6 +122,7 @@ fn move_player(dir: Direction) {
 }
 
 // Enum with Data
+// (เวอร์ชันที่ส่งผ่าน network ได้อยู่ใน rust_tutorial::protocol::Message)
 enum Message {
     Quit,
     Move { x: i32, y: i32 },
@@ -141,6 +146,7 @@ fn print_option(opt: Option<i32>) {
     }
 }
 
+// (ทอนเงินด้วยชุดเหรียญต่างๆ: rust_tutorial::change)
 #[derive(Debug, Clone, Copy)]
 enum Coin {
     Penny,
@@ -0,0 +1,334 @@
+// ===========================================
+// GraphQL - endpoint /graphql คู่กับ REST routes
+// ===========================================
+//
+// ใช้ข้อมูลชุดเดียวกับ REST (AppState) และแยกตาม tenant เหมือนกัน
+// - Query:        users, user(id)
+// - Mutation:     createUser, updateUser, deleteUser
+// - Subscription: userChanged (ส่งผ่าน Server-Sent Events)
+
+use crate::{ApiError, AppState, TenantId, UpdateUser, User, UserChangeKind, invalid_input};
+use async_graphql::{
+    ComplexObject, Context, ErrorExtensions, Object, Schema, SimpleObject, Subscription,
+};
+use axum::{
+    extract::{Json, State},
+    response::{
+        Html,
+        sse::{Event, KeepAlive, Sse},
+    },
+};
+use rust_tutorial::users::{Age, Email, User as Profile, UserName};
+use std::convert::Infallible;
+use std::sync::Arc;
+use tokio_stream::{Stream, StreamExt, wrappers::BroadcastStream};
+
+pub type UserSchema = Schema<QueryRoot, MutationRoot, SubscriptionRoot>;
+
+// หน้า query แบบ offline (ไม่โหลดอะไรจาก CDN) - เขียนเอง ไม่ใช่ GraphiQL
+const PLAYGROUND_HTML: &str = include_str!("playground.html");
+
+pub fn build_schema() -> UserSchema {
+    Schema::build(QueryRoot, MutationRoot, SubscriptionRoot).finish()
+}
+
+// Tenant ของ request ปัจจุบัน (ใส่ไว้ใน context ทุก request)
+struct RequestTenant(String);
+
+// ดึง state และ tenant ออกจาก context
+fn scope<'a>(ctx: &Context<'a>) -> async_graphql::Result<(&'a Arc<AppState>, &'a str)> {
+    let state = ctx.data::<Arc<AppState>>()?;
+    let tenant = ctx.data::<RequestTenant>()?;
+    Ok((state, tenant.0.as_str()))
+}
+
+// แปลง error ของ REST เป็น GraphQL error
+fn to_graphql_error((status, message): ApiError) -> async_graphql::Error {
+    async_graphql::Error::new(message).extend_with(|_, e| e.set("status", status.as_u16()))
+}
+
+// ฟิลด์ของ Profile เป็น String/Int ธรรมดาใน schema (ค่าถูกตรวจตอนรับเข้ามาแล้ว)
+#[ComplexObject]
+impl User {
+    async fn name(&self) -> &str {
+        self.profile.name.as_str()
+    }
+
+    async fn email(&self) -> &str {
+        self.profile.email.as_str()
+    }
+
+    async fn age(&self) -> Option<u8> {
+        self.profile.age.map(Age::get)
+    }
+}
+
+pub struct QueryRoot;
+
+#[Object]
+impl QueryRoot {
+    async fn users(&self, ctx: &Context<'_>) -> async_graphql::Result<Vec<User>> {
+        let (state, tenant) = scope(ctx)?;
+        state.list_users(tenant).map_err(to_graphql_error)
+    }
+
+    // คืน null ถ้าไม่พบ user
+    async fn user(&self, ctx: &Context<'_>, id: u32) -> async_graphql::Result<Option<User>> {
+        let (state, tenant) = scope(ctx)?;
+        match state.get_user(tenant, id) {
+            Ok(user) => Ok(Some(user)),
+            Err((status, _)) if status == axum::http::StatusCode::NOT_FOUND => {
+                // tenant ไม่มีอยู่จริงยังถือเป็น error
+                state.list_users(tenant).map_err(to_graphql_error)?;
+                Ok(None)
+            }
+            Err(e) => Err(to_graphql_error(e)),
+        }
+    }
+}
+
+pub struct MutationRoot;
+
+#[Object]
+impl MutationRoot {
+    async fn create_user(
+        &self,
+        ctx: &Context<'_>,
+        name: String,
+        email: String,
+        age: Option<i64>,
+    ) -> async_graphql::Result<User> {
+        let (state, tenant) = scope(ctx)?;
+        let profile = new_profile(name, email, age).map_err(to_graphql_error)?;
+        state
+            .create_user(tenant, profile)
+            .await
+            .map_err(to_graphql_error)
+    }
+
+    async fn update_user(
+        &self,
+        ctx: &Context<'_>,
+        id: u32,
+        name: Option<String>,
+        email: Option<String>,
+        age: Option<i64>,
+    ) -> async_graphql::Result<User> {
+        let (state, tenant) = scope(ctx)?;
+        let invalid = |e| to_graphql_error(invalid_input(e));
+        let input = UpdateUser {
+            name: name.map(UserName::new).transpose().map_err(invalid)?,
+            email: email.map(Email::new).transpose().map_err(invalid)?,
+            age: age.map(Age::new).transpose().map_err(invalid)?,
+        };
+        state
+            .update_user(tenant, id, input)
+            .map_err(to_graphql_error)
+    }
+
+    async fn delete_user(&self, ctx: &Context<'_>, id: u32) -> async_graphql::Result<bool> {
+        let (state, tenant) = scope(ctx)?;
+        state.delete_user(tenant, id).map_err(to_graphql_error)?;
+        Ok(true)
+    }
+}
+
+// ตรวจ argument ด้วยกฎเดียวกับ JSON ของ REST
+fn new_profile(name: String, email: String, age: Option<i64>) -> Result<Profile, ApiError> {
+    let profile = Profile::new(
+        UserName::new(name).map_err(invalid_input)?,
+        Email::new(email).map_err(invalid_input)?,
+    );
+    match age {
+        Some(age) => Ok(profile.with_age(Age::new(age).map_err(invalid_input)?)),
+        None => Ok(profile),
+    }
+}
+
+#[derive(SimpleObject)]
+pub struct UserChange {
+    kind: UserChangeKind,
+    user: User,
+}
+
+pub struct SubscriptionRoot;
+
+#[Subscription]
+impl SubscriptionRoot {
+    // ทุกครั้งที่ user ใน tenant นี้ถูกสร้าง แก้ไข หรือลบ
+    async fn user_changed(
+        &self,
+        ctx: &Context<'_>,
+    ) -> async_graphql::Result<impl Stream<Item = UserChange>> {
+        let (state, tenant) = scope(ctx)?;
+        let tenant = tenant.to_string();
+        let events = BroadcastStream::new(state.events.subscribe());
+
+        // subscriber ที่ตามไม่ทัน (Lagged) จะข้าม event ที่หายไป
+        Ok(events.filter_map(move |event| match event {
+            Ok(event) if event.tenant == tenant => Some(UserChange {
+                kind: event.kind,
+                user: event.user,
+            }),
+            _ => None,
+        }))
+    }
+}
+
+// Handlers
+
+pub async fn playground() -> Html<&'static str> {
+    Html(PLAYGROUND_HTML)
+}
+
+pub async fn sdl(State(state): State<Arc<AppState>>) -> String {
+    state.schema.sdl()
+}
+
+pub async fn execute(
+    TenantId(tenant): TenantId,
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<async_graphql::Request>,
+) -> Json<async_graphql::Response> {
+    let request = request.data(state.clone()).data(RequestTenant(tenant));
+
+    Json(state.schema.execute(request).await)
+}
+
+// Subscription ผ่าน SSE: แต่ละผลลัพธ์ส่งเป็น event ชื่อ "next"
+pub async fn subscribe(
+    TenantId(tenant): TenantId,
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<async_graphql::Request>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let request = request.data(state.clone()).data(RequestTenant(tenant));
+    let responses = state.schema.execute_stream(request);
+
+    Sse::new(responses.map(|response| {
+        Ok(Event::default()
+            .event("next")
+            .json_data(response)
+            .unwrap_or_else(|e| Event::default().event("error").data(e.to_string())))
+    }))
+    .keep_alive(KeepAlive::default())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::test_state;
+    use async_graphql::{Request, Variables};
+    use serde_json::{Value, json};
+    use std::time::Duration;
+
+    async fn run(state: &Arc<AppState>, tenant: &str, query: &str, variables: Value) -> Value {
+        let request = Request::new(query)
+            .variables(Variables::from_json(variables))
+            .data(state.clone())
+            .data(RequestTenant(tenant.to_string()));
+        let response = state.schema.execute(request).await;
+        serde_json::to_value(response).unwrap()
+    }
+
+    const CREATE: &str = "mutation($name: String!, $email: String!, $age: Int) {
+        createUser(name: $name, email: $email, age: $age) { id name email age }
+    }";
+
+    #[tokio::test]
+    async fn queries_are_scoped_to_the_tenant() {
+        let state = test_state().await;
+        let users = "{ users { id name } }";
+
+        let response = run(&state, "default", users, json!({})).await;
+        assert_eq!(
+            response["data"]["users"],
+            json!([{ "id": 1, "name": "Alice" }, { "id": 2, "name": "Bob" }])
+        );
+        assert_eq!(
+            run(&state, "acme", users, json!({})).await["data"]["users"],
+            json!([])
+        );
+
+        let user = "query($id: Int!) { user(id: $id) { name email age } }";
+        let response = run(&state, "default", user, json!({ "id": 2 })).await;
+        assert_eq!(
+            response["data"]["user"],
+            json!({ "name": "Bob", "email": "bob@example.com", "age": null })
+        );
+        let response = run(&state, "acme", user, json!({ "id": 2 })).await;
+        assert_eq!(response["data"]["user"], Value::Null);
+        assert!(response.get("errors").is_none());
+
+        // tenant ที่ไม่มีอยู่จริงเป็น error ไม่ใช่ null
+        let response = run(&state, "nobody", user, json!({ "id": 1 })).await;
+        assert_eq!(response["errors"][0]["extensions"]["status"], 404);
+    }
+
+    #[tokio::test]
+    async fn mutations_validate_and_change_users() {
+        let state = test_state().await;
+
+        let carol = json!({ "name": "Carol", "email": "carol@example.com", "age": 30 });
+        let response = run(&state, "acme", CREATE, carol).await;
+        assert_eq!(
+            response["data"]["createUser"],
+            json!({ "id": 1, "name": "Carol", "email": "carol@example.com", "age": 30 })
+        );
+
+        let invalid = json!({ "name": "Dave", "email": "not-an-email" });
+        let response = run(&state, "acme", CREATE, invalid).await;
+        assert_eq!(response["errors"][0]["extensions"]["status"], 422);
+        assert_eq!(state.list_users("acme").unwrap().len(), 1);
+
+        let update =
+            "mutation { updateUser(id: 1, name: \"Caroline\", age: 31) { name email age } }";
+        let response = run(&state, "acme", update, json!({})).await;
+        assert_eq!(
+            response["data"]["updateUser"],
+            json!({ "name": "Caroline", "email": "carol@example.com", "age": 31 })
+        );
+
+        let update = "mutation { updateUser(id: 1, age: 500) { age } }";
+        let response = run(&state, "acme", update, json!({})).await;
+        assert_eq!(response["errors"][0]["extensions"]["status"], 422);
+        assert_eq!(
+            state.get_user("acme", 1).unwrap().profile.age.map(Age::get),
+            Some(31)
+        );
+
+        let delete = "mutation { deleteUser(id: 1) }";
+        assert_eq!(
+            run(&state, "acme", delete, json!({})).await["data"]["deleteUser"],
+            true
+        );
+        let response = run(&state, "acme", delete, json!({})).await;
+        assert_eq!(response["errors"][0]["extensions"]["status"], 404);
+    }
+
+    #[tokio::test]
+    async fn subscription_only_sees_its_own_tenant() {
+        let state = test_state().await;
+        let request = Request::new("subscription { userChanged { kind user { name } } }")
+            .data(state.clone())
+            .data(RequestTenant("acme".to_string()));
+        let mut stream = state.schema.execute_stream(request);
+
+        // poll ครั้งแรกเพื่อให้ subscription เริ่มฟัง event
+        let pending = tokio::time::timeout(Duration::from_millis(50), stream.next()).await;
+        assert!(pending.is_err());
+
+        let erin = json!({ "name": "Erin", "email": "erin@example.com" });
+        run(&state, "default", CREATE, erin).await;
+        let carol = json!({ "name": "Carol", "email": "carol@example.com" });
+        run(&state, "acme", CREATE, carol).await;
+
+        let response = tokio::time::timeout(Duration::from_secs(5), stream.next())
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            serde_json::to_value(response).unwrap()["data"]["userChanged"],
+            json!({ "kind": "CREATED", "user": { "name": "Carol" } })
+        );
+    }
+}
@@ -0,0 +1,535 @@
+// ===========================================
+// Background Jobs - คิวงานเบื้องหลังเก็บใน SQLite
+// ===========================================
+//
+// - JobQueue: เก็บงานลงตาราง jobs (อยู่รอดแม้ server restart)
+// - run_worker: ดึงงานที่ถึงเวลามาทำ, retry แบบ exponential backoff
+// - schedule_every: สร้างงานซ้ำตามรอบเวลา (cron-like)
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::{SqlitePool, SqlitePoolOptions};
+use std::future::Future;
+use std::time::Duration;
+
+// จำนวนครั้งที่ลองใหม่ก่อนถือว่างานล้มเหลว
+const DEFAULT_MAX_ATTEMPTS: i64 = 5;
+
+// Backoff: 2s, 4s, 8s, ... สูงสุด 5 นาที
+const BACKOFF_BASE_MS: i64 = 2_000;
+const BACKOFF_MAX_MS: i64 = 5 * 60 * 1_000;
+
+// ถ้าไม่มีงานรอ worker จะหลับนานเท่านี้ก่อนเช็คใหม่
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+// ชนิดของงาน - เก็บเป็น JSON ใน column payload
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum Job {
+    SendWelcomeEmail {
+        tenant: String,
+        user_id: u32,
+        name: String,
+        email: String,
+    },
+    PurgeDeletedUsers,
+}
+
+impl Job {
+    // ชื่อสั้นๆ ใช้ใน column kind (สำหรับ filter และกันงานซ้ำ)
+    fn kind(&self) -> &'static str {
+        match self {
+            Job::SendWelcomeEmail { .. } => "send_welcome_email",
+            Job::PurgeDeletedUsers => "purge_deleted_users",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum JobStatus {
+    Pending,
+    Running,
+    Done,
+    Failed,
+}
+
+impl JobStatus {
+    fn as_str(self) -> &'static str {
+        match self {
+            JobStatus::Pending => "pending",
+            JobStatus::Running => "running",
+            JobStatus::Done => "done",
+            JobStatus::Failed => "failed",
+        }
+    }
+
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pending" => Some(JobStatus::Pending),
+            "running" => Some(JobStatus::Running),
+            "done" => Some(JobStatus::Done),
+            "failed" => Some(JobStatus::Failed),
+            _ => None,
+        }
+    }
+}
+
+// งานหนึ่งรายการ (สำหรับแสดงผลผ่าน /admin/jobs)
+#[derive(Debug, Clone, Serialize)]
+pub struct JobRecord {
+    pub id: i64,
+    pub job: Job,
+    pub status: JobStatus,
+    pub attempts: i64,
+    pub max_attempts: i64,
+    pub run_at: i64,
+    pub last_error: Option<String>,
+    pub created_at: i64,
+    pub updated_at: i64,
+}
+
+// แถวดิบจากตาราง jobs
+#[derive(sqlx::FromRow)]
+struct JobRow {
+    id: i64,
+    payload: String,
+    status: String,
+    attempts: i64,
+    max_attempts: i64,
+    run_at: i64,
+    last_error: Option<String>,
+    created_at: i64,
+    updated_at: i64,
+}
+
+impl TryFrom<JobRow> for JobRecord {
+    type Error = sqlx::Error;
+
+    fn try_from(row: JobRow) -> Result<Self, Self::Error> {
+        let job = serde_json::from_str(&row.payload).map_err(|e| sqlx::Error::Decode(e.into()))?;
+        let status = JobStatus::parse(&row.status).ok_or_else(|| {
+            sqlx::Error::Decode(format!("unknown job status '{}'", row.status).into())
+        })?;
+
+        Ok(JobRecord {
+            id: row.id,
+            job,
+            status,
+            attempts: row.attempts,
+            max_attempts: row.max_attempts,
+            run_at: row.run_at,
+            last_error: row.last_error,
+            created_at: row.created_at,
+            updated_at: row.updated_at,
+        })
+    }
+}
+
+#[derive(Clone)]
+pub struct JobQueue {
+    pool: SqlitePool,
+}
+
+impl JobQueue {
+    // เปิด (หรือสร้าง) database และเตรียมตาราง
+    pub async fn connect(url: &str) -> Result<Self, sqlx::Error> {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(5)
+            .connect(url)
+            .await?;
+
+        sqlx::query(
+            r#"
+            CREATE TABLE IF NOT EXISTS jobs (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                kind TEXT NOT NULL,
+                payload TEXT NOT NULL,
+                status TEXT NOT NULL,
+                attempts INTEGER NOT NULL DEFAULT 0,
+                max_attempts INTEGER NOT NULL,
+                run_at INTEGER NOT NULL,
+                last_error TEXT,
+                created_at INTEGER NOT NULL,
+                updated_at INTEGER NOT NULL
+            )
+            "#,
+        )
+        .execute(&pool)
+        .await?;
+
+        // งานที่ค้างสถานะ running แปลว่า server ดับกลางคัน - ให้ทำใหม่
+        sqlx::query("UPDATE jobs SET status = 'pending' WHERE status = 'running'")
+            .execute(&pool)
+            .await?;
+
+        Ok(Self { pool })
+    }
+
+    // เพิ่มงานให้ทำทันที
+    pub async fn enqueue(&self, job: &Job) -> Result<i64, sqlx::Error> {
+        self.enqueue_at(job, now_ms()).await
+    }
+
+    // เพิ่มงานให้ทำเมื่อถึงเวลา run_at (unix ms)
+    pub async fn enqueue_at(&self, job: &Job, run_at: i64) -> Result<i64, sqlx::Error> {
+        let payload = serde_json::to_string(job).map_err(|e| sqlx::Error::Encode(e.into()))?;
+        let now = now_ms();
+
+        let result = sqlx::query(
+            r#"
+            INSERT INTO jobs (kind, payload, status, max_attempts, run_at, created_at, updated_at)
+            VALUES (?, ?, 'pending', ?, ?, ?, ?)
+            "#,
+        )
+        .bind(job.kind())
+        .bind(payload)
+        .bind(DEFAULT_MAX_ATTEMPTS)
+        .bind(run_at)
+        .bind(now)
+        .bind(now)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(result.last_insert_rowid())
+    }
+
+    // มีงานชนิดเดียวกันรอทำอยู่แล้วหรือไม่ (กัน scheduler สร้างงานซ้ำ)
+    async fn has_pending(&self, job: &Job) -> Result<bool, sqlx::Error> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM jobs WHERE kind = ? AND status IN ('pending', 'running')",
+        )
+        .bind(job.kind())
+        .fetch_one(&self.pool)
+        .await?;
+
+        Ok(count > 0)
+    }
+
+    // หนึ่งรอบของ scheduler - คืน None ถ้าข้ามเพราะงานก่อนหน้ายังไม่เสร็จ
+    async fn enqueue_unless_pending(&self, job: &Job) -> Result<Option<i64>, sqlx::Error> {
+        if self.has_pending(job).await? {
+            return Ok(None);
+        }
+        self.enqueue(job).await.map(Some)
+    }
+
+    // จองงานถัดไปที่ถึงเวลาแล้ว (เปลี่ยนเป็น running ในคำสั่งเดียว)
+    async fn claim_next(&self) -> Result<Option<JobRecord>, sqlx::Error> {
+        let now = now_ms();
+        let row: Option<JobRow> = sqlx::query_as(
+            r#"
+            UPDATE jobs
+            SET status = 'running', attempts = attempts + 1, updated_at = ?
+            WHERE id = (
+                SELECT id FROM jobs
+                WHERE status = 'pending' AND run_at <= ?
+                ORDER BY run_at, id
+                LIMIT 1
+            )
+            RETURNING id, payload, status, attempts, max_attempts, run_at,
+                      last_error, created_at, updated_at
+            "#,
+        )
+        .bind(now)
+        .bind(now)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(JobRecord::try_from).transpose()
+    }
+
+    async fn complete(&self, id: i64) -> Result<(), sqlx::Error> {
+        sqlx::query(
+            "UPDATE jobs SET status = 'done', last_error = NULL, updated_at = ? WHERE id = ?",
+        )
+        .bind(now_ms())
+        .bind(id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    // งานล้มเหลว: ถ้ายังไม่ครบจำนวนครั้งให้รอ backoff แล้วลองใหม่
+    async fn fail(&self, record: &JobRecord, error: &str) -> Result<(), sqlx::Error> {
+        let now = now_ms();
+        let (status, run_at) = if record.attempts < record.max_attempts {
+            (JobStatus::Pending, now + backoff_ms(record.attempts))
+        } else {
+            (JobStatus::Failed, record.run_at)
+        };
+
+        sqlx::query(
+            "UPDATE jobs SET status = ?, run_at = ?, last_error = ?, updated_at = ? WHERE id = ?",
+        )
+        .bind(status.as_str())
+        .bind(run_at)
+        .bind(error)
+        .bind(now)
+        .bind(record.id)
+        .execute(&self.pool)
+        .await?;
+
+        Ok(())
+    }
+
+    pub async fn get(&self, id: i64) -> Result<Option<JobRecord>, sqlx::Error> {
+        let row: Option<JobRow> = sqlx::query_as(
+            r#"
+            SELECT id, payload, status, attempts, max_attempts, run_at,
+                   last_error, created_at, updated_at
+            FROM jobs WHERE id = ?
+            "#,
+        )
+        .bind(id)
+        .fetch_optional(&self.pool)
+        .await?;
+
+        row.map(JobRecord::try_from).transpose()
+    }
+
+    // รายการงานล่าสุด (กรองตามสถานะได้)
+    pub async fn list(
+        &self,
+        status: Option<JobStatus>,
+        limit: u32,
+    ) -> Result<Vec<JobRecord>, sqlx::Error> {
+        let rows: Vec<JobRow> = sqlx::query_as(
+            r#"
+            SELECT id, payload, status, attempts, max_attempts, run_at,
+                   last_error, created_at, updated_at
+            FROM jobs
+            WHERE ?1 IS NULL OR status = ?1
+            ORDER BY id DESC
+            LIMIT ?2
+            "#,
+        )
+        .bind(status.map(JobStatus::as_str))
+        .bind(limit)
+        .fetch_all(&self.pool)
+        .await?;
+
+        rows.into_iter().map(JobRecord::try_from).collect()
+    }
+}
+
+// Worker loop - รันตลอดอายุ server
+//
+// `perform` คือโค้ดที่ทำงานจริง คืน Err(ข้อความ) เพื่อให้ retry
+pub async fn run_worker<F, Fut>(queue: JobQueue, perform: F)
+where
+    F: Fn(Job) -> Fut,
+    Fut: Future<Output = Result<(), String>>,
+{
+    loop {
+        let record = match queue.claim_next().await {
+            Ok(Some(record)) => record,
+            Ok(None) => {
+                tokio::time::sleep(POLL_INTERVAL).await;
+                continue;
+            }
+            Err(e) => {
+                eprintln!("⚠️ job queue error: {}", e);
+                tokio::time::sleep(POLL_INTERVAL).await;
+                continue;
+            }
+        };
+
+        let result = match perform(record.job.clone()).await {
+            Ok(()) => queue.complete(record.id).await,
+            Err(error) => {
+                eprintln!(
+                    "⚠️ job {} ({}) failed on attempt {}: {}",
+                    record.id,
+                    record.job.kind(),
+                    record.attempts,
+                    error
+                );
+                queue.fail(&record, &error).await
+            }
+        };
+
+        if let Err(e) = result {
+            eprintln!("⚠️ could not update job {}: {}", record.id, e);
+        }
+    }
+}
+
+// Scheduler - สร้างงานซ้ำทุกๆ `every` (ข้ามรอบถ้างานก่อนหน้ายังไม่เสร็จ)
+pub async fn schedule_every(queue: JobQueue, every: Duration, job: Job) {
+    let mut interval = tokio::time::interval(every);
+
+    loop {
+        interval.tick().await;
+
+        if let Err(e) = queue.enqueue_unless_pending(&job).await {
+            eprintln!("⚠️ could not schedule {}: {}", job.kind(), e);
+        }
+    }
+}
+
+fn backoff_ms(attempts: i64) -> i64 {
+    let exponent = (attempts - 1).clamp(0, 20) as u32;
+    (BACKOFF_BASE_MS * 2_i64.pow(exponent)).min(BACKOFF_MAX_MS)
+}
+
+fn now_ms() -> i64 {
+    Utc::now().timestamp_millis()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn welcome() -> Job {
+        Job::SendWelcomeEmail {
+            tenant: "default".to_string(),
+            user_id: 1,
+            name: "Alice".to_string(),
+            email: "alice@example.com".to_string(),
+        }
+    }
+
+    async fn memory_queue() -> JobQueue {
+        JobQueue::connect("sqlite::memory:").await.unwrap()
+    }
+
+    // เลื่อน run_at มาเป็นอดีต แทนการรอ backoff จริง
+    async fn make_due(queue: &JobQueue, id: i64) {
+        sqlx::query("UPDATE jobs SET run_at = 0 WHERE id = ?")
+            .bind(id)
+            .execute(&queue.pool)
+            .await
+            .unwrap();
+    }
+
+    async fn status(queue: &JobQueue, id: i64) -> JobRecord {
+        queue.get(id).await.unwrap().unwrap()
+    }
+
+    #[test]
+    fn backoff_doubles_up_to_the_limit() {
+        let delays: Vec<i64> = (1..=5).map(backoff_ms).collect();
+        assert_eq!(delays, [2_000, 4_000, 8_000, 16_000, 32_000]);
+        assert_eq!(backoff_ms(20), BACKOFF_MAX_MS);
+    }
+
+    #[tokio::test]
+    async fn claim_runs_due_jobs_in_order() {
+        let queue = memory_queue().await;
+        let later = queue
+            .enqueue_at(&welcome(), now_ms() + 60_000)
+            .await
+            .unwrap();
+        let first = queue.enqueue(&welcome()).await.unwrap();
+        let second = queue.enqueue(&Job::PurgeDeletedUsers).await.unwrap();
+
+        let record = queue.claim_next().await.unwrap().unwrap();
+        assert_eq!(
+            (record.id, record.status, record.attempts),
+            (first, JobStatus::Running, 1)
+        );
+        assert_eq!(record.job, welcome());
+        assert_eq!(queue.claim_next().await.unwrap().unwrap().id, second);
+        // งานที่ยังไม่ถึงเวลาไม่ถูกจอง
+        assert!(queue.claim_next().await.unwrap().is_none());
+
+        queue.complete(first).await.unwrap();
+        assert_eq!(status(&queue, first).await.status, JobStatus::Done);
+        assert_eq!(status(&queue, later).await.status, JobStatus::Pending);
+    }
+
+    #[tokio::test]
+    async fn failed_job_is_retried_after_backoff() {
+        let queue = memory_queue().await;
+        let id = queue.enqueue(&welcome()).await.unwrap();
+
+        for (attempt, delay) in [(1, 2_000), (2, 4_000)] {
+            let record = queue.claim_next().await.unwrap().unwrap();
+            assert_eq!(record.attempts, attempt);
+
+            let before = now_ms();
+            queue.fail(&record, "smtp down").await.unwrap();
+            let after = now_ms();
+
+            let record = status(&queue, id).await;
+            assert_eq!(record.status, JobStatus::Pending);
+            assert_eq!(record.last_error.as_deref(), Some("smtp down"));
+            assert!((before + delay..=after + delay).contains(&record.run_at));
+            // ยังไม่ครบเวลา backoff
+            assert!(queue.claim_next().await.unwrap().is_none());
+            make_due(&queue, id).await;
+        }
+
+        let record = queue.claim_next().await.unwrap().unwrap();
+        queue.complete(record.id).await.unwrap();
+        let record = status(&queue, id).await;
+        assert_eq!((record.status, record.attempts), (JobStatus::Done, 3));
+        assert_eq!(record.last_error, None);
+    }
+
+    #[tokio::test]
+    async fn job_fails_after_max_attempts() {
+        let queue = memory_queue().await;
+        let id = queue.enqueue(&welcome()).await.unwrap();
+
+        for _ in 0..DEFAULT_MAX_ATTEMPTS {
+            make_due(&queue, id).await;
+            let record = queue.claim_next().await.unwrap().unwrap();
+            queue.fail(&record, "smtp down").await.unwrap();
+        }
+
+        let record = status(&queue, id).await;
+        assert_eq!(record.status, JobStatus::Failed);
+        assert_eq!(record.attempts, DEFAULT_MAX_ATTEMPTS);
+        make_due(&queue, id).await;
+        assert!(queue.claim_next().await.unwrap().is_none());
+
+        let failed = queue.list(Some(JobStatus::Failed), 10).await.unwrap();
+        assert_eq!(failed.len(), 1);
+    }
+
+    #[tokio::test]
+    async fn scheduler_skips_while_previous_run_is_pending() {
+        let queue = memory_queue().await;
+        let job = Job::PurgeDeletedUsers;
+
+        let id = queue.enqueue_unless_pending(&job).await.unwrap().unwrap();
+        assert_eq!(queue.enqueue_unless_pending(&job).await.unwrap(), None);
+
+        // กำลังทำอยู่ก็ยังข้าม
+        queue.claim_next().await.unwrap().unwrap();
+        assert_eq!(queue.enqueue_unless_pending(&job).await.unwrap(), None);
+
+        // งานชนิดอื่นไม่เกี่ยว
+        assert!(
+            queue
+                .enqueue_unless_pending(&welcome())
+                .await
+                .unwrap()
+                .is_some()
+        );
+
+        queue.complete(id).await.unwrap();
+        assert!(queue.enqueue_unless_pending(&job).await.unwrap().is_some());
+        assert_eq!(queue.list(None, 10).await.unwrap().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn running_jobs_are_requeued_on_connect() {
+        let dir = tempfile::tempdir().unwrap();
+        let url = format!("sqlite:{}?mode=rwc", dir.path().join("jobs.db").display());
+
+        let queue = JobQueue::connect(&url).await.unwrap();
+        let id = queue.enqueue(&welcome()).await.unwrap();
+        queue.claim_next().await.unwrap().unwrap();
+        // server ดับระหว่างทำงาน
+        queue.pool.close().await;
+
+        let queue = JobQueue::connect(&url).await.unwrap();
+        let record = status(&queue, id).await;
+        assert_eq!((record.status, record.attempts), (JobStatus::Pending, 1));
+        assert_eq!(queue.claim_next().await.unwrap().unwrap().id, id);
+    }
+}
@@ -0,0 +1,54 @@
+// ===========================================
+// Mailbox - กล่องจดหมายจำลองสำหรับพัฒนา
+// ===========================================
+//
+// แทนที่จะส่ง email จริง ให้เขียนแต่ละฉบับเป็นไฟล์ใน directory
+// เปิดอ่านได้ที่ mailbox/<เวลา>-<ผู้รับ>.eml
+
+use chrono::Utc;
+use std::io;
+use std::path::PathBuf;
+
+#[derive(Clone)]
+pub struct Mailbox {
+    dir: PathBuf,
+}
+
+impl Mailbox {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub async fn send(&self, to: &str, subject: &str, body: &str) -> io::Result<PathBuf> {
+        tokio::fs::create_dir_all(&self.dir).await?;
+
+        // ใช้เฉพาะตัวอักษรที่ปลอดภัยในชื่อไฟล์
+        let safe_to: String = to
+            .chars()
+            .map(|c| {
+                if c.is_ascii_alphanumeric() || c == '.' || c == '@' {
+                    c
+                } else {
+                    '_'
+                }
+            })
+            .collect();
+        let now = Utc::now();
+        let path = self.dir.join(format!(
+            "{}-{}.eml",
+            now.format("%Y%m%dT%H%M%S%.3f"),
+            safe_to
+        ));
+
+        let message = format!(
+            "Date: {}\nTo: {}\nSubject: {}\n\n{}\n",
+            now.to_rfc2822(),
+            to,
+            subject,
+            body
+        );
+        tokio::fs::write(&path, message).await?;
+
+        Ok(path)
+    }
+}
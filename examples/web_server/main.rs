@@ -0,0 +1,905 @@
+// ===========================================
+// Web Server - บทที่ 19
+// รัน: cargo run --example web_server
+// เปิด: http://localhost:3000
+// ===========================================
+
+mod graphql;
+mod jobs;
+mod mailbox;
+mod todos;
+
+use axum::{
+    Router, async_trait,
+    extract::{FromRequestParts, Json, Path, Query, State},
+    http::{StatusCode, header, request::Parts},
+    response::IntoResponse,
+    routing::{delete, get, post},
+};
+use chrono::{DateTime, Duration as ChronoDuration, Utc};
+use jobs::{Job, JobQueue, JobRecord, JobStatus};
+use mailbox::Mailbox;
+use rust_tutorial::todo::SyncStore;
+// ข้อมูลที่ user กรอก (ตรวจแล้ว) - User ข้างล่างคือ record ที่เก็บใน tenant
+use rust_tutorial::users::{Age, Email, User as Profile, UserError, UserName};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+// Header ที่ใช้เลือก tenant (ถ้าไม่ได้ใช้ path prefix /tenants/:tenant)
+const TENANT_HEADER: &str = "x-tenant";
+const DEFAULT_TENANT: &str = "default";
+
+// /admin/* ต้องส่ง "Authorization: Bearer <token>" - token มาจาก env นี้
+// (ถ้าไม่ได้ตั้ง จะสุ่มใหม่ทุกครั้งที่เปิด server แล้วพิมพ์ให้ดู)
+const ADMIN_TOKEN_ENV: &str = "ADMIN_TOKEN";
+
+// Background jobs
+const DEFAULT_JOBS_DATABASE_URL: &str = "sqlite:jobs.db?mode=rwc";
+const MAILBOX_DIR: &str = "mailbox";
+const PURGE_EVERY: Duration = Duration::from_secs(60);
+
+// จำนวน event ที่ subscriber ช้าๆ ตามหลังได้ก่อนจะหลุด
+const EVENT_CAPACITY: usize = 64;
+
+// Error ที่ handler คืนให้ client
+type ApiError = (StatusCode, String);
+
+// user ที่ถูกลบ (soft delete) นานกว่านี้จะถูกลบถาวร
+fn purge_after() -> ChronoDuration {
+    ChronoDuration::minutes(10)
+}
+
+// Shared state - แยก users ตาม tenant
+struct AppState {
+    tenants: Mutex<HashMap<String, Tenant>>,
+    jobs: JobQueue,
+    events: broadcast::Sender<UserEvent>,
+    schema: graphql::UserSchema,
+    admin_token: String,
+}
+
+// ข้อมูลของแต่ละ tenant - users และ ID sequence แยกกัน
+struct Tenant {
+    users: Vec<User>,
+    next_id: u32,
+    // todo list ของแต่ละ user (key = user id)
+    todos: HashMap<u32, SyncStore>,
+}
+
+impl Tenant {
+    fn with_users(users: Vec<User>) -> Self {
+        let next_id = users.iter().map(|u| u.id).max().unwrap_or(0) + 1;
+        Self {
+            users,
+            next_id,
+            todos: HashMap::new(),
+        }
+    }
+
+    // users ที่ยังไม่ถูกลบ
+    fn active_users(&self) -> impl Iterator<Item = &User> {
+        self.users.iter().filter(|u| u.deleted_at.is_none())
+    }
+}
+
+impl AppState {
+    fn new(tenants: HashMap<String, Tenant>, jobs: JobQueue, admin_token: String) -> Self {
+        let (events, _) = broadcast::channel(EVENT_CAPACITY);
+        Self {
+            tenants: Mutex::new(tenants),
+            jobs,
+            events,
+            schema: graphql::build_schema(),
+            admin_token,
+        }
+    }
+
+    // การจัดการ users ใช้ร่วมกันระหว่าง REST และ GraphQL
+
+    fn list_users(&self, tenant: &str) -> Result<Vec<User>, ApiError> {
+        let tenants = self.tenants.lock().unwrap();
+        let tenant = find_tenant(&tenants, tenant)?;
+
+        Ok(tenant.active_users().cloned().collect())
+    }
+
+    fn get_user(&self, tenant: &str, id: u32) -> Result<User, ApiError> {
+        let tenants = self.tenants.lock().unwrap();
+        let tenant = find_tenant(&tenants, tenant)?;
+
+        tenant
+            .active_users()
+            .find(|u| u.id == id)
+            .cloned()
+            .ok_or((StatusCode::NOT_FOUND, format!("User {} not found", id)))
+    }
+
+    async fn create_user(&self, tenant_name: &str, profile: Profile) -> Result<User, ApiError> {
+        let user = {
+            let mut tenants = self.tenants.lock().unwrap();
+            let tenant = find_tenant_mut(&mut tenants, tenant_name)?;
+
+            // ID ไม่ซ้ำแม้จะลบ user ไปแล้ว
+            let id = tenant.next_id;
+            tenant.next_id += 1;
+            let user = User {
+                id,
+                profile,
+                deleted_at: None,
+            };
+
+            tenant.users.push(user.clone());
+            user
+        };
+        self.notify(tenant_name, UserChangeKind::Created, &user);
+
+        // ส่ง welcome email เป็นงานเบื้องหลัง (ไม่ให้ request ต้องรอ)
+        let job = Job::SendWelcomeEmail {
+            tenant: tenant_name.to_string(),
+            user_id: user.id,
+            name: user.profile.name.to_string(),
+            email: user.profile.email.to_string(),
+        };
+        if let Err(e) = self.jobs.enqueue(&job).await {
+            eprintln!("⚠️ could not enqueue welcome email: {}", e);
+        }
+
+        Ok(user)
+    }
+
+    fn update_user(&self, tenant: &str, id: u32, input: UpdateUser) -> Result<User, ApiError> {
+        let user = {
+            let mut tenants = self.tenants.lock().unwrap();
+            let user = find_tenant_mut(&mut tenants, tenant)?
+                .users
+                .iter_mut()
+                .find(|u| u.id == id && u.deleted_at.is_none())
+                .ok_or((StatusCode::NOT_FOUND, format!("User {} not found", id)))?;
+
+            if let Some(name) = input.name {
+                user.profile.name = name;
+            }
+            if let Some(email) = input.email {
+                user.profile.email = email;
+            }
+            if let Some(age) = input.age {
+                user.profile.age = Some(age);
+            }
+            user.clone()
+        };
+        self.notify(tenant, UserChangeKind::Updated, &user);
+
+        Ok(user)
+    }
+
+    fn delete_user(&self, tenant: &str, id: u32) -> Result<(), ApiError> {
+        let user = {
+            let mut tenants = self.tenants.lock().unwrap();
+            let user = find_tenant_mut(&mut tenants, tenant)?
+                .users
+                .iter_mut()
+                .find(|u| u.id == id && u.deleted_at.is_none())
+                .ok_or((StatusCode::NOT_FOUND, format!("User {} not found", id)))?;
+
+            // Soft delete - งาน PurgeDeletedUsers จะลบถาวรภายหลัง
+            user.deleted_at = Some(Utc::now());
+            user.clone()
+        };
+        self.notify(tenant, UserChangeKind::Deleted, &user);
+
+        Ok(())
+    }
+
+    // แจ้ง subscribers (ถ้าไม่มีใครฟังอยู่ send จะ error ซึ่งไม่เป็นไร)
+    fn notify(&self, tenant: &str, kind: UserChangeKind, user: &User) {
+        let _ = self.events.send(UserEvent {
+            tenant: tenant.to_string(),
+            kind,
+            user: user.clone(),
+        });
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize, async_graphql::SimpleObject)]
+#[graphql(complex)]
+struct User {
+    id: u32,
+    // name, email, age อยู่ระดับเดียวกับ id ใน JSON (ฟิลด์ GraphQL อยู่ใน graphql.rs)
+    #[serde(flatten)]
+    #[graphql(skip)]
+    profile: Profile,
+    // เวลาที่ถูกลบ (soft delete) - ไม่ส่งออกไปใน JSON
+    #[serde(skip)]
+    #[graphql(skip)]
+    deleted_at: Option<DateTime<Utc>>,
+}
+
+// Event เมื่อ user เปลี่ยนแปลง (ใช้กับ GraphQL subscription)
+#[derive(Clone)]
+struct UserEvent {
+    tenant: String,
+    kind: UserChangeKind,
+    user: User,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, async_graphql::Enum)]
+enum UserChangeKind {
+    Created,
+    Updated,
+    Deleted,
+}
+
+// ฟิลด์ที่ไม่ได้ส่งมาจะไม่ถูกแก้ไข
+#[derive(Default)]
+struct UpdateUser {
+    name: Option<UserName>,
+    email: Option<Email>,
+    age: Option<Age>,
+}
+
+#[derive(Deserialize)]
+struct CreateTenant {
+    name: String,
+}
+
+#[derive(Deserialize)]
+struct UserPath {
+    id: u32,
+}
+
+#[derive(Deserialize)]
+struct JobsQuery {
+    status: Option<JobStatus>,
+    limit: Option<u32>,
+}
+
+#[derive(Deserialize)]
+struct QueryParams {
+    page: Option<u32>,
+    limit: Option<u32>,
+}
+
+#[tokio::main]
+async fn main() {
+    println!("🦀 Web Server Demo\n");
+
+    // Initialize state with sample data
+    let default_tenant = Tenant::with_users(vec![
+        sample_user(1, "Alice", "alice@example.com"),
+        sample_user(2, "Bob", "bob@example.com"),
+    ]);
+    // Job queue (SQLite) - ตั้งค่าได้ผ่าน JOBS_DATABASE_URL
+    let jobs_url = std::env::var("JOBS_DATABASE_URL")
+        .unwrap_or_else(|_| DEFAULT_JOBS_DATABASE_URL.to_string());
+    let queue = JobQueue::connect(&jobs_url)
+        .await
+        .expect("Failed to open job queue");
+
+    let admin_token = std::env::var(ADMIN_TOKEN_ENV)
+        .ok()
+        .filter(|token| !token.is_empty())
+        .unwrap_or_else(|| {
+            let token = random_token();
+            println!(
+                "🔑 Admin token (set {} to choose one): {}",
+                ADMIN_TOKEN_ENV, token
+            );
+            token
+        });
+
+    let state = Arc::new(AppState::new(
+        HashMap::from([(DEFAULT_TENANT.to_string(), default_tenant)]),
+        queue.clone(),
+        admin_token,
+    ));
+
+    // Background worker และงานตามรอบเวลา
+    let worker_state = state.clone();
+    let mailbox = Mailbox::new(MAILBOX_DIR);
+    tokio::spawn(jobs::run_worker(queue.clone(), move |job| {
+        perform_job(worker_state.clone(), mailbox.clone(), job)
+    }));
+    tokio::spawn(jobs::schedule_every(
+        queue,
+        PURGE_EVERY,
+        Job::PurgeDeletedUsers,
+    ));
+
+    let app = router(state);
+
+    println!("🚀 Server running on http://localhost:3000");
+    println!();
+    println!("Try these endpoints:");
+    println!("  GET  /              - Welcome message");
+    println!("  GET  /hello         - Hello World");
+    println!("  GET  /hello/:name   - Personalized greeting");
+    println!("  GET  /users         - List all users");
+    println!("  POST /users         - Create user (JSON body)");
+    println!("  GET  /users/:id     - Get user by ID");
+    println!("  DELETE /users/:id   - Delete user");
+    println!("  GET  /users/:id/todos        - List user's todos");
+    println!("  POST /users/:id/todos        - Create todo (JSON body)");
+    println!("  PUT  /users/:id/todos/:todo_id - Update todo");
+    println!("  POST /users/:id/todos/sync   - Two-way sync (since + changes)");
+    println!("  GET  /search?page=1&limit=10 - Query params");
+    println!();
+    println!("GraphQL:");
+    println!("  GET  /graphql        - Query page (offline, not GraphiQL)");
+    println!("  POST /graphql        - Queries & mutations");
+    println!("  POST /graphql/stream - Subscriptions (Server-Sent Events)");
+    println!("  GET  /graphql/schema - Schema (SDL)");
+    println!();
+    println!(
+        "Tenants (header X-Tenant or prefix /tenants/:tenant - demo only, not access control):"
+    );
+    println!("  GET  /tenants/:tenant/users - List users of a tenant");
+    println!("  GET  /admin/tenants         - List tenants");
+    println!("  POST /admin/tenants         - Create tenant (JSON body)");
+    println!("  DELETE /admin/tenants/:tenant - Delete tenant");
+    println!("  (/admin/* requires header Authorization: Bearer <admin token>)");
+    println!();
+    println!("Background jobs:");
+    println!("  GET  /admin/jobs?status=failed - List jobs");
+    println!("  GET  /admin/jobs/:id           - Job status");
+    println!();
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:3000")
+        .await
+        .unwrap();
+
+    axum::serve(listener, app).await.unwrap();
+}
+
+// Routes ทั้งหมด (แยกจาก main เพื่อให้ tests เรียกได้โดยไม่ต้องเปิด port)
+fn router(state: Arc<AppState>) -> Router {
+    // User routes - ใช้ได้ทั้งแบบ header และแบบ path prefix
+    let user_routes = Router::new()
+        .route("/users", get(list_users).post(create_user))
+        .route("/users/:id", get(get_user).delete(delete_user))
+        .route(
+            "/users/:id/todos",
+            get(todos::list_todos).post(todos::create_todo),
+        )
+        .route(
+            "/users/:id/todos/:todo_id",
+            get(todos::get_todo)
+                .put(todos::update_todo)
+                .delete(todos::delete_todo),
+        )
+        .route("/users/:id/todos/sync", post(todos::sync_todos))
+        .route("/graphql", get(graphql::playground).post(graphql::execute))
+        .route("/graphql/stream", post(graphql::subscribe))
+        .route("/graphql/schema", get(graphql::sdl));
+
+    Router::new()
+        // Basic routes
+        .route("/", get(root))
+        .route("/hello", get(hello))
+        .route("/hello/:name", get(hello_name))
+        // User routes (tenant จาก header X-Tenant)
+        .merge(user_routes.clone())
+        // User routes (tenant จาก path prefix)
+        .nest("/tenants/:tenant", user_routes)
+        // Admin routes (ต้องมี admin token)
+        .route("/admin/tenants", get(list_tenants).post(create_tenant))
+        .route("/admin/tenants/:tenant", delete(delete_tenant))
+        .route("/admin/jobs", get(list_jobs))
+        .route("/admin/jobs/:id", get(get_job))
+        // Query params example
+        .route("/search", get(search))
+        // State
+        .with_state(state)
+}
+
+// Handlers
+
+async fn root() -> &'static str {
+    "🦀 Welcome to Rust Web Server!"
+}
+
+async fn hello() -> &'static str {
+    "Hello, World!"
+}
+
+async fn hello_name(Path(name): Path<String>) -> String {
+    format!("Hello, {}!", name)
+}
+
+async fn list_users(
+    TenantId(tenant): TenantId,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<User>>, ApiError> {
+    state.list_users(&tenant).map(Json)
+}
+
+async fn get_user(
+    TenantId(tenant): TenantId,
+    Path(UserPath { id }): Path<UserPath>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<User>, ApiError> {
+    state.get_user(&tenant, id).map(Json)
+}
+
+// body ที่ผิด (เช่น email ไม่มี @) ถูกปฏิเสธตั้งแต่ตอน deserialize (422)
+async fn create_user(
+    TenantId(tenant): TenantId,
+    State(state): State<Arc<AppState>>,
+    Json(profile): Json<Profile>,
+) -> Result<impl IntoResponse, ApiError> {
+    let user = state.create_user(&tenant, profile).await?;
+
+    Ok((StatusCode::CREATED, Json(user)))
+}
+
+async fn delete_user(
+    TenantId(tenant): TenantId,
+    Path(UserPath { id }): Path<UserPath>,
+    State(state): State<Arc<AppState>>,
+) -> Result<StatusCode, ApiError> {
+    state.delete_user(&tenant, id)?;
+
+    Ok(StatusCode::NO_CONTENT)
+}
+
+// Admin handlers
+
+async fn list_tenants(_: Admin, State(state): State<Arc<AppState>>) -> Json<Vec<String>> {
+    let tenants = state.tenants.lock().unwrap();
+    let mut names: Vec<String> = tenants.keys().cloned().collect();
+    names.sort();
+    Json(names)
+}
+
+async fn create_tenant(
+    _: Admin,
+    State(state): State<Arc<AppState>>,
+    Json(input): Json<CreateTenant>,
+) -> Result<StatusCode, ApiError> {
+    if !is_valid_tenant_name(&input.name) {
+        return Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("Invalid tenant name '{}'", input.name),
+        ));
+    }
+
+    let mut tenants = state.tenants.lock().unwrap();
+    if tenants.contains_key(&input.name) {
+        return Err((
+            StatusCode::CONFLICT,
+            format!("Tenant {} already exists", input.name),
+        ));
+    }
+
+    tenants.insert(input.name, Tenant::with_users(Vec::new()));
+    Ok(StatusCode::CREATED)
+}
+
+async fn delete_tenant(
+    _: Admin,
+    Path(name): Path<String>,
+    State(state): State<Arc<AppState>>,
+) -> Result<StatusCode, ApiError> {
+    let mut tenants = state.tenants.lock().unwrap();
+
+    match tenants.remove(&name) {
+        Some(_) => Ok(StatusCode::NO_CONTENT),
+        None => Err((StatusCode::NOT_FOUND, format!("Tenant {} not found", name))),
+    }
+}
+
+async fn list_jobs(
+    _: Admin,
+    Query(params): Query<JobsQuery>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<JobRecord>>, ApiError> {
+    let limit = params.limit.unwrap_or(50).min(500);
+
+    state
+        .jobs
+        .list(params.status, limit)
+        .await
+        .map(Json)
+        .map_err(internal_error)
+}
+
+async fn get_job(
+    _: Admin,
+    Path(id): Path<i64>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<JobRecord>, ApiError> {
+    state
+        .jobs
+        .get(id)
+        .await
+        .map_err(internal_error)?
+        .map(Json)
+        .ok_or((StatusCode::NOT_FOUND, format!("Job {} not found", id)))
+}
+
+async fn search(Query(params): Query<QueryParams>) -> String {
+    let page = params.page.unwrap_or(1);
+    let limit = params.limit.unwrap_or(10);
+    format!("Searching page {} with limit {}", page, limit)
+}
+
+// Admin extractor - ใส่เป็น argument ของ handler ที่ต้องเป็น admin
+//
+// ต้องส่ง "Authorization: Bearer <token>" ที่ตรงกับ ADMIN_TOKEN ไม่อย่างนั้นได้ 401
+struct Admin;
+
+#[async_trait]
+impl FromRequestParts<Arc<AppState>> for Admin {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(
+        parts: &mut Parts,
+        state: &Arc<AppState>,
+    ) -> Result<Self, Self::Rejection> {
+        let token = parts
+            .headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "));
+
+        match token {
+            Some(token) if same_token(token, &state.admin_token) => Ok(Admin),
+            _ => Err((
+                StatusCode::UNAUTHORIZED,
+                "Admin token required (Authorization: Bearer <token>)".to_string(),
+            )),
+        }
+    }
+}
+
+// เทียบทุกตัวอักษรเสมอ เวลาที่ใช้จะได้ไม่บอกว่าตรงกันกี่ตัวแรก
+fn same_token(given: &str, expected: &str) -> bool {
+    given.len() == expected.len()
+        && given
+            .bytes()
+            .zip(expected.bytes())
+            .fold(0, |diff, (a, b)| diff | (a ^ b))
+            == 0
+}
+
+fn random_token() -> String {
+    use rand::Rng;
+    use rand::distributions::Alphanumeric;
+
+    rand::thread_rng()
+        .sample_iter(&Alphanumeric)
+        .take(32)
+        .map(char::from)
+        .collect()
+}
+
+// Tenant extractor
+//
+// อ่าน tenant จาก path prefix (/tenants/:tenant/...) ก่อน
+// ถ้าไม่มีจึงอ่านจาก header X-Tenant และถ้าไม่มีทั้งคู่ใช้ "default"
+//
+// ⚠️ สำหรับ demo เท่านั้น: client เลือก tenant เองได้ จึงอ่าน/แก้ข้อมูลของ tenant ไหนก็ได้
+// ระบบจริงต้องผูก tenant กับตัวตนของผู้เรียก (เช่น tenant ใน token ที่ server ออกให้)
+// แล้วปฏิเสธ request ที่ขอ tenant อื่น
+struct TenantId(String);
+
+#[async_trait]
+impl<S: Send + Sync> FromRequestParts<S> for TenantId {
+    type Rejection = ApiError;
+
+    async fn from_request_parts(parts: &mut Parts, state: &S) -> Result<Self, Self::Rejection> {
+        if let Ok(Path(params)) =
+            Path::<HashMap<String, String>>::from_request_parts(parts, state).await
+            && let Some(tenant) = params.get("tenant")
+        {
+            return Ok(TenantId(tenant.clone()));
+        }
+
+        match parts.headers.get(TENANT_HEADER) {
+            Some(value) => value
+                .to_str()
+                .map(|s| TenantId(s.to_string()))
+                .map_err(|_| {
+                    (
+                        StatusCode::BAD_REQUEST,
+                        "Invalid X-Tenant header".to_string(),
+                    )
+                }),
+            None => Ok(TenantId(DEFAULT_TENANT.to_string())),
+        }
+    }
+}
+
+fn find_tenant<'a>(
+    tenants: &'a HashMap<String, Tenant>,
+    name: &str,
+) -> Result<&'a Tenant, ApiError> {
+    tenants
+        .get(name)
+        .ok_or((StatusCode::NOT_FOUND, format!("Tenant {} not found", name)))
+}
+
+fn find_tenant_mut<'a>(
+    tenants: &'a mut HashMap<String, Tenant>,
+    name: &str,
+) -> Result<&'a mut Tenant, ApiError> {
+    tenants
+        .get_mut(name)
+        .ok_or((StatusCode::NOT_FOUND, format!("Tenant {} not found", name)))
+}
+
+// ชื่อ tenant ใช้ได้เฉพาะ a-z, 0-9, '-' และ '_'
+fn is_valid_tenant_name(name: &str) -> bool {
+    !name.is_empty()
+        && name
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_')
+}
+
+// ข้อมูลไม่ผ่านการตรวจ = 422 เหมือน JSON ที่ผิดใน REST (axum ปฏิเสธด้วย 422)
+fn invalid_input(e: UserError) -> ApiError {
+    (StatusCode::UNPROCESSABLE_ENTITY, e.to_string())
+}
+
+fn internal_error(e: sqlx::Error) -> ApiError {
+    (StatusCode::INTERNAL_SERVER_ERROR, e.to_string())
+}
+
+fn sample_user(id: u32, name: &str, email: &str) -> User {
+    let name = UserName::new(name).expect("valid sample name");
+    let email = Email::new(email).expect("valid sample email");
+    User {
+        id,
+        profile: Profile::new(name, email),
+        deleted_at: None,
+    }
+}
+
+// Job handlers
+
+async fn perform_job(state: Arc<AppState>, mailbox: Mailbox, job: Job) -> Result<(), String> {
+    match job {
+        Job::SendWelcomeEmail {
+            tenant,
+            user_id,
+            name,
+            email,
+        } => {
+            let body = format!(
+                "Hello {}!\n\nYour account #{} in tenant '{}' is ready.",
+                name, user_id, tenant
+            );
+            let path = mailbox
+                .send(&email, "Welcome to Rust Web Server 🦀", &body)
+                .await
+                .map_err(|e| e.to_string())?;
+            println!("📧 Welcome email for {} → {}", email, path.display());
+            Ok(())
+        }
+        Job::PurgeDeletedUsers => {
+            let cutoff = Utc::now() - purge_after();
+            let mut tenants = state.tenants.lock().unwrap();
+            let mut purged = 0;
+
+            for tenant in tenants.values_mut() {
+                let len = tenant.users.len();
+                tenant
+                    .users
+                    .retain(|u| u.deleted_at.is_none_or(|at| at > cutoff));
+                purged += len - tenant.users.len();
+
+                // todos ของ user ที่ถูกลบถาวรไม่มีใครเข้าถึงได้แล้ว
+                let Tenant { users, todos, .. } = tenant;
+                todos.retain(|user_id, _| users.iter().any(|u| u.id == *user_id));
+            }
+
+            if purged > 0 {
+                println!("🧹 Purged {} deleted users", purged);
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use axum::body::{Body, to_bytes};
+    use axum::http::Request;
+    use serde_json::{Value, json};
+    use tower::ServiceExt;
+
+    const TOKEN: &str = "test-admin-token";
+
+    // tenant "default" มี Alice (1) และ Bob (2), tenant "acme" ยังว่าง
+    pub(crate) async fn test_state() -> Arc<AppState> {
+        let jobs = JobQueue::connect("sqlite::memory:").await.unwrap();
+        let tenants = HashMap::from([
+            (
+                DEFAULT_TENANT.to_string(),
+                Tenant::with_users(vec![
+                    sample_user(1, "Alice", "alice@example.com"),
+                    sample_user(2, "Bob", "bob@example.com"),
+                ]),
+            ),
+            ("acme".to_string(), Tenant::with_users(Vec::new())),
+        ]);
+        Arc::new(AppState::new(tenants, jobs, TOKEN.to_string()))
+    }
+
+    async fn send(app: &Router, request: Request<Body>) -> (StatusCode, Value) {
+        let response = app.clone().oneshot(request).await.unwrap();
+        let status = response.status();
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let body = serde_json::from_slice(&body).unwrap_or(Value::Null);
+        (status, body)
+    }
+
+    fn get(uri: &str) -> Request<Body> {
+        Request::get(uri).body(Body::empty()).unwrap()
+    }
+
+    fn post_json(uri: &str, body: Value) -> Request<Body> {
+        Request::post(uri)
+            .header(header::CONTENT_TYPE, "application/json")
+            .body(Body::from(body.to_string()))
+            .unwrap()
+    }
+
+    fn new_user(name: &str) -> Value {
+        json!({ "name": name, "email": format!("{}@example.com", name.to_lowercase()) })
+    }
+
+    fn names(users: &Value) -> Vec<&str> {
+        users
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|u| u["name"].as_str().unwrap())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn tenants_do_not_see_each_others_users() {
+        let app = router(test_state().await);
+
+        let (status, carol) = send(&app, post_json("/tenants/acme/users", new_user("Carol"))).await;
+        assert_eq!(status, StatusCode::CREATED);
+
+        let (_, users) = send(&app, get("/users")).await;
+        assert_eq!(names(&users), ["Alice", "Bob"]);
+        let (_, users) = send(&app, get("/tenants/acme/users")).await;
+        assert_eq!(names(&users), ["Carol"]);
+
+        // header X-Tenant ให้ผลเดียวกับ path prefix
+        let request = Request::get("/users")
+            .header(TENANT_HEADER, "acme")
+            .body(Body::empty())
+            .unwrap();
+        let (_, users) = send(&app, request).await;
+        assert_eq!(names(&users), ["Carol"]);
+
+        // id ของ Carol ใน tenant อื่นคือ Alice ไม่ใช่ Carol
+        let id = carol["id"].as_u64().unwrap();
+        let (_, user) = send(&app, get(&format!("/users/{}", id))).await;
+        assert_eq!(user["name"], "Alice");
+        let (status, _) = send(&app, get("/tenants/acme/users/2")).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+
+        let (status, _) = send(&app, get("/tenants/nobody/users")).await;
+        assert_eq!(status, StatusCode::NOT_FOUND);
+    }
+
+    #[tokio::test]
+    async fn user_ids_are_numbered_per_tenant() {
+        let app = router(test_state().await);
+
+        let mut ids = Vec::new();
+        for (uri, name) in [
+            ("/tenants/acme/users", "Carol"),
+            ("/tenants/acme/users", "Dave"),
+            ("/users", "Erin"),
+        ] {
+            let (_, user) = send(&app, post_json(uri, new_user(name))).await;
+            ids.push(user["id"].as_u64().unwrap());
+        }
+        assert_eq!(ids, [1, 2, 3]);
+
+        // ลบแล้วก็ไม่ใช้ id ซ้ำ
+        let request = Request::delete("/tenants/acme/users/2")
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(send(&app, request).await.0, StatusCode::NO_CONTENT);
+        let (_, user) = send(&app, post_json("/tenants/acme/users", new_user("Frank"))).await;
+        assert_eq!(user["id"], 3);
+    }
+
+    #[tokio::test]
+    async fn invalid_input_is_422() {
+        let app = router(test_state().await);
+
+        let bad_email = json!({ "name": "Carol", "email": "not-an-email" });
+        let (status, _) = send(&app, post_json("/users", bad_email)).await;
+        assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+
+        let request = Request::post("/admin/tenants")
+            .header(header::CONTENT_TYPE, "application/json")
+            .header(header::AUTHORIZATION, format!("Bearer {}", TOKEN))
+            .body(Body::from(json!({ "name": "Bad Name" }).to_string()))
+            .unwrap();
+        assert_eq!(
+            send(&app, request).await.0,
+            StatusCode::UNPROCESSABLE_ENTITY
+        );
+    }
+
+    #[tokio::test]
+    async fn purge_removes_only_users_deleted_long_ago() {
+        let state = test_state().await;
+        {
+            let mut tenants = state.tenants.lock().unwrap();
+            let tenant = tenants.get_mut(DEFAULT_TENANT).unwrap();
+            tenant
+                .users
+                .push(sample_user(3, "Carol", "carol@example.com"));
+            tenant.users[0].deleted_at =
+                Some(Utc::now() - purge_after() - ChronoDuration::minutes(1));
+            tenant.users[1].deleted_at = Some(Utc::now() - ChronoDuration::minutes(1));
+            tenant.todos.insert(1, SyncStore::new());
+            tenant.todos.insert(3, SyncStore::new());
+        }
+
+        let mailbox = Mailbox::new(tempfile::tempdir().unwrap().path());
+        perform_job(state.clone(), mailbox, Job::PurgeDeletedUsers)
+            .await
+            .unwrap();
+
+        let tenants = state.tenants.lock().unwrap();
+        let tenant = &tenants[DEFAULT_TENANT];
+        // Bob เพิ่งถูกลบ ยังกู้คืนได้จนกว่าจะครบ purge_after()
+        let ids: Vec<u32> = tenant.users.iter().map(|u| u.id).collect();
+        assert_eq!(ids, [2, 3]);
+        let mut with_todos: Vec<u32> = tenant.todos.keys().copied().collect();
+        with_todos.sort();
+        assert_eq!(with_todos, [3]);
+    }
+
+    #[tokio::test]
+    async fn admin_routes_require_the_token() {
+        let app = router(test_state().await);
+        let with_auth = |auth: &str| {
+            Request::get("/admin/tenants")
+                .header(header::AUTHORIZATION, auth)
+                .body(Body::empty())
+                .unwrap()
+        };
+
+        assert_eq!(
+            send(&app, get("/admin/tenants")).await.0,
+            StatusCode::UNAUTHORIZED
+        );
+        for auth in ["Bearer wrong-token", "Bearer test-admin-toke", TOKEN] {
+            assert_eq!(
+                send(&app, with_auth(auth)).await.0,
+                StatusCode::UNAUTHORIZED
+            );
+        }
+
+        let (status, tenants) = send(&app, with_auth(&format!("Bearer {}", TOKEN))).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(tenants, json!(["acme", "default"]));
+
+        // request ที่ถูกปฏิเสธต้องไม่มีผล
+        let (status, _) = send(&app, post_json("/admin/tenants", json!({ "name": "evil" }))).await;
+        assert_eq!(status, StatusCode::UNAUTHORIZED);
+        let request = Request::delete("/admin/tenants/acme")
+            .header(header::AUTHORIZATION, "Bearer wrong-token")
+            .body(Body::empty())
+            .unwrap();
+        assert_eq!(send(&app, request).await.0, StatusCode::UNAUTHORIZED);
+        let (_, tenants) = send(&app, with_auth(&format!("Bearer {}", TOKEN))).await;
+        assert_eq!(tenants, json!(["acme", "default"]));
+    }
+}
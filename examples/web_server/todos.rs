@@ -0,0 +1,144 @@
+// ===========================================
+// Todos - /users/:id/todos พร้อม sync หลายเครื่อง
+// ===========================================
+//
+// แต่ละ user มี todo list ของตัวเอง (rust_tutorial::todo::SyncStore)
+// - REST:  GET/POST /users/:id/todos, GET/PUT/DELETE /users/:id/todos/:todo_id
+// - Sync:  POST /users/:id/todos/sync { since, changes } → รายการที่ merge แล้ว
+
+use crate::{ApiError, AppState, TenantId, find_tenant_mut};
+use axum::{
+    extract::{Json, Path, State},
+    http::StatusCode,
+    response::IntoResponse,
+};
+use chrono::Utc;
+use rust_tutorial::todo::{SyncRequest, SyncResponse, SyncStore, SyncedTodo, TodoFields};
+use serde::Deserialize;
+use std::sync::Arc;
+
+#[derive(Deserialize)]
+pub struct TodoListPath {
+    id: u32,
+}
+
+#[derive(Deserialize)]
+pub struct TodoPath {
+    id: u32,
+    todo_id: u32,
+}
+
+// เรียก `f` กับ todo list ของ user (สร้างให้ถ้ายังไม่มี)
+fn with_todos<T>(
+    state: &AppState,
+    tenant: &str,
+    user_id: u32,
+    f: impl FnOnce(&mut SyncStore) -> Result<T, ApiError>,
+) -> Result<T, ApiError> {
+    let mut tenants = state.tenants.lock().unwrap();
+    let tenant = find_tenant_mut(&mut tenants, tenant)?;
+
+    if !tenant.active_users().any(|u| u.id == user_id) {
+        return Err((StatusCode::NOT_FOUND, format!("User {} not found", user_id)));
+    }
+
+    f(tenant.todos.entry(user_id).or_default())
+}
+
+fn todo_not_found(id: u32) -> ApiError {
+    (StatusCode::NOT_FOUND, format!("Todo {} not found", id))
+}
+
+fn validate(fields: &TodoFields) -> Result<(), ApiError> {
+    if fields.title.trim().is_empty() {
+        return Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            "Todo title must not be empty".to_string(),
+        ));
+    }
+    Ok(())
+}
+
+pub async fn list_todos(
+    TenantId(tenant): TenantId,
+    Path(TodoListPath { id }): Path<TodoListPath>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<Vec<SyncedTodo>>, ApiError> {
+    with_todos(&state, &tenant, id, |todos| {
+        Ok(Json(todos.todos().cloned().collect()))
+    })
+}
+
+pub async fn create_todo(
+    TenantId(tenant): TenantId,
+    Path(TodoListPath { id }): Path<TodoListPath>,
+    State(state): State<Arc<AppState>>,
+    Json(fields): Json<TodoFields>,
+) -> Result<impl IntoResponse, ApiError> {
+    validate(&fields)?;
+
+    with_todos(&state, &tenant, id, |todos| {
+        let todo = todos.create(fields, Utc::now()).clone();
+        Ok((StatusCode::CREATED, Json(todo)))
+    })
+}
+
+pub async fn get_todo(
+    TenantId(tenant): TenantId,
+    Path(TodoPath { id, todo_id }): Path<TodoPath>,
+    State(state): State<Arc<AppState>>,
+) -> Result<Json<SyncedTodo>, ApiError> {
+    with_todos(&state, &tenant, id, |todos| {
+        todos
+            .get(todo_id)
+            .cloned()
+            .map(Json)
+            .ok_or_else(|| todo_not_found(todo_id))
+    })
+}
+
+pub async fn update_todo(
+    TenantId(tenant): TenantId,
+    Path(TodoPath { id, todo_id }): Path<TodoPath>,
+    State(state): State<Arc<AppState>>,
+    Json(fields): Json<TodoFields>,
+) -> Result<Json<SyncedTodo>, ApiError> {
+    validate(&fields)?;
+
+    with_todos(&state, &tenant, id, |todos| {
+        todos
+            .update(todo_id, fields, Utc::now())
+            .cloned()
+            .map(Json)
+            .ok_or_else(|| todo_not_found(todo_id))
+    })
+}
+
+pub async fn delete_todo(
+    TenantId(tenant): TenantId,
+    Path(TodoPath { id, todo_id }): Path<TodoPath>,
+    State(state): State<Arc<AppState>>,
+) -> Result<StatusCode, ApiError> {
+    with_todos(&state, &tenant, id, |todos| {
+        if todos.delete(todo_id, Utc::now()) {
+            Ok(StatusCode::NO_CONTENT)
+        } else {
+            Err(todo_not_found(todo_id))
+        }
+    })
+}
+
+pub async fn sync_todos(
+    TenantId(tenant): TenantId,
+    Path(TodoListPath { id }): Path<TodoListPath>,
+    State(state): State<Arc<AppState>>,
+    Json(request): Json<SyncRequest>,
+) -> Result<Json<SyncResponse>, ApiError> {
+    for fields in request.changes.iter().filter_map(|c| c.fields.as_ref()) {
+        validate(fields)?;
+    }
+
+    with_todos(&state, &tenant, id, |todos| {
+        Ok(Json(todos.sync(request, Utc::now())))
+    })
+}
@@ -0,0 +1,256 @@
+// ===========================================
+// cli.rs - Command parsing และการรายงานผลแบบฝึกหัด
+// ===========================================
+
+use rust_tutorial::exercises::{
+    self, Chapter, ChapterReport, Checker, ExerciseError, Outcome, SnippetResult,
+};
+use std::io::{self, Write};
+use std::path::PathBuf;
+use thiserror::Error;
+
+pub const USAGE: &str = "\
+Usage: exercises <command> [args]
+
+Commands:
+  list                    List chapters with their exercises and snippets
+  check [chapter...]      Compile every rust snippet (all chapters by default):
+                          compile_fail must fail, solutions must compile and run
+      --include-ignored     Also check snippets marked ignore
+      -v, --verbose         Show compiler/program output of failed snippets
+  help                    Show this help
+
+Environment:
+  EXERCISES_DIR           Exercise markdown files (default: docs/src/appendix/exercises)
+  EXERCISES_WORK_DIR      Scratch cargo project (default: target/exercise-check)";
+
+// จำนวนบรรทัดของ output ที่แสดงเมื่อไม่ใช้ --verbose
+const OUTPUT_PREVIEW_LINES: usize = 6;
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    List,
+    Check(CheckOptions),
+    Help,
+}
+
+#[derive(Debug, Default, PartialEq)]
+pub struct CheckOptions {
+    // ว่าง = ทุกบท
+    pub chapters: Vec<u32>,
+    pub include_ignored: bool,
+    pub verbose: bool,
+}
+
+#[derive(Error, Debug)]
+pub enum AppError {
+    #[error("No exercises for chapter {0}")]
+    UnknownChapter(u32),
+
+    #[error("{0} snippet(s) failed")]
+    Failed(usize),
+
+    #[error(transparent)]
+    Exercise(#[from] ExerciseError),
+
+    #[error("Terminal error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("{0}")]
+    Usage(String),
+}
+
+pub struct Context {
+    pub exercises_dir: PathBuf,
+    pub work_dir: PathBuf,
+    // project ที่ให้ dependency กับ snippet (Cargo.toml + Cargo.lock)
+    pub project_dir: PathBuf,
+}
+
+impl Command {
+    // args ไม่รวมชื่อโปรแกรม (args[0])
+    pub fn from_args(args: &[String]) -> Result<Self, AppError> {
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        match args.as_slice() {
+            [] | ["help" | "-h" | "--help"] => Ok(Command::Help),
+            ["list" | "ls"] => Ok(Command::List),
+            ["check", rest @ ..] => parse_check(rest),
+            [other, ..] => Err(usage(format!("unknown command '{}'", other))),
+        }
+    }
+}
+
+fn usage(message: impl Into<String>) -> AppError {
+    AppError::Usage(message.into())
+}
+
+fn parse_check(args: &[&str]) -> Result<Command, AppError> {
+    let mut options = CheckOptions::default();
+    for &arg in args {
+        match arg {
+            "--include-ignored" => options.include_ignored = true,
+            "-v" | "--verbose" => options.verbose = true,
+            _ => {
+                let chapter = arg
+                    .parse()
+                    .map_err(|_| usage(format!("check: invalid chapter '{}'", arg)))?;
+                options.chapters.push(chapter);
+            }
+        }
+    }
+    Ok(Command::Check(options))
+}
+
+pub fn run(command: Command, context: &Context, out: &mut impl Write) -> Result<(), AppError> {
+    match command {
+        Command::List => {
+            for chapter in exercises::load_book(&context.exercises_dir)? {
+                writeln!(
+                    out,
+                    "{:>2}. {:<28} {} exercises  {} snippets",
+                    chapter.chapter,
+                    chapter.title,
+                    chapter.exercises.len(),
+                    chapter.snippet_count()
+                )?;
+            }
+        }
+        Command::Check(options) => check(options, context, out)?,
+        Command::Help => writeln!(out, "{}", USAGE)?,
+    }
+    Ok(())
+}
+
+fn check(options: CheckOptions, context: &Context, out: &mut impl Write) -> Result<(), AppError> {
+    let book = exercises::load_book(&context.exercises_dir)?;
+    let chapters = select_chapters(book, &options.chapters)?;
+
+    let mut checker = Checker::new(&context.work_dir).with_project(&context.project_dir)?;
+    checker.include_ignored = options.include_ignored;
+
+    let (mut passed, mut failed, mut ignored) = (0, 0, 0);
+    for chapter in &chapters {
+        writeln!(out, "📘 บทที่ {} - {}", chapter.chapter, chapter.title)?;
+        out.flush()?;
+
+        let report = checker.check_chapter(chapter)?;
+        write_report(out, &report, options.verbose)?;
+        passed += report.passed();
+        failed += report.failed();
+        ignored += report.ignored();
+    }
+
+    writeln!(
+        out,
+        "\n🏁 {} chapters: {} passed, {} failed, {} ignored",
+        chapters.len(),
+        passed,
+        failed,
+        ignored
+    )?;
+    if failed > 0 {
+        return Err(AppError::Failed(failed));
+    }
+    Ok(())
+}
+
+// เลือกบทตามที่ขอ (เรียงตามที่พิมพ์มา)
+fn select_chapters(book: Vec<Chapter>, wanted: &[u32]) -> Result<Vec<Chapter>, AppError> {
+    if wanted.is_empty() {
+        return Ok(book);
+    }
+    wanted
+        .iter()
+        .map(|&number| {
+            book.iter()
+                .find(|chapter| chapter.chapter == number)
+                .cloned()
+                .ok_or(AppError::UnknownChapter(number))
+        })
+        .collect()
+}
+
+fn write_report(out: &mut impl Write, report: &ChapterReport, verbose: bool) -> io::Result<()> {
+    let file = report
+        .path
+        .file_name()
+        .unwrap_or(report.path.as_os_str())
+        .to_string_lossy();
+
+    for result in &report.results {
+        write_result(out, report.chapter, &file, result, verbose)?;
+    }
+    writeln!(
+        out,
+        "   {} passed, {} failed, {} ignored",
+        report.passed(),
+        report.failed(),
+        report.ignored()
+    )
+}
+
+fn write_result(
+    out: &mut impl Write,
+    chapter: u32,
+    file: &str,
+    result: &SnippetResult,
+    verbose: bool,
+) -> io::Result<()> {
+    let Some(expectation) = result.expectation else {
+        return Ok(());
+    };
+    let name = format!(
+        "{}.{} {:<8}",
+        chapter,
+        result.exercise,
+        result.place.as_str()
+    );
+
+    match &result.outcome {
+        Outcome::Passed => writeln!(out, "  ✅ {} {}", name, expectation.describe()),
+        Outcome::Failed { reason, output } => {
+            writeln!(
+                out,
+                "  ❌ {} should {}: {} ({}:{})",
+                name,
+                should(expectation.describe()),
+                reason,
+                file,
+                result.line
+            )?;
+            let lines: Vec<&str> = output.lines().collect();
+            let shown = if verbose {
+                lines.len()
+            } else {
+                lines.len().min(OUTPUT_PREVIEW_LINES)
+            };
+            for line in &lines[..shown] {
+                writeln!(out, "     │ {}", line)?;
+            }
+            if shown < lines.len() {
+                writeln!(
+                    out,
+                    "     │ ... ({} more lines, use -v)",
+                    lines.len() - shown
+                )?;
+            }
+            Ok(())
+        }
+        Outcome::Ignored => Ok(()),
+    }
+}
+
+// "fails to compile" → "fail to compile" (ต่อท้าย "should")
+fn should(description: &str) -> String {
+    description
+        .split(' ')
+        .map(|word| match word {
+            "fails" => "fail",
+            "compiles" => "compile",
+            "runs" => "run",
+            "panics" => "panic",
+            other => other,
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
@@ -0,0 +1,50 @@
+// ===========================================
+// Exercises CLI - ตรวจว่าโจทย์และเฉลยในแบบฝึกหัดยัง compile ได้ตามที่เขียนไว้
+// รัน: cargo run --bin exercises -- check [chapter...]
+// ===========================================
+
+mod cli;
+
+use cli::{AppError, Command, Context};
+use std::env;
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+// Exit codes
+const EXIT_ERROR: u8 = 1; // เช่น snippet ไม่ผ่าน, อ่านไฟล์ไม่ได้
+const EXIT_USAGE: u8 = 2; // ใช้คำสั่งผิด
+
+const PROJECT_DIR: &str = env!("CARGO_MANIFEST_DIR");
+const DEFAULT_EXERCISES_DIR: &str =
+    concat!(env!("CARGO_MANIFEST_DIR"), "/docs/src/appendix/exercises");
+const DEFAULT_WORK_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/target/exercise-check");
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = Command::from_args(&args).and_then(|command| {
+        let context = Context {
+            exercises_dir: env_path("EXERCISES_DIR", DEFAULT_EXERCISES_DIR),
+            work_dir: env_path("EXERCISES_WORK_DIR", DEFAULT_WORK_DIR),
+            project_dir: PathBuf::from(PROJECT_DIR),
+        };
+        cli::run(command, &context, &mut io::stdout())
+    });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(AppError::Usage(message)) => {
+            eprintln!("Error: {}\n\n{}", message, cli::USAGE);
+            ExitCode::from(EXIT_USAGE)
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
+
+fn env_path(name: &str, default: &str) -> PathBuf {
+    env::var_os(name).unwrap_or_else(|| default.into()).into()
+}
@@ -0,0 +1,215 @@
+// ===========================================
+// cli.rs - Command parsing ของ preprocessor และคำสั่งตรวจหนังสือ
+// ===========================================
+
+use rust_tutorial::preprocessor::{self, Found, PreprocessError, Preprocessor};
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+pub const USAGE: &str = "\
+Usage: mdbook-examples [command]
+
+mdBook preprocessor that replaces {{#example name:region}} and {{#example_output name}}
+with code and output from examples/*.rs (configured in docs/book.toml)
+
+Commands:
+  (none)                  Preprocess the book: read [context, book] JSON from stdin
+  supports <renderer>     Tell mdbook that every renderer is supported
+  check                   Verify every directive in the book without mdbook
+      --output              Also build and run the examples to capture output
+  list                    List examples and their // ANCHOR: regions
+  help                    Show this help
+
+Environment:
+  BOOK_SRC_DIR            Book markdown files (default: docs/src)
+  EXAMPLES_DIR            Example programs (default: examples)";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Preprocess,
+    Supports(String),
+    Check { output: bool },
+    List,
+    Help,
+}
+
+#[derive(Error, Debug)]
+pub enum AppError {
+    #[error("{0} directive error(s) in the book")]
+    Failed(usize),
+
+    #[error(transparent)]
+    Preprocess(#[from] PreprocessError),
+
+    #[error("Failed to read {path}: {source}")]
+    Read {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("Terminal error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("{0}")]
+    Usage(String),
+}
+
+pub struct Context {
+    pub book_src_dir: PathBuf,
+    pub examples_dir: PathBuf,
+    // project ที่มี Cargo.toml (ใช้ build example ตอนเก็บ output)
+    pub project_dir: PathBuf,
+}
+
+impl Command {
+    // args ไม่รวมชื่อโปรแกรม (args[0])
+    pub fn from_args(args: &[String]) -> Result<Self, AppError> {
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        match args.as_slice() {
+            [] => Ok(Command::Preprocess),
+            ["help" | "-h" | "--help"] => Ok(Command::Help),
+            ["supports", renderer] => Ok(Command::Supports(renderer.to_string())),
+            ["check"] => Ok(Command::Check { output: false }),
+            ["check", "--output"] => Ok(Command::Check { output: true }),
+            ["check", other, ..] => Err(usage(format!("unknown option '{}'", other))),
+            ["list" | "ls"] => Ok(Command::List),
+            [other, ..] => Err(usage(format!("unknown command '{}'", other))),
+        }
+    }
+}
+
+fn usage(message: impl Into<String>) -> AppError {
+    AppError::Usage(message.into())
+}
+
+pub fn run(
+    command: Command,
+    context: &Context,
+    input: &mut impl Read,
+    out: &mut impl Write,
+) -> Result<(), AppError> {
+    match command {
+        Command::Preprocess => {
+            let mut json = String::new();
+            input.read_to_string(&mut json)?;
+            out.write_all(preprocessor::preprocess(&json)?.as_bytes())?;
+            Ok(())
+        }
+        // output เป็น markdown ธรรมดา ใช้ได้กับทุก renderer
+        Command::Supports(_) => Ok(()),
+        Command::Check { output } => check(context, output, out),
+        Command::List => list(context, out),
+        Command::Help => Ok(writeln!(out, "{}", USAGE)?),
+    }
+}
+
+fn check(context: &Context, output: bool, out: &mut impl Write) -> Result<(), AppError> {
+    let mut preprocessor = Preprocessor::new(&context.examples_dir, &context.project_dir);
+    preprocessor.capture_output = output;
+
+    let mut files = 0;
+    let mut directives = 0;
+    let mut errors = 0;
+    for path in markdown_files(&context.book_src_dir)? {
+        let content = read(&path)?;
+        let chapter = path
+            .strip_prefix(&context.book_src_dir)
+            .unwrap_or(&path)
+            .display()
+            .to_string();
+        let count = match preprocessor::find(&content) {
+            Ok(found) => found
+                .iter()
+                .filter(|item| matches!(item, Found::Directive(_)))
+                .count(),
+            // process() ด้านล่างจะรายงาน error นี้พร้อมเลขบรรทัด
+            Err(_) => 1,
+        };
+        if count == 0 {
+            continue;
+        }
+
+        files += 1;
+        directives += count;
+        match preprocessor.process(&chapter, &content) {
+            Ok(_) => writeln!(out, "✅ {} ({} directive(s))", chapter, count)?,
+            Err(e) => {
+                errors += 1;
+                writeln!(out, "❌ {}", e)?;
+            }
+        }
+    }
+
+    writeln!(
+        out,
+        "\n🏁 {} directive(s) in {} file(s), {} error(s)",
+        directives, files, errors
+    )?;
+    if errors > 0 {
+        return Err(AppError::Failed(errors));
+    }
+    Ok(())
+}
+
+fn list(context: &Context, out: &mut impl Write) -> Result<(), AppError> {
+    let mut examples = Vec::new();
+    for entry in read_dir(&context.examples_dir)? {
+        let (name, file) = if entry.is_dir() {
+            (entry.file_name(), entry.join("main.rs"))
+        } else if entry.extension().is_some_and(|e| e == "rs") {
+            (entry.file_stem(), entry.clone())
+        } else {
+            continue;
+        };
+        if let Some(name) = name.and_then(|n| n.to_str())
+            && file.is_file()
+        {
+            examples.push((name.to_string(), file));
+        }
+    }
+
+    for (name, file) in examples {
+        let regions = preprocessor::names(&read(&file)?);
+        if regions.is_empty() {
+            writeln!(out, "📘 {}", name)?;
+        } else {
+            writeln!(out, "📘 {}: {}", name, regions.join(", "))?;
+        }
+    }
+    Ok(())
+}
+
+fn read(path: &Path) -> Result<String, AppError> {
+    fs::read_to_string(path).map_err(|source| AppError::Read {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+// ไฟล์และโฟลเดอร์ย่อยเรียงตามชื่อ
+fn read_dir(dir: &Path) -> Result<Vec<PathBuf>, AppError> {
+    let entries = fs::read_dir(dir).map_err(|source| AppError::Read {
+        path: dir.to_path_buf(),
+        source,
+    })?;
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .collect();
+    paths.sort();
+    Ok(paths)
+}
+
+fn markdown_files(dir: &Path) -> Result<Vec<PathBuf>, AppError> {
+    let mut files = Vec::new();
+    for path in read_dir(dir)? {
+        if path.is_dir() {
+            files.extend(markdown_files(&path)?);
+        } else if path.extension().is_some_and(|e| e == "md") {
+            files.push(path);
+        }
+    }
+    Ok(files)
+}
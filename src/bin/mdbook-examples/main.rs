@@ -0,0 +1,50 @@
+// ===========================================
+// mdbook-examples - ดึงโค้ดและ output จริงจาก examples/*.rs เข้าหนังสือ
+// mdbook เรียกเองตอน build (ดู [preprocessor.examples] ใน docs/book.toml)
+// ตรวจหนังสือ: cargo run --bin mdbook-examples -- check
+// ===========================================
+
+mod cli;
+
+use cli::{AppError, Command, Context};
+use std::env;
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+// Exit codes
+const EXIT_ERROR: u8 = 1; // เช่น หา region ไม่เจอ (mdbook build จะล้มเหลว)
+const EXIT_USAGE: u8 = 2; // ใช้คำสั่งผิด
+
+const PROJECT_DIR: &str = env!("CARGO_MANIFEST_DIR");
+const DEFAULT_BOOK_SRC_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/docs/src");
+const DEFAULT_EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = Command::from_args(&args).and_then(|command| {
+        let context = Context {
+            book_src_dir: env_path("BOOK_SRC_DIR", DEFAULT_BOOK_SRC_DIR),
+            examples_dir: env_path("EXAMPLES_DIR", DEFAULT_EXAMPLES_DIR),
+            project_dir: PathBuf::from(PROJECT_DIR),
+        };
+        cli::run(command, &context, &mut io::stdin(), &mut io::stdout())
+    });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(AppError::Usage(message)) => {
+            eprintln!("Error: {}\n\n{}", message, cli::USAGE);
+            ExitCode::from(EXIT_USAGE)
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
+
+fn env_path(name: &str, default: &str) -> PathBuf {
+    env::var_os(name).unwrap_or_else(|| default.into()).into()
+}
@@ -0,0 +1,231 @@
+// ===========================================
+// cli.rs - Command parsing, server และ client แบบพิมพ์คำสั่ง
+// ===========================================
+
+use rust_tutorial::protocol::{
+    Connection, DecodeError, FrameError, Message, Server, from_line, to_line,
+};
+use std::io::{self, Write};
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+
+pub const USAGE: &str = "\
+Usage: messages <command> [options]
+
+Commands:
+  serve                   Start a server that keeps each client's position, color and chat log
+    -p, --port <port>       Port to listen on (default: 4040, 0 = any free port)
+        --host <addr>       Address to listen on (default: 127.0.0.1, classroom: 0.0.0.0)
+  connect [addr]          Connect to a server (default: 127.0.0.1:4040)
+  help                    Show this help
+
+Client input (one message per line):
+  MOVE 10 20              Move to (10, 20)
+  COLOR 255 0 0           Change color
+  WRITE hello             Chat (any other text is sent as WRITE too)
+  /who                    Who is online
+  QUIT                    Leave (also on Ctrl+D)";
+
+pub const DEFAULT_ADDR: &str = "127.0.0.1:4040";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Serve { host: String, port: u16 },
+    Connect(String),
+    Help,
+}
+
+#[derive(Error, Debug)]
+pub enum AppError {
+    #[error("Cannot listen on {addr}: {source}")]
+    Listen {
+        addr: String,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("Cannot connect to {addr}: {source} (is `messages serve` running?)")]
+    Connect {
+        addr: String,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("Connection error: {0}")]
+    Frame(#[from] FrameError),
+
+    #[error("Terminal error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("{0}")]
+    Usage(String),
+}
+
+impl Command {
+    // args ไม่รวมชื่อโปรแกรม (args[0])
+    pub fn from_args(args: &[String]) -> Result<Self, AppError> {
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        match args.as_slice() {
+            [] | ["help" | "-h" | "--help"] => Ok(Command::Help),
+            ["serve", options @ ..] => serve_options(options),
+            ["connect"] => Ok(Command::Connect(DEFAULT_ADDR.to_string())),
+            ["connect", addr] => Ok(Command::Connect(addr.to_string())),
+            ["connect", _, extra, ..] => Err(usage(format!("unexpected argument '{}'", extra))),
+            [other, ..] => Err(usage(format!("unknown command '{}'", other))),
+        }
+    }
+}
+
+fn serve_options(options: &[&str]) -> Result<Command, AppError> {
+    let mut host = "127.0.0.1".to_string();
+    let mut port = 4040;
+    let mut options = options.iter();
+    while let Some(option) = options.next() {
+        let mut value = || {
+            options
+                .next()
+                .ok_or_else(|| usage(format!("{} needs a value", option)))
+        };
+        match *option {
+            "-p" | "--port" => {
+                let value = value()?;
+                port = value
+                    .parse()
+                    .map_err(|_| usage(format!("invalid port '{}'", value)))?;
+            }
+            "--host" => host = value()?.to_string(),
+            other => return Err(usage(format!("unknown option '{}'", other))),
+        }
+    }
+    Ok(Command::Serve { host, port })
+}
+
+fn usage(message: impl Into<String>) -> AppError {
+    AppError::Usage(message.into())
+}
+
+pub async fn run(
+    command: Command,
+    input: impl AsyncBufRead + Unpin,
+    out: &mut impl Write,
+) -> Result<(), AppError> {
+    match command {
+        Command::Serve { host, port } => serve(&host, port, out).await,
+        Command::Connect(addr) => connect(&addr, input, out).await,
+        Command::Help => Ok(writeln!(out, "{}", USAGE)?),
+    }
+}
+
+async fn serve(host: &str, port: u16, out: &mut impl Write) -> Result<(), AppError> {
+    let addr = format!("{}:{}", host, port);
+    let listener = TcpListener::bind(&addr)
+        .await
+        .map_err(|source| AppError::Listen {
+            addr: addr.clone(),
+            source,
+        })?;
+    writeln!(out, "📡 Listening on {}", listener.local_addr()?)?;
+    writeln!(
+        out,
+        "   connect with: cargo run --bin messages -- connect {}",
+        listener.local_addr()?
+    )?;
+    out.flush()?;
+
+    let server = Arc::new(Server::new());
+    let mut updates = server.subscribe();
+    let mut running = tokio::spawn(server.clone().run(listener));
+    // พิมพ์ทุก update เป็น log ของ server
+    loop {
+        tokio::select! {
+            result = &mut running => {
+                return match result {
+                    Ok(result) => Ok(result?),
+                    Err(e) => Err(AppError::Io(io::Error::other(e))),
+                };
+            }
+            update = updates.recv() => match update {
+                Ok(text) => writeln!(out, "📝 {}", text)?,
+                Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                    writeln!(out, "📝 ({} update(s) not logged)", skipped)?
+                }
+                Err(broadcast::error::RecvError::Closed) => return Ok(()),
+            },
+        }
+        out.flush()?;
+    }
+}
+
+async fn connect(
+    addr: &str,
+    input: impl AsyncBufRead + Unpin,
+    out: &mut impl Write,
+) -> Result<(), AppError> {
+    let mut connection = Connection::connect(addr)
+        .await
+        .map_err(|source| AppError::Connect {
+            addr: addr.to_string(),
+            source,
+        })?;
+    writeln!(out, "🔌 Connected to {} (type help for commands)", addr)?;
+    out.flush()?;
+
+    let mut lines = input.lines();
+    // หลังส่ง QUIT (หรือ Ctrl+D) ไม่อ่าน input อีก รอ server ปิด connection
+    let mut reading = true;
+    loop {
+        tokio::select! {
+            line = lines.next_line(), if reading => {
+                let Some(line) = line? else {
+                    reading = false;
+                    connection.send(&Message::Quit).await?;
+                    continue;
+                };
+                match parse_input(&line) {
+                    Ok(None) => {}
+                    Ok(Some(message)) => {
+                        reading = message != Message::Quit;
+                        connection.send(&message).await?;
+                    }
+                    Err(Input::Help) => writeln!(out, "{}", USAGE)?,
+                    Err(Input::Invalid(e)) => writeln!(out, "❌ {}", e)?,
+                }
+            }
+            received = connection.recv() => match received {
+                Ok(Some(Message::Write(text))) => writeln!(out, "{}", text)?,
+                Ok(Some(other)) => writeln!(out, "← {}", to_line(&other))?,
+                Ok(None) => {
+                    writeln!(out, "👋 Disconnected")?;
+                    return Ok(());
+                }
+                Err(FrameError::Decode(e)) => writeln!(out, "❌ Invalid message from server: {}", e)?,
+                Err(e) => return Err(e.into()),
+            },
+        }
+        out.flush()?;
+    }
+}
+
+enum Input {
+    Help,
+    Invalid(DecodeError),
+}
+
+// บรรทัดว่าง = ไม่ส่ง, คำสั่งที่ไม่รู้จัก = ข้อความ chat
+fn parse_input(line: &str) -> Result<Option<Message>, Input> {
+    let line = line.trim_end();
+    if line.trim().is_empty() {
+        return Ok(None);
+    }
+    if line.trim() == "help" {
+        return Err(Input::Help);
+    }
+    match from_line(line) {
+        Ok(message) => Ok(Some(message)),
+        Err(DecodeError::UnknownCommand(_)) => Ok(Some(Message::Write(line.to_string()))),
+        Err(e) => Err(Input::Invalid(e)),
+    }
+}
@@ -0,0 +1,42 @@
+// ===========================================
+// Messages - server / client ที่ส่ง Message (บทที่ 6) ผ่าน TCP
+// รัน: cargo run --bin messages -- serve
+//      cargo run --bin messages -- connect     (เปิดหลาย terminal ได้)
+// ===========================================
+
+mod cli;
+
+use cli::{AppError, Command};
+use std::env;
+use std::io;
+use std::process::ExitCode;
+use tokio::io::BufReader;
+
+// Exit codes
+const EXIT_ERROR: u8 = 1; // เช่น เปิด port ไม่ได้, ต่อ server ไม่ได้
+const EXIT_USAGE: u8 = 2; // ใช้คำสั่งผิด
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match Command::from_args(&args) {
+        Ok(command) => {
+            let input = BufReader::new(tokio::io::stdin());
+            cli::run(command, input, &mut io::stdout()).await
+        }
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(AppError::Usage(message)) => {
+            eprintln!("Error: {}\n\n{}", message, cli::USAGE);
+            ExitCode::from(EXIT_USAGE)
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
@@ -0,0 +1,237 @@
+// ===========================================
+// cli.rs - Command parsing และการเปิด playground server
+// ===========================================
+
+use rust_tutorial::playground::{self, Limits, Playground, PlaygroundError, Sandbox};
+use std::io::{self, Write};
+use std::net::SocketAddr;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::Duration;
+use thiserror::Error;
+
+pub const USAGE: &str = "\
+Usage: playground [options]
+
+Serve an offline replacement for play.rust-lang.org, used by the book's Run buttons
+(when the book is opened from this server, Run uses it automatically)
+
+Options:
+  -p, --port <port>       Port to listen on (default: 3030)
+      --host <addr>       Address to listen on (default: 127.0.0.1)
+      --book <dir>        Serve the built book from <dir> (default: docs/book if built)
+      --no-book           Only serve the playground API
+      --timeout <secs>    Run time limit per snippet (default: 5)
+      --memory <MiB>      Memory limit per snippet (default: 256)
+      --jobs <n>          Snippets compiled at the same time (default: CPU count)
+      --allow-network     Do not isolate snippets from the network
+      --allow-filesystem  Do not isolate snippets from the filesystem
+      --allow-origin <url>
+                          Also accept Run requests from pages served at <url>
+                          (e.g. http://localhost:3000 for mdbook serve)
+  -h, --help              Show this help
+
+Environment:
+  PLAYGROUND_WORK_DIR     Scratch directory for snippets (default: system temp dir)";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Serve(Options),
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Options {
+    pub host: String,
+    pub port: u16,
+    // None = ใช้ค่าเริ่มต้น (docs/book ถ้ามี)
+    pub book: Option<PathBuf>,
+    pub no_book: bool,
+    pub timeout: u64,
+    pub memory: u64,
+    pub jobs: Option<usize>,
+    pub allow_network: bool,
+    pub allow_filesystem: bool,
+    // origin อื่นนอกจากตัว server เอง
+    pub allowed_origins: Vec<String>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        let limits = Limits::default();
+        Self {
+            host: "127.0.0.1".to_string(),
+            port: 3030,
+            book: None,
+            no_book: false,
+            timeout: limits.timeout.as_secs(),
+            memory: limits.memory / (1024 * 1024),
+            jobs: None,
+            allow_network: false,
+            allow_filesystem: false,
+            allowed_origins: Vec::new(),
+        }
+    }
+}
+
+#[derive(Error, Debug)]
+pub enum AppError {
+    #[error("{0}\n(run with --allow-network --allow-filesystem to disable isolation)")]
+    Isolation(PlaygroundError),
+
+    #[error(transparent)]
+    Playground(PlaygroundError),
+
+    #[error("Cannot listen on {addr}: {source}")]
+    Listen {
+        addr: String,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("Book directory not found: {0}")]
+    BookNotFound(PathBuf),
+
+    #[error("Terminal error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("{0}")]
+    Usage(String),
+}
+
+impl From<PlaygroundError> for AppError {
+    fn from(error: PlaygroundError) -> Self {
+        match error {
+            PlaygroundError::Isolation(_) => AppError::Isolation(error),
+            other => AppError::Playground(other),
+        }
+    }
+}
+
+pub struct Context {
+    pub work_dir: PathBuf,
+    // หนังสือที่ build แล้ว (docs/book)
+    pub default_book: PathBuf,
+}
+
+impl Command {
+    // args ไม่รวมชื่อโปรแกรม (args[0])
+    pub fn from_args(args: &[String]) -> Result<Self, AppError> {
+        let mut options = Options::default();
+        let mut args = args.iter().map(String::as_str);
+        while let Some(arg) = args.next() {
+            let mut value = || {
+                args.next()
+                    .ok_or_else(|| usage(format!("{} needs a value", arg)))
+            };
+            match arg {
+                "-h" | "--help" | "help" => return Ok(Command::Help),
+                "-p" | "--port" => options.port = number(arg, value()?)?,
+                "--host" => options.host = value()?.to_string(),
+                "--book" => options.book = Some(PathBuf::from(value()?)),
+                "--no-book" => options.no_book = true,
+                "--timeout" => options.timeout = number(arg, value()?)?,
+                "--memory" => options.memory = number(arg, value()?)?,
+                "--jobs" => options.jobs = Some(number(arg, value()?)?),
+                "--allow-network" => options.allow_network = true,
+                "--allow-filesystem" => options.allow_filesystem = true,
+                "--allow-origin" => options.allowed_origins.push(value()?.to_string()),
+                other => return Err(usage(format!("unknown option '{}'", other))),
+            }
+        }
+        Ok(Command::Serve(options))
+    }
+}
+
+fn isolation(allowed: bool) -> &'static str {
+    if allowed { "allowed" } else { "isolated" }
+}
+
+fn usage(message: impl Into<String>) -> AppError {
+    AppError::Usage(message.into())
+}
+
+fn number<T: std::str::FromStr>(option: &str, value: &str) -> Result<T, AppError> {
+    value
+        .parse()
+        .map_err(|_| usage(format!("{}: invalid number '{}'", option, value)))
+}
+
+pub async fn run(
+    command: Command,
+    context: &Context,
+    out: &mut impl Write,
+) -> Result<(), AppError> {
+    match command {
+        Command::Serve(options) => serve(options, context, out).await,
+        Command::Help => Ok(writeln!(out, "{}", USAGE)?),
+    }
+}
+
+async fn serve(options: Options, context: &Context, out: &mut impl Write) -> Result<(), AppError> {
+    let book = match (&options.book, options.no_book) {
+        (_, true) => None,
+        (Some(dir), false) if !dir.is_dir() => return Err(AppError::BookNotFound(dir.clone())),
+        (Some(dir), false) => Some(dir.clone()),
+        (None, false) => Some(context.default_book.clone()).filter(|dir| dir.is_dir()),
+    };
+
+    let mut sandbox = Sandbox::new(&context.work_dir);
+    sandbox.limits = Limits {
+        timeout: Duration::from_secs(options.timeout),
+        memory: options.memory * 1024 * 1024,
+        isolate_network: !options.allow_network,
+        isolate_filesystem: !options.allow_filesystem,
+        ..Limits::default()
+    };
+    // หา rustc และลองแยก network ก่อน ถ้าทำไม่ได้ให้รู้ตั้งแต่ตอนเปิด server
+    let rustc = sandbox.probe()?;
+    let jobs = options.jobs.unwrap_or_else(|| {
+        std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1)
+    });
+
+    let addr = format!("{}:{}", options.host, options.port);
+    let listener = tokio::net::TcpListener::bind(&addr)
+        .await
+        .map_err(|source| AppError::Listen {
+            addr: addr.clone(),
+            source,
+        })?;
+    let local: SocketAddr = listener.local_addr()?;
+
+    writeln!(out, "🦀 Offline playground ({})", rustc)?;
+    writeln!(
+        out,
+        "   limits: {}s, {} MiB, network {}, filesystem {}, {} jobs at a time",
+        options.timeout,
+        options.memory,
+        isolation(options.allow_network),
+        isolation(options.allow_filesystem),
+        jobs
+    )?;
+    writeln!(out, "▶️  POST http://{}/evaluate.json", local)?;
+    match &book {
+        Some(dir) => writeln!(out, "📘 Book: http://{}/ ({})", local, dir.display())?,
+        None => writeln!(
+            out,
+            "📘 Book not served (build it with: cd docs && mdbook build)"
+        )?,
+    }
+    for origin in &options.allowed_origins {
+        writeln!(out, "🌐 Also accepting Run requests from {}", origin)?;
+    }
+    out.flush()?;
+
+    // หนังสือที่ server นี้เสิร์ฟเองเรียก API ได้เสมอ
+    let mut origins = vec![format!("http://{}", local)];
+    if local.ip().is_loopback() {
+        origins.push(format!("http://localhost:{}", local.port()));
+    }
+    origins.extend(options.allowed_origins);
+    let playground = Playground::new(sandbox, jobs, book).with_allowed_origins(origins);
+    let app = playground::router(Arc::new(playground));
+    axum::serve(listener, app).await?;
+    Ok(())
+}
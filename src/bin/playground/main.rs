@@ -0,0 +1,49 @@
+// ===========================================
+// Playground - รัน code ในหนังสือได้โดยไม่ต้องมี internet
+// รัน: cargo run --bin playground
+// เปิด: http://localhost:3030 (ต้อง build หนังสือก่อน: cd docs && mdbook build)
+// ===========================================
+
+mod cli;
+
+use cli::{AppError, Command, Context};
+use std::env;
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+// Exit codes
+const EXIT_ERROR: u8 = 1; // เช่น เปิด port ไม่ได้, ไม่มี rustc
+const EXIT_USAGE: u8 = 2; // ใช้คำสั่งผิด
+
+const DEFAULT_BOOK_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/docs/book");
+
+#[tokio::main]
+async fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = match Command::from_args(&args) {
+        Ok(command) => {
+            let context = Context {
+                work_dir: env::var_os("PLAYGROUND_WORK_DIR")
+                    .map(PathBuf::from)
+                    .unwrap_or_else(|| env::temp_dir().join("rust-tutorial-playground")),
+                default_book: PathBuf::from(DEFAULT_BOOK_DIR),
+            };
+            cli::run(command, &context, &mut io::stdout()).await
+        }
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(AppError::Usage(message)) => {
+            eprintln!("Error: {}\n\n{}", message, cli::USAGE);
+            ExitCode::from(EXIT_USAGE)
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
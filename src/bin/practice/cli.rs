@@ -0,0 +1,325 @@
+// ===========================================
+// cli.rs - Command parsing สำหรับ practice (init / check / hint)
+// ===========================================
+
+use rust_tutorial::exercises::{
+    self, Checker, ExerciseError, MANIFEST, Practice, Status, Workspace,
+};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+pub const USAGE: &str = "\
+Usage: practice <command> [args]
+
+Commands:
+  init <chapter> [dir]    Create a workspace with one crate per exercise
+                          (default dir: practice/chNN)
+  check [exercise]        Run the hidden tests (all exercises by default)
+      -v, --verbose         Show the full compiler/test output
+  hint <exercise>         Show hints for an exercise
+  help                    Show this help
+
+Options:
+  -d, --dir <dir>         Workspace to use for check/hint (default: current directory)
+
+Environment:
+  EXERCISES_DIR           Exercise markdown files (default: docs/src/appendix/exercises)
+  EXERCISES_WORK_DIR      Scratch cargo project (default: target/exercise-check)";
+
+// จำนวนบรรทัดของ output ที่แสดงเมื่อไม่ใช้ --verbose
+const OUTPUT_PREVIEW_LINES: usize = 8;
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Init {
+        chapter: u32,
+        dir: Option<PathBuf>,
+    },
+    Check {
+        exercise: Option<u32>,
+        dir: Option<PathBuf>,
+        verbose: bool,
+    },
+    Hint {
+        exercise: u32,
+        dir: Option<PathBuf>,
+    },
+    Help,
+}
+
+#[derive(Error, Debug)]
+pub enum AppError {
+    #[error("No exercises for chapter {0}")]
+    UnknownChapter(u32),
+
+    #[error("No exercise {0} in this workspace")]
+    UnknownExercise(u32),
+
+    #[error("{0} is not a practice workspace (create one with: practice init <chapter>)")]
+    NotWorkspace(PathBuf),
+
+    #[error("{0} exercise(s) not done yet")]
+    Unfinished(usize),
+
+    #[error(transparent)]
+    Exercise(#[from] ExerciseError),
+
+    #[error("Terminal error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("{0}")]
+    Usage(String),
+}
+
+pub struct Context {
+    pub exercises_dir: PathBuf,
+    pub work_dir: PathBuf,
+    // project ที่ให้ dependency กับเฉลย (Cargo.toml + Cargo.lock)
+    pub project_dir: PathBuf,
+    pub current_dir: PathBuf,
+}
+
+impl Command {
+    // args ไม่รวมชื่อโปรแกรม (args[0])
+    pub fn from_args(args: &[String]) -> Result<Self, AppError> {
+        let args: Vec<&str> = args.iter().map(String::as_str).collect();
+        match args.as_slice() {
+            [] | ["help" | "-h" | "--help"] => Ok(Command::Help),
+            ["init", chapter] => Ok(Command::Init {
+                chapter: number("init", "chapter", chapter)?,
+                dir: None,
+            }),
+            ["init", chapter, dir] => Ok(Command::Init {
+                chapter: number("init", "chapter", chapter)?,
+                dir: Some(PathBuf::from(dir)),
+            }),
+            ["init", ..] => Err(usage("init: expected <chapter> [dir]")),
+            ["check", rest @ ..] => parse_check(rest),
+            ["hint", rest @ ..] => parse_hint(rest),
+            [other, ..] => Err(usage(format!("unknown command '{}'", other))),
+        }
+    }
+}
+
+fn usage(message: impl Into<String>) -> AppError {
+    AppError::Usage(message.into())
+}
+
+fn number(command: &str, what: &str, arg: &str) -> Result<u32, AppError> {
+    arg.parse()
+        .map_err(|_| usage(format!("{}: invalid {} '{}'", command, what, arg)))
+}
+
+// แยก -d/--dir ออกจาก args ที่เหลือ
+fn take_dir<'a>(
+    command: &str,
+    args: &[&'a str],
+) -> Result<(Option<PathBuf>, Vec<&'a str>), AppError> {
+    let mut dir = None;
+    let mut rest = Vec::new();
+    let mut args = args.iter();
+    while let Some(&arg) = args.next() {
+        match arg {
+            "-d" | "--dir" => {
+                let value = args
+                    .next()
+                    .ok_or_else(|| usage(format!("{}: {} needs a directory", command, arg)))?;
+                dir = Some(PathBuf::from(value));
+            }
+            _ => rest.push(arg),
+        }
+    }
+    Ok((dir, rest))
+}
+
+fn parse_check(args: &[&str]) -> Result<Command, AppError> {
+    let (dir, rest) = take_dir("check", args)?;
+    let mut exercise = None;
+    let mut verbose = false;
+    for arg in rest {
+        match arg {
+            "-v" | "--verbose" => verbose = true,
+            _ if exercise.is_none() => exercise = Some(number("check", "exercise", arg)?),
+            _ => return Err(usage(format!("check: unexpected argument '{}'", arg))),
+        }
+    }
+    Ok(Command::Check {
+        exercise,
+        dir,
+        verbose,
+    })
+}
+
+fn parse_hint(args: &[&str]) -> Result<Command, AppError> {
+    let (dir, rest) = take_dir("hint", args)?;
+    match rest.as_slice() {
+        [exercise] => Ok(Command::Hint {
+            exercise: number("hint", "exercise", exercise)?,
+            dir,
+        }),
+        _ => Err(usage("hint: expected <exercise>")),
+    }
+}
+
+pub fn run(command: Command, context: &Context, out: &mut impl Write) -> Result<(), AppError> {
+    match command {
+        Command::Init { chapter, dir } => init(chapter, dir, context, out),
+        Command::Check {
+            exercise,
+            dir,
+            verbose,
+        } => check(exercise, &workspace_dir(dir, context), verbose, out),
+        Command::Hint { exercise, dir } => hint(exercise, &workspace_dir(dir, context), out),
+        Command::Help => Ok(writeln!(out, "{}", USAGE)?),
+    }
+}
+
+fn workspace_dir(dir: Option<PathBuf>, context: &Context) -> PathBuf {
+    context.current_dir.join(dir.unwrap_or_default())
+}
+
+fn load(dir: &Path) -> Result<Workspace, AppError> {
+    if !dir.join(MANIFEST).is_file() {
+        return Err(AppError::NotWorkspace(dir.to_path_buf()));
+    }
+    Ok(Workspace::load(dir)?)
+}
+
+fn init(
+    chapter: u32,
+    dir: Option<PathBuf>,
+    context: &Context,
+    out: &mut impl Write,
+) -> Result<(), AppError> {
+    let book = exercises::load_book(&context.exercises_dir)?;
+    let chapter = book
+        .iter()
+        .find(|c| c.chapter == chapter)
+        .ok_or(AppError::UnknownChapter(chapter))?;
+
+    let dir = dir.unwrap_or_else(|| PathBuf::from(format!("practice/ch{:02}", chapter.chapter)));
+    writeln!(
+        out,
+        "📘 บทที่ {} - {} → {}",
+        chapter.chapter,
+        chapter.title,
+        dir.display()
+    )?;
+    out.flush()?;
+
+    // เฉลยถูกรันเพื่อเก็บ output ที่ต้องพิมพ์ให้เหมือน
+    let checker = Checker::new(&context.work_dir).with_project(&context.project_dir)?;
+    let workspace = Workspace::generate(chapter, &checker, context.current_dir.join(&dir))?;
+
+    for practice in &workspace.exercises {
+        writeln!(
+            out,
+            "  ✏️  {:<5} {}. {}",
+            practice.dir, practice.number, practice.title
+        )?;
+    }
+    for skipped in &workspace.skipped {
+        writeln!(
+            out,
+            "  ⏭️  skipped {}. {}: {}",
+            skipped.number, skipped.title, skipped.reason
+        )?;
+    }
+    writeln!(
+        out,
+        "\n🏁 {} exercises ready: cd {} && practice check",
+        workspace.exercises.len(),
+        dir.display()
+    )?;
+    Ok(())
+}
+
+fn check(
+    exercise: Option<u32>,
+    dir: &Path,
+    verbose: bool,
+    out: &mut impl Write,
+) -> Result<(), AppError> {
+    let workspace = load(dir)?;
+    let selected: Vec<&Practice> = match exercise {
+        Some(number) => vec![
+            workspace
+                .find(number)
+                .ok_or(AppError::UnknownExercise(number))?,
+        ],
+        None => workspace.exercises.iter().collect(),
+    };
+
+    writeln!(out, "📘 บทที่ {} - {}", workspace.chapter, workspace.title)?;
+    let mut done = 0;
+    for practice in &selected {
+        let status = practice.check(dir)?;
+        write_status(out, practice, &status, verbose)?;
+        out.flush()?;
+        if status.is_done() {
+            done += 1;
+        }
+    }
+
+    writeln!(out, "\n🏁 {}/{} exercises done", done, selected.len())?;
+    if done < selected.len() {
+        return Err(AppError::Unfinished(selected.len() - done));
+    }
+    Ok(())
+}
+
+fn write_status(
+    out: &mut impl Write,
+    practice: &Practice,
+    status: &Status,
+    verbose: bool,
+) -> io::Result<()> {
+    match status {
+        Status::Done => writeln!(out, "  ✅ {}. {}", practice.number, practice.title),
+        Status::Unfinished { reason, output } => {
+            writeln!(
+                out,
+                "  ❌ {}. {} - {}",
+                practice.number, practice.title, reason
+            )?;
+            let lines: Vec<&str> = output.lines().collect();
+            let shown = if verbose {
+                lines.len()
+            } else {
+                lines.len().min(OUTPUT_PREVIEW_LINES)
+            };
+            for line in &lines[..shown] {
+                writeln!(out, "     │ {}", line)?;
+            }
+            if shown < lines.len() {
+                writeln!(
+                    out,
+                    "     │ ... ({} more lines, use -v)",
+                    lines.len() - shown
+                )?;
+            }
+            if !practice.hints.is_empty() {
+                writeln!(out, "     💡 practice hint {}", practice.number)?;
+            }
+            Ok(())
+        }
+    }
+}
+
+fn hint(exercise: u32, dir: &Path, out: &mut impl Write) -> Result<(), AppError> {
+    let workspace = load(dir)?;
+    let practice = workspace
+        .find(exercise)
+        .ok_or(AppError::UnknownExercise(exercise))?;
+
+    writeln!(out, "💡 {}. {}", practice.number, practice.title)?;
+    if practice.hints.is_empty() {
+        writeln!(out, "  (no hints for this exercise)")?;
+    }
+    for (i, hint) in practice.hints.iter().enumerate() {
+        writeln!(out, "  {}. {}", i + 1, hint)?;
+    }
+    writeln!(out, "\nเฉลยเต็ม: .hidden/{}_solution.rs", practice.dir)?;
+    Ok(())
+}
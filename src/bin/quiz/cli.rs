@@ -0,0 +1,402 @@
+// ===========================================
+// cli.rs - Command parsing และการทำ quiz แบบโต้ตอบ
+// ===========================================
+
+use chrono::Utc;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rust_tutorial::quiz::{
+    self, ChapterProgress, ProgressError, ProgressStore, Question, Quiz, QuizError,
+};
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::PathBuf;
+use thiserror::Error;
+
+pub const USAGE: &str = "\
+Usage: quiz [options] <command> [args]
+
+Commands:
+  list                    List chapters with your best scores
+  run <chapter>           Take the quiz for a chapter (shortcut: quiz <chapter>)
+      --seed <n>            Repeatable question order
+  progress                Show your scores and missed questions
+  reset [chapter]         Forget your scores (one chapter or all)
+  validate                Check every quiz file (answers, options, rust snippets)
+  export [-o <file>]      Write the whole quiz bank as JSON (default: stdout)
+  help                    Show this help
+
+Options:
+  -l, --learner <name>    Whose progress to use (default: $QUIZ_LEARNER, then $USER)
+
+Environment:
+  QUIZ_DIR                Quiz markdown files (default: docs/src/appendix/quiz)
+  QUIZ_PROGRESS_DIR       Where progress is kept (default: .quiz-progress)";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    List,
+    Run { chapter: u32, seed: Option<u64> },
+    Progress,
+    Reset(Option<u32>),
+    Validate,
+    Export(Option<PathBuf>),
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Invocation {
+    pub command: Command,
+    pub learner: Option<String>,
+}
+
+#[derive(Error, Debug)]
+pub enum AppError {
+    #[error("No quiz for chapter {0}")]
+    UnknownChapter(u32),
+
+    #[error("Quiz bank has {0} problem(s)")]
+    Invalid(usize),
+
+    #[error(transparent)]
+    Quiz(#[from] QuizError),
+
+    #[error(transparent)]
+    Progress(#[from] ProgressError),
+
+    #[error("Terminal error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("{0}")]
+    Usage(String),
+}
+
+pub struct Context {
+    pub quiz_dir: PathBuf,
+    pub store: ProgressStore,
+    pub learner: String,
+}
+
+impl Invocation {
+    // args ไม่รวมชื่อโปรแกรม (args[0])
+    pub fn from_args(args: &[String]) -> Result<Self, AppError> {
+        let mut learner = None;
+        let mut rest = Vec::new();
+
+        let mut iter = args.iter();
+        while let Some(arg) = iter.next() {
+            match arg.as_str() {
+                "-l" | "--learner" => {
+                    let name = iter
+                        .next()
+                        .ok_or_else(|| usage(format!("{}: missing value", arg)))?;
+                    learner = Some(name.clone());
+                }
+                _ => rest.push(arg.as_str()),
+            }
+        }
+
+        let command = match rest.as_slice() {
+            [] | ["help" | "-h" | "--help"] => Command::Help,
+            ["list" | "ls"] => Command::List,
+            ["progress"] => Command::Progress,
+            ["reset"] => Command::Reset(None),
+            ["reset", chapter] => Command::Reset(Some(parse_chapter(chapter)?)),
+            ["validate"] => Command::Validate,
+            ["export"] => Command::Export(None),
+            ["export", "-o" | "--output", file] => Command::Export(Some(PathBuf::from(file))),
+            ["run", args @ ..] => parse_run(args)?,
+            [chapter, ..] if chapter.parse::<u32>().is_ok() => parse_run(&rest)?,
+            [other, ..] => return Err(usage(format!("unknown command '{}'", other))),
+        };
+
+        Ok(Self { command, learner })
+    }
+}
+
+fn usage(message: impl Into<String>) -> AppError {
+    AppError::Usage(message.into())
+}
+
+fn parse_chapter(arg: &str) -> Result<u32, AppError> {
+    arg.parse()
+        .map_err(|_| usage(format!("invalid chapter '{}'", arg)))
+}
+
+fn parse_run(args: &[&str]) -> Result<Command, AppError> {
+    let mut chapter = None;
+    let mut seed = None;
+
+    let mut iter = args.iter();
+    while let Some(&arg) = iter.next() {
+        match arg {
+            "--seed" => {
+                let value = iter.next().ok_or_else(|| usage("--seed: missing value"))?;
+                seed = Some(
+                    value
+                        .parse()
+                        .map_err(|_| usage(format!("--seed: invalid number '{}'", value)))?,
+                );
+            }
+            _ if chapter.is_none() => chapter = Some(parse_chapter(arg)?),
+            other => return Err(usage(format!("run: unexpected argument '{}'", other))),
+        }
+    }
+
+    let chapter = chapter.ok_or_else(|| usage("run: missing <chapter>"))?;
+    Ok(Command::Run { chapter, seed })
+}
+
+pub fn run(
+    command: Command,
+    context: &Context,
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+) -> Result<(), AppError> {
+    match command {
+        Command::List => {
+            let progress = context.store.load(&context.learner)?;
+            for quiz in quiz::load_bank(&context.quiz_dir)? {
+                let best = match progress.chapter(quiz.chapter) {
+                    Some(p) => format!("best {}/{}", p.best_score, p.total),
+                    None => "not taken".to_string(),
+                };
+                writeln!(
+                    out,
+                    "{:>2}. {:<28} {} questions  {}",
+                    quiz.chapter,
+                    quiz.title,
+                    quiz.questions.len(),
+                    best
+                )?;
+            }
+        }
+        Command::Run { chapter, seed } => {
+            let path = context.quiz_dir.join(format!("ch{:02}-quiz.md", chapter));
+            if !path.exists() {
+                return Err(AppError::UnknownChapter(chapter));
+            }
+            let mut quiz = Quiz::load(&path)?;
+            let mut rng = match seed {
+                Some(seed) => StdRng::seed_from_u64(seed),
+                None => StdRng::from_entropy(),
+            };
+            quiz.shuffle(&mut rng);
+
+            let Some(result) = take_quiz(&quiz, input, out)? else {
+                writeln!(out, "\n👋 Quiz stopped - nothing was saved")?;
+                return Ok(());
+            };
+
+            let mut progress = context.store.load(&context.learner)?;
+            let total = quiz.questions.len();
+            let saved = progress.record(chapter, result.score, total, result.missed, Utc::now());
+            writeln!(
+                out,
+                "\n🏁 Score: {}/{} ({}%)  best: {}/{}  attempts: {}",
+                result.score,
+                total,
+                result.score * 100 / total,
+                saved.best_score,
+                saved.total,
+                saved.attempts
+            )?;
+            context.store.save(&progress)?;
+        }
+        Command::Progress => {
+            let progress = context.store.load(&context.learner)?;
+            if progress.chapters.is_empty() {
+                writeln!(out, "📭 No quizzes taken yet. Start with: quiz run 1")?;
+            }
+            for (chapter, p) in &progress.chapters {
+                write_progress(out, *chapter, p)?;
+            }
+        }
+        Command::Reset(chapter) => {
+            let mut progress = context.store.load(&context.learner)?;
+            progress.reset(chapter);
+            context.store.save(&progress)?;
+            match chapter {
+                Some(chapter) => writeln!(out, "🗑️ Reset chapter {}", chapter)?,
+                None => writeln!(out, "🗑️ Reset all chapters")?,
+            }
+        }
+        Command::Validate => {
+            let report = quiz::validate_bank(&context.quiz_dir)?;
+            for issue in &report.issues {
+                writeln!(out, "❌ {}", issue)?;
+            }
+            if !report.is_ok() {
+                return Err(AppError::Invalid(report.issues.len()));
+            }
+            writeln!(
+                out,
+                "✅ {} quizzes, {} questions - no problems found",
+                report.quizzes.len(),
+                report.question_count()
+            )?;
+        }
+        Command::Export(file) => {
+            // export เฉพาะคลังที่ถูกต้องทั้งหมด
+            let report = quiz::validate_bank(&context.quiz_dir)?;
+            for issue in &report.issues {
+                eprintln!("❌ {}", issue);
+            }
+            if !report.is_ok() {
+                return Err(AppError::Invalid(report.issues.len()));
+            }
+
+            let json = serde_json::to_string_pretty(&report.quizzes)
+                .expect("quiz bank is always serializable");
+            match file {
+                Some(path) => {
+                    fs::write(&path, json + "\n")?;
+                    writeln!(
+                        out,
+                        "📦 Exported {} questions to {}",
+                        report.question_count(),
+                        path.display()
+                    )?;
+                }
+                None => writeln!(out, "{}", json)?,
+            }
+        }
+        Command::Help => writeln!(out, "{}", USAGE)?,
+    }
+
+    Ok(())
+}
+
+fn write_progress(out: &mut impl Write, chapter: u32, p: &ChapterProgress) -> io::Result<()> {
+    writeln!(
+        out,
+        "{:>2}. best {}/{}  last {}/{}  attempts {}  ({})",
+        chapter,
+        p.best_score,
+        p.total,
+        p.last_score,
+        p.total,
+        p.attempts,
+        p.last_taken.format("%Y-%m-%d")
+    )?;
+    if !p.missed.is_empty() {
+        writeln!(out, "    missed: {}", p.missed.join(", "))?;
+    }
+    Ok(())
+}
+
+// -------------------------------------------
+// ทำ quiz แบบโต้ตอบ
+// -------------------------------------------
+
+pub struct QuizResult {
+    pub score: usize,
+    // หัวข้อของคำถามที่ตอบผิด
+    pub missed: Vec<String>,
+}
+
+// คืน None ถ้าผู้เรียนออกกลางคัน (q หรือ EOF)
+pub fn take_quiz(
+    quiz: &Quiz,
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+) -> io::Result<Option<QuizResult>> {
+    let total = quiz.questions.len();
+    writeln!(
+        out,
+        "📝 Quiz: บทที่ {} - {} ({} questions)",
+        quiz.chapter, quiz.title, total
+    )?;
+    writeln!(out, "Answer with a letter, or q to quit.")?;
+
+    let mut score = 0;
+    let mut missed = Vec::new();
+
+    for (i, question) in quiz.questions.iter().enumerate() {
+        writeln!(out, "\n[{}/{}] {}\n", i + 1, total, question.heading)?;
+        writeln!(out, "{}\n", render_markdown(&question.prompt))?;
+        for choice in &question.choices {
+            writeln!(out, "  {}. {}", choice.letter, choice.text)?;
+        }
+
+        let Some(letter) = ask(question, input, out)? else {
+            return Ok(None);
+        };
+
+        if question.is_correct(letter) {
+            score += 1;
+            writeln!(out, "✅ Correct!")?;
+        } else {
+            missed.push(question);
+            writeln!(
+                out,
+                "❌ Wrong - the answer is {}. {}",
+                question.answer.letter, question.answer.text
+            )?;
+        }
+        if !question.explanation.is_empty() {
+            writeln!(out, "\n{}", render_markdown(&question.explanation))?;
+        }
+    }
+
+    // เรียงตามลำดับในไฟล์ ไม่ใช่ลำดับที่สุ่มมา
+    missed.sort_by_key(|q| q.number);
+    Ok(Some(QuizResult {
+        score,
+        missed: missed.iter().map(|q| q.heading.clone()).collect(),
+    }))
+}
+
+// ถามซ้ำจนกว่าจะได้ตัวเลือกที่มีอยู่จริง
+fn ask(
+    question: &Question,
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+) -> io::Result<Option<char>> {
+    loop {
+        write!(out, "> ")?;
+        out.flush()?;
+
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            return Ok(None);
+        }
+
+        let answer = line.trim();
+        if answer.eq_ignore_ascii_case("q") {
+            return Ok(None);
+        }
+
+        let mut chars = answer.chars();
+        match (chars.next(), chars.next()) {
+            (Some(letter), None) if question.choice(letter).is_some() => {
+                return Ok(Some(letter.to_ascii_uppercase()));
+            }
+            _ => {
+                let letters: Vec<String> = question
+                    .choices
+                    .iter()
+                    .map(|c| c.letter.to_string())
+                    .collect();
+                writeln!(out, "Please answer {} (or q to quit)", letters.join("/"))?;
+            }
+        }
+    }
+}
+
+// แสดง markdown ใน terminal: ตัดบรรทัด ``` และเยื้อง code
+fn render_markdown(text: &str) -> String {
+    let mut in_code = false;
+    let mut lines = Vec::new();
+
+    for line in text.lines() {
+        if line.trim_start().starts_with("```") {
+            in_code = !in_code;
+        } else if in_code {
+            lines.push(format!("    {}", line));
+        } else {
+            lines.push(line.to_string());
+        }
+    }
+    lines.join("\n")
+}
@@ -0,0 +1,61 @@
+// ===========================================
+// Quiz CLI - ทำแบบทดสอบท้ายบทแบบ offline
+// รัน: cargo run --bin quiz -- <command>
+// ===========================================
+
+mod cli;
+
+use cli::{AppError, Context, Invocation};
+use rust_tutorial::quiz::ProgressStore;
+use std::env;
+use std::io;
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+// Exit codes
+const EXIT_ERROR: u8 = 1; // เช่น ไม่มี quiz ของบทนั้น, อ่านไฟล์ไม่ได้
+const EXIT_USAGE: u8 = 2; // ใช้คำสั่งผิด
+
+const DEFAULT_QUIZ_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/docs/src/appendix/quiz");
+const DEFAULT_PROGRESS_DIR: &str = ".quiz-progress";
+const DEFAULT_LEARNER: &str = "default";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = Invocation::from_args(&args).and_then(|invocation| {
+        let learner = invocation
+            .learner
+            .or_else(|| env::var("QUIZ_LEARNER").ok())
+            .or_else(|| env::var("USER").ok())
+            .unwrap_or_else(|| DEFAULT_LEARNER.to_string());
+
+        let context = Context {
+            quiz_dir: env_path("QUIZ_DIR", DEFAULT_QUIZ_DIR),
+            store: ProgressStore::new(env_path("QUIZ_PROGRESS_DIR", DEFAULT_PROGRESS_DIR)),
+            learner,
+        };
+        cli::run(
+            invocation.command,
+            &context,
+            &mut io::stdin().lock(),
+            &mut io::stdout(),
+        )
+    });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(AppError::Usage(message)) => {
+            eprintln!("Error: {}\n\n{}", message, cli::USAGE);
+            ExitCode::from(EXIT_USAGE)
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
+
+fn env_path(name: &str, default: &str) -> PathBuf {
+    env::var_os(name).unwrap_or_else(|| default.into()).into()
+}
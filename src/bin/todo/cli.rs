@@ -0,0 +1,239 @@
+// ===========================================
+// cli.rs - Command parsing และการทำงานของแต่ละคำสั่ง
+// ===========================================
+
+use chrono::NaiveDate;
+use rust_tutorial::todo::{
+    self, JournalBackend, JournaledStore, Operation, Priority, Query, SortOrder, Status,
+    StorageError, Todo,
+};
+use thiserror::Error;
+
+pub const USAGE: &str = "\
+Usage: todo <command> [args]
+
+Commands:
+  add <title> [options]   Add a new todo
+      -p, --priority <p>    low | medium | high (default: medium)
+      -d, --due <when>      today, tomorrow, \"in 3 days\", friday, 2026-01-31
+      -t, --tag <tag>       Add a tag (repeatable)
+  list [options]          List todos
+      -t, --tag <tag>       Only todos with this tag (repeatable)
+      --overdue             Only overdue todos
+      --pending | --done    Only pending / completed todos
+      -s, --sort <order>    id | due | priority | created | title
+      -r, --reverse         Reverse the order
+  complete <id>           Mark todo as complete
+  uncomplete <id>         Mark todo as not complete
+  remove <id>             Remove a todo
+  clear                   Remove all todos
+  undo                    Undo the last change
+  redo                    Redo the last undone change
+  compact                 Merge the change journal into a snapshot
+  help                    Show this help
+
+Environment:
+  TODO_FILE               Where todos are kept (default: todos.json)
+                          *.db / *.sqlite / *.sqlite3 use SQLite, anything else JSON";
+
+#[derive(Debug)]
+pub enum Command {
+    Add(Todo),
+    List(Query),
+    Complete(u32),
+    Uncomplete(u32),
+    Remove(u32),
+    Clear,
+    Undo,
+    Redo,
+    Compact,
+    Help,
+}
+
+#[derive(Error, Debug)]
+pub enum AppError {
+    #[error("Todo with id {0} not found")]
+    NotFound(u32),
+
+    #[error(transparent)]
+    Storage(#[from] StorageError),
+
+    #[error("{0}")]
+    Usage(String),
+}
+
+impl Command {
+    // args ไม่รวมชื่อโปรแกรม (args[0])
+    // `today` ใช้แปลงวันครบกำหนดแบบ relative และหา todo ที่เลยกำหนด
+    pub fn from_args(args: &[String], today: NaiveDate) -> Result<Self, AppError> {
+        let Some(name) = args.first() else {
+            return Ok(Command::Help);
+        };
+
+        match name.as_str() {
+            "add" => parse_add(&args[1..], today).map(Command::Add),
+            "list" | "ls" => parse_list(&args[1..], today).map(Command::List),
+            "complete" | "done" => parse_id(name, args).map(Command::Complete),
+            "uncomplete" => parse_id(name, args).map(Command::Uncomplete),
+            "remove" | "rm" => parse_id(name, args).map(Command::Remove),
+            "clear" => Ok(Command::Clear),
+            "undo" => Ok(Command::Undo),
+            "redo" => Ok(Command::Redo),
+            "compact" => Ok(Command::Compact),
+            "help" | "-h" | "--help" => Ok(Command::Help),
+            other => Err(usage(format!("unknown command '{}'", other))),
+        }
+    }
+}
+
+fn usage(message: impl Into<String>) -> AppError {
+    AppError::Usage(message.into())
+}
+
+// รับ id เดียว - `todo done 3 4` เป็น error (ไม่ใช่ทำแค่ #3 แล้วเงียบ)
+fn parse_id(command: &str, args: &[String]) -> Result<u32, AppError> {
+    match &args[1..] {
+        [arg] => arg
+            .parse()
+            .map_err(|_| usage(format!("{}: invalid id '{}'", command, arg))),
+        [] => Err(usage(format!("{}: missing <id>", command))),
+        [_, extra, ..] => Err(usage(format!(
+            "{}: unexpected argument '{}'",
+            command, extra
+        ))),
+    }
+}
+
+// ค่าของ option ถัดไป เช่น `--due tomorrow`
+fn option_value<'a>(
+    option: &str,
+    args: &mut impl Iterator<Item = &'a String>,
+) -> Result<&'a str, AppError> {
+    args.next()
+        .map(String::as_str)
+        .ok_or_else(|| usage(format!("{}: missing value", option)))
+}
+
+fn parse_add(args: &[String], today: NaiveDate) -> Result<Todo, AppError> {
+    let mut words = Vec::new();
+    let mut priority = Priority::default();
+    let mut due = None;
+    let mut tags = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "-p" | "--priority" => {
+                priority = option_value(arg, &mut args)?
+                    .parse()
+                    .map_err(|e| usage(format!("{}", e)))?;
+            }
+            "-d" | "--due" => {
+                let value = option_value(arg, &mut args)?;
+                due = Some(todo::parse_due(value, today).map_err(|e| usage(e.to_string()))?);
+            }
+            "-t" | "--tag" => tags.push(option_value(arg, &mut args)?),
+            word => words.push(word),
+        }
+    }
+
+    let title = words.join(" ");
+    if title.trim().is_empty() {
+        return Err(usage("add: missing <title>"));
+    }
+
+    // id จริงจะถูกกำหนดตอนบันทึก
+    let mut todo = Todo::new(0, title).with_priority(priority);
+    todo.due = due;
+    for tag in tags {
+        todo.add_tag(tag);
+    }
+    Ok(todo)
+}
+
+fn parse_list(args: &[String], today: NaiveDate) -> Result<Query, AppError> {
+    let mut query = Query::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        query = match arg.as_str() {
+            "-t" | "--tag" => query.tag(option_value(arg, &mut args)?),
+            "--overdue" => query.overdue(today),
+            "--pending" => query.status(Status::Pending),
+            "--done" => query.status(Status::Completed),
+            "-s" | "--sort" => {
+                let order: SortOrder = option_value(arg, &mut args)?.parse().map_err(usage)?;
+                query.sort_by(order)
+            }
+            "-r" | "--reverse" => query.reversed(),
+            other => return Err(usage(format!("list: unknown option '{}'", other))),
+        };
+    }
+
+    Ok(query)
+}
+
+pub fn run<B: JournalBackend>(
+    command: Command,
+    store: &mut JournaledStore<B>,
+) -> Result<(), AppError> {
+    match command {
+        Command::Add(todo) => {
+            let todo = store.add(todo)?;
+            println!("✅ Added: {}", todo.title);
+        }
+        Command::List(query) => {
+            if store.todos().is_empty() {
+                println!("📭 No todos yet. Add one with: todo add <title>");
+            }
+            for todo in query.apply(store.todos()) {
+                println!("{}", todo);
+            }
+        }
+        Command::Complete(id) => {
+            let todo = store
+                .update(id, Todo::complete)?
+                .ok_or(AppError::NotFound(id))?;
+            println!("✅ Completed: {}", todo.title);
+        }
+        Command::Uncomplete(id) => {
+            let todo = store
+                .update(id, Todo::uncomplete)?
+                .ok_or(AppError::NotFound(id))?;
+            println!("↩️ Uncompleted: {}", todo.title);
+        }
+        Command::Remove(id) => {
+            let todo = store.remove(id)?.ok_or(AppError::NotFound(id))?;
+            println!("✅ Removed: {}", todo.title);
+        }
+        Command::Clear => {
+            let count = store.clear()?;
+            println!("🗑️ Removed {} todos", count);
+        }
+        Command::Undo => match store.undo()? {
+            Some(operation) => println!("↩️ Undone: {}", describe(&operation)),
+            None => println!("Nothing to undo"),
+        },
+        Command::Redo => match store.redo()? {
+            Some(operation) => println!("↪️ Redone: {}", describe(&operation)),
+            None => println!("Nothing to redo"),
+        },
+        Command::Compact => {
+            let entries = store.journal_len();
+            store.compact()?;
+            println!("🗜️ Compacted {} journal entries", entries);
+        }
+        Command::Help => println!("{}", USAGE),
+    }
+
+    Ok(())
+}
+
+fn describe(operation: &Operation) -> String {
+    match operation {
+        Operation::Add { todo } => format!("add \"{}\"", todo.title),
+        Operation::Update { after, .. } => format!("update \"{}\"", after.title),
+        Operation::Remove { todo, .. } => format!("remove \"{}\"", todo.title),
+        Operation::Clear { todos } => format!("clear ({} todos)", todos.len()),
+    }
+}
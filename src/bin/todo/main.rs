@@ -0,0 +1,55 @@
+// ===========================================
+// Todo CLI - บทที่ 20 (Final Project)
+// รัน: cargo run --bin todo -- <command>
+// ===========================================
+
+mod cli;
+
+use chrono::Local;
+use cli::{AppError, Command};
+use rust_tutorial::todo::{
+    JournalBackend, JournaledStore, JsonJournal, SqliteJournal, StorageError,
+};
+use std::env;
+use std::path::Path;
+use std::process::ExitCode;
+
+// Exit codes
+const EXIT_ERROR: u8 = 1; // เช่น หา todo ไม่เจอ, อ่าน/เขียนไฟล์ไม่ได้
+const EXIT_USAGE: u8 = 2; // ใช้คำสั่งผิด
+
+const DEFAULT_FILE: &str = "todos.json";
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let path = env::var_os("TODO_FILE").unwrap_or_else(|| DEFAULT_FILE.into());
+    let today = Local::now().date_naive();
+
+    let result = Command::from_args(&args, today).and_then(|command| {
+        let mut store = JournaledStore::open(open_backend(Path::new(&path))?)?;
+        cli::run(command, &mut store)
+    });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(AppError::Usage(message)) => {
+            eprintln!("Error: {}\n\n{}", message, cli::USAGE);
+            ExitCode::from(EXIT_USAGE)
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
+
+// เลือก backend จากนามสกุลไฟล์
+fn open_backend(path: &Path) -> Result<Box<dyn JournalBackend>, StorageError> {
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("");
+
+    if matches!(extension, "db" | "sqlite" | "sqlite3") {
+        Ok(Box::new(SqliteJournal::open(path)?))
+    } else {
+        Ok(Box::new(JsonJournal::new(path)))
+    }
+}
@@ -0,0 +1,284 @@
+// ===========================================
+// cli.rs - Command parsing, เมนูเลือก example และการรัน
+// ===========================================
+
+use rust_tutorial::examples::{self, CatalogError, Example, ExampleError, Selection};
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+use std::process::{self, ExitStatus};
+use thiserror::Error;
+
+pub const USAGE: &str = "\
+Usage: tutorial [options] [command]
+
+Commands:
+  (none)                  Menu: pick examples by number or name until you quit
+  list                    List examples with their chapters
+  run <example>           Show the source, then build and run the example
+                          (shortcut: tutorial <example>)
+      --no-source           Only run it
+  show <example>          Show the source only
+
+<example> is a number from the list or part of a name: 3, iter, smart, smrtptr
+
+Options:
+      --no-color          Plain output (also when NO_COLOR is set or not a terminal)
+
+Environment:
+  EXAMPLES_DIR            Example programs (default: examples)
+  BOOK_SRC_DIR            Book chapters, to point at the matching chapter (default: docs/src)";
+
+#[derive(Debug, PartialEq)]
+pub enum Command {
+    Menu,
+    List,
+    Run { query: String, source: bool },
+    Show(String),
+    Help,
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Invocation {
+    pub command: Command,
+    pub no_color: bool,
+}
+
+#[derive(Error, Debug)]
+pub enum AppError {
+    #[error("No example matches '{0}' (see: tutorial list)")]
+    NotFound(String),
+
+    #[error("'{query}' matches more than one example: {}", .names.join(", "))]
+    Ambiguous { query: String, names: Vec<String> },
+
+    #[error("examples/{name} exited with {status}")]
+    Failed { name: String, status: ExitStatus },
+
+    #[error(transparent)]
+    Catalog(#[from] CatalogError),
+
+    #[error(transparent)]
+    Example(#[from] ExampleError),
+
+    #[error("Terminal error: {0}")]
+    Io(#[from] io::Error),
+
+    #[error("{0}")]
+    Usage(String),
+}
+
+pub struct Context {
+    pub examples_dir: PathBuf,
+    pub book_src_dir: PathBuf,
+    // project ที่มี Cargo.toml (ใช้ build example)
+    pub project_dir: PathBuf,
+    pub color: bool,
+}
+
+impl Invocation {
+    // args ไม่รวมชื่อโปรแกรม (args[0])
+    pub fn from_args(args: &[String]) -> Result<Self, AppError> {
+        let no_color = args.iter().any(|arg| arg == "--no-color");
+        let rest: Vec<&str> = args
+            .iter()
+            .map(String::as_str)
+            .filter(|arg| *arg != "--no-color")
+            .collect();
+
+        let command = match rest.as_slice() {
+            [] => Command::Menu,
+            ["help" | "-h" | "--help"] => Command::Help,
+            ["list" | "ls"] => Command::List,
+            ["run"] | ["show"] => return Err(usage("which example? e.g. tutorial run 3")),
+            ["run", query] => run(query, true),
+            ["run", query, "--no-source"] | ["run", "--no-source", query] => run(query, false),
+            ["show", query] => Command::Show(query.to_string()),
+            [option, ..] if option.starts_with('-') => {
+                return Err(usage(format!("unknown option '{}'", option)));
+            }
+            [query] => run(query, true),
+            [query, "--no-source"] => run(query, false),
+            [_, extra, ..] => return Err(usage(format!("unexpected argument '{}'", extra))),
+        };
+        Ok(Self { command, no_color })
+    }
+}
+
+fn run(query: &str, source: bool) -> Command {
+    Command::Run {
+        query: query.to_string(),
+        source,
+    }
+}
+
+fn usage(message: impl Into<String>) -> AppError {
+    AppError::Usage(message.into())
+}
+
+pub fn execute(
+    command: Command,
+    context: &Context,
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+) -> Result<(), AppError> {
+    let catalog = examples::load(&context.examples_dir)?;
+    match command {
+        Command::Menu => menu(&catalog, context, input, out),
+        Command::List => list(&catalog, out),
+        Command::Run { query, source } => {
+            let example = find(&catalog, &query)?;
+            if source {
+                show(example, context, out)?;
+            }
+            launch(example, context, out)
+        }
+        Command::Show(query) => show(find(&catalog, &query)?, context, out),
+        Command::Help => Ok(writeln!(out, "{}", USAGE)?),
+    }
+}
+
+fn find<'a>(catalog: &'a [Example], query: &str) -> Result<&'a Example, AppError> {
+    match examples::select(catalog, query) {
+        Selection::Found(example) => Ok(example),
+        Selection::Ambiguous(matches) => Err(AppError::Ambiguous {
+            query: query.to_string(),
+            names: matches.iter().map(|e| e.name.clone()).collect(),
+        }),
+        Selection::NotFound => Err(AppError::NotFound(query.to_string())),
+    }
+}
+
+fn list(catalog: &[Example], out: &mut impl Write) -> Result<(), AppError> {
+    let width = catalog.iter().map(|e| e.name.len()).max().unwrap_or(0);
+    writeln!(
+        out,
+        "{:>3}  {:<7}  {:<width$}  Title",
+        "#", "Chapter", "Example"
+    )?;
+    for (index, example) in catalog.iter().enumerate() {
+        writeln!(
+            out,
+            "{:>3}  {:<7}  {:<width$}  {}",
+            index + 1,
+            example.chapters.to_string(),
+            example.name,
+            example.title
+        )?;
+    }
+    Ok(())
+}
+
+fn menu(
+    catalog: &[Example],
+    context: &Context,
+    input: &mut impl BufRead,
+    out: &mut impl Write,
+) -> Result<(), AppError> {
+    writeln!(out, "🦀 Rust Tutorial examples\n")?;
+    list(catalog, out)?;
+    writeln!(
+        out,
+        "\nPick an example by number or name (l = list, q = quit)"
+    )?;
+
+    loop {
+        write!(out, "> ")?;
+        out.flush()?;
+        let mut line = String::new();
+        if input.read_line(&mut line)? == 0 {
+            writeln!(out)?;
+            return Ok(());
+        }
+        let query = line.trim();
+        let result = match query {
+            "" => continue,
+            "q" | "quit" | "exit" => return Ok(()),
+            "l" | "list" => list(catalog, out),
+            _ => find(catalog, query).and_then(|example| {
+                show(example, context, out)?;
+                launch(example, context, out)
+            }),
+        };
+        // example ที่ล้มเหลวหรือพิมพ์ผิดไม่ต้องออกจากเมนู
+        match result {
+            Ok(()) => {}
+            Err(AppError::Io(e)) => return Err(AppError::Io(e)),
+            Err(e) => writeln!(out, "❌ {}", e)?,
+        }
+    }
+}
+
+fn show(example: &Example, context: &Context, out: &mut impl Write) -> Result<(), AppError> {
+    writeln!(
+        out,
+        "\n📄 {} - {} (บทที่ {})",
+        display_path(example, context),
+        example.title,
+        example.chapters
+    )?;
+    if let Some(chapter) = book_chapter(&context.book_src_dir, example.chapters.first) {
+        writeln!(out, "📘 Book: {}", chapter.display())?;
+    }
+    writeln!(out)?;
+
+    let source = example.source()?;
+    let source = if context.color {
+        examples::highlight(&source)
+    } else {
+        source
+    };
+    writeln!(
+        out,
+        "{}",
+        examples::with_line_numbers(&source, context.color)
+    )?;
+    Ok(())
+}
+
+fn launch(example: &Example, context: &Context, out: &mut impl Write) -> Result<(), AppError> {
+    writeln!(out, "\n🔨 cargo build --example {}", example.name)?;
+    out.flush()?;
+    let executable = examples::build(&context.project_dir, &example.name)?;
+
+    writeln!(out, "▶️  Output:\n")?;
+    out.flush()?;
+    // ใช้ stdin/stdout ของ terminal ตรงๆ (web_server รันจนกด Ctrl+C)
+    let status = process::Command::new(&executable)
+        .current_dir(&context.project_dir)
+        .status()
+        .map_err(|source| ExampleError::Command {
+            program: executable.display().to_string(),
+            source,
+        })?;
+
+    if !status.success() {
+        return Err(AppError::Failed {
+            name: example.name.clone(),
+            status,
+        });
+    }
+    writeln!(out, "\n🏁 Done (cargo run --example {})", example.name)?;
+    Ok(())
+}
+
+fn display_path(example: &Example, context: &Context) -> String {
+    let relative = example
+        .path
+        .strip_prefix(&context.project_dir)
+        .unwrap_or(&example.path);
+    relative.display().to_string()
+}
+
+// docs/src/ch13-iterators-closures/ ของบทที่ 13
+fn book_chapter(book_src_dir: &Path, chapter: u32) -> Option<PathBuf> {
+    let prefix = format!("ch{:02}-", chapter);
+    let mut dirs: Vec<PathBuf> = fs::read_dir(book_src_dir)
+        .ok()?
+        .filter_map(Result::ok)
+        .filter(|entry| entry.file_name().to_string_lossy().starts_with(&prefix))
+        .map(|entry| entry.path())
+        .collect();
+    dirs.sort();
+    dirs.into_iter().next()
+}
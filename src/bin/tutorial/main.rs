@@ -0,0 +1,59 @@
+// ===========================================
+// Tutorial - เมนูรวมโปรแกรมตัวอย่างทุกบท (ไม่ต้องจำชื่อ example)
+// รัน: cargo run --bin tutorial            (เมนู)
+//      cargo run --bin tutorial -- iter    (รัน iterators)
+// ===========================================
+
+mod cli;
+
+use cli::{AppError, Context, Invocation};
+use std::env;
+use std::io::{self, IsTerminal};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+// Exit codes
+const EXIT_ERROR: u8 = 1; // เช่น หา example ไม่เจอ, example ล้มเหลว
+const EXIT_USAGE: u8 = 2; // ใช้คำสั่งผิด
+
+const PROJECT_DIR: &str = env!("CARGO_MANIFEST_DIR");
+const DEFAULT_EXAMPLES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/examples");
+const DEFAULT_BOOK_SRC_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/docs/src");
+
+fn main() -> ExitCode {
+    let args: Vec<String> = env::args().skip(1).collect();
+
+    let result = Invocation::from_args(&args).and_then(|invocation| {
+        let context = Context {
+            examples_dir: env_path("EXAMPLES_DIR", DEFAULT_EXAMPLES_DIR),
+            book_src_dir: env_path("BOOK_SRC_DIR", DEFAULT_BOOK_SRC_DIR),
+            project_dir: PathBuf::from(PROJECT_DIR),
+            // https://no-color.org
+            color: !invocation.no_color
+                && env::var_os("NO_COLOR").is_none()
+                && io::stdout().is_terminal(),
+        };
+        cli::execute(
+            invocation.command,
+            &context,
+            &mut io::stdin().lock(),
+            &mut io::stdout(),
+        )
+    });
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(AppError::Usage(message)) => {
+            eprintln!("Error: {}\n\n{}", message, cli::USAGE);
+            ExitCode::from(EXIT_USAGE)
+        }
+        Err(e) => {
+            eprintln!("Error: {}", e);
+            ExitCode::from(EXIT_ERROR)
+        }
+    }
+}
+
+fn env_path(name: &str, default: &str) -> PathBuf {
+    env::var_os(name).unwrap_or_else(|| default.into()).into()
+}
@@ -0,0 +1,121 @@
+// ===========================================
+// error.rs - error พร้อมตำแหน่งใน input
+// ===========================================
+
+use std::fmt;
+use thiserror::Error;
+
+// ช่วงตัวอักษร [start, end) นับเป็น char (ไม่ใช่ byte) เริ่มที่ 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Span {
+    pub start: usize,
+    pub end: usize,
+}
+
+impl Span {
+    pub fn new(start: usize, end: usize) -> Self {
+        Self { start, end }
+    }
+
+    // ครอบตั้งแต่ต้น self ถึงท้าย other
+    pub fn to(self, other: Span) -> Span {
+        Span::new(self.start.min(other.start), self.end.max(other.end))
+    }
+
+    pub fn len(self) -> usize {
+        self.end - self.start
+    }
+
+    pub fn is_empty(self) -> bool {
+        self.start == self.end
+    }
+}
+
+impl fmt::Display for Span {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // แสดงแบบคนนับ (column เริ่มที่ 1)
+        if self.len() <= 1 {
+            write!(f, "column {}", self.start + 1)
+        } else {
+            write!(f, "columns {}-{}", self.start + 1, self.end)
+        }
+    }
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum ErrorKind {
+    // tokenize
+    #[error("unexpected character '{0}'")]
+    UnexpectedChar(char),
+
+    #[error("invalid number '{0}'")]
+    InvalidNumber(String),
+
+    // parse
+    #[error("unexpected {0}")]
+    UnexpectedToken(String),
+
+    #[error("unexpected end of input, expected {0}")]
+    UnexpectedEnd(&'static str),
+
+    #[error("this '(' is never closed")]
+    UnclosedParen,
+
+    #[error("expression is nested too deeply")]
+    TooDeep,
+
+    // evaluate
+    #[error("unknown variable '{0}'")]
+    UnknownVariable(String),
+
+    #[error("unknown function '{0}'")]
+    UnknownFunction(String),
+
+    #[error("{name}() takes {expected}, got {got}")]
+    WrongArgumentCount {
+        name: String,
+        expected: String,
+        got: usize,
+    },
+
+    #[error("division by zero")]
+    DivisionByZero,
+
+    #[error("result is too large")]
+    Overflow,
+
+    #[error("{0}")]
+    Domain(&'static str),
+
+    #[error("'{0}' is a constant and cannot be assigned")]
+    ReadOnly(String),
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+#[error("{kind} at {span}")]
+pub struct CalcError {
+    pub kind: ErrorKind,
+    pub span: Span,
+}
+
+impl CalcError {
+    pub fn new(kind: ErrorKind, span: Span) -> Self {
+        Self { kind, span }
+    }
+
+    // input บรรทัดเดิม แล้วขีด ^ ใต้ส่วนที่ผิด:
+    //
+    //   1 + 2 / (3 - 3)
+    //           ^^^^^^^ division by zero
+    pub fn render(&self, source: &str) -> String {
+        // tab ใน input ก็ใช้ tab เพื่อให้ ^ ตรงตำแหน่ง
+        let padding: String = source
+            .chars()
+            .chain(std::iter::repeat(' '))
+            .take(self.span.start)
+            .map(|c| if c == '\t' { '\t' } else { ' ' })
+            .collect();
+        let carets = "^".repeat(self.span.len().max(1));
+        format!("{}\n{}{} {}", source, padding, carets, self.kind)
+    }
+}
@@ -0,0 +1,258 @@
+// ===========================================
+// eval.rs - คำนวณ Expr แบบตรวจทุกขั้น
+// ===========================================
+//
+// ไม่มี inf หรือ NaN หลุดออกไป: หารด้วย 0, ผลใหญ่เกิน f64
+// และค่านอกขอบเขตของฟังก์ชัน (sqrt(-1)) เป็น error พร้อมตำแหน่ง
+
+use super::{
+    BinaryOp, CalcError, ErrorKind, Expr, ExprKind, Span, Statement, parse, parse_statement,
+};
+use std::collections::BTreeMap;
+use std::f64::consts::{E, PI, TAU};
+
+const CONSTANTS: &[(&str, f64)] = &[("pi", PI), ("e", E), ("tau", TAU)];
+
+// จำนวน argument: พอดี หรืออย่างน้อย
+#[derive(Clone, Copy)]
+enum Arity {
+    Exactly(usize),
+    AtLeast(usize),
+}
+
+struct Function {
+    name: &'static str,
+    arity: Arity,
+    apply: fn(&[f64]) -> Result<f64, ErrorKind>,
+}
+
+const FUNCTIONS: &[Function] = &[
+    Function {
+        name: "sqrt",
+        arity: Arity::Exactly(1),
+        apply: |a| match a[0] {
+            x if x < 0.0 => Err(ErrorKind::Domain("sqrt of a negative number")),
+            x => Ok(x.sqrt()),
+        },
+    },
+    Function {
+        name: "abs",
+        arity: Arity::Exactly(1),
+        apply: |a| Ok(a[0].abs()),
+    },
+    Function {
+        name: "ln",
+        arity: Arity::Exactly(1),
+        apply: |a| log(a[0], f64::ln),
+    },
+    Function {
+        name: "log10",
+        arity: Arity::Exactly(1),
+        apply: |a| log(a[0], f64::log10),
+    },
+    Function {
+        name: "exp",
+        arity: Arity::Exactly(1),
+        apply: |a| Ok(a[0].exp()),
+    },
+    Function {
+        name: "sin",
+        arity: Arity::Exactly(1),
+        apply: |a| Ok(a[0].sin()),
+    },
+    Function {
+        name: "cos",
+        arity: Arity::Exactly(1),
+        apply: |a| Ok(a[0].cos()),
+    },
+    Function {
+        name: "tan",
+        arity: Arity::Exactly(1),
+        apply: |a| Ok(a[0].tan()),
+    },
+    Function {
+        name: "floor",
+        arity: Arity::Exactly(1),
+        apply: |a| Ok(a[0].floor()),
+    },
+    Function {
+        name: "ceil",
+        arity: Arity::Exactly(1),
+        apply: |a| Ok(a[0].ceil()),
+    },
+    Function {
+        name: "round",
+        arity: Arity::Exactly(1),
+        apply: |a| Ok(a[0].round()),
+    },
+    Function {
+        name: "min",
+        arity: Arity::AtLeast(1),
+        apply: |a| Ok(a.iter().copied().fold(f64::INFINITY, f64::min)),
+    },
+    Function {
+        name: "max",
+        arity: Arity::AtLeast(1),
+        apply: |a| Ok(a.iter().copied().fold(f64::NEG_INFINITY, f64::max)),
+    },
+];
+
+fn log(x: f64, f: fn(f64) -> f64) -> Result<f64, ErrorKind> {
+    if x <= 0.0 {
+        Err(ErrorKind::Domain(
+            "logarithm of a number that is not positive",
+        ))
+    } else {
+        Ok(f(x))
+    }
+}
+
+// คำนวณครั้งเดียวโดยไม่มีตัวแปร (มีแค่ pi, e, tau)
+pub fn evaluate(source: &str) -> Result<f64, CalcError> {
+    Calculator::new().eval(source)
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Calculator {
+    variables: BTreeMap<String, f64>,
+}
+
+impl Calculator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // ตั้งค่าตัวแปรจากโค้ด (ชื่อค่าคงที่ pi, e, tau ใช้ไม่ได้)
+    pub fn set(&mut self, name: &str, value: f64) -> Result<(), ErrorKind> {
+        if is_constant(name) {
+            return Err(ErrorKind::ReadOnly(name.to_string()));
+        }
+        self.variables.insert(name.to_string(), value);
+        Ok(())
+    }
+
+    pub fn get(&self, name: &str) -> Option<f64> {
+        self.variables.get(name).copied().or_else(|| constant(name))
+    }
+
+    pub fn variables(&self) -> impl Iterator<Item = (&str, f64)> {
+        self.variables
+            .iter()
+            .map(|(name, value)| (name.as_str(), *value))
+    }
+
+    // expression อย่างเดียว: "2 * x + 1"
+    pub fn eval(&self, source: &str) -> Result<f64, CalcError> {
+        self.evaluate(&parse(source)?)
+    }
+
+    // รับการกำหนดค่าด้วย: "x = 2 * 3" เก็บ x แล้วคืน 6
+    pub fn run(&mut self, source: &str) -> Result<f64, CalcError> {
+        match parse_statement(source)? {
+            Statement::Expr(expr) => self.evaluate(&expr),
+            Statement::Assign {
+                name,
+                name_span,
+                value,
+            } => {
+                let value = self.evaluate(&value)?;
+                self.set(&name, value)
+                    .map_err(|kind| CalcError::new(kind, name_span))?;
+                Ok(value)
+            }
+        }
+    }
+
+    pub fn evaluate(&self, expr: &Expr) -> Result<f64, CalcError> {
+        let fail = |kind| CalcError::new(kind, expr.span);
+        match &expr.kind {
+            ExprKind::Number(value) => Ok(*value),
+            ExprKind::Variable(name) => self
+                .get(name)
+                .ok_or_else(|| fail(ErrorKind::UnknownVariable(name.clone()))),
+            ExprKind::Neg(inner) => Ok(-self.evaluate(inner)?),
+            ExprKind::Binary { op, lhs, rhs } => {
+                let a = self.evaluate(lhs)?;
+                let b = self.evaluate(rhs)?;
+                binary(*op, a, b, expr.span, rhs.span)
+            }
+            ExprKind::Call { name, args } => {
+                let function = FUNCTIONS
+                    .iter()
+                    .find(|function| function.name == name)
+                    .ok_or_else(|| fail(ErrorKind::UnknownFunction(name.clone())))?;
+                check_arity(function, args.len()).map_err(fail)?;
+                let values = args
+                    .iter()
+                    .map(|arg| self.evaluate(arg))
+                    .collect::<Result<Vec<f64>, _>>()?;
+                (function.apply)(&values).and_then(finite).map_err(fail)
+            }
+        }
+    }
+}
+
+fn is_constant(name: &str) -> bool {
+    constant(name).is_some()
+}
+
+fn constant(name: &str) -> Option<f64> {
+    CONSTANTS
+        .iter()
+        .find(|(constant, _)| *constant == name)
+        .map(|(_, value)| *value)
+}
+
+fn check_arity(function: &Function, got: usize) -> Result<(), ErrorKind> {
+    let (ok, expected) = match function.arity {
+        Arity::Exactly(n) => (got == n, plural(n, "")),
+        Arity::AtLeast(n) => (got >= n, plural(n, "at least ")),
+    };
+    if ok {
+        Ok(())
+    } else {
+        Err(ErrorKind::WrongArgumentCount {
+            name: function.name.to_string(),
+            expected,
+            got,
+        })
+    }
+}
+
+fn plural(n: usize, prefix: &str) -> String {
+    let noun = if n == 1 { "argument" } else { "arguments" };
+    format!("{}{} {}", prefix, n, noun)
+}
+
+// error ชี้ทั้ง expression ยกเว้นหารด้วย 0 ที่ชี้ตัวหาร
+fn binary(op: BinaryOp, a: f64, b: f64, span: Span, rhs: Span) -> Result<f64, CalcError> {
+    let result = match op {
+        BinaryOp::Add => a + b,
+        BinaryOp::Sub => a - b,
+        BinaryOp::Mul => a * b,
+        BinaryOp::Div | BinaryOp::Rem if b == 0.0 => {
+            return Err(CalcError::new(ErrorKind::DivisionByZero, rhs));
+        }
+        BinaryOp::Div => a / b,
+        BinaryOp::Rem => a % b,
+        BinaryOp::Pow if a == 0.0 && b < 0.0 => {
+            return Err(CalcError::new(ErrorKind::DivisionByZero, span));
+        }
+        BinaryOp::Pow if a < 0.0 && b.fract() != 0.0 => {
+            let kind = ErrorKind::Domain("fractional power of a negative number");
+            return Err(CalcError::new(kind, span));
+        }
+        BinaryOp::Pow => a.powf(b),
+    };
+    finite(result).map_err(|kind| CalcError::new(kind, span))
+}
+
+fn finite(value: f64) -> Result<f64, ErrorKind> {
+    if value.is_nan() {
+        Err(ErrorKind::Domain("result is not a number"))
+    } else if value.is_infinite() {
+        Err(ErrorKind::Overflow)
+    } else {
+        Ok(value)
+    }
+}
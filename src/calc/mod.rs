@@ -0,0 +1,22 @@
+// ===========================================
+// Calc - เครื่องคิดเลขจากข้อความ (ต่อยอดจาก divide() บทที่ 9)
+// ===========================================
+//
+// - token.rs:  แยกข้อความเป็น token (ตัวเลข, ชื่อ, เครื่องหมาย)
+// - parser.rs: สร้าง Expr ตามลำดับความสำคัญของเครื่องหมาย
+// - eval.rs:   Calculator คำนวณ Expr พร้อมตัวแปรและฟังก์ชัน (sqrt, min, ...)
+// - error.rs:  CalcError บอกตำแหน่งที่ผิด (Span) และวาด ^^^ ใต้จุดนั้น
+//
+// ทุกขั้นคืน Result และต่อกันด้วย ? : tokenize → parse → evaluate
+// ลำดับความสำคัญ (น้อยไปมาก): + -   * / %   - (ติดลบ)   ^ (ยกกำลัง, จับขวา)
+// เช่น -2^2 = -4 และ 2^3^2 = 2^9
+
+mod error;
+mod eval;
+mod parser;
+mod token;
+
+pub use error::{CalcError, ErrorKind, Span};
+pub use eval::{Calculator, evaluate};
+pub use parser::{BinaryOp, Expr, ExprKind, MAX_DEPTH, Statement, parse, parse_statement};
+pub use token::{Token, TokenKind, tokenize};
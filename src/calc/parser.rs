@@ -0,0 +1,290 @@
+// ===========================================
+// parser.rs - token → Expr (recursive descent)
+// ===========================================
+//
+// statement := NAME '=' expr | expr
+// expr      := term (('+' | '-') term)*
+// term      := unary (('*' | '/' | '%') unary)*
+// unary     := ('-' | '+') unary | power
+// power     := primary ('^' unary)?
+// primary   := NUMBER | NAME | NAME '(' [expr (',' expr)*] ')' | '(' expr ')'
+
+use super::{CalcError, ErrorKind, Span, Token, TokenKind, tokenize};
+
+// วงเล็บ/เครื่องหมายซ้อนกันได้ลึกสุดเท่านี้ (กัน stack overflow)
+pub const MAX_DEPTH: usize = 200;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BinaryOp {
+    Add,
+    Sub,
+    Mul,
+    Div,
+    Rem,
+    Pow,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ExprKind {
+    Number(f64),
+    Variable(String),
+    Neg(Box<Expr>),
+    Binary {
+        op: BinaryOp,
+        lhs: Box<Expr>,
+        rhs: Box<Expr>,
+    },
+    Call {
+        name: String,
+        args: Vec<Expr>,
+    },
+}
+
+// span ครอบทั้ง expression (รวมวงเล็บถ้ามี) ใช้ชี้ตำแหน่งตอนคำนวณผิดพลาด
+#[derive(Debug, Clone, PartialEq)]
+pub struct Expr {
+    pub kind: ExprKind,
+    pub span: Span,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Statement {
+    Expr(Expr),
+    Assign {
+        name: String,
+        name_span: Span,
+        value: Expr,
+    },
+}
+
+pub fn parse(source: &str) -> Result<Expr, CalcError> {
+    let mut parser = Parser::new(source)?;
+    let expr = parser.expr()?;
+    parser.finish()?;
+    Ok(expr)
+}
+
+pub fn parse_statement(source: &str) -> Result<Statement, CalcError> {
+    let mut parser = Parser::new(source)?;
+    let statement = match parser.tokens.as_slice() {
+        [
+            Token {
+                kind: TokenKind::Ident(name),
+                span,
+            },
+            Token {
+                kind: TokenKind::Equals,
+                ..
+            },
+            ..,
+        ] => {
+            let (name, name_span) = (name.clone(), *span);
+            parser.position = 2;
+            Statement::Assign {
+                name,
+                name_span,
+                value: parser.expr()?,
+            }
+        }
+        _ => Statement::Expr(parser.expr()?),
+    };
+    parser.finish()?;
+    Ok(statement)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    position: usize,
+    depth: usize,
+    // ตำแหน่งท้าย input (ใช้กับ error "unexpected end")
+    end: usize,
+}
+
+impl Parser {
+    fn new(source: &str) -> Result<Self, CalcError> {
+        Ok(Self {
+            tokens: tokenize(source)?,
+            position: 0,
+            depth: 0,
+            end: source.chars().count(),
+        })
+    }
+
+    fn peek(&self) -> Option<&TokenKind> {
+        self.tokens.get(self.position).map(|token| &token.kind)
+    }
+
+    fn next(&mut self) -> Option<Token> {
+        let token = self.tokens.get(self.position).cloned();
+        self.position += 1;
+        token
+    }
+
+    // ใช้ token ถัดไปถ้าตรงกับที่ต้องการ
+    fn eat(&mut self, kind: &TokenKind) -> Option<Span> {
+        let token = self.tokens.get(self.position)?;
+        if token.kind == *kind {
+            self.position += 1;
+            Some(token.span)
+        } else {
+            None
+        }
+    }
+
+    fn finish(&mut self) -> Result<(), CalcError> {
+        match self.next() {
+            None => Ok(()),
+            Some(token) => Err(unexpected(&token)),
+        }
+    }
+
+    fn end_error(&self, expected: &'static str) -> CalcError {
+        CalcError::new(
+            ErrorKind::UnexpectedEnd(expected),
+            Span::new(self.end, self.end + 1),
+        )
+    }
+
+    fn binary(op: BinaryOp, lhs: Expr, rhs: Expr) -> Expr {
+        let span = lhs.span.to(rhs.span);
+        Expr {
+            kind: ExprKind::Binary {
+                op,
+                lhs: Box::new(lhs),
+                rhs: Box::new(rhs),
+            },
+            span,
+        }
+    }
+
+    fn expr(&mut self) -> Result<Expr, CalcError> {
+        let mut lhs = self.term()?;
+        loop {
+            let op = match self.peek() {
+                Some(TokenKind::Plus) => BinaryOp::Add,
+                Some(TokenKind::Minus) => BinaryOp::Sub,
+                _ => return Ok(lhs),
+            };
+            self.position += 1;
+            let rhs = self.term()?;
+            lhs = Parser::binary(op, lhs, rhs);
+        }
+    }
+
+    fn term(&mut self) -> Result<Expr, CalcError> {
+        let mut lhs = self.unary()?;
+        loop {
+            let op = match self.peek() {
+                Some(TokenKind::Star) => BinaryOp::Mul,
+                Some(TokenKind::Slash) => BinaryOp::Div,
+                Some(TokenKind::Percent) => BinaryOp::Rem,
+                _ => return Ok(lhs),
+            };
+            self.position += 1;
+            let rhs = self.unary()?;
+            lhs = Parser::binary(op, lhs, rhs);
+        }
+    }
+
+    fn unary(&mut self) -> Result<Expr, CalcError> {
+        self.depth += 1;
+        if self.depth > MAX_DEPTH {
+            let span = self
+                .tokens
+                .get(self.position)
+                .map_or(Span::new(self.end, self.end + 1), |token| token.span);
+            return Err(CalcError::new(ErrorKind::TooDeep, span));
+        }
+        let result = if let Some(sign) = self.eat(&TokenKind::Minus) {
+            self.unary().map(|expr| Expr {
+                span: sign.to(expr.span),
+                kind: ExprKind::Neg(Box::new(expr)),
+            })
+        } else if let Some(sign) = self.eat(&TokenKind::Plus) {
+            self.unary().map(|expr| Expr {
+                span: sign.to(expr.span),
+                ..expr
+            })
+        } else {
+            self.power()
+        };
+        self.depth -= 1;
+        result
+    }
+
+    fn power(&mut self) -> Result<Expr, CalcError> {
+        let base = self.primary()?;
+        if self.eat(&TokenKind::Caret).is_some() {
+            // จับขวา: 2^3^2 = 2^(3^2) และ 2^-1 ได้
+            let exponent = self.unary()?;
+            return Ok(Parser::binary(BinaryOp::Pow, base, exponent));
+        }
+        Ok(base)
+    }
+
+    fn primary(&mut self) -> Result<Expr, CalcError> {
+        let token = self.next().ok_or_else(|| self.end_error("a number"))?;
+        match token.kind {
+            TokenKind::Number(value) => Ok(Expr {
+                kind: ExprKind::Number(value),
+                span: token.span,
+            }),
+            TokenKind::Ident(name) => {
+                if self.eat(&TokenKind::LParen).is_some() {
+                    let (args, close) = self.arguments(token.span)?;
+                    Ok(Expr {
+                        kind: ExprKind::Call { name, args },
+                        span: token.span.to(close),
+                    })
+                } else {
+                    Ok(Expr {
+                        kind: ExprKind::Variable(name),
+                        span: token.span,
+                    })
+                }
+            }
+            TokenKind::LParen => {
+                let inner = self.expr()?;
+                let close = self.close_paren(token.span)?;
+                Ok(Expr {
+                    span: token.span.to(close),
+                    ..inner
+                })
+            }
+            _ => Err(unexpected(&token)),
+        }
+    }
+
+    // หลัง '(' ของการเรียกฟังก์ชัน คืน (args, span ของ ')')
+    fn arguments(&mut self, open: Span) -> Result<(Vec<Expr>, Span), CalcError> {
+        let mut args = Vec::new();
+        if let Some(close) = self.eat(&TokenKind::RParen) {
+            return Ok((args, close));
+        }
+        loop {
+            args.push(self.expr()?);
+            if self.eat(&TokenKind::Comma).is_none() {
+                return Ok((args, self.close_paren(open)?));
+            }
+        }
+    }
+
+    fn close_paren(&mut self, open: Span) -> Result<Span, CalcError> {
+        match self.next() {
+            Some(Token {
+                kind: TokenKind::RParen,
+                span,
+            }) => Ok(span),
+            // ชี้ที่ '(' ที่ไม่มีคู่ ดีกว่าชี้ที่ท้ายบรรทัด
+            None => Err(CalcError::new(ErrorKind::UnclosedParen, open)),
+            Some(token) => Err(unexpected(&token)),
+        }
+    }
+}
+
+fn unexpected(token: &Token) -> CalcError {
+    CalcError::new(
+        ErrorKind::UnexpectedToken(token.kind.to_string()),
+        token.span,
+    )
+}
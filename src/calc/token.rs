@@ -0,0 +1,132 @@
+// ===========================================
+// token.rs - แยก input เป็น token
+// ===========================================
+
+use super::{CalcError, ErrorKind, Span};
+use std::fmt;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenKind {
+    Number(f64),
+    Ident(String),
+    Plus,
+    Minus,
+    Star,
+    Slash,
+    Percent,
+    Caret,
+    LParen,
+    RParen,
+    Comma,
+    Equals,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Token {
+    pub kind: TokenKind,
+    pub span: Span,
+}
+
+impl fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let symbol = match self {
+            TokenKind::Number(n) => return write!(f, "number {}", n),
+            TokenKind::Ident(name) => return write!(f, "name '{}'", name),
+            TokenKind::Plus => "+",
+            TokenKind::Minus => "-",
+            TokenKind::Star => "*",
+            TokenKind::Slash => "/",
+            TokenKind::Percent => "%",
+            TokenKind::Caret => "^",
+            TokenKind::LParen => "(",
+            TokenKind::RParen => ")",
+            TokenKind::Comma => ",",
+            TokenKind::Equals => "=",
+        };
+        write!(f, "'{}'", symbol)
+    }
+}
+
+// ตัวเลข: 42, 3.14, .5, 1e-3   ชื่อ: x, rate_2, sqrt
+pub fn tokenize(source: &str) -> Result<Vec<Token>, CalcError> {
+    let chars: Vec<char> = source.chars().collect();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let start = i;
+        let kind = match c {
+            _ if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '0'..='9' | '.' => {
+                i = end_of_number(&chars, i);
+                let text: String = chars[start..i].iter().collect();
+                match text.parse::<f64>() {
+                    Ok(value) if value.is_finite() => TokenKind::Number(value),
+                    _ => {
+                        return Err(CalcError::new(
+                            ErrorKind::InvalidNumber(text),
+                            Span::new(start, i),
+                        ));
+                    }
+                }
+            }
+            _ if c.is_alphabetic() || c == '_' => {
+                while i < chars.len() && (chars[i].is_alphanumeric() || chars[i] == '_') {
+                    i += 1;
+                }
+                TokenKind::Ident(chars[start..i].iter().collect())
+            }
+            _ => {
+                i += 1;
+                match c {
+                    '+' => TokenKind::Plus,
+                    '-' => TokenKind::Minus,
+                    '*' | '×' => TokenKind::Star,
+                    '/' | '÷' => TokenKind::Slash,
+                    '%' => TokenKind::Percent,
+                    '^' => TokenKind::Caret,
+                    '(' => TokenKind::LParen,
+                    ')' => TokenKind::RParen,
+                    ',' => TokenKind::Comma,
+                    '=' => TokenKind::Equals,
+                    _ => {
+                        return Err(CalcError::new(
+                            ErrorKind::UnexpectedChar(c),
+                            Span::new(start, i),
+                        ));
+                    }
+                }
+            }
+        };
+        tokens.push(Token {
+            kind,
+            span: Span::new(start, i),
+        });
+    }
+    Ok(tokens)
+}
+
+// กินตัวเลข จุด และ exponent (นับเมื่อ e ตามด้วยตัวเลขเท่านั้น: "2e" = เลข 2 ตามด้วยชื่อ e)
+// ตัวเลขผิดรูป เช่น 1.2.3 ถูกกินทั้งก้อนเพื่อให้ error ครอบทั้งตัวเลข
+fn end_of_number(chars: &[char], mut i: usize) -> usize {
+    while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == '.') {
+        i += 1;
+    }
+    if matches!(chars.get(i), Some('e' | 'E')) {
+        let digits_at = match chars.get(i + 1) {
+            Some('+' | '-') => i + 2,
+            _ => i + 1,
+        };
+        if chars.get(digits_at).is_some_and(char::is_ascii_digit) {
+            i = digits_at;
+            while i < chars.len() && chars[i].is_ascii_digit() {
+                i += 1;
+            }
+        }
+    }
+    i
+}
@@ -0,0 +1,133 @@
+// ===========================================
+// coins.rs - ชนิดเหรียญและชุดเหรียญ
+// ===========================================
+
+use super::ChangeError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+// เหรียญ US แบบเดียวกับ examples/structs_enums.rs
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Coin {
+    Penny,
+    Nickel,
+    Dime,
+    Quarter,
+}
+
+impl Coin {
+    pub const ALL: [Coin; 4] = [Coin::Penny, Coin::Nickel, Coin::Dime, Coin::Quarter];
+
+    pub fn value_in_cents(self) -> u32 {
+        match self {
+            Coin::Penny => 1,
+            Coin::Nickel => 5,
+            Coin::Dime => 10,
+            Coin::Quarter => 25,
+        }
+    }
+}
+
+// เหรียญหนึ่งชนิดในชุด (value เป็นหน่วยเล็กที่สุดของสกุลเงิน)
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct Denomination {
+    pub name: String,
+    pub value: u32,
+}
+
+impl fmt::Display for Denomination {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.name)
+    }
+}
+
+// อ่านจาก JSON ผ่าน CoinSet::new เสมอ (เหรียญค่า 0 จะทำให้หารด้วยศูนย์ตอนทอน)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "RawCoinSet")]
+pub struct CoinSet {
+    pub name: String,
+    // เรียงจากค่ามากไปน้อย
+    coins: Vec<Denomination>,
+}
+
+// รูปแบบใน JSON ก่อนตรวจ
+#[derive(Deserialize)]
+struct RawCoinSet {
+    name: String,
+    coins: Vec<Denomination>,
+}
+
+impl TryFrom<RawCoinSet> for CoinSet {
+    type Error = ChangeError;
+
+    fn try_from(raw: RawCoinSet) -> Result<Self, Self::Error> {
+        CoinSet::new(raw.name, raw.coins)
+    }
+}
+
+impl CoinSet {
+    // ค่าต้องมากกว่า 0 และไม่ซ้ำกัน
+    pub fn new(
+        name: impl Into<String>,
+        coins: impl IntoIterator<Item = Denomination>,
+    ) -> Result<Self, ChangeError> {
+        let mut coins: Vec<Denomination> = coins.into_iter().collect();
+        if coins.is_empty() {
+            return Err(ChangeError::EmptySet);
+        }
+        if coins.iter().any(|coin| coin.value == 0) {
+            return Err(ChangeError::ZeroCoin);
+        }
+        coins.sort_by_key(|coin| std::cmp::Reverse(coin.value));
+        if let Some(pair) = coins.windows(2).find(|pair| pair[0].value == pair[1].value) {
+            return Err(ChangeError::DuplicateCoin(pair[0].value));
+        }
+        Ok(Self {
+            name: name.into(),
+            coins,
+        })
+    }
+
+    // ชื่อเหรียญเป็นตัวเลขของค่า
+    pub fn custom(values: &[u32]) -> Result<Self, ChangeError> {
+        let coins = values.iter().map(|&value| Denomination {
+            name: value.to_string(),
+            value,
+        });
+        CoinSet::new("custom", coins)
+    }
+
+    // หน่วยเป็น cent
+    pub fn us() -> Self {
+        let coins = Coin::ALL.map(|coin| Denomination {
+            name: format!("{:?}", coin),
+            value: coin.value_in_cents(),
+        });
+        CoinSet::new("US", coins).expect("US coins are valid")
+    }
+
+    // เหรียญบาทที่ใช้อยู่ หน่วยเป็นสตางค์ (1 บาท = 100 สตางค์)
+    pub fn thai_baht() -> Self {
+        let coins = [
+            ("25 สตางค์", 25),
+            ("50 สตางค์", 50),
+            ("1 บาท", 100),
+            ("2 บาท", 200),
+            ("5 บาท", 500),
+            ("10 บาท", 1000),
+        ]
+        .map(|(name, value)| Denomination {
+            name: name.to_string(),
+            value,
+        });
+        CoinSet::new("Thai baht", coins).expect("baht coins are valid")
+    }
+
+    pub fn coins(&self) -> &[Denomination] {
+        &self.coins
+    }
+
+    pub fn get(&self, value: u32) -> Option<&Denomination> {
+        self.coins.iter().find(|coin| coin.value == value)
+    }
+}
@@ -0,0 +1,29 @@
+// ===========================================
+// error.rs - error ของการทอนเงิน
+// ===========================================
+
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ChangeError {
+    #[error("a coin set needs at least one coin")]
+    EmptySet,
+
+    #[error("coin value must be greater than 0")]
+    ZeroCoin,
+
+    #[error("coin value {0} appears more than once")]
+    DuplicateCoin(u32),
+
+    #[error("{0} is not a coin in this set")]
+    UnknownCoin(u32),
+
+    #[error("{amount} cannot be paid exactly with these coins")]
+    NoExactChange { amount: u32 },
+
+    #[error("amount {amount} is larger than the limit of {max}")]
+    TooLarge { amount: u32, max: u32 },
+
+    #[error("the number of ways to pay {amount} does not fit in 128 bits")]
+    TooManyWays { amount: u32 },
+}
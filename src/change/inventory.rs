@@ -0,0 +1,148 @@
+// ===========================================
+// inventory.rs - ทอนจากเหรียญที่มีอยู่จริง (ตู้กดน้ำ, ลิ้นชักเก็บเงิน)
+// ===========================================
+//
+// เหรียญมีจำกัด คำตอบแบบไม่จำกัดอาจใช้ไม่ได้ เช่น มีเหรียญ 5 บาทเหลือ 1 เหรียญ
+// จึงต้องหาใหม่ด้วย DP แบบ bounded: แตกเหรียญ c อันเป็นกอง 1, 2, 4, ... (รวมได้ทุกจำนวน 0..c)
+// แล้วเลือกแต่ละกองแบบ ใช้ / ไม่ใช้ (0/1 knapsack)
+
+use super::solve::check_amount;
+use super::{Breakdown, ChangeError, CoinSet};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Inventory {
+    set: CoinSet,
+    // จำนวนเหรียญ เรียงตาม set.coins()
+    counts: Vec<u32>,
+}
+
+impl Inventory {
+    // เริ่มจากไม่มีเหรียญเลย
+    pub fn new(set: CoinSet) -> Self {
+        let counts = vec![0; set.coins().len()];
+        Self { set, counts }
+    }
+
+    pub fn set(&self) -> &CoinSet {
+        &self.set
+    }
+
+    // เติมเหรียญ (value ต้องอยู่ในชุด)
+    pub fn add(&mut self, value: u32, count: u32) -> Result<(), ChangeError> {
+        let index = self.index(value)?;
+        self.counts[index] = self.counts[index].saturating_add(count);
+        Ok(())
+    }
+
+    pub fn count(&self, value: u32) -> u32 {
+        self.index(value).map_or(0, |index| self.counts[index])
+    }
+
+    // มูลค่ารวมของเหรียญทั้งหมด
+    pub fn total(&self) -> u64 {
+        self.set
+            .coins()
+            .iter()
+            .zip(&self.counts)
+            .map(|(coin, count)| u64::from(coin.value) * u64::from(*count))
+            .sum()
+    }
+
+    // ทอนด้วยเหรียญน้อยที่สุดจากที่มี (ยังไม่หักเหรียญออก)
+    pub fn make_change(&self, amount: u32) -> Result<Breakdown, ChangeError> {
+        check_amount(amount)?;
+        if self.total() < u64::from(amount) {
+            return Err(ChangeError::NoExactChange { amount });
+        }
+
+        // กองเหรียญ (index ของเหรียญ, จำนวนในกอง) - ไม่ต้องมีเกินกว่าที่ทอนได้
+        let mut lots: Vec<(usize, u32)> = Vec::new();
+        for (index, coin) in self.set.coins().iter().enumerate() {
+            let mut remaining = self.counts[index].min(amount / coin.value);
+            let mut size = 1;
+            while remaining > 0 {
+                let take = size.min(remaining);
+                lots.push((index, take));
+                remaining -= take;
+                size *= 2;
+            }
+        }
+
+        let size = amount as usize + 1;
+        let mut best = vec![u32::MAX; size];
+        best[0] = 0;
+        // taken[lot] = จำนวนเงินที่ใช้กองนี้ (bitset)
+        let words = size.div_ceil(64);
+        let mut taken = vec![vec![0u64; words]; lots.len()];
+        for (lot, &(index, count)) in lots.iter().enumerate() {
+            let value = (self.set.coins()[index].value * count) as usize;
+            for a in (value..size).rev() {
+                let from = best[a - value];
+                if from != u32::MAX && from + count < best[a] {
+                    best[a] = from + count;
+                    taken[lot][a / 64] |= 1 << (a % 64);
+                }
+            }
+        }
+        if best[amount as usize] == u32::MAX {
+            return Err(ChangeError::NoExactChange { amount });
+        }
+
+        // ย้อนจากกองสุดท้าย: ถ้ากองนี้ถูกใช้ที่จำนวน a ก็หักออกแล้วดูกองก่อนหน้า
+        let mut counts = vec![0; self.counts.len()];
+        let mut a = amount as usize;
+        for (lot, &(index, count)) in lots.iter().enumerate().rev() {
+            if taken[lot][a / 64] & (1 << (a % 64)) != 0 {
+                counts[index] += count;
+                a -= (self.set.coins()[index].value * count) as usize;
+            }
+        }
+        debug_assert_eq!(a, 0);
+        Ok(Breakdown::from_counts(&self.set, amount, &counts))
+    }
+
+    // ทอนแล้วหักเหรียญออก (ทอนไม่ได้ = ไม่หักอะไรเลย)
+    pub fn dispense(&mut self, amount: u32) -> Result<Breakdown, ChangeError> {
+        let change = self.make_change(amount)?;
+        for (coin, count) in &change.coins {
+            let index = self.index(coin.value)?;
+            self.counts[index] -= count;
+        }
+        Ok(change)
+    }
+
+    // จำนวนวิธีทอนจากเหรียญที่มี
+    pub fn count_ways(&self, amount: u32) -> Result<u128, ChangeError> {
+        check_amount(amount)?;
+        let size = amount as usize + 1;
+        let mut ways = vec![0u128; size];
+        ways[0] = 1;
+        for (coin, &count) in self.set.coins().iter().zip(&self.counts) {
+            let value = coin.value as usize;
+            let previous = ways.clone();
+            // ways[a] = previous[a] + previous[a-v] + ... + previous[a-count*v]
+            // = previous[a] + (ways[a-v] - previous[a-(count+1)*v])
+            for a in value..size {
+                let mut window = ways[a - value];
+                if let Some(outside) = (count as usize + 1)
+                    .checked_mul(value)
+                    .and_then(|span| a.checked_sub(span))
+                {
+                    window -= previous[outside];
+                }
+                ways[a] = previous[a]
+                    .checked_add(window)
+                    .ok_or(ChangeError::TooManyWays { amount })?;
+            }
+        }
+        Ok(ways[amount as usize])
+    }
+
+    fn index(&self, value: u32) -> Result<usize, ChangeError> {
+        self.set
+            .coins()
+            .iter()
+            .position(|coin| coin.value == value)
+            .ok_or(ChangeError::UnknownCoin(value))
+    }
+}
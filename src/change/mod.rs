@@ -0,0 +1,20 @@
+// ===========================================
+// Change - ทอนเงินด้วยเหรียญน้อยที่สุด (ต่อยอดจาก enum Coin ในบทที่ 6)
+// ===========================================
+//
+// - coins.rs:     Coin (เหรียญ US), Denomination, CoinSet (US, เหรียญบาท, กำหนดเอง)
+// - solve.rs:     จำนวนเหรียญน้อยที่สุด (dynamic programming) และจำนวนวิธีทอน
+// - inventory.rs: ทอนจากเหรียญที่มีจำกัด (เช่น ตู้กดน้ำ)
+// - error.rs:     ChangeError
+//
+// จำนวนเงินเป็นหน่วยเล็กที่สุดเสมอ (cent, สตางค์) จะได้ไม่มีปัญหาทศนิยมของ f64
+
+mod coins;
+mod error;
+mod inventory;
+mod solve;
+
+pub use coins::{Coin, CoinSet, Denomination};
+pub use error::ChangeError;
+pub use inventory::Inventory;
+pub use solve::{Breakdown, MAX_AMOUNT, count_ways, min_coins};
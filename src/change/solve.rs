@@ -0,0 +1,118 @@
+// ===========================================
+// solve.rs - ทอนเงินเมื่อมีเหรียญทุกชนิดไม่จำกัด
+// ===========================================
+//
+// greedy (หยิบเหรียญใหญ่สุดก่อน) ได้คำตอบดีที่สุดกับเหรียญ US และเหรียญบาท
+// แต่ไม่ใช่กับทุกชุด เช่น เหรียญ 1, 3, 4 ทอน 6: greedy ได้ 4+1+1 แต่ 3+3 ใช้น้อยกว่า
+// จึงใช้ dynamic programming: คำตอบของทุกจำนวนตั้งแต่ 0 ถึง amount
+
+use super::{ChangeError, CoinSet, Denomination};
+use std::fmt;
+
+// ตาราง DP ใช้ memory ตามจำนวนเงิน จึงจำกัดไว้ (1,000,000 สตางค์ = 10,000 บาท)
+pub const MAX_AMOUNT: u32 = 1_000_000;
+
+const UNREACHABLE: u32 = u32::MAX;
+
+// เหรียญที่ใช้ทอน เรียงจากค่ามากไปน้อย (ไม่มีชนิดที่ใช้ 0 เหรียญ)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Breakdown {
+    pub amount: u32,
+    pub coins: Vec<(Denomination, u32)>,
+}
+
+impl Breakdown {
+    // counts เรียงตาม set.coins()
+    pub(crate) fn from_counts(set: &CoinSet, amount: u32, counts: &[u32]) -> Self {
+        let coins = set
+            .coins()
+            .iter()
+            .zip(counts)
+            .filter(|(_, count)| **count > 0)
+            .map(|(coin, count)| (coin.clone(), *count))
+            .collect();
+        Self { amount, coins }
+    }
+
+    pub fn coin_count(&self) -> u32 {
+        self.coins.iter().map(|(_, count)| count).sum()
+    }
+
+    pub fn count_of(&self, value: u32) -> u32 {
+        self.coins
+            .iter()
+            .find(|(coin, _)| coin.value == value)
+            .map_or(0, |(_, count)| *count)
+    }
+}
+
+impl fmt::Display for Breakdown {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.coins.is_empty() {
+            return write!(f, "no coins");
+        }
+        let parts: Vec<String> = self
+            .coins
+            .iter()
+            .map(|(coin, count)| format!("{} × {}", count, coin))
+            .collect();
+        write!(f, "{} ({} coins)", parts.join(" + "), self.coin_count())
+    }
+}
+
+pub(crate) fn check_amount(amount: u32) -> Result<(), ChangeError> {
+    if amount > MAX_AMOUNT {
+        return Err(ChangeError::TooLarge {
+            amount,
+            max: MAX_AMOUNT,
+        });
+    }
+    Ok(())
+}
+
+// จำนวนเหรียญน้อยที่สุด (เท่ากันหลายแบบ เลือกแบบที่ใช้เหรียญใหญ่กว่า)
+pub fn min_coins(set: &CoinSet, amount: u32) -> Result<Breakdown, ChangeError> {
+    check_amount(amount)?;
+    let size = amount as usize + 1;
+    // best[a] = จำนวนเหรียญน้อยสุดที่รวมได้ a, last[a] = เหรียญสุดท้ายที่ใช้ (index ใน set)
+    let mut best = vec![UNREACHABLE; size];
+    let mut last = vec![0; size];
+    best[0] = 0;
+    for a in 1..size {
+        for (index, coin) in set.coins().iter().enumerate() {
+            let value = coin.value as usize;
+            if value <= a && best[a - value] != UNREACHABLE && best[a - value] + 1 < best[a] {
+                best[a] = best[a - value] + 1;
+                last[a] = index;
+            }
+        }
+    }
+    if best[amount as usize] == UNREACHABLE {
+        return Err(ChangeError::NoExactChange { amount });
+    }
+
+    let mut counts = vec![0; set.coins().len()];
+    let mut a = amount as usize;
+    while a > 0 {
+        counts[last[a]] += 1;
+        a -= set.coins()[last[a]].value as usize;
+    }
+    Ok(Breakdown::from_counts(set, amount, &counts))
+}
+
+// จำนวนวิธีทอน (ไม่สนลำดับ: 5+1 กับ 1+5 คือวิธีเดียวกัน)
+pub fn count_ways(set: &CoinSet, amount: u32) -> Result<u128, ChangeError> {
+    check_amount(amount)?;
+    let mut ways = vec![0u128; amount as usize + 1];
+    ways[0] = 1;
+    // วนเหรียญข้างนอก แต่ละวิธีจึงถูกนับครั้งเดียว
+    for coin in set.coins() {
+        let value = coin.value as usize;
+        for a in value..ways.len() {
+            ways[a] = ways[a]
+                .checked_add(ways[a - value])
+                .ok_or(ChangeError::TooManyWays { amount })?;
+        }
+    }
+    Ok(ways[amount as usize])
+}
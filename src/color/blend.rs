@@ -0,0 +1,40 @@
+// ===========================================
+// blend.rs - ซ้อนสีโปร่งแสงและผสมสี
+// ===========================================
+
+use super::Color;
+
+impl Color {
+    // วาง self (โปร่งแสงได้) ทับบน background แบบ "source over" ของ CSS/Canvas
+    pub fn over(self, background: Color) -> Color {
+        let top = self.a as f64 / 255.0;
+        let bottom = background.a as f64 / 255.0;
+        let alpha = top + bottom * (1.0 - top);
+        if alpha == 0.0 {
+            return Color::TRANSPARENT;
+        }
+        let channel = |front: u8, back: u8| {
+            let value = (front as f64 * top + back as f64 * bottom * (1.0 - top)) / alpha;
+            value.round() as u8
+        };
+        Color::rgba(
+            channel(self.r, background.r),
+            channel(self.g, background.g),
+            channel(self.b, background.b),
+            (alpha * 255.0).round() as u8,
+        )
+    }
+
+    // ผสมแบบเส้นตรงทุกช่อง (รวม alpha): t = 0 ได้ self, t = 1 ได้ other
+    pub fn mix(self, other: Color, t: f64) -> Color {
+        let t = t.clamp(0.0, 1.0);
+        let channel =
+            |from: u8, to: u8| (from as f64 + (to as f64 - from as f64) * t).round() as u8;
+        Color::rgba(
+            channel(self.r, other.r),
+            channel(self.g, other.g),
+            channel(self.b, other.b),
+            channel(self.a, other.a),
+        )
+    }
+}
@@ -0,0 +1,70 @@
+// ===========================================
+// contrast.rs - ความต่างของสีตาม WCAG 2 (อ่านตัวหนังสือบนพื้นหลังออกไหม)
+// ===========================================
+//
+// contrast ratio อยู่ระหว่าง 1:1 (สีเดียวกัน) ถึง 21:1 (ดำบนขาว)
+// คิดจากสีทึบเท่านั้น: สีโปร่งแสงให้ over() บนพื้นหลังก่อน
+
+use super::Color;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum WcagLevel {
+    AA,
+    AAA,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextSize {
+    Normal,
+    // 18pt ขึ้นไป หรือ 14pt ตัวหนา
+    Large,
+}
+
+impl WcagLevel {
+    pub fn min_ratio(self, text: TextSize) -> f64 {
+        match (self, text) {
+            (WcagLevel::AA, TextSize::Normal) => 4.5,
+            (WcagLevel::AA, TextSize::Large) => 3.0,
+            (WcagLevel::AAA, TextSize::Normal) => 7.0,
+            (WcagLevel::AAA, TextSize::Large) => 4.5,
+        }
+    }
+}
+
+impl Color {
+    // ความสว่างตามที่ตามองเห็น 0.0 (ดำ) - 1.0 (ขาว)
+    pub fn relative_luminance(self) -> f64 {
+        let linear = |channel: u8| {
+            let c = channel as f64 / 255.0;
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+    }
+
+    // สลับลำดับได้ผลเท่ากัน
+    pub fn contrast_ratio(self, other: Color) -> f64 {
+        let (a, b) = (self.relative_luminance(), other.relative_luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    // ระดับสูงสุดที่ผ่าน (None = ไม่ผ่านแม้แต่ AA)
+    pub fn wcag_level(self, background: Color, text: TextSize) -> Option<WcagLevel> {
+        let ratio = self.contrast_ratio(background);
+        [WcagLevel::AAA, WcagLevel::AA]
+            .into_iter()
+            .find(|level| ratio >= level.min_ratio(text))
+    }
+
+    // ดำหรือขาว อันไหนอ่านบนพื้นนี้ได้ชัดกว่า
+    pub fn readable_text_color(self) -> Color {
+        if self.contrast_ratio(Color::BLACK) >= self.contrast_ratio(Color::WHITE) {
+            Color::BLACK
+        } else {
+            Color::WHITE
+        }
+    }
+}
@@ -0,0 +1,17 @@
+// ===========================================
+// error.rs - error ของการอ่านสี
+// ===========================================
+
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum ColorError {
+    #[error("empty color")]
+    Empty,
+
+    #[error("'{0}' is not a hex color (use #rgb, #rgba, #rrggbb or #rrggbbaa)")]
+    InvalidHex(String),
+
+    #[error("unknown color name '{0}'")]
+    UnknownName(String),
+}
@@ -0,0 +1,121 @@
+// ===========================================
+// hsl.rs - แปลง RGB ไป-กลับ HSL และ HSV
+// ===========================================
+//
+// h = มุมบนวงล้อสี 0-360 องศา (0 = แดง, 120 = เขียว, 240 = น้ำเงิน)
+// s, l, v = 0.0-1.0
+// alpha ไม่ได้อยู่ใน Hsl/Hsv จึงส่งแยก (แปลงกลับจาก Color เก็บ alpha เดิมไว้ไม่ได้)
+
+use super::Color;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Hsl {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Hsv {
+    pub h: f64,
+    pub s: f64,
+    pub v: f64,
+}
+
+impl Hsl {
+    // h วนรอบ (-30 = 330), s และ l ถูกบีบให้อยู่ใน 0-1
+    pub fn new(h: f64, s: f64, l: f64) -> Self {
+        Self {
+            h: h.rem_euclid(360.0),
+            s: s.clamp(0.0, 1.0),
+            l: l.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Hsv {
+    pub fn new(h: f64, s: f64, v: f64) -> Self {
+        Self {
+            h: h.rem_euclid(360.0),
+            s: s.clamp(0.0, 1.0),
+            v: v.clamp(0.0, 1.0),
+        }
+    }
+}
+
+impl Color {
+    pub fn to_hsl(self) -> Hsl {
+        let (h, max, min) = hue(self);
+        let l = (max + min) / 2.0;
+        let delta = max - min;
+        let s = if delta == 0.0 {
+            0.0
+        } else {
+            delta / (1.0 - (2.0 * l - 1.0).abs())
+        };
+        Hsl::new(h, s, l)
+    }
+
+    pub fn from_hsl(hsl: Hsl) -> Color {
+        let hsl = Hsl::new(hsl.h, hsl.s, hsl.l);
+        let chroma = (1.0 - (2.0 * hsl.l - 1.0).abs()) * hsl.s;
+        from_chroma(hsl.h, chroma, hsl.l - chroma / 2.0)
+    }
+
+    pub fn to_hsv(self) -> Hsv {
+        let (h, max, min) = hue(self);
+        let s = if max == 0.0 { 0.0 } else { (max - min) / max };
+        Hsv::new(h, s, max)
+    }
+
+    pub fn from_hsv(hsv: Hsv) -> Color {
+        let hsv = Hsv::new(hsv.h, hsv.s, hsv.v);
+        let chroma = hsv.v * hsv.s;
+        from_chroma(hsv.h, chroma, hsv.v - chroma)
+    }
+
+    // ปรับความสว่าง (HSL) เช่น 0.1 = สว่างขึ้น 10%
+    pub fn lighten(self, amount: f64) -> Color {
+        let hsl = self.to_hsl();
+        Color::from_hsl(Hsl::new(hsl.h, hsl.s, hsl.l + amount)).with_alpha(self.a)
+    }
+
+    pub fn darken(self, amount: f64) -> Color {
+        self.lighten(-amount)
+    }
+}
+
+// (มุม h, ค่ามากสุด, ค่าน้อยสุด) ของ r g b ในช่วง 0-1
+fn hue(color: Color) -> (f64, f64, f64) {
+    let [r, g, b] = [color.r, color.g, color.b].map(|c| c as f64 / 255.0);
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let h = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    (h, max, min)
+}
+
+// สูตรร่วมของ HSL และ HSV: chroma = ช่วงกว้างของสี, offset = ค่าที่บวกทุกช่อง
+fn from_chroma(h: f64, chroma: f64, offset: f64) -> Color {
+    let sector = h / 60.0;
+    let x = chroma * (1.0 - (sector.rem_euclid(2.0) - 1.0).abs());
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    let channel = |value: f64| ((value + offset) * 255.0).round().clamp(0.0, 255.0) as u8;
+    Color::rgb(channel(r), channel(g), channel(b))
+}
@@ -0,0 +1,24 @@
+// ===========================================
+// Color - สี RGBA (ต่อยอดจาก tuple struct Color(u8, u8, u8) บทที่ 5)
+// ===========================================
+//
+// - rgb.rs:      Color, อ่าน/เขียน #rrggbb, #rgb และชื่อสี CSS (FromStr, Display, serde)
+// - names.rs:    ตารางชื่อสี CSS
+// - hsl.rs:      แปลงไป-กลับ HSL และ HSV
+// - blend.rs:    ซ้อนสีโปร่งแสง (alpha) และผสมสี
+// - contrast.rs: ความต่างของสีตาม WCAG (อ่านตัวหนังสือออกไหม)
+// - error.rs:    ColorError
+//
+// ไม่ใช้ crate ภายนอก (นอกจาก serde)
+
+mod blend;
+mod contrast;
+mod error;
+mod hsl;
+mod names;
+mod rgb;
+
+pub use contrast::{TextSize, WcagLevel};
+pub use error::ColorError;
+pub use hsl::{Hsl, Hsv};
+pub use rgb::Color;
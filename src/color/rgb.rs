@@ -0,0 +1,133 @@
+// ===========================================
+// rgb.rs - Color (RGBA 8 บิตต่อช่อง) และรูปแบบข้อความ
+// ===========================================
+//
+// "#ff8800", "#f80", "#ff880080" (มี alpha), "orange", "Transparent"
+// Display เขียนเป็น #rrggbb (หรือ #rrggbbaa ถ้าโปร่งแสง) ตัวพิมพ์เล็ก
+// serde ใช้ข้อความแบบเดียวกัน: "#ff8800"
+
+use super::{ColorError, names};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Color {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+    // 0 = โปร่งใส, 255 = ทึบ
+    pub a: u8,
+}
+
+impl Color {
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    pub const TRANSPARENT: Color = Color::rgba(0, 0, 0, 0);
+
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b, a: 255 }
+    }
+
+    pub const fn rgba(r: u8, g: u8, b: u8, a: u8) -> Self {
+        Self { r, g, b, a }
+    }
+
+    // 0xRRGGBB
+    pub const fn from_hex(hex: u32) -> Self {
+        Color::rgb((hex >> 16) as u8, (hex >> 8) as u8, hex as u8)
+    }
+
+    pub fn with_alpha(self, a: u8) -> Self {
+        Self { a, ..self }
+    }
+
+    pub fn is_opaque(self) -> bool {
+        self.a == 255
+    }
+
+    // ชื่อสี CSS ถ้าตรงพอดี (สีทึบเท่านั้น ยกเว้น transparent)
+    pub fn name(self) -> Option<&'static str> {
+        names::name_of(self)
+    }
+
+    pub fn to_hex(self) -> String {
+        self.to_string()
+    }
+
+    // "#rgb", "#rgba", "#rrggbb", "#rrggbbaa"
+    fn parse_hex(text: &str, digits: &str) -> Result<Self, ColorError> {
+        let invalid = || ColorError::InvalidHex(text.to_string());
+        if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+        let channel = |index: usize, width: usize| {
+            let value = u8::from_str_radix(&digits[index * width..(index + 1) * width], 16)
+                .expect("checked hex digits");
+            // #f80 = #ff8800
+            if width == 1 { value * 17 } else { value }
+        };
+        let width = match digits.len() {
+            3 | 4 => 1,
+            6 | 8 => 2,
+            _ => return Err(invalid()),
+        };
+        let a = if digits.len() / width == 4 {
+            channel(3, width)
+        } else {
+            255
+        };
+        Ok(Color::rgba(
+            channel(0, width),
+            channel(1, width),
+            channel(2, width),
+            a,
+        ))
+    }
+}
+
+impl FromStr for Color {
+    type Err = ColorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let text = s.trim();
+        if text.is_empty() {
+            return Err(ColorError::Empty);
+        }
+        match text.strip_prefix('#') {
+            Some(digits) => Color::parse_hex(text, digits),
+            None => names::lookup(text).ok_or_else(|| ColorError::UnknownName(text.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)?;
+        if !self.is_opaque() {
+            write!(f, "{:02x}", self.a)?;
+        }
+        Ok(())
+    }
+}
+
+impl TryFrom<String> for Color {
+    type Error = ColorError;
+
+    fn try_from(text: String) -> Result<Self, Self::Error> {
+        text.parse()
+    }
+}
+
+impl From<Color> for String {
+    fn from(color: Color) -> Self {
+        color.to_string()
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from((r, g, b): (u8, u8, u8)) -> Self {
+        Color::rgb(r, g, b)
+    }
+}
@@ -0,0 +1,51 @@
+// ===========================================
+// error.rs - error ของการโหลด config
+// ===========================================
+//
+// แยก "ไม่มี" (Missing) ออกจาก "มีแต่ใช้ไม่ได้" (Unreadable, Parse, InvalidValue)
+
+use super::Source;
+use std::io;
+use std::path::PathBuf;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("config file {} does not exist", .0.display())]
+    Missing(PathBuf),
+
+    #[error("cannot read config file {}: {source}", .path.display())]
+    Unreadable {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("{} is not valid {format}: {message}", .path.display())]
+    Parse {
+        path: PathBuf,
+        format: &'static str,
+        message: String,
+    },
+
+    #[error("{} has an unsupported format (use .toml or .json)", .0.display())]
+    UnsupportedFormat(PathBuf),
+
+    #[error("'{0}' is not a key=value override")]
+    InvalidOverride(String),
+
+    // origin = ค่านี้มาจากไหน (ชื่อ source ใช้ไม่ได้ เพราะ thiserror ถือว่าเป็น error ต้นเหตุ)
+    #[error("unknown key '{key}' (from {origin})")]
+    UnknownKey { key: String, origin: Source },
+
+    #[error("invalid value for '{key}' (from {origin}): {message}")]
+    InvalidValue {
+        key: String,
+        origin: Source,
+        message: String,
+    },
+
+    // ค่าที่รวมแล้วแปลงเป็น struct ไม่ได้ (เช่น ขาดฟิลด์ที่ไม่มี default)
+    #[error("invalid configuration: {0}")]
+    Invalid(String),
+}
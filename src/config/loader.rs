@@ -0,0 +1,333 @@
+// ===========================================
+// loader.rs - ConfigLoader: เพิ่มชั้นทีละชั้น แล้ว load() ครั้งเดียว
+// ===========================================
+//
+//   let config: Loaded<AppConfig> = ConfigLoader::new()
+//       .defaults(&AppConfig::default())
+//       .optional_file("app.toml")
+//       .env("APP")                    // APP_SERVER__PORT → server.port
+//       .overrides(["server.port=9000"])
+//       .load()?;
+//   println!("{}", config.report());  // ค่าไหนมาจากไหน
+
+use super::value::{coerce, flatten, kind_name, same_kind, unflatten};
+use super::{ConfigError, Source};
+use serde::Serialize;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+enum Layer {
+    Defaults(Result<Value, String>),
+    File {
+        path: PathBuf,
+        required: bool,
+    },
+    Env {
+        prefix: String,
+        vars: Vec<(String, String)>,
+    },
+    Overrides(Vec<String>),
+}
+
+#[derive(Default)]
+pub struct ConfigLoader {
+    layers: Vec<Layer>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Loaded<T> {
+    pub value: T,
+    entries: BTreeMap<String, (Value, Source)>,
+    missing_files: Vec<PathBuf>,
+}
+
+impl ConfigLoader {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // ค่าเริ่มต้น และรายชื่อ key ที่มีได้ (key อื่นถือว่าสะกดผิด)
+    pub fn defaults<T: Serialize>(mut self, defaults: &T) -> Self {
+        let value = serde_json::to_value(defaults).map_err(|e| e.to_string());
+        self.layers.push(Layer::Defaults(value));
+        self
+    }
+
+    // ต้องมีไฟล์นี้ (.toml หรือ .json)
+    pub fn file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.layers.push(Layer::File {
+            path: path.into(),
+            required: true,
+        });
+        self
+    }
+
+    // ไม่มีไฟล์ก็ได้ แต่ถ้ามีแล้วอ่านไม่ได้หรือผิดรูปแบบยังเป็น error
+    pub fn optional_file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.layers.push(Layer::File {
+            path: path.into(),
+            required: false,
+        });
+        self
+    }
+
+    // ตัวแปร PREFIX_... ของ process นี้
+    pub fn env(self, prefix: &str) -> Self {
+        let vars = env::vars_os().filter_map(|(name, value)| {
+            Some((name.into_string().ok()?, value.into_string().ok()?))
+        });
+        self.env_vars(prefix, vars)
+    }
+
+    // APP_LOG_LEVEL → log_level, APP_SERVER__PORT → server.port (__ = ลงไปใน section)
+    pub fn env_vars(
+        mut self,
+        prefix: &str,
+        vars: impl IntoIterator<Item = (String, String)>,
+    ) -> Self {
+        self.layers.push(Layer::Env {
+            prefix: prefix.to_string(),
+            vars: vars.into_iter().collect(),
+        });
+        self
+    }
+
+    // "key=value" ทีละตัว เช่นจาก --set server.port=9000
+    pub fn overrides<S: Into<String>>(mut self, overrides: impl IntoIterator<Item = S>) -> Self {
+        self.layers.push(Layer::Overrides(
+            overrides.into_iter().map(Into::into).collect(),
+        ));
+        self
+    }
+
+    pub fn load<T: DeserializeOwned>(&self) -> Result<Loaded<T>, ConfigError> {
+        let mut merge = Merge::default();
+        let mut missing_files = Vec::new();
+
+        for layer in &self.layers {
+            match layer {
+                Layer::Defaults(value) => {
+                    let value = value.clone().map_err(ConfigError::Invalid)?;
+                    let known = merge.known.get_or_insert_with(BTreeMap::new);
+                    for (key, value) in flatten(&value) {
+                        known.insert(key.clone(), value.clone());
+                        merge.entries.insert(key, (value, Source::Default));
+                    }
+                }
+                Layer::File { path, required } => match read_file(path)? {
+                    Some(value) => {
+                        for (key, value) in flatten(&value) {
+                            merge.set(key, value, Source::File(path.clone()))?;
+                        }
+                    }
+                    None if *required => return Err(ConfigError::Missing(path.clone())),
+                    None => missing_files.push(path.clone()),
+                },
+                Layer::Env { prefix, vars } => {
+                    // เรียงตามชื่อ ให้ผลเหมือนเดิมทุกครั้ง
+                    let mut vars: Vec<&(String, String)> = vars.iter().collect();
+                    vars.sort();
+                    for (name, raw) in vars {
+                        if let Some(key) = env_key(prefix, name) {
+                            merge.set_raw(key, raw, Source::Env(name.clone()))?;
+                        }
+                    }
+                }
+                Layer::Overrides(overrides) => {
+                    for text in overrides {
+                        let (key, raw) = text
+                            .split_once('=')
+                            .filter(|(key, _)| !key.trim().is_empty())
+                            .ok_or_else(|| ConfigError::InvalidOverride(text.clone()))?;
+                        merge.set_raw(key.trim().to_string(), raw, Source::Cli(text.clone()))?;
+                    }
+                }
+            }
+        }
+
+        let tree = unflatten(merge.entries.iter().map(|(key, (value, _))| (key, value)));
+        let value =
+            serde_json::from_value(tree).map_err(|e| ConfigError::Invalid(e.to_string()))?;
+        Ok(Loaded {
+            value,
+            entries: merge.entries,
+            missing_files,
+        })
+    }
+}
+
+#[derive(Default)]
+struct Merge {
+    entries: BTreeMap<String, (Value, Source)>,
+    // key จาก defaults (None = ไม่ได้ให้ defaults จึงรับทุก key)
+    known: Option<BTreeMap<String, Value>>,
+}
+
+impl Merge {
+    fn set(&mut self, key: String, value: Value, source: Source) -> Result<(), ConfigError> {
+        self.check_known(&key, &source)?;
+        if let Some((existing, _)) = self.entries.get(&key)
+            && !same_kind(existing, &value)
+        {
+            return Err(ConfigError::InvalidValue {
+                key,
+                origin: source,
+                message: format!(
+                    "expected {}, got {}",
+                    kind_name(existing),
+                    kind_name(&value)
+                ),
+            });
+        }
+        // "tls" = null ถูกแทนด้วย "tls.cert" = ...
+        for ancestor in ancestors(&key) {
+            self.entries.remove(ancestor);
+        }
+        self.entries.insert(key, (value, source));
+        Ok(())
+    }
+
+    // ข้อความจาก env/CLI: แปลงตามชนิดของค่าที่มีอยู่ก่อน
+    fn set_raw(&mut self, key: String, raw: &str, source: Source) -> Result<(), ConfigError> {
+        self.check_known(&key, &source)?;
+        let existing = self.entries.get(&key).map(|(value, _)| value);
+        match coerce(raw, existing) {
+            Ok(value) => self.set(key, value, source),
+            Err(message) => Err(ConfigError::InvalidValue {
+                key,
+                origin: source,
+                message,
+            }),
+        }
+    }
+
+    // key ที่มีใน defaults หรืออยู่ใต้ section ที่เปิดกว้าง (map ว่าง, Option ที่เป็น None)
+    fn check_known(&self, key: &str, source: &Source) -> Result<(), ConfigError> {
+        // server = 1 ทั้งที่ server เป็น section
+        let section = format!("{}.", key);
+        if self
+            .entries
+            .keys()
+            .any(|existing| existing.starts_with(&section))
+        {
+            return Err(ConfigError::InvalidValue {
+                key: key.to_string(),
+                origin: source.clone(),
+                message: "this is a section, set one of its keys instead".into(),
+            });
+        }
+        let Some(known) = &self.known else {
+            return Ok(());
+        };
+        let open_section = ancestors(key)
+            .any(|ancestor| matches!(known.get(ancestor), Some(Value::Object(_) | Value::Null)));
+        if known.contains_key(key) || open_section {
+            Ok(())
+        } else {
+            Err(ConfigError::UnknownKey {
+                key: key.to_string(),
+                origin: source.clone(),
+            })
+        }
+    }
+}
+
+// "a.b.c" → "a", "a.b"
+fn ancestors(key: &str) -> impl Iterator<Item = &str> {
+    key.match_indices('.').map(move |(index, _)| &key[..index])
+}
+
+fn env_key(prefix: &str, name: &str) -> Option<String> {
+    let rest = name.strip_prefix(prefix)?.strip_prefix('_')?;
+    let parts: Vec<String> = rest.split("__").map(str::to_lowercase).collect();
+    if parts.iter().any(String::is_empty) {
+        return None;
+    }
+    Some(parts.join("."))
+}
+
+// None = ไม่มีไฟล์ (ส่วน error อื่นๆ เช่นไม่มีสิทธิ์อ่าน ต้องบอกผู้ใช้)
+fn read_file(path: &Path) -> Result<Option<Value>, ConfigError> {
+    let extension = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_ascii_lowercase);
+    let format = match extension.as_deref() {
+        Some("toml") => "TOML",
+        Some("json") => "JSON",
+        _ => return Err(ConfigError::UnsupportedFormat(path.to_path_buf())),
+    };
+
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+        Err(source) => {
+            return Err(ConfigError::Unreadable {
+                path: path.to_path_buf(),
+                source,
+            });
+        }
+    };
+
+    let parse_error = |message: String| ConfigError::Parse {
+        path: path.to_path_buf(),
+        format,
+        message,
+    };
+    let value: Value = if format == "TOML" {
+        let table: toml::Table =
+            toml::from_str(&text).map_err(|e| parse_error(e.message().to_string()))?;
+        serde_json::to_value(table).map_err(|e| parse_error(e.to_string()))?
+    } else {
+        serde_json::from_str(&text).map_err(|e| parse_error(e.to_string()))?
+    };
+    if !value.is_object() {
+        return Err(parse_error("the top level must be a table of keys".into()));
+    }
+    Ok(Some(value))
+}
+
+impl<T> Loaded<T> {
+    pub fn source(&self, key: &str) -> Option<&Source> {
+        self.entries.get(key).map(|(_, source)| source)
+    }
+
+    // (key, ค่า, ที่มา) เรียงตาม key
+    pub fn entries(&self) -> impl Iterator<Item = (&str, &Value, &Source)> {
+        self.entries
+            .iter()
+            .map(|(key, (value, source))| (key.as_str(), value, source))
+    }
+
+    // optional_file ที่ไม่พบ (ให้ผู้ใช้รู้ว่าไม่ได้อ่าน)
+    pub fn missing_files(&self) -> &[PathBuf] {
+        &self.missing_files
+    }
+
+    //   server.host = "127.0.0.1"  (default)
+    //   server.port = 9000         (env APP_SERVER__PORT)
+    pub fn report(&self) -> String {
+        let lines: Vec<(String, &Source)> = self
+            .entries()
+            .map(|(key, value, source)| (format!("{} = {}", key, value), source))
+            .collect();
+        let width = lines
+            .iter()
+            .map(|(line, _)| line.chars().count())
+            .max()
+            .unwrap_or(0);
+        let mut out: Vec<String> = lines
+            .iter()
+            .map(|(line, source)| format!("{:<width$}  ({})", line, source))
+            .collect();
+        for path in &self.missing_files {
+            out.push(format!("# not found: {}", path.display()));
+        }
+        out.join("\n")
+    }
+}
@@ -0,0 +1,24 @@
+// ===========================================
+// Config - รวมค่า config จากหลายแหล่ง (ต่อยอดจาก read_username บทที่ 9)
+// ===========================================
+//
+// - loader.rs: ConfigLoader (ลำดับชั้น) และ Loaded (ค่า + ที่มาของแต่ละค่า)
+// - source.rs: Source - ค่านี้มาจากไหน
+// - value.rs:  แตก/รวม key แบบ "server.port" และแปลงข้อความจาก env/CLI ตามชนิด
+// - error.rs:  ConfigError
+//
+// ชั้นที่เพิ่มทีหลังทับชั้นก่อนหน้า ปกติใช้:
+//   defaults → ไฟล์ (TOML/JSON) → environment (APP_SERVER__PORT) → CLI (server.port=8080)
+//
+// ต่างจาก read_username ที่ fallback เงียบๆ ทุก error:
+// ไฟล์ที่ไม่มี (optional) ใช้ค่าเดิมต่อได้ แต่ไฟล์ที่อ่านไม่ได้หรือผิดรูปแบบเป็น error เสมอ
+// และ key ที่สะกดผิด (ไม่มีใน defaults) ก็เป็น error
+
+mod error;
+mod loader;
+mod source;
+mod value;
+
+pub use error::ConfigError;
+pub use loader::{ConfigLoader, Loaded};
+pub use source::Source;
@@ -0,0 +1,27 @@
+// ===========================================
+// source.rs - ที่มาของค่า config
+// ===========================================
+
+use std::fmt;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    Default,
+    File(PathBuf),
+    // ชื่อตัวแปร เช่น APP_SERVER__PORT
+    Env(String),
+    // ข้อความที่ส่งมา เช่น server.port=8080
+    Cli(String),
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Source::Default => write!(f, "default"),
+            Source::File(path) => write!(f, "file {}", path.display()),
+            Source::Env(name) => write!(f, "env {}", name),
+            Source::Cli(arg) => write!(f, "argument '{}'", arg),
+        }
+    }
+}
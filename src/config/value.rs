@@ -0,0 +1,130 @@
+// ===========================================
+// value.rs - แตก/รวม key แบบจุด และแปลงข้อความตามชนิดของค่าเดิม
+// ===========================================
+//
+// {"server": {"port": 80}}  ⇄  {"server.port": 80}
+// array ถือเป็นค่าเดียว (ชั้นหลังแทนทั้ง array ไม่ได้ต่อท้าย)
+
+use serde_json::{Map, Value};
+use std::collections::BTreeMap;
+
+pub(crate) fn flatten(value: &Value) -> BTreeMap<String, Value> {
+    let mut out = BTreeMap::new();
+    if let Value::Object(map) = value {
+        flatten_into(map, "", &mut out);
+    }
+    out
+}
+
+fn flatten_into(map: &Map<String, Value>, prefix: &str, out: &mut BTreeMap<String, Value>) {
+    for (key, value) in map {
+        let key = if prefix.is_empty() {
+            key.clone()
+        } else {
+            format!("{}.{}", prefix, key)
+        };
+        match value {
+            // section ว่างก็ยังนับเป็น key (ให้รู้ว่ามีอยู่)
+            Value::Object(inner) if !inner.is_empty() => flatten_into(inner, &key, out),
+            _ => {
+                out.insert(key, value.clone());
+            }
+        }
+    }
+}
+
+pub(crate) fn unflatten<'a>(entries: impl IntoIterator<Item = (&'a String, &'a Value)>) -> Value {
+    let mut root = Map::new();
+    for (key, value) in entries {
+        let mut parts: Vec<&str> = key.split('.').collect();
+        let last = parts.pop().expect("split returns at least one part");
+        let mut map = &mut root;
+        for part in parts {
+            let entry = map
+                .entry(part.to_string())
+                .or_insert_with(|| Value::Object(Map::new()));
+            if !entry.is_object() {
+                *entry = Value::Object(Map::new());
+            }
+            map = entry.as_object_mut().expect("just made an object");
+        }
+        map.insert(last.to_string(), value.clone());
+    }
+    Value::Object(root)
+}
+
+// ชนิดเข้ากันได้ไหม (null เดิมรับได้ทุกชนิด: Option<T> ที่ default เป็น None)
+pub(crate) fn same_kind(existing: &Value, new: &Value) -> bool {
+    matches!(
+        (existing, new),
+        (Value::Null, _)
+            | (Value::Bool(_), Value::Bool(_))
+            | (Value::Number(_), Value::Number(_))
+            | (Value::String(_), Value::String(_))
+            | (Value::Array(_), Value::Array(_))
+            | (Value::Object(_), Value::Object(_))
+    )
+}
+
+pub(crate) fn kind_name(value: &Value) -> &'static str {
+    match value {
+        Value::Null => "null",
+        Value::Bool(_) => "a boolean",
+        Value::Number(_) => "a number",
+        Value::String(_) => "a string",
+        Value::Array(_) => "a list",
+        Value::Object(_) => "a section",
+    }
+}
+
+// ข้อความจาก env/CLI → ค่าตามชนิดของค่าเดิม
+// ไม่มีค่าเดิม (หรือเป็น null) เดาเอา: true/false, ตัวเลข, ไม่งั้นเป็นข้อความ
+pub(crate) fn coerce(raw: &str, existing: Option<&Value>) -> Result<Value, String> {
+    match existing {
+        Some(Value::String(_)) => Ok(Value::String(raw.to_string())),
+        Some(Value::Bool(_)) => parse_bool(raw).ok_or_else(|| expected("true or false", raw)),
+        Some(Value::Number(_)) => parse_number(raw).ok_or_else(|| expected("a number", raw)),
+        // "a,b,c" หรือ JSON array '["a", "b"]'
+        Some(Value::Array(items)) => {
+            if raw.trim_start().starts_with('[') {
+                return serde_json::from_str(raw).map_err(|e| e.to_string());
+            }
+            let element = items.first();
+            raw.split(',')
+                .filter(|item| !item.trim().is_empty())
+                .map(|item| coerce(item.trim(), element))
+                .collect::<Result<Vec<_>, _>>()
+                .map(Value::Array)
+        }
+        Some(Value::Object(_)) => Err("this is a section, set one of its keys instead".into()),
+        Some(Value::Null) | None => Ok(parse_bool(raw)
+            .or_else(|| parse_number(raw))
+            .unwrap_or_else(|| Value::String(raw.to_string()))),
+    }
+}
+
+fn expected(what: &str, raw: &str) -> String {
+    format!("expected {}, got '{}'", what, raw)
+}
+
+fn parse_bool(raw: &str) -> Option<Value> {
+    match raw.trim().to_ascii_lowercase().as_str() {
+        "true" => Some(Value::Bool(true)),
+        "false" => Some(Value::Bool(false)),
+        _ => None,
+    }
+}
+
+fn parse_number(raw: &str) -> Option<Value> {
+    let raw = raw.trim();
+    if let Ok(n) = raw.parse::<i64>() {
+        return Some(n.into());
+    }
+    if let Ok(n) = raw.parse::<u64>() {
+        return Some(n.into());
+    }
+    raw.parse::<f64>()
+        .ok()
+        .and_then(serde_json::Number::from_f64)
+        .map(Value::Number)
+}
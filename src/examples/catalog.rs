@@ -0,0 +1,197 @@
+// ===========================================
+// catalog.rs - รายการ example พร้อมชื่อและบทจาก header comment
+// ===========================================
+//
+// ทุกไฟล์เริ่มด้วย header แบบนี้:
+//
+//   // ===========================================
+//   // Iterators & Closures - บทที่ 13
+//   // รัน: cargo run --example iterators
+//   // ===========================================
+//
+// example ที่มีหลายไฟล์อยู่ใน examples/<name>/main.rs
+
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+const CHAPTER_MARKER: &str = " - บทที่ ";
+
+#[derive(Error, Debug)]
+pub enum CatalogError {
+    #[error("Failed to read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("{0}: header must look like '// Title - บทที่ 13'")]
+    Header(PathBuf),
+}
+
+// บทเดียว (13) หรือช่วงบท (6-7)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Chapters {
+    pub first: u32,
+    pub last: u32,
+}
+
+impl fmt::Display for Chapters {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.first == self.last {
+            write!(f, "{}", self.first)
+        } else {
+            write!(f, "{}-{}", self.first, self.last)
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Example {
+    // ชื่อที่ใช้กับ cargo run --example
+    pub name: String,
+    pub title: String,
+    pub chapters: Chapters,
+    pub path: PathBuf,
+}
+
+impl Example {
+    pub fn source(&self) -> Result<String, CatalogError> {
+        read(&self.path)
+    }
+}
+
+// ทุก example เรียงตามบท
+pub fn load(examples_dir: &Path) -> Result<Vec<Example>, CatalogError> {
+    let entries = fs::read_dir(examples_dir).map_err(|source| CatalogError::Io {
+        path: examples_dir.to_path_buf(),
+        source,
+    })?;
+
+    let mut examples = Vec::new();
+    for entry in entries.filter_map(Result::ok) {
+        let path = entry.path();
+        let (name, file) = if path.is_dir() {
+            (path.file_name(), path.join("main.rs"))
+        } else if path.extension().is_some_and(|e| e == "rs") {
+            (path.file_stem(), path.clone())
+        } else {
+            continue;
+        };
+        let Some(name) = name.and_then(|n| n.to_str()).map(str::to_string) else {
+            continue;
+        };
+        if !file.is_file() {
+            continue;
+        }
+
+        let (title, chapters) =
+            parse_header(&read(&file)?).ok_or(CatalogError::Header(file.clone()))?;
+        examples.push(Example {
+            name,
+            title,
+            chapters,
+            path: file,
+        });
+    }
+    examples.sort_by(|a, b| (a.chapters, &a.name).cmp(&(b.chapters, &b.name)));
+    Ok(examples)
+}
+
+fn read(path: &Path) -> Result<String, CatalogError> {
+    fs::read_to_string(path).map_err(|source| CatalogError::Io {
+        path: path.to_path_buf(),
+        source,
+    })
+}
+
+// หา "// Title - บทที่ N" ในบรรทัด comment ช่วงต้นไฟล์
+pub fn parse_header(source: &str) -> Option<(String, Chapters)> {
+    source
+        .lines()
+        .take_while(|line| line.starts_with("//"))
+        .find_map(|line| {
+            let text = line.trim_start_matches('/').trim();
+            let (title, chapters) = text.rsplit_once(CHAPTER_MARKER)?;
+            Some((title.trim().to_string(), parse_chapters(chapters.trim())?))
+        })
+}
+
+fn parse_chapters(text: &str) -> Option<Chapters> {
+    let (first, last) = text.split_once('-').unwrap_or((text, text));
+    let chapters = Chapters {
+        first: first.trim().parse().ok()?,
+        last: last.trim().parse().ok()?,
+    };
+    (chapters.first <= chapters.last).then_some(chapters)
+}
+
+// -------------------------------------------
+// เลือก example จากเลขในเมนูหรือชื่อแบบคร่าวๆ
+// -------------------------------------------
+
+#[derive(Debug, PartialEq)]
+pub enum Selection<'a> {
+    Found(&'a Example),
+    // ตรงกับหลายตัวเท่าๆ กัน
+    Ambiguous(Vec<&'a Example>),
+    NotFound,
+}
+
+// "3"      → ตัวที่ 3 ในเมนู (เริ่มที่ 1)
+// "iter"   → ชื่อขึ้นต้นด้วย / มีคำนี้
+// "smrtptr" → ตัวอักษรเรียงตามลำดับในชื่อ (เช่น smart_pointers)
+// ตรงชื่อทั้งหมดดีกว่าขึ้นต้น ดีกว่ามีอยู่ในชื่อหรือหัวข้อ ดีกว่าตัวอักษรเรียงกัน
+pub fn select<'a>(examples: &'a [Example], query: &str) -> Selection<'a> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Selection::NotFound;
+    }
+    if let Ok(number) = query.parse::<usize>() {
+        return match number.checked_sub(1).and_then(|index| examples.get(index)) {
+            Some(example) => Selection::Found(example),
+            None => Selection::NotFound,
+        };
+    }
+
+    let Some(best) = examples.iter().filter_map(|e| score(e, &query)).min() else {
+        return Selection::NotFound;
+    };
+    let matches: Vec<&Example> = examples
+        .iter()
+        .filter(|e| score(e, &query) == Some(best))
+        .collect();
+    match matches.as_slice() {
+        [only] => Selection::Found(only),
+        _ => Selection::Ambiguous(matches),
+    }
+}
+
+// ยิ่งน้อยยิ่งตรง (None = ไม่ตรงเลย)
+fn score(example: &Example, query: &str) -> Option<u8> {
+    let name = example.name.to_lowercase();
+    let title = example.title.to_lowercase();
+    let query_words = query.replace(['-', ' '], "_");
+    if name == query_words {
+        Some(0)
+    } else if name.starts_with(&query_words) {
+        Some(1)
+    } else if name.contains(&query_words) || title.contains(query) {
+        Some(2)
+    } else if is_subsequence(query, &name) {
+        Some(3)
+    } else {
+        None
+    }
+}
+
+fn is_subsequence(query: &str, text: &str) -> bool {
+    let mut text = text.chars();
+    query
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .all(|c| text.any(|t| t == c))
+}
@@ -0,0 +1,187 @@
+// ===========================================
+// highlight.rs - ใส่สีให้ Rust source ด้วย ANSI escape (สำหรับ terminal)
+// ===========================================
+//
+// แยก token แบบง่ายๆ พอสำหรับโค้ดตัวอย่าง: comment, string, char, lifetime,
+// ตัวเลข, keyword, macro (println!), ชื่อ type (ขึ้นต้นตัวใหญ่) และ attribute (#[...])
+// สีไม่คร่อมบรรทัด จึงเติมเลขบรรทัดข้างหน้าได้โดยไม่ทำให้สีเพี้ยน
+
+const RESET: &str = "\x1b[0m";
+const COMMENT: &str = "\x1b[90m";
+const STRING: &str = "\x1b[32m";
+const NUMBER: &str = "\x1b[33m";
+const KEYWORD: &str = "\x1b[1;35m";
+const MACRO: &str = "\x1b[34m";
+const TYPE: &str = "\x1b[36m";
+const ATTRIBUTE: &str = "\x1b[2m";
+
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while",
+];
+
+pub fn highlight(source: &str) -> String {
+    let chars: Vec<char> = source.chars().collect();
+    let mut out = String::with_capacity(source.len() * 2);
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let start = i;
+
+        let color = if c == '/' && next == Some('/') {
+            i = end_of_line(&chars, i);
+            Some(COMMENT)
+        } else if c == '/' && next == Some('*') {
+            i = find(&chars, i + 2, "*/").map_or(chars.len(), |end| end + 2);
+            Some(COMMENT)
+        } else if c == '"' {
+            i = end_of_string(&chars, i + 1);
+            Some(STRING)
+        } else if c == 'r' && matches!(next, Some('"' | '#')) && raw_string_end(&chars, i).is_some()
+        {
+            i = raw_string_end(&chars, i).unwrap_or(chars.len());
+            Some(STRING)
+        } else if c == 'b' && next == Some('"') {
+            i = end_of_string(&chars, i + 2);
+            Some(STRING)
+        } else if c == '\'' {
+            // 'a' / '\n' เป็น char ส่วน 'a (ไม่มี ' ปิด) เป็น lifetime
+            match char_end(&chars, i) {
+                Some(end) => {
+                    i = end;
+                    Some(STRING)
+                }
+                None => {
+                    i = end_of_word(&chars, i + 1);
+                    Some(TYPE)
+                }
+            }
+        } else if c == '#' && matches!(next, Some('[' | '!')) {
+            i = find(&chars, i, "]").map_or(chars.len(), |end| end + 1);
+            Some(ATTRIBUTE)
+        } else if c.is_ascii_digit() {
+            i = end_of_word(&chars, i);
+            // 1.5 (แต่ไม่ใช่ 1..5)
+            if chars.get(i) == Some(&'.') && chars.get(i + 1).is_some_and(char::is_ascii_digit) {
+                i = end_of_word(&chars, i + 1);
+            }
+            Some(NUMBER)
+        } else if c.is_alphabetic() || c == '_' {
+            i = end_of_word(&chars, i);
+            let word: String = chars[start..i].iter().collect();
+            if chars.get(i) == Some(&'!') && chars.get(i + 1) != Some(&'=') {
+                i += 1;
+                Some(MACRO)
+            } else if KEYWORDS.contains(&word.as_str()) {
+                Some(KEYWORD)
+            } else if word.starts_with(|c: char| c.is_uppercase()) {
+                Some(TYPE)
+            } else {
+                None
+            }
+        } else {
+            i += 1;
+            None
+        };
+
+        let text: String = chars[start..i].iter().collect();
+        match color {
+            Some(color) => paint(&mut out, color, &text),
+            None => out.push_str(&text),
+        }
+    }
+    out
+}
+
+// ใส่สีทีละบรรทัด (ไม่ให้ escape code คร่อม \n)
+fn paint(out: &mut String, color: &str, text: &str) {
+    for (index, line) in text.split('\n').enumerate() {
+        if index > 0 {
+            out.push('\n');
+        }
+        if !line.is_empty() {
+            out.push_str(color);
+            out.push_str(line);
+            out.push_str(RESET);
+        }
+    }
+}
+
+// เลขบรรทัดด้านหน้า (ใช้ได้ทั้งกับ source ที่มีสีและไม่มีสี)
+pub fn with_line_numbers(source: &str, color: bool) -> String {
+    let lines: Vec<&str> = source.lines().collect();
+    let width = lines.len().to_string().len();
+    lines
+        .iter()
+        .enumerate()
+        .map(|(index, line)| {
+            if color {
+                format!("{}{:>width$} │{} {}", COMMENT, index + 1, RESET, line)
+            } else {
+                format!("{:>width$} │ {}", index + 1, line)
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn end_of_line(chars: &[char], from: usize) -> usize {
+    chars[from..]
+        .iter()
+        .position(|&c| c == '\n')
+        .map_or(chars.len(), |offset| from + offset)
+}
+
+fn end_of_word(chars: &[char], from: usize) -> usize {
+    chars[from..]
+        .iter()
+        .position(|&c| !(c.is_alphanumeric() || c == '_'))
+        .map_or(chars.len(), |offset| from + offset)
+}
+
+fn find(chars: &[char], from: usize, pattern: &str) -> Option<usize> {
+    let pattern: Vec<char> = pattern.chars().collect();
+    (from..chars.len()).find(|&i| chars[i..].starts_with(&pattern))
+}
+
+// from = ตัวแรกหลัง " เปิด, คืนตำแหน่งหลัง " ปิด
+fn end_of_string(chars: &[char], from: usize) -> usize {
+    let mut i = from;
+    while i < chars.len() {
+        match chars[i] {
+            '\\' => i += 2,
+            '"' => return i + 1,
+            _ => i += 1,
+        }
+    }
+    chars.len()
+}
+
+// r"..." หรือ r#"..."# เริ่มที่ตัว r
+fn raw_string_end(chars: &[char], from: usize) -> Option<usize> {
+    let hashes = chars[from + 1..].iter().take_while(|&&c| c == '#').count();
+    let open = from + 1 + hashes;
+    if chars.get(open) != Some(&'"') {
+        return None;
+    }
+    let close: String = std::iter::once('"')
+        .chain(std::iter::repeat_n('#', hashes))
+        .collect();
+    Some(find(chars, open + 1, &close).map_or(chars.len(), |end| end + close.chars().count()))
+}
+
+// 'x' หรือ '\n' หรือ '\u{1F980}' เริ่มที่ ' แรก
+fn char_end(chars: &[char], from: usize) -> Option<usize> {
+    match chars.get(from + 1)? {
+        '\\' => {
+            let close = chars[from + 2..].iter().take(10).position(|&c| c == '\'')?;
+            Some(from + 2 + close + 1)
+        }
+        _ if chars.get(from + 2) == Some(&'\'') => Some(from + 3),
+        _ => None,
+    }
+}
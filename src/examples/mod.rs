@@ -0,0 +1,15 @@
+// ===========================================
+// Examples - โปรแกรมใน examples/ (cargo run --example <name>)
+// ===========================================
+//
+// - catalog.rs:   รายการ example พร้อมชื่อและบทจาก header + เลือกด้วยเลขหรือชื่อแบบคร่าวๆ
+// - highlight.rs: ใส่สีให้ source สำหรับแสดงใน terminal
+// - run.rs:       build example ด้วย cargo แล้วรันโดยจำกัดเวลา เก็บ stdout/stderr
+
+mod catalog;
+mod highlight;
+mod run;
+
+pub use catalog::{CatalogError, Chapters, Example, Selection, load, parse_header, select};
+pub use highlight::{highlight, with_line_numbers};
+pub use run::{ExampleError, Run, build, run};
@@ -0,0 +1,106 @@
+// ===========================================
+// run.rs - build example ด้วย cargo แล้วรันไฟล์ที่ได้
+// ===========================================
+//
+// รันไฟล์ที่ build แล้วตรงๆ แทน cargo run เพราะถ้าหมดเวลา
+// kill cargo ได้แต่โปรแกรมที่ cargo เปิดไว้จะยังค้างอยู่
+
+use crate::exercises::run_with_timeout;
+use crate::i18n::Locale;
+use serde_json::Value;
+use std::env;
+use std::ffi::OsString;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus};
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ExampleError {
+    #[error("Failed to run {program}: {source}")]
+    Command {
+        program: String,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("cargo build --example {name} failed:\n{stderr}")]
+    Build { name: String, stderr: String },
+
+    #[error("cargo did not report an executable for example '{0}'")]
+    NoExecutable(String),
+
+    #[error("example '{name}' did not finish within {}s", .timeout.as_secs())]
+    Timeout { name: String, timeout: Duration },
+}
+
+#[derive(Debug)]
+pub struct Run {
+    pub status: ExitStatus,
+    pub stdout: String,
+    pub stderr: String,
+}
+
+impl Run {
+    pub fn success(&self) -> bool {
+        self.status.success()
+    }
+}
+
+// คืน path ของไฟล์ที่ build ได้ (เช่น target/debug/examples/ownership)
+pub fn build(project_dir: &Path, name: &str) -> Result<PathBuf, ExampleError> {
+    // ถ้าถูกเรียกผ่าน cargo (run, test) จะมี $CARGO ชี้ไปที่ cargo ตัวเดียวกัน
+    let cargo = env::var_os("CARGO").unwrap_or_else(|| OsString::from("cargo"));
+    let build = Command::new(&cargo)
+        .args(["build", "--quiet", "--example", name])
+        .args(["--message-format", "json-render-diagnostics"])
+        .current_dir(project_dir)
+        .output()
+        .map_err(|source| ExampleError::Command {
+            program: cargo.to_string_lossy().into_owned(),
+            source,
+        })?;
+    if !build.status.success() {
+        return Err(ExampleError::Build {
+            name: name.to_string(),
+            stderr: String::from_utf8_lossy(&build.stderr)
+                .trim_end()
+                .to_string(),
+        });
+    }
+    String::from_utf8_lossy(&build.stdout)
+        .lines()
+        .filter_map(|line| serde_json::from_str::<Value>(line).ok())
+        .filter(|message| message["reason"] == "compiler-artifact")
+        .filter(|message| message["target"]["name"] == name)
+        .find_map(|message| message["executable"].as_str().map(PathBuf::from))
+        .ok_or_else(|| ExampleError::NoExecutable(name.to_string()))
+}
+
+// build แล้วรันใน project_dir (exit code ไม่ใช่ 0 ไม่ถือเป็น error ให้ดูจาก Run::success)
+// ข้อความใน output เป็นภาษา `locale` ไม่ขึ้นกับ $LANG ของเครื่อง
+pub fn run(
+    project_dir: &Path,
+    name: &str,
+    locale: Locale,
+    timeout: Duration,
+) -> Result<Run, ExampleError> {
+    let executable = build(project_dir, name)?;
+    let mut command = Command::new(&executable);
+    command.current_dir(project_dir).env("LANG", locale.lang());
+    let output = run_with_timeout(command, timeout)
+        .map_err(|source| ExampleError::Command {
+            program: executable.display().to_string(),
+            source,
+        })?
+        .ok_or_else(|| ExampleError::Timeout {
+            name: name.to_string(),
+            timeout,
+        })?;
+    Ok(Run {
+        status: output.status,
+        stdout: String::from_utf8_lossy(&output.stdout).into_owned(),
+        stderr: String::from_utf8_lossy(&output.stderr).into_owned(),
+    })
+}
@@ -0,0 +1,403 @@
+// ===========================================
+// book.rs - อ่านไฟล์แบบฝึกหัด (docs/src/appendix/exercises/chNN-exercises.md)
+// ===========================================
+//
+// รูปแบบของแต่ละไฟล์:
+//
+//   # แบบฝึกหัด: บทที่ 5 - Ownership
+//
+//   ## แบบฝึกหัดที่ 1: Move
+//   <โจทย์>
+//   ```rust,compile_fail      ← โค้ดโจทย์ (อยู่นอก <details>)
+//   ```
+//   <details>
+//   <summary>ดูเฉลย</summary>
+//   ```rust                   ← เฉลย (มีได้หลายวิธี)
+//   ```
+//   </details>
+
+use serde::{Serialize, Serializer};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+const TITLE_PREFIX: &str = "# แบบฝึกหัด: บทที่ ";
+const EXERCISE_PREFIX: &str = "## แบบฝึกหัดที่ ";
+
+#[derive(Error, Debug)]
+pub enum ExerciseError {
+    #[error("Failed to read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("{path}:{line}: {message}")]
+    Parse {
+        path: PathBuf,
+        line: usize,
+        message: String,
+    },
+
+    #[error("Failed to run {program}: {source}")]
+    Command {
+        program: String,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("{0} already exists and is not empty")]
+    WorkspaceExists(PathBuf),
+
+    #[error("Invalid workspace file {path}: {source}")]
+    Manifest {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+// แบบฝึกหัดของหนึ่งบท
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Chapter {
+    pub chapter: u32,
+    pub title: String,
+    #[serde(rename = "file", serialize_with = "file_name")]
+    pub path: PathBuf,
+    pub exercises: Vec<Exercise>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Exercise {
+    // เลขในหัวข้อ "## แบบฝึกหัดที่ 3: ..."
+    pub number: u32,
+    pub title: String,
+    pub line: usize,
+    // Markdown ของโจทย์ (รวม code block แต่ไม่รวมเฉลย)
+    pub description: String,
+    // ข้อความอธิบายในเฉลย (ไม่รวม code) เช่น "**Error:** `s1` ถูก move ..."
+    pub explanation: String,
+    // code block ทั้งหมดของข้อนี้ ตามลำดับในไฟล์
+    pub snippets: Vec<Snippet>,
+}
+
+// code block อยู่ในโจทย์ หรือในเฉลย (<details>)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Place {
+    Problem,
+    Solution,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Snippet {
+    pub place: Place,
+    // ข้อความหลัง ``` เช่น "rust,compile_fail"
+    pub info: String,
+    // บรรทัดแรกของโค้ด
+    pub line: usize,
+    pub code: String,
+}
+
+// annotation ของ code block แบบเดียวกับ rustdoc
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Attributes {
+    pub ignore: bool,
+    pub compile_fail: bool,
+    pub no_run: bool,
+    pub should_panic: bool,
+    // "edition2021" → "2021"
+    pub edition: Option<String>,
+    // compile_fail,E0382 → error ที่ต้องเจอ
+    pub error_codes: Vec<String>,
+}
+
+impl Chapter {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, ExerciseError> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|source| ExerciseError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::parse(path, &content)
+    }
+
+    // `path` ใช้ในข้อความ error เท่านั้น
+    pub fn parse(path: impl AsRef<Path>, content: &str) -> Result<Self, ExerciseError> {
+        Parser::new(path.as_ref()).parse(content)
+    }
+
+    pub fn snippet_count(&self) -> usize {
+        self.exercises.iter().map(|e| e.snippets.len()).sum()
+    }
+}
+
+impl Place {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Place::Problem => "problem",
+            Place::Solution => "solution",
+        }
+    }
+}
+
+impl Snippet {
+    pub fn is_rust(&self) -> bool {
+        self.info.split(',').next().map(str::trim) == Some("rust")
+    }
+
+    pub fn attributes(&self) -> Attributes {
+        let mut attributes = Attributes::default();
+        for token in self.info.split([',', ' ']).map(str::trim) {
+            match token {
+                "ignore" => attributes.ignore = true,
+                "compile_fail" => attributes.compile_fail = true,
+                "no_run" => attributes.no_run = true,
+                "should_panic" => attributes.should_panic = true,
+                _ => {
+                    if let Some(edition) = token.strip_prefix("edition") {
+                        attributes.edition = Some(edition.to_string());
+                    } else if is_error_code(token) {
+                        attributes.error_codes.push(token.to_string());
+                    }
+                }
+            }
+        }
+        attributes
+    }
+}
+
+// "E0382"
+fn is_error_code(token: &str) -> bool {
+    token.len() == 5 && token.starts_with('E') && token[1..].chars().all(|c| c.is_ascii_digit())
+}
+
+// อ่านทุกไฟล์ chNN-exercises.md ในโฟลเดอร์ เรียงตามบท
+pub fn load_book(dir: impl AsRef<Path>) -> Result<Vec<Chapter>, ExerciseError> {
+    let dir = dir.as_ref();
+    let io_error = |source| ExerciseError::Io {
+        path: dir.to_path_buf(),
+        source,
+    };
+
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        let is_exercise = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("ch") && name.ends_with("-exercises.md"));
+        if is_exercise {
+            files.push(path);
+        }
+    }
+
+    let mut chapters = files
+        .iter()
+        .map(Chapter::load)
+        .collect::<Result<Vec<_>, _>>()?;
+    chapters.sort_by_key(|chapter| chapter.chapter);
+    Ok(chapters)
+}
+
+// export เฉพาะชื่อไฟล์ (path เต็มขึ้นกับเครื่องที่รัน)
+fn file_name<S: Serializer>(path: &Path, serializer: S) -> Result<S::Ok, S::Error> {
+    let name = path.file_name().unwrap_or(path.as_os_str());
+    serializer.serialize_str(&name.to_string_lossy())
+}
+
+// -------------------------------------------
+// Parser
+// -------------------------------------------
+
+// code block ที่เปิดอยู่
+struct Fence {
+    ticks: usize,
+    info: String,
+    line: usize,
+    code: Vec<String>,
+}
+
+struct Parser<'a> {
+    path: &'a Path,
+    fence: Option<Fence>,
+    // บรรทัดของ <details> ที่ยังไม่ปิด
+    details: Option<usize>,
+    exercises: Vec<Exercise>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(path: &'a Path) -> Self {
+        Self {
+            path,
+            fence: None,
+            details: None,
+            exercises: Vec::new(),
+        }
+    }
+
+    fn error(&self, line: usize, message: impl Into<String>) -> ExerciseError {
+        ExerciseError::Parse {
+            path: self.path.to_path_buf(),
+            line,
+            message: message.into(),
+        }
+    }
+
+    fn parse(mut self, content: &str) -> Result<Chapter, ExerciseError> {
+        let mut lines = content.lines().enumerate().map(|(i, line)| (i + 1, line));
+
+        let (chapter, title) = lines
+            .next()
+            .and_then(|(_, first)| parse_title(first))
+            .ok_or_else(|| {
+                self.error(1, "expected '# แบบฝึกหัด: บทที่ N - Title' on the first line")
+            })?;
+
+        for (number, line) in lines {
+            self.line(number, line)?;
+        }
+
+        if let Some(fence) = &self.fence {
+            return Err(self.error(
+                fence.line,
+                format!(
+                    "code block opened with {} backticks is never closed",
+                    fence.ticks
+                ),
+            ));
+        }
+        if let Some(line) = self.details {
+            return Err(self.error(line, "missing </details>"));
+        }
+        if self.exercises.is_empty() {
+            return Err(self.error(1, "no exercises found"));
+        }
+        for exercise in &mut self.exercises {
+            exercise.description = trim_lines(&exercise.description);
+            exercise.explanation = trim_lines(&exercise.explanation);
+        }
+
+        Ok(Chapter {
+            chapter,
+            title,
+            path: self.path.to_path_buf(),
+            exercises: self.exercises,
+        })
+    }
+
+    fn line(&mut self, number: usize, line: &str) -> Result<(), ExerciseError> {
+        if self.track_fence(number, line) {
+            if self.details.is_none() {
+                self.push_text(line);
+            }
+            return Ok(());
+        }
+
+        let trimmed = line.trim();
+        if line.starts_with(EXERCISE_PREFIX) {
+            if let Some(open) = self.details {
+                return Err(self.error(open, "missing </details>"));
+            }
+            let (exercise, title) = parse_heading(line)
+                .ok_or_else(|| self.error(number, "expected '## แบบฝึกหัดที่ N: Title'"))?;
+            self.exercises.push(Exercise {
+                number: exercise,
+                title,
+                line: number,
+                description: String::new(),
+                explanation: String::new(),
+                snippets: Vec::new(),
+            });
+        } else if trimmed == "<details>" {
+            if let Some(open) = self.details {
+                return Err(self.error(open, "nested <details>"));
+            }
+            self.details = Some(number);
+        } else if trimmed == "</details>" {
+            if self.details.take().is_none() {
+                return Err(self.error(number, "</details> without <details>"));
+            }
+        } else if !trimmed.starts_with("<summary>") {
+            self.push_text(line);
+        }
+        Ok(())
+    }
+
+    // ข้อความนอก <details> เป็นโจทย์ ใน <details> เป็นคำอธิบายเฉลย
+    // (ไม่เอาเส้นคั่น --- และลิงก์ไปบทถัดไป)
+    fn push_text(&mut self, line: &str) {
+        let trimmed = line.trim();
+        if trimmed == "---" || trimmed.starts_with('👉') {
+            return;
+        }
+        let in_details = self.details.is_some();
+        if let Some(exercise) = self.exercises.last_mut() {
+            let text = if in_details {
+                &mut exercise.explanation
+            } else {
+                &mut exercise.description
+            };
+            text.push_str(line);
+            text.push('\n');
+        }
+    }
+
+    // เปิด/ปิด code block (``` ปิดได้ด้วย backticks ที่ยาวอย่างน้อยเท่ากันและไม่มีข้อความต่อท้าย)
+    // คืน true ถ้าบรรทัดนี้เป็นส่วนของ code block
+    fn track_fence(&mut self, number: usize, line: &str) -> bool {
+        let trimmed = line.trim_start();
+        let ticks = trimmed.chars().take_while(|&c| c == '`').count();
+        let rest = &trimmed[ticks..];
+
+        match self.fence.as_mut() {
+            None if ticks >= 3 => {
+                self.fence = Some(Fence {
+                    ticks,
+                    info: rest.trim().to_string(),
+                    line: number,
+                    code: Vec::new(),
+                });
+            }
+            None => return false,
+            Some(fence) if ticks >= fence.ticks && rest.trim().is_empty() => {
+                let fence = self.fence.take().expect("fence is open");
+                let place = match self.details {
+                    Some(_) => Place::Solution,
+                    None => Place::Problem,
+                };
+                if let Some(exercise) = self.exercises.last_mut() {
+                    exercise.snippets.push(Snippet {
+                        place,
+                        info: fence.info,
+                        line: fence.line + 1,
+                        code: fence.code.join("\n"),
+                    });
+                }
+            }
+            Some(fence) => fence.code.push(line.to_string()),
+        }
+        true
+    }
+}
+
+// "# แบบฝึกหัด: บทที่ 5 - Ownership" → (5, "Ownership")
+fn parse_title(line: &str) -> Option<(u32, String)> {
+    let rest = line.strip_prefix(TITLE_PREFIX)?;
+    let (chapter, title) = rest.split_once(" - ")?;
+    Some((chapter.trim().parse().ok()?, title.trim().to_string()))
+}
+
+// ตัดบรรทัดว่างหัวท้าย
+fn trim_lines(text: &str) -> String {
+    text.trim_matches('\n').trim_end().to_string()
+}
+
+// "## แบบฝึกหัดที่ 1: Move" → (1, "Move")
+fn parse_heading(line: &str) -> Option<(u32, String)> {
+    let rest = line.strip_prefix(EXERCISE_PREFIX)?;
+    let (number, title) = rest.split_once(':')?;
+    Some((number.trim().parse().ok()?, title.trim().to_string()))
+}
@@ -0,0 +1,481 @@
+// ===========================================
+// check.rs - compile และรันทุก snippet ในแบบฝึกหัด
+// ===========================================
+//
+// แต่ละ snippet ถูกเขียนเป็น src/main.rs ของ cargo project ชั่วคราว
+// (work_dir) แล้ว build ด้วย cargo ตาม annotation แบบ rustdoc:
+//
+//   rust,compile_fail  ต้อง compile ไม่ผ่าน (ถ้าระบุ E0382 ต้องเจอ error นั้น)
+//   rust,no_run        compile ผ่าน
+//   rust,should_panic  compile ผ่าน และ panic ตอนรัน
+//   rust               compile ผ่าน และรันจบโดยไม่ error
+//   rust,ignore        ข้าม (ยกเว้น include_ignored)
+//
+// snippet ที่ใช้ crate ภายนอก (tokio, serde, ...) จะได้ dependency เดียวกับ
+// Cargo.toml ของ project นี้ และใช้ Cargo.lock เดียวกัน
+
+use super::book::{Chapter, ExerciseError, Place, Snippet};
+use crate::preprocessor::strip_hidden_lines;
+use std::fs;
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+pub(super) const DEFAULT_EDITION: &str = "2024";
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+// สิ่งที่ snippet ต้องทำได้
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Expectation {
+    CompileFail,
+    Compile,
+    Run,
+    Panic,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Passed,
+    // reason: สรุปสั้นๆ, output: ข้อความจาก compiler หรือโปรแกรม
+    Failed { reason: String, output: String },
+    Ignored,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct SnippetResult {
+    pub exercise: u32,
+    pub place: Place,
+    pub line: usize,
+    // None = ignore
+    pub expectation: Option<Expectation>,
+    pub outcome: Outcome,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChapterReport {
+    pub chapter: u32,
+    pub title: String,
+    pub path: PathBuf,
+    pub results: Vec<SnippetResult>,
+}
+
+// dependency หนึ่งบรรทัดจาก [dependencies] เช่น `tokio = { version = "1", ... }`
+#[derive(Debug, Clone, PartialEq)]
+struct Dependency {
+    name: String,
+    line: String,
+}
+
+pub struct Checker {
+    work_dir: PathBuf,
+    dependencies: Vec<Dependency>,
+    lockfile: Option<PathBuf>,
+    // ตรวจ snippet ที่เป็น ignore ด้วย (เหมือน cargo test -- --include-ignored)
+    pub include_ignored: bool,
+    // เวลาสูงสุดที่ให้โปรแกรมรัน
+    pub timeout: Duration,
+}
+
+impl Expectation {
+    pub fn describe(self) -> &'static str {
+        match self {
+            Expectation::CompileFail => "fails to compile",
+            Expectation::Compile => "compiles",
+            Expectation::Run => "compiles and runs",
+            Expectation::Panic => "compiles and panics",
+        }
+    }
+}
+
+impl SnippetResult {
+    pub fn passed(&self) -> bool {
+        self.outcome == Outcome::Passed
+    }
+
+    pub fn failed(&self) -> bool {
+        matches!(self.outcome, Outcome::Failed { .. })
+    }
+}
+
+impl ChapterReport {
+    pub fn passed(&self) -> usize {
+        self.results.iter().filter(|r| r.passed()).count()
+    }
+
+    pub fn failed(&self) -> usize {
+        self.results.iter().filter(|r| r.failed()).count()
+    }
+
+    pub fn ignored(&self) -> usize {
+        self.results
+            .iter()
+            .filter(|r| r.outcome == Outcome::Ignored)
+            .count()
+    }
+
+    pub fn is_ok(&self) -> bool {
+        self.failed() == 0
+    }
+}
+
+// annotation → สิ่งที่ต้องตรวจ (None = ข้าม)
+pub fn expectation(snippet: &Snippet, include_ignored: bool) -> Option<Expectation> {
+    let attributes = snippet.attributes();
+    if attributes.ignore && !include_ignored {
+        return None;
+    }
+    Some(if attributes.compile_fail {
+        Expectation::CompileFail
+    } else if attributes.no_run {
+        Expectation::Compile
+    } else if attributes.should_panic {
+        Expectation::Panic
+    } else {
+        Expectation::Run
+    })
+}
+
+impl Checker {
+    // ใช้ได้เฉพาะ std (ไม่มี dependency)
+    pub fn new(work_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            work_dir: work_dir.into(),
+            dependencies: Vec::new(),
+            lockfile: None,
+            include_ignored: false,
+            timeout: DEFAULT_TIMEOUT,
+        }
+    }
+
+    // ให้ snippet ใช้ [dependencies] และ Cargo.lock ของ project ใน `dir` ได้
+    pub fn with_project(mut self, dir: impl AsRef<Path>) -> Result<Self, ExerciseError> {
+        let manifest = dir.as_ref().join("Cargo.toml");
+        let content = fs::read_to_string(&manifest).map_err(|source| ExerciseError::Io {
+            path: manifest.clone(),
+            source,
+        })?;
+        self.dependencies = parse_dependencies(&content);
+
+        let lockfile = dir.as_ref().join("Cargo.lock");
+        self.lockfile = lockfile.exists().then_some(lockfile);
+        Ok(self)
+    }
+
+    pub fn check_chapter(&self, chapter: &Chapter) -> Result<ChapterReport, ExerciseError> {
+        let mut results = Vec::new();
+
+        for exercise in &chapter.exercises {
+            for snippet in exercise.snippets.iter().filter(|s| s.is_rust()) {
+                let expectation = expectation(snippet, self.include_ignored);
+                let outcome = match expectation {
+                    Some(expectation) => self.check(snippet, expectation)?,
+                    None => Outcome::Ignored,
+                };
+                results.push(SnippetResult {
+                    exercise: exercise.number,
+                    place: snippet.place,
+                    line: snippet.line,
+                    expectation,
+                    outcome,
+                });
+            }
+        }
+
+        Ok(ChapterReport {
+            chapter: chapter.chapter,
+            title: chapter.title.clone(),
+            path: chapter.path.clone(),
+            results,
+        })
+    }
+
+    // Err เฉพาะตอนที่เตรียม project หรือเรียก cargo ไม่ได้
+    pub fn check(
+        &self,
+        snippet: &Snippet,
+        expectation: Expectation,
+    ) -> Result<Outcome, ExerciseError> {
+        let attributes = snippet.attributes();
+        let build = self.build(snippet)?;
+        let compiler_output = String::from_utf8_lossy(&build.stderr).into_owned();
+
+        if expectation == Expectation::CompileFail {
+            if build.status.success() {
+                return Ok(failed("compiled, but it should not", ""));
+            }
+            let missing: Vec<&str> = attributes
+                .error_codes
+                .iter()
+                .map(String::as_str)
+                .filter(|code| !compiler_output.contains(&format!("error[{}]", code)))
+                .collect();
+            if !missing.is_empty() {
+                return Ok(failed(
+                    format!("failed to compile, but without {}", missing.join(", ")),
+                    &compiler_output,
+                ));
+            }
+            return Ok(Outcome::Passed);
+        }
+
+        if !build.status.success() {
+            return Ok(failed("does not compile", &compiler_output));
+        }
+        if expectation == Expectation::Compile {
+            return Ok(Outcome::Passed);
+        }
+
+        let Some(run) = self.run_binary()? else {
+            return Ok(failed(
+                format!("still running after {}s", self.timeout.as_secs()),
+                "",
+            ));
+        };
+
+        let output = format!(
+            "{}{}",
+            String::from_utf8_lossy(&run.stdout),
+            String::from_utf8_lossy(&run.stderr)
+        );
+        Ok(match (expectation, run.status.success()) {
+            (Expectation::Panic, false) | (Expectation::Run, true) => Outcome::Passed,
+            (Expectation::Panic, true) => failed("ran to the end, but it should panic", &output),
+            _ => failed(format!("exited with {}", run.status), &output),
+        })
+    }
+
+    // stdout ของ snippet ที่ compile และรันจบได้ (None ถ้าทำไม่ได้)
+    pub fn output(&self, snippet: &Snippet) -> Result<Option<String>, ExerciseError> {
+        if !self.build(snippet)?.status.success() {
+            return Ok(None);
+        }
+        Ok(self
+            .run_binary()?
+            .filter(|run| run.status.success())
+            .map(|run| String::from_utf8_lossy(&run.stdout).into_owned()))
+    }
+
+    fn build(&self, snippet: &Snippet) -> Result<Output, ExerciseError> {
+        let attributes = snippet.attributes();
+        let edition = attributes.edition.as_deref().unwrap_or(DEFAULT_EDITION);
+        self.write_project(&program(&snippet.code), edition)?;
+        self.cargo_build()
+    }
+
+    // None = หมดเวลา
+    fn run_binary(&self) -> Result<Option<Output>, ExerciseError> {
+        let binary = self.work_dir.join("target/debug/snippet");
+        run_with_timeout(Command::new(&binary), self.timeout)
+            .map_err(|source| command_error(&binary.display().to_string(), source))
+    }
+
+    fn write_project(&self, code: &str, edition: &str) -> Result<(), ExerciseError> {
+        let dependencies = self.dependencies_for(code);
+
+        // [workspace] ว่าง = ไม่ถูกนับเป็นส่วนของ workspace อื่นที่อยู่โฟลเดอร์แม่
+        let manifest = format!(
+            "[package]\nname = \"snippet\"\nversion = \"0.0.0\"\nedition = \"{}\"\npublish = false\n\n\
+             [dependencies]\n{}\n\n[workspace]\n",
+            edition,
+            dependencies.join("\n")
+        );
+
+        let src = self.work_dir.join("src");
+        write(&src.join("main.rs"), code)?;
+        write(&self.work_dir.join("Cargo.toml"), &manifest)?;
+        // lockfile ถูก cargo แก้ทุกครั้งที่ dependency เปลี่ยน จึง copy ใหม่ทุกครั้ง
+        if let Some(lockfile) = &self.lockfile {
+            let target = self.work_dir.join("Cargo.lock");
+            fs::copy(lockfile, &target).map_err(|source| ExerciseError::Io {
+                path: target,
+                source,
+            })?;
+        }
+        Ok(())
+    }
+
+    // บรรทัดใน [dependencies] ที่ code ต้องใช้
+    pub(super) fn dependencies_for(&self, code: &str) -> Vec<&str> {
+        self.dependencies
+            .iter()
+            .filter(|dependency| uses_crate(code, &dependency.name))
+            .map(|dependency| dependency.line.as_str())
+            .collect()
+    }
+
+    pub(super) fn lockfile(&self) -> Option<&Path> {
+        self.lockfile.as_deref()
+    }
+
+    fn cargo_build(&self) -> Result<Output, ExerciseError> {
+        Command::new("cargo")
+            .args(["build", "--quiet", "--color", "never"])
+            .current_dir(&self.work_dir)
+            // อย่าใช้ target dir ของ project ที่เรียกเรา (เช่นตอน cargo test)
+            .env_remove("CARGO_TARGET_DIR")
+            .output()
+            .map_err(|source| command_error("cargo", source))
+    }
+}
+
+fn failed(reason: impl Into<String>, output: &str) -> Outcome {
+    Outcome::Failed {
+        reason: reason.into(),
+        output: output.trim_end().to_string(),
+    }
+}
+
+pub(super) fn command_error(program: &str, source: io::Error) -> ExerciseError {
+    ExerciseError::Command {
+        program: program.to_string(),
+        source,
+    }
+}
+
+pub(super) fn write(path: &Path, content: &str) -> Result<(), ExerciseError> {
+    let io_error = |source| ExerciseError::Io {
+        path: path.to_path_buf(),
+        source,
+    };
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(io_error)?;
+    }
+    fs::write(path, content).map_err(io_error)
+}
+
+// -------------------------------------------
+// แปลง snippet เป็นโปรแกรมเต็ม (แบบเดียวกับ rustdoc)
+// -------------------------------------------
+//
+// - บรรทัด "# " ที่ซ่อนไว้ใน mdBook เป็นส่วนหนึ่งของโปรแกรม
+// - ไม่เตือน unused (โจทย์มักประกาศตัวแปรไว้เฉยๆ)
+// - ถ้าไม่มี fn main ให้ครอบทั้ง snippet ด้วย fn main() { ... }
+
+pub fn program(snippet: &str) -> String {
+    let (attributes, body) = split_attributes(snippet);
+    let mut header = vec!["#![allow(unused)]".to_string()];
+    header.extend(attributes);
+    format!("{}\n{}", header.join("\n"), with_main(&body))
+}
+
+// #![...] ต้องอยู่บนสุดของไฟล์ ครอบใน fn main ไม่ได้
+// → (บรรทัด #![...], โค้ดที่เหลือ) หลังเปิดบรรทัดที่ซ่อนไว้แล้ว
+pub(super) fn split_attributes(snippet: &str) -> (Vec<String>, String) {
+    let code = strip_hidden_lines(snippet);
+    let mut lines = code.lines().peekable();
+    let mut attributes = Vec::new();
+    while let Some(line) = lines.next_if(|line| line.trim_start().starts_with("#![")) {
+        attributes.push(line.to_string());
+    }
+    (attributes, lines.collect::<Vec<_>>().join("\n"))
+}
+
+// snippet ที่ไม่มี #![...] → ไฟล์ที่มี fn main เสมอ
+pub(super) fn with_main(code: &str) -> String {
+    if has_main(code) {
+        format!("{}\n", code)
+    } else {
+        format!("fn main() {{\n{}\n}}\n", code)
+    }
+}
+
+pub(super) fn has_main(code: &str) -> bool {
+    syn::parse_file(code).is_ok_and(|file| {
+        file.items
+            .iter()
+            .any(|item| matches!(item, syn::Item::Fn(f) if f.sig.ident == "main"))
+    })
+}
+
+// "tokio" → ใช้ `tokio::...`, `use tokio`, `#[tokio::main]`
+// ("async-graphql" เขียนใน code ว่า async_graphql)
+fn uses_crate(code: &str, name: &str) -> bool {
+    let ident = name.replace('-', "_");
+    let path = format!("{}::", ident);
+
+    code.match_indices(&path).any(|(at, _)| {
+        let before = code[..at].chars().next_back();
+        !before.is_some_and(|c| c.is_alphanumeric() || c == '_' || c == ':')
+    }) || code.lines().any(|line| {
+        let line = line.trim_start();
+        line.strip_prefix("use ")
+            .or_else(|| line.strip_prefix("extern crate "))
+            .is_some_and(|rest| {
+                rest.strip_prefix(ident.as_str())
+                    .is_some_and(|after| after.starts_with([';', ' ']))
+            })
+    })
+}
+
+// อ่านเฉพาะบรรทัด `name = ...` ในหมวด [dependencies]
+fn parse_dependencies(manifest: &str) -> Vec<Dependency> {
+    let mut in_dependencies = false;
+    let mut dependencies = Vec::new();
+
+    for line in manifest.lines() {
+        let trimmed = line.trim();
+        if trimmed.starts_with('[') {
+            in_dependencies = trimmed == "[dependencies]";
+        } else if in_dependencies
+            && !trimmed.starts_with('#')
+            && let Some((name, _)) = trimmed.split_once('=')
+        {
+            dependencies.push(Dependency {
+                name: name.trim().to_string(),
+                line: trimmed.to_string(),
+            });
+        }
+    }
+    dependencies
+}
+
+// -------------------------------------------
+// รันโปรแกรมโดยจำกัดเวลา
+// -------------------------------------------
+
+// None = หมดเวลา (process ถูก kill แล้ว)
+pub(crate) fn run_with_timeout(
+    mut command: Command,
+    timeout: Duration,
+) -> io::Result<Option<Output>> {
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // อ่าน output ใน thread แยก ไม่อย่างนั้นโปรแกรมที่พิมพ์เยอะจะค้างเพราะ pipe เต็ม
+    let stdout = read_in_background(child.stdout.take());
+    let stderr = read_in_background(child.stderr.take());
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break status;
+        }
+        if started.elapsed() >= timeout {
+            child.kill()?;
+            child.wait()?;
+            return Ok(None);
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+
+    Ok(Some(Output {
+        status,
+        stdout: stdout.join().unwrap_or_default(),
+        stderr: stderr.join().unwrap_or_default(),
+    }))
+}
+
+fn read_in_background(pipe: Option<impl Read + Send + 'static>) -> thread::JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buffer = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buffer);
+        }
+        buffer
+    })
+}
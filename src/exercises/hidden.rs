@@ -0,0 +1,819 @@
+// ===========================================
+// hidden.rs - สร้าง hidden tests จากเฉลย
+// ===========================================
+//
+// ไฟล์ test ที่ได้ include! โค้ดของผู้เรียนไว้ที่ root และเฉลยไว้ใน `mod solution`
+// แล้วตรวจ 3 แบบ:
+//
+// 1. API เหมือนเฉลย (ตรวจตอน compile):
+//    fn ต้องมี signature เดียวกัน, struct ต้องมี field ครบและ type ตรง,
+//    enum ต้องมี variant ครบ, impl Trait for Type ต้องมี, method ต้องมี
+// 2. fn ที่รับ/คืน type พื้นฐาน (ตัวเลข, bool, char, &str, String, Vec, Option, ...)
+//    ต้องให้ผลเหมือนเฉลยกับ input ตัวอย่าง (รวมถึง panic เหมือนกัน)
+// 3. โปรแกรมรันจบได้ และ (ถ้าโจทย์ให้โค้ดตั้งต้นมา) พิมพ์ผลเหมือนเฉลย
+// 4. ถ้าเฉลยมี #[test] ผู้เรียนต้องเขียน test เองด้วย
+
+use std::collections::HashSet;
+use syn::spanned::Spanned;
+use syn::{
+    Fields, FnArg, GenericArgument, GenericParam, Generics, Item, ItemFn, PathArguments,
+    ReturnType, Signature, Type, Visibility,
+};
+
+// จำนวน input ตัวอย่างสูงสุดต่อหนึ่ง fn
+const MAX_CASES: usize = 24;
+
+// ข้อมูลของข้อที่ใช้สร้างไฟล์ test
+pub(super) struct HiddenTests<'a> {
+    pub chapter: u32,
+    pub number: u32,
+    pub title: &'a str,
+    // ชื่อ package/binary เช่น "ch05-ex1"
+    pub package: &'a str,
+    // โฟลเดอร์ของ crate เช่น "ex1"
+    pub dir: &'a str,
+    // เฉลยที่มี fn main แล้ว (ไม่มี #![...])
+    pub solution: &'a str,
+    // โปรแกรมต้องรันจบโดยไม่ error
+    pub runs: bool,
+    // มี = ต้องพิมพ์เหมือนเฉลย (เก็บใน <dir>.out)
+    pub expected_output: bool,
+}
+
+impl HiddenTests<'_> {
+    pub fn render(&self) -> String {
+        let file = syn::parse_file(self.solution).ok();
+        let items = file.as_ref().map(|f| f.items.as_slice()).unwrap_or(&[]);
+
+        let mut collector = Collector::new(self.solution);
+        collector.items(items, "");
+        let Collector { api, behaviour, .. } = collector;
+
+        let mut out = format!(
+            "// ===========================================\n\
+             // Hidden tests - บทที่ {} แบบฝึกหัดที่ {}: {}\n\
+             // สร้างจากเฉลยในหนังสือ - ไม่ต้องแก้ไฟล์นี้ (ตรวจด้วย: practice check {})\n\
+             // ===========================================\n\n\
+             #![allow(dead_code, unused)]\n\n\
+             // โค้ดของคุณ\n\
+             include!(\"../{}/src/main.rs\");\n\n",
+            self.chapter, self.number, self.title, self.number, self.dir
+        );
+
+        out.push_str("// เฉลย (เปิดดูได้ แต่ลองทำเองก่อนนะ)\nmod solution {\n");
+        out.push_str(&format!("    include!(\"{}_solution.rs\");\n", self.dir));
+        if !behaviour.is_empty() {
+            out.push_str(COMPARE_HELPERS);
+            for test in &behaviour {
+                out.push('\n');
+                out.push_str(&indent(test));
+            }
+        }
+        out.push_str("}\n");
+
+        for check in &api {
+            out.push('\n');
+            out.push_str(check);
+        }
+
+        if self.runs {
+            out.push_str(&format!(
+                "\nfn run_your_program() -> std::process::Output {{\n    \
+                 std::process::Command::new(env!(\"CARGO_BIN_EXE_{}\"))\n        \
+                 .output()\n        \
+                 .expect(\"failed to run your program\")\n}}\n",
+                self.package
+            ));
+            out.push_str(RUNS_TEST);
+        }
+        // โจทย์ให้เขียน test เอง → ต้องมี #[test] อย่างน้อยเท่าเฉลย
+        let tests = self.solution.matches("#[test]").count();
+        if tests > 0 {
+            out.push_str(
+                &OWN_TESTS
+                    .replace("{main}", &format!("../{}/src/main.rs", self.dir))
+                    .replace("{count}", &tests.to_string()),
+            );
+        }
+        if self.expected_output {
+            out.push_str(&OUTPUT_TEST.replace("{out}", &format!("{}.out", self.dir)));
+        }
+        out
+    }
+}
+
+const RUNS_TEST: &str = r#"
+#[test]
+fn runs_without_errors() {
+    let output = run_your_program();
+    assert!(
+        output.status.success(),
+        "your program exited with {}\n{}",
+        output.status,
+        String::from_utf8_lossy(&output.stderr)
+    );
+}
+"#;
+
+const OUTPUT_TEST: &str = r#"
+#[test]
+fn prints_the_same_as_the_solution() {
+    let output = run_your_program();
+    let yours = String::from_utf8_lossy(&output.stdout);
+    let expected = include_str!("{out}");
+    assert_eq!(
+        yours.trim_end(),
+        expected.trim_end(),
+        "your program prints something different from the solution"
+    );
+}
+"#;
+
+const OWN_TESTS: &str = r##"
+#[test]
+fn has_its_own_tests() {
+    let yours = include_str!("{main}").matches("#[test]").count();
+    assert!(
+        yours >= {count},
+        "write at least {count} #[test] functions (you have {})",
+        yours
+    );
+}
+"##;
+
+// ใช้ใน mod solution: เทียบผลของผู้เรียนกับเฉลย (panic ทั้งคู่ถือว่าเหมือนกัน)
+const COMPARE_HELPERS: &str = r#"
+    fn same(call: &str, yours: impl FnOnce() -> String, expected: impl FnOnce() -> String) {
+        let yours = std::panic::catch_unwind(std::panic::AssertUnwindSafe(yours));
+        let expected = std::panic::catch_unwind(std::panic::AssertUnwindSafe(expected));
+        match (yours, expected) {
+            (Ok(yours), Ok(expected)) => assert_eq!(yours, expected, "{}", call),
+            (Err(_), Err(_)) => {}
+            (Ok(yours), Err(_)) => panic!("{}: the solution panics, but yours returned {}", call, yours),
+            (Err(_), Ok(expected)) => panic!("{}: yours panics, the solution returns {}", call, expected),
+        }
+    }
+
+    fn close(call: &str, yours: impl FnOnce() -> f64, expected: impl FnOnce() -> f64) {
+        same(
+            call,
+            || format!("{:.6}", yours()),
+            || format!("{:.6}", expected()),
+        );
+    }
+"#;
+
+fn indent(text: &str) -> String {
+    text.lines()
+        .map(|line| match line {
+            "" => "\n".to_string(),
+            _ => format!("    {}\n", line),
+        })
+        .collect()
+}
+
+// -------------------------------------------
+// อ่าน items ของเฉลย
+// -------------------------------------------
+
+// api: fn ที่ตรวจตอน compile, behaviour: #[test] ที่เทียบผลกับเฉลย
+struct Collector<'a> {
+    source: &'a str,
+    api: Vec<String>,
+    behaviour: Vec<String>,
+    names: HashSet<String>,
+}
+
+impl<'a> Collector<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            api: Vec::new(),
+            behaviour: Vec::new(),
+            names: HashSet::new(),
+        }
+    }
+
+    // ชื่อ fn ที่ไม่ซ้ำ เช่น "_person", "_person_2"
+    fn name(&mut self, base: &str) -> String {
+        let base = format!("_{}", snake(base));
+        let mut name = base.clone();
+        let mut n = 1;
+        while !self.names.insert(name.clone()) {
+            n += 1;
+            name = format!("{}_{}", base, n);
+        }
+        name
+    }
+
+    // `prefix` = path ของ module เช่น "shapes::" (ว่าง = root)
+    fn items(&mut self, items: &[Item], prefix: &str) {
+        // ใน module ผู้เรียนเข้าถึงได้เฉพาะ item ที่เป็น pub
+        let visible = |vis: &Visibility| prefix.is_empty() || !matches!(vis, Visibility::Inherited);
+        let source = self.source;
+
+        for item in items {
+            match item {
+                Item::Fn(f) if f.sig.ident == "main" && prefix.is_empty() => {}
+                Item::Fn(f) if visible(&f.vis) => {
+                    let path = format!("{}{}", prefix, f.sig.ident);
+                    if prefix.is_empty() {
+                        self.api.extend(fn_signature(source, f));
+                    } else if f.sig.generics.params.is_empty() {
+                        let name = self.name(&path);
+                        self.api
+                            .push(format!("fn {}() {{\n    let _ = {};\n}}\n", name, path));
+                    }
+                    self.behaviour.extend(fn_behaviour(&path, f));
+                }
+                Item::Struct(s) if visible(&s.vis) => {
+                    let path = format!("{}{}", prefix, s.ident);
+                    let name = self.name(&path);
+                    let check = if prefix.is_empty() {
+                        struct_fields(source, &name, &path, &s.generics, &s.fields)
+                    } else {
+                        type_exists(source, &name, &path, &s.generics)
+                    };
+                    self.api.push(format!("// struct {}\n{}", path, check));
+                }
+                Item::Enum(e) if visible(&e.vis) => {
+                    let path = format!("{}{}", prefix, e.ident);
+                    let name = self.name(&path);
+                    let (generics, args, where_clause) = generic_parts(source, &e.generics);
+                    let arms: String = e
+                        .variants
+                        .iter()
+                        .map(|v| {
+                            let pattern = match v.fields {
+                                Fields::Named(_) => " { .. }",
+                                Fields::Unnamed(_) => "(..)",
+                                Fields::Unit => "",
+                            };
+                            format!("        {}::{}{} => {{}}\n", path, v.ident, pattern)
+                        })
+                        .collect();
+                    self.api.push(format!(
+                        "// enum {} (variant ต้องครบและไม่เกิน)\nfn {}{}(value: &{}{}){} {{\n    match value {{\n{}    }}\n}}\n",
+                        path, name, generics, path, args, where_clause, arms
+                    ));
+                }
+                Item::Trait(t) if visible(&t.vis) && t.generics.params.is_empty() => {
+                    let path = format!("{}{}", prefix, t.ident);
+                    let name = self.name(&path);
+                    let methods: String = t
+                        .items
+                        .iter()
+                        .filter_map(|item| match item {
+                            syn::TraitItem::Fn(m) if m.sig.generics.params.is_empty() => {
+                                Some(format!("    let _ = T::{};\n", m.sig.ident))
+                            }
+                            _ => None,
+                        })
+                        .collect();
+                    self.api.push(format!(
+                        "// trait {}\nfn {}<T: {}>() {{\n{}}}\n",
+                        path, name, path, methods
+                    ));
+                }
+                Item::Impl(i) if prefix.is_empty() => self.impl_block(i),
+                Item::Const(c) if visible(&c.vis) => {
+                    self.api.push(format!(
+                        "const _: {} = {}{};\n",
+                        text(source, &c.ty),
+                        prefix,
+                        c.ident
+                    ));
+                }
+                Item::Mod(m) if visible(&m.vis) => {
+                    if let Some((_, items)) = &m.content {
+                        self.items(items, &format!("{}{}::", prefix, m.ident));
+                    }
+                }
+                _ => {}
+            }
+        }
+    }
+
+    // impl Trait for Type → where Type: Trait, impl Type → method ต้องมี
+    fn impl_block(&mut self, block: &syn::ItemImpl) {
+        let source = self.source;
+        let self_ty = text(source, &block.self_ty);
+        let (generics, _, where_clause) = generic_parts(source, &block.generics);
+
+        match &block.trait_ {
+            Some((_, trait_path, _)) => {
+                let trait_path = text(source, trait_path);
+                let name = self.name(&format!("impl {} {}", self_ty, trait_path));
+                let bound = if where_clause.is_empty() {
+                    format!("\nwhere\n    {}: {},", self_ty, trait_path)
+                } else {
+                    let comma = if where_clause.trim_end().ends_with(',') {
+                        ""
+                    } else {
+                        ","
+                    };
+                    format!(
+                        "{}{}\n    {}: {},",
+                        where_clause, comma, self_ty, trait_path
+                    )
+                };
+                self.api.push(format!(
+                    "// impl {} for {}\nfn {}{}(){} {{}}\n",
+                    trait_path, self_ty, name, generics, bound
+                ));
+            }
+            None => {
+                let methods: String = block
+                    .items
+                    .iter()
+                    .filter_map(|item| match item {
+                        syn::ImplItem::Fn(m) if m.sig.generics.params.is_empty() => {
+                            Some(format!("    let _ = <{}>::{};\n", self_ty, m.sig.ident))
+                        }
+                        _ => None,
+                    })
+                    .collect();
+                if !methods.is_empty() {
+                    let name = self.name(&format!("impl {}", self_ty));
+                    self.api.push(format!(
+                        "// impl {}\nfn {}{}(){} {{\n{}}}\n",
+                        self_ty, name, generics, where_clause, methods
+                    ));
+                }
+            }
+        }
+    }
+}
+
+// ชื่อสิ่งที่เฉลยประกาศไว้ เช่น ["struct Person", "fn introduce"] (ใช้เป็น hint)
+pub(super) fn outline(solution: &str) -> Vec<String> {
+    let Ok(file) = syn::parse_file(solution) else {
+        return Vec::new();
+    };
+    let mut names = Vec::new();
+    outline_items(&file.items, "", &mut names);
+    names
+}
+
+fn outline_items(items: &[Item], prefix: &str, names: &mut Vec<String>) {
+    for item in items {
+        match item {
+            Item::Fn(f) if f.sig.ident != "main" || !prefix.is_empty() => {
+                names.push(format!("fn {}{}", prefix, f.sig.ident));
+            }
+            Item::Struct(s) => {
+                let fields: Vec<String> = s
+                    .fields
+                    .iter()
+                    .filter_map(|f| f.ident.as_ref().map(|i| i.to_string()))
+                    .collect();
+                if fields.is_empty() {
+                    names.push(format!("struct {}{}", prefix, s.ident));
+                } else {
+                    names.push(format!(
+                        "struct {}{} {{ {} }}",
+                        prefix,
+                        s.ident,
+                        fields.join(", ")
+                    ));
+                }
+            }
+            Item::Enum(e) => names.push(format!("enum {}{}", prefix, e.ident)),
+            Item::Trait(t) => names.push(format!("trait {}{}", prefix, t.ident)),
+            Item::Macro(m) => {
+                if let Some(ident) = &m.ident {
+                    names.push(format!("macro {}!", ident));
+                }
+            }
+            Item::Const(c) => names.push(format!("const {}{}", prefix, c.ident)),
+            Item::Mod(m) => {
+                names.push(format!("mod {}{}", prefix, m.ident));
+                if let Some((_, items)) = &m.content {
+                    outline_items(items, &format!("{}{}::", prefix, m.ident), names);
+                }
+            }
+            Item::Impl(i) => {
+                for item in &i.items {
+                    if let syn::ImplItem::Fn(m) = item {
+                        names.push(format!("fn {}{}", prefix, m.sig.ident));
+                    }
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+// -------------------------------------------
+// API (ตรวจตอน compile)
+// -------------------------------------------
+
+// fn add(a: i32, b: i32) -> i32  →  let _: fn(i32, i32) -> i32 = add;
+fn fn_signature(source: &str, f: &ItemFn) -> Option<String> {
+    let sig = &f.sig;
+    if sig.asyncness.is_some() || sig.constness.is_some() || sig.variadic.is_some() {
+        return None;
+    }
+    // fn pointer มีได้แค่ lifetime generics (for<'a>)
+    let mut lifetimes = Vec::new();
+    for param in &sig.generics.params {
+        match param {
+            GenericParam::Lifetime(l) if l.bounds.is_empty() => {
+                lifetimes.push(text(source, &l.lifetime))
+            }
+            _ => return None,
+        }
+    }
+    if sig.generics.where_clause.is_some() {
+        return None;
+    }
+
+    let mut inputs = Vec::new();
+    for input in &sig.inputs {
+        let FnArg::Typed(arg) = input else {
+            return None;
+        };
+        if contains_impl_trait(&arg.ty) {
+            return None;
+        }
+        inputs.push(text(source, &arg.ty));
+    }
+    let output = match &sig.output {
+        ReturnType::Default => String::new(),
+        ReturnType::Type(_, ty) if contains_impl_trait(ty) => return None,
+        ReturnType::Type(_, ty) => format!(" -> {}", text(source, ty)),
+    };
+
+    let binder = if lifetimes.is_empty() {
+        String::new()
+    } else {
+        format!("for<{}> ", lifetimes.join(", "))
+    };
+    let unsafety = if sig.unsafety.is_some() {
+        "unsafe "
+    } else {
+        ""
+    };
+    // extern "C" fn → fn pointer แบบ extern "C" เหมือนกัน
+    let abi = match &sig.abi {
+        Some(abi) => format!("{} ", text(source, abi)),
+        None => String::new(),
+    };
+    Some(format!(
+        "// fn {} (signature เดียวกับเฉลย)\nconst _: {}{}{}fn({}){} = {};\n",
+        sig.ident,
+        binder,
+        unsafety,
+        abi,
+        inputs.join(", "),
+        output,
+        sig.ident
+    ))
+}
+
+// struct ต้องมี field ครบ (ไม่ขาดไม่เกิน) และ type ตรงกับเฉลย
+fn struct_fields(
+    source: &str,
+    name: &str,
+    path: &str,
+    generics: &Generics,
+    fields: &Fields,
+) -> String {
+    let (params, args, where_clause) = generic_parts(source, generics);
+    let mut body = String::new();
+
+    match fields {
+        Fields::Named(named) => {
+            let names: Vec<String> = named
+                .named
+                .iter()
+                .filter_map(|f| f.ident.as_ref().map(|i| i.to_string()))
+                .collect();
+            body.push_str(&format!(
+                "    let {} {{ {} }} = value;\n",
+                path,
+                names.join(", ")
+            ));
+            for (name, field) in names.iter().zip(&named.named) {
+                body.push_str(&format!(
+                    "    let _: &{} = {};\n",
+                    text(source, &field.ty),
+                    name
+                ));
+            }
+        }
+        Fields::Unnamed(unnamed) => {
+            let names: Vec<String> = (0..unnamed.unnamed.len())
+                .map(|i| format!("f{}", i))
+                .collect();
+            body.push_str(&format!(
+                "    let {}({}) = value;\n",
+                path,
+                names.join(", ")
+            ));
+            for (name, field) in names.iter().zip(&unnamed.unnamed) {
+                body.push_str(&format!(
+                    "    let _: &{} = {};\n",
+                    text(source, &field.ty),
+                    name
+                ));
+            }
+        }
+        Fields::Unit => body.push_str(&format!("    let {} = value;\n", path)),
+    }
+
+    format!(
+        "fn {}{}(value: &{}{}){} {{\n{}}}\n",
+        name, params, path, args, where_clause, body
+    )
+}
+
+fn type_exists(source: &str, name: &str, path: &str, generics: &Generics) -> String {
+    let (params, args, where_clause) = generic_parts(source, generics);
+    format!(
+        "fn {}{}(value: &{}{}){} {{}}\n",
+        name, params, path, args, where_clause
+    )
+}
+
+// <'a, T: Display> → ("<'a, T: Display>", "<'a, T>", " where ...")
+fn generic_parts(source: &str, generics: &Generics) -> (String, String, String) {
+    if generics.params.is_empty() {
+        return Default::default();
+    }
+    let params: Vec<String> = generics.params.iter().map(|p| text(source, p)).collect();
+    let args: Vec<String> = generics
+        .params
+        .iter()
+        .map(|p| match p {
+            GenericParam::Lifetime(l) => text(source, &l.lifetime),
+            GenericParam::Type(t) => t.ident.to_string(),
+            GenericParam::Const(c) => c.ident.to_string(),
+        })
+        .collect();
+    let where_clause = generics
+        .where_clause
+        .as_ref()
+        .map(|w| format!("\n{}", text(source, w)))
+        .unwrap_or_default();
+    (
+        format!("<{}>", params.join(", ")),
+        format!("<{}>", args.join(", ")),
+        where_clause,
+    )
+}
+
+fn contains_impl_trait(ty: &Type) -> bool {
+    match ty {
+        Type::ImplTrait(_) => true,
+        Type::Reference(r) => contains_impl_trait(&r.elem),
+        Type::Slice(s) => contains_impl_trait(&s.elem),
+        Type::Array(a) => contains_impl_trait(&a.elem),
+        Type::Tuple(t) => t.elems.iter().any(contains_impl_trait),
+        Type::Paren(p) => contains_impl_trait(&p.elem),
+        Type::Path(p) => p.path.segments.iter().any(|s| match &s.arguments {
+            PathArguments::AngleBracketed(a) => a.args.iter().any(|arg| match arg {
+                GenericArgument::Type(t) => contains_impl_trait(t),
+                _ => false,
+            }),
+            _ => false,
+        }),
+        _ => false,
+    }
+}
+
+// -------------------------------------------
+// ผลลัพธ์เหมือนเฉลย
+// -------------------------------------------
+
+// `path` = "add" หรือ "math::add"
+fn fn_behaviour(path: &str, f: &ItemFn) -> Option<String> {
+    let sig: &Signature = &f.sig;
+    if !sig.generics.params.is_empty() || sig.asyncness.is_some() || sig.unsafety.is_some() {
+        return None;
+    }
+    // fn ที่ไม่คืนค่า (เช่น แค่ print) ไม่มีอะไรให้เทียบ
+    match &sig.output {
+        ReturnType::Type(_, ty) if is_simple(ty) => {}
+        _ => return None,
+    }
+
+    let mut samples = Vec::new();
+    for input in &sig.inputs {
+        let FnArg::Typed(arg) = input else {
+            return None;
+        };
+        samples.push(samples_for(&arg.ty)?);
+    }
+
+    let float = matches!(&sig.output, ReturnType::Type(_, ty) if is_float(ty));
+    let name = path;
+    let mut body = String::new();
+    for case in cases(&samples) {
+        let args = case.join(", ");
+        let call = format!("{}({})", name, args).replace('"', "\\\"");
+        if float {
+            body.push_str(&format!(
+                "    close(\"{}\", || crate::{}({}) as f64, || {}({}) as f64);\n",
+                call, name, args, name, args
+            ));
+        } else {
+            body.push_str(&format!(
+                "    same(\n        \"{}\",\n        || format!(\"{{:?}}\", crate::{}({})),\n        || format!(\"{{:?}}\", {}({})),\n    );\n",
+                call, name, args, name, args
+            ));
+        }
+    }
+
+    Some(format!(
+        "#[test]\nfn {}_matches_the_solution() {{\n{}}}\n",
+        snake(path),
+        body
+    ))
+}
+
+// ทุก combination ของ input (ไม่เกิน MAX_CASES)
+fn cases(samples: &[Vec<String>]) -> Vec<Vec<String>> {
+    let mut cases: Vec<Vec<String>> = vec![Vec::new()];
+    for values in samples {
+        cases = cases
+            .iter()
+            .flat_map(|case| {
+                values.iter().map(move |value| {
+                    let mut case = case.clone();
+                    case.push(value.clone());
+                    case
+                })
+            })
+            .take(MAX_CASES)
+            .collect();
+    }
+    cases
+}
+
+const SIGNED: [&str; 6] = ["0", "1", "-1", "7", "42", "-13"];
+const UNSIGNED: [&str; 6] = ["0", "1", "2", "7", "42", "100"];
+const FLOATS: [&str; 4] = ["0.0", "1.5", "-2.0", "10.0"];
+const CHARS: [&str; 4] = ["'a'", "'Z'", "'5'", "' '"];
+const STRS: [&str; 5] = [
+    "\"\"",
+    "\"hello\"",
+    "\"Rust\"",
+    "\"hello world\"",
+    "\"สวัสดี\"",
+];
+
+// ค่าตัวอย่างของ type (เขียนเป็น Rust expression) - None = สร้างไม่ได้
+fn samples_for(ty: &Type) -> Option<Vec<String>> {
+    let strings = |values: &[&str]| values.iter().map(|v| v.to_string()).collect::<Vec<_>>();
+
+    match ty {
+        Type::Reference(r) if r.mutability.is_none() => match &*r.elem {
+            Type::Path(p) if p.path.is_ident("str") => Some(strings(&STRS)),
+            Type::Slice(slice) => {
+                let items = samples_for(&slice.elem)?;
+                Some(
+                    lists(&items)
+                        .into_iter()
+                        .map(|l| format!("&{}", l))
+                        .collect(),
+                )
+            }
+            elem => Some(
+                samples_for(elem)?
+                    .into_iter()
+                    .map(|v| format!("&{}", v))
+                    .collect(),
+            ),
+        },
+        Type::Path(p) if p.qself.is_none() => {
+            let segment = p.path.segments.last()?;
+            let name = segment.ident.to_string();
+            match (name.as_str(), inner_types(segment).as_slice()) {
+                ("i8" | "i16" | "i32" | "i64" | "i128" | "isize", []) => Some(strings(&SIGNED)),
+                ("u8" | "u16" | "u32" | "u64" | "u128" | "usize", []) => Some(strings(&UNSIGNED)),
+                ("f32" | "f64", []) => Some(strings(&FLOATS)),
+                ("bool", []) => Some(strings(&["true", "false"])),
+                ("char", []) => Some(strings(&CHARS)),
+                ("String", []) => Some(
+                    STRS.iter()
+                        .map(|s| format!("String::from({})", s))
+                        .collect(),
+                ),
+                ("Vec", [inner]) => {
+                    let items = samples_for(inner)?;
+                    Some(
+                        lists(&items)
+                            .into_iter()
+                            .map(|l| format!("vec!{}", l))
+                            .collect(),
+                    )
+                }
+                ("Option", [inner]) => {
+                    let mut values = vec!["None".to_string()];
+                    values.extend(
+                        samples_for(inner)?
+                            .into_iter()
+                            .take(3)
+                            .map(|v| format!("Some({})", v)),
+                    );
+                    Some(values)
+                }
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+// [a, b, c, ...] → "[]", "[a]", "[b, a, c]", "[c, c, a, b]"
+fn lists(items: &[String]) -> Vec<String> {
+    let at = |i: usize| items[i % items.len()].clone();
+    vec![
+        "[]".to_string(),
+        format!("[{}]", at(0)),
+        format!("[{}, {}, {}]", at(1), at(0), at(2)),
+        format!("[{}, {}, {}, {}]", at(2), at(2), at(0), at(1)),
+    ]
+}
+
+fn inner_types(segment: &syn::PathSegment) -> Vec<&Type> {
+    match &segment.arguments {
+        PathArguments::AngleBracketed(a) => a
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(t) => Some(t),
+                _ => None,
+            })
+            .collect(),
+        _ => Vec::new(),
+    }
+}
+
+// type ของ std ที่เทียบด้วย {:?} ได้ (ไม่มี type ที่ผู้เรียนประกาศเอง)
+fn is_simple(ty: &Type) -> bool {
+    const NAMES: [&str; 21] = [
+        "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16", "u32", "u64", "u128", "usize",
+        "f32", "f64", "bool", "char", "str", "String", "Vec", "Option", "Result",
+    ];
+    match ty {
+        Type::Reference(r) => is_simple(&r.elem),
+        Type::Slice(s) => is_simple(&s.elem),
+        Type::Tuple(t) => t.elems.iter().all(is_simple),
+        Type::Path(p) if p.qself.is_none() && p.path.segments.len() == 1 => {
+            let segment = &p.path.segments[0];
+            NAMES.contains(&segment.ident.to_string().as_str())
+                && inner_types(segment).into_iter().all(is_simple)
+        }
+        _ => false,
+    }
+}
+
+fn is_float(ty: &Type) -> bool {
+    matches!(ty, Type::Path(p) if p.path.is_ident("f32") || p.path.is_ident("f64"))
+}
+
+// -------------------------------------------
+// ตัวช่วย
+// -------------------------------------------
+
+// ข้อความในเฉลยตรงตำแหน่งของ node (คงรูปแบบเดิม เช่น "&'a str")
+fn text(source: &str, node: &impl Spanned) -> String {
+    let span = node.span();
+    let (start, end) = (span.start(), span.end());
+    let offset = |line: usize, column: usize| -> usize {
+        let line_start: usize = source
+            .split_inclusive('\n')
+            .take(line - 1)
+            .map(str::len)
+            .sum();
+        let rest = &source[line_start..];
+        line_start
+            + rest
+                .char_indices()
+                .nth(column)
+                .map(|(i, _)| i)
+                .unwrap_or(rest.len())
+    };
+    source[offset(start.line, start.column)..offset(end.line, end.column)].to_string()
+}
+
+// "shapes::Circle<T>" → "shapes_circle_t"
+fn snake(name: &str) -> String {
+    let mut out = String::new();
+    let mut previous_lower = false;
+    for c in name.chars() {
+        if c.is_ascii_alphanumeric() {
+            if c.is_ascii_uppercase() && previous_lower {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+            previous_lower = c.is_ascii_lowercase() || c.is_ascii_digit();
+        } else if !out.ends_with('_') && !out.is_empty() {
+            out.push('_');
+            previous_lower = false;
+        }
+    }
+    out.trim_end_matches('_').to_string()
+}
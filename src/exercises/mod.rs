@@ -0,0 +1,20 @@
+// ===========================================
+// Exercises - แบบฝึกหัดท้ายบทจาก docs/src/appendix/exercises
+// ===========================================
+//
+// - book.rs:  อ่านไฟล์ markdown เป็น Chapter / Exercise / Snippet
+// - check.rs: compile และรัน snippet ตาม annotation (compile_fail, ignore, ...)
+// - workspace.rs: สร้างโฟลเดอร์ฝึกทำ หนึ่ง crate ต่อข้อ และตรวจด้วย hidden tests
+// - hidden.rs: สร้าง hidden tests จากเฉลย
+
+mod book;
+mod check;
+mod hidden;
+mod workspace;
+
+pub use book::{Attributes, Chapter, Exercise, ExerciseError, Place, Snippet, load_book};
+pub(crate) use check::run_with_timeout;
+pub use check::{
+    ChapterReport, Checker, Expectation, Outcome, SnippetResult, expectation, program,
+};
+pub use workspace::{MANIFEST, Practice, Skipped, Status, Workspace};
@@ -0,0 +1,488 @@
+// ===========================================
+// workspace.rs - สร้างโฟลเดอร์ฝึกทำแบบฝึกหัด (แบบ rustlings)
+// ===========================================
+//
+// หนึ่งบท = หนึ่ง cargo workspace, หนึ่งข้อ = หนึ่ง crate:
+//
+//   practice/ch05/
+//   ├── Cargo.toml            [workspace] members = ["ex1", "ex2", ...]
+//   ├── README.md
+//   ├── ex1/
+//   │   ├── Cargo.toml        [[test]] hidden → ../.hidden/ex1.rs
+//   │   ├── README.md         โจทย์
+//   │   └── src/main.rs       โค้ดตั้งต้นจากโจทย์ (มักจะยัง compile ไม่ผ่าน)
+//   └── .hidden/
+//       ├── exercises.json    รายการข้อ + คำใบ้
+//       ├── ex1.rs            hidden tests (สร้างจากเฉลย ดู hidden.rs)
+//       ├── ex1_solution.rs   เฉลย
+//       └── ex1.out           สิ่งที่เฉลยพิมพ์ (ถ้าต้องพิมพ์ให้เหมือน)
+//
+// ตรวจแต่ละข้อด้วย `cargo test -p ch05-ex1`
+
+use super::book::{Chapter, Exercise, ExerciseError, Place, Snippet};
+use super::check::{
+    Checker, DEFAULT_EDITION, Expectation, command_error, expectation, has_main, run_with_timeout,
+    split_attributes, with_main, write,
+};
+use super::hidden::{HiddenTests, outline};
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use std::time::Duration;
+
+pub const MANIFEST: &str = ".hidden/exercises.json";
+
+// เวลาสูงสุดของ cargo test หนึ่งข้อ (รวม compile)
+const CHECK_TIMEOUT: Duration = Duration::from_secs(120);
+
+// จำนวนคอมเมนต์จากเฉลยที่ใช้เป็นคำใบ้
+const MAX_COMMENT_HINTS: usize = 3;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Workspace {
+    pub chapter: u32,
+    pub title: String,
+    pub exercises: Vec<Practice>,
+    // ข้อที่สร้างไม่ได้ (เช่น ไม่มีเฉลยที่เป็นโค้ด)
+    pub skipped: Vec<Skipped>,
+}
+
+// หนึ่งข้อใน workspace
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Practice {
+    pub number: u32,
+    pub title: String,
+    // ชื่อ package เช่น "ch05-ex1"
+    pub package: String,
+    // โฟลเดอร์ เช่น "ex1"
+    pub dir: String,
+    // เรียงจากใบ้น้อยไปใบ้มาก
+    pub hints: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Skipped {
+    pub number: u32,
+    pub title: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Status {
+    Done,
+    // reason: สรุปสั้นๆ, output: error จาก compiler หรือ test ที่ไม่ผ่าน
+    Unfinished { reason: String, output: String },
+}
+
+impl Status {
+    pub fn is_done(&self) -> bool {
+        matches!(self, Status::Done)
+    }
+}
+
+impl Workspace {
+    // สร้าง workspace ของบทลงใน `dir` (ต้องยังไม่มีหรือว่างอยู่)
+    // ใช้ checker รันเฉลยเพื่อเก็บ output และเอา dependency ของ project มาใส่
+    pub fn generate(
+        chapter: &Chapter,
+        checker: &Checker,
+        dir: impl AsRef<Path>,
+    ) -> Result<Self, ExerciseError> {
+        let dir = dir.as_ref();
+        if fs::read_dir(dir).is_ok_and(|mut entries| entries.next().is_some()) {
+            return Err(ExerciseError::WorkspaceExists(dir.to_path_buf()));
+        }
+
+        let mut workspace = Workspace {
+            chapter: chapter.chapter,
+            title: chapter.title.clone(),
+            exercises: Vec::new(),
+            skipped: Vec::new(),
+        };
+
+        for exercise in &chapter.exercises {
+            let Some(solution) = solution(exercise) else {
+                workspace.skipped.push(Skipped {
+                    number: exercise.number,
+                    title: exercise.title.clone(),
+                    reason:
+                        "no solution code that can be checked (only text, ignore or compile_fail)"
+                            .to_string(),
+                });
+                continue;
+            };
+            let practice = Practice {
+                number: exercise.number,
+                title: exercise.title.clone(),
+                package: format!("ch{:02}-ex{}", chapter.chapter, exercise.number),
+                dir: format!("ex{}", exercise.number),
+                hints: hints(exercise, solution),
+            };
+            write_exercise(dir, chapter, exercise, &practice, solution, checker)?;
+            workspace.exercises.push(practice);
+        }
+
+        write_root(dir, &workspace, checker)?;
+        let json =
+            serde_json::to_string_pretty(&workspace).map_err(|source| ExerciseError::Manifest {
+                path: dir.join(MANIFEST),
+                source,
+            })?;
+        write(&dir.join(MANIFEST), &format!("{}\n", json))?;
+        Ok(workspace)
+    }
+
+    pub fn load(dir: impl AsRef<Path>) -> Result<Self, ExerciseError> {
+        let path = dir.as_ref().join(MANIFEST);
+        let content = fs::read_to_string(&path).map_err(|source| ExerciseError::Io {
+            path: path.clone(),
+            source,
+        })?;
+        serde_json::from_str(&content).map_err(|source| ExerciseError::Manifest { path, source })
+    }
+
+    pub fn find(&self, number: u32) -> Option<&Practice> {
+        self.exercises
+            .iter()
+            .find(|practice| practice.number == number)
+    }
+}
+
+impl Practice {
+    // รัน hidden tests ของข้อนี้ (`dir` = โฟลเดอร์ของ workspace)
+    pub fn check(&self, dir: impl AsRef<Path>) -> Result<Status, ExerciseError> {
+        let mut command = Command::new("cargo");
+        command
+            .args(["test", "--quiet", "--color", "never", "-p", &self.package])
+            .current_dir(dir.as_ref())
+            // build ลง target/ ของ workspace เสมอ
+            .env_remove("CARGO_TARGET_DIR")
+            // test ที่ไม่ผ่านไม่ต้องมี stack backtrace
+            .env("RUST_BACKTRACE", "0");
+
+        let Some(output) = run_with_timeout(command, CHECK_TIMEOUT)
+            .map_err(|source| command_error("cargo", source))?
+        else {
+            return Ok(Status::Unfinished {
+                reason: format!("timed out after {}s", CHECK_TIMEOUT.as_secs()),
+                output: String::new(),
+            });
+        };
+        if output.status.success() {
+            return Ok(Status::Done);
+        }
+
+        let stderr = String::from_utf8_lossy(&output.stderr);
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Ok(if stderr.contains("error: could not compile") {
+            Status::Unfinished {
+                reason: "does not compile".to_string(),
+                output: self.tidy_paths(&compile_errors(&stderr)),
+            }
+        } else {
+            Status::Unfinished {
+                reason: "tests failed".to_string(),
+                output: self.tidy_paths(&test_failures(&stdout)),
+            }
+        })
+    }
+
+    // "ex1/../.hidden/../ex1/src/main.rs" → "ex1/src/main.rs"
+    fn tidy_paths(&self, output: &str) -> String {
+        output
+            .replace(&format!("{}/../.hidden/../", self.dir), "")
+            .replace(&format!("{}/../.hidden/", self.dir), ".hidden/")
+    }
+}
+
+// -------------------------------------------
+// เลือกโค้ดจากหนังสือ
+// -------------------------------------------
+
+// เฉลยแรกที่ compile ผ่าน (ข้าม compile_fail และ ignore)
+fn solution(exercise: &Exercise) -> Option<&Snippet> {
+    exercise.snippets.iter().find(|snippet| {
+        snippet.place == Place::Solution
+            && snippet.is_rust()
+            && matches!(
+                expectation(snippet, false),
+                Some(Expectation::Run | Expectation::Compile | Expectation::Panic)
+            )
+    })
+}
+
+fn starter(exercise: &Exercise) -> Option<&Snippet> {
+    exercise
+        .snippets
+        .iter()
+        .find(|snippet| snippet.place == Place::Problem && snippet.is_rust())
+}
+
+// โค้ดที่ include! ได้: ไม่มี #![...] และมี fn main
+// snippet ที่มีแต่ item (fn, struct, mod tests, ...) ได้ fn main ว่างต่อท้าย
+// ไม่ครอบด้วย fn main เพราะ hidden tests ต้องเห็น item และ `use super::*` ใน mod tests ต้องใช้ได้
+fn source(snippet: &Snippet) -> String {
+    let (_, code) = split_attributes(&snippet.code);
+    if is_items_only(&code) {
+        format!("{}\n\nfn main() {{}}\n", code.trim_end())
+    } else {
+        with_main(&code)
+    }
+}
+
+fn is_items_only(code: &str) -> bool {
+    syn::parse_file(code).is_ok_and(|file| {
+        !file.items.is_empty()
+            && file.items.iter().all(|item| match item {
+                syn::Item::Fn(f) => f.sig.ident != "main",
+                // println!(...); ก็ parse เป็น item ได้ แต่ต้องอยู่ใน fn
+                syn::Item::Macro(m) => m.ident.is_some(),
+                _ => true,
+            })
+    })
+}
+
+// เฉลยที่ include! คู่กับโค้ดของผู้เรียน: ชื่อ symbol แบบ no_mangle จะชนกันตอน link
+fn solution_source(snippet: &Snippet) -> String {
+    source(snippet)
+        .lines()
+        .filter(|line| !matches!(line.trim(), "#[no_mangle]" | "#[unsafe(no_mangle)]"))
+        .map(|line| format!("{}\n", line))
+        .collect()
+}
+
+// แบบเดียวกับ source แต่ย่อหน้าให้อ่านง่าย (ไฟล์นี้ผู้เรียนต้องแก้เอง)
+fn starter_source(snippet: &Snippet) -> String {
+    let (_, code) = split_attributes(&snippet.code);
+    if has_main(&code) {
+        return format!("{}\n", code.trim_end());
+    }
+    if is_items_only(&code) {
+        return format!("{}\n\nfn main() {{}}\n", code.trim_end());
+    }
+    let body: String = code
+        .trim_end()
+        .lines()
+        .map(|line| match line {
+            "" => "\n".to_string(),
+            _ => format!("    {}\n", line),
+        })
+        .collect();
+    format!("fn main() {{\n{}}}\n", body)
+}
+
+// บรรทัดที่พิมพ์ผล เช่น `println!("{}", s);`
+fn print_lines(code: &str) -> Vec<&str> {
+    let mut lines: Vec<&str> = code
+        .lines()
+        .map(str::trim)
+        .filter(|line| line.contains("print!(") || line.contains("println!("))
+        .collect();
+    lines.sort_unstable();
+    lines
+}
+
+// คำใบ้: คำอธิบายในเฉลย → คอมเมนต์ในโค้ดเฉลย → สิ่งที่เฉลยประกาศไว้
+fn hints(exercise: &Exercise, solution: &Snippet) -> Vec<String> {
+    let mut hints: Vec<String> = exercise
+        .explanation
+        .lines()
+        .map(str::trim)
+        // ข้ามหัวข้อเปล่าๆ เช่น "**วิธีแก้:**"
+        .filter(|line| !line.is_empty())
+        .filter(|line| !(line.starts_with("**") && line.ends_with(":**")))
+        // แสดงใน terminal จึงเอา markdown ตัวหนาออก
+        .map(|line| line.trim_start_matches("- ").replace("**", ""))
+        .collect();
+
+    hints.extend(
+        solution
+            .code
+            .lines()
+            .filter_map(|line| line.trim().strip_prefix("// "))
+            // "// src/main.rs" เป็นชื่อไฟล์ ไม่ใช่คำใบ้
+            .filter(|comment| !comment.ends_with(".rs"))
+            .take(MAX_COMMENT_HINTS)
+            .map(str::to_string),
+    );
+
+    let names = outline(&source(solution));
+    if !names.is_empty() {
+        hints.push(format!("เฉลยมี: {}", names.join(", ")));
+    }
+    hints
+}
+
+// -------------------------------------------
+// เขียนไฟล์
+// -------------------------------------------
+
+fn write_exercise(
+    dir: &Path,
+    chapter: &Chapter,
+    exercise: &Exercise,
+    practice: &Practice,
+    solution: &Snippet,
+    checker: &Checker,
+) -> Result<(), ExerciseError> {
+    let crate_dir = dir.join(&practice.dir);
+    let hidden_dir = dir.join(".hidden");
+    let solution_source = solution_source(solution);
+    let starter = starter(exercise);
+
+    let header = format!(
+        "// แบบฝึกหัดที่ {}: {} (บทที่ {} - {})\n\
+         // โจทย์อยู่ใน README.md - ตรวจด้วย `practice check {}` ใบ้ด้วย `practice hint {}`\n\n",
+        exercise.number,
+        exercise.title,
+        chapter.chapter,
+        chapter.title,
+        exercise.number,
+        exercise.number
+    );
+    let main = match starter {
+        Some(snippet) => format!("{}{}", header, starter_source(snippet)),
+        None => format!(
+            "{}fn main() {{\n    todo!(\"เขียนตามโจทย์ใน README.md\")\n}}\n",
+            header
+        ),
+    };
+
+    // ต้องพิมพ์เหมือนเฉลยเฉพาะข้อที่ให้โค้ดตั้งต้นมา และเฉลยไม่ได้เปลี่ยนสิ่งที่พิมพ์
+    // (output ต้องเหมือนเดิมทุกครั้งที่รัน)
+    let runs = expectation(solution, false) == Some(Expectation::Run);
+    let mut expected_output = None;
+    if runs
+        && let Some(starter) = starter
+        && print_lines(&starter.code) == print_lines(&solution.code)
+        && let Some(first) = checker.output(solution)?
+        && checker.output(solution)?.as_ref() == Some(&first)
+    {
+        expected_output = Some(first);
+    }
+
+    let tests = HiddenTests {
+        chapter: chapter.chapter,
+        number: exercise.number,
+        title: &exercise.title,
+        package: &practice.package,
+        dir: &practice.dir,
+        solution: &solution_source,
+        runs,
+        expected_output: expected_output.is_some(),
+    };
+
+    let edition = solution.attributes().edition;
+    let dependencies = checker.dependencies_for(&solution_source);
+    let manifest = format!(
+        "[package]\nname = \"{}\"\nversion = \"0.1.0\"\nedition = \"{}\"\npublish = false\n\n\
+         [dependencies]\n{}\n\n\
+         # hidden tests ที่สร้างจากเฉลย\n[[test]]\nname = \"hidden\"\npath = \"../.hidden/{}.rs\"\n",
+        practice.package,
+        edition.as_deref().unwrap_or(DEFAULT_EDITION),
+        dependencies.join("\n"),
+        practice.dir
+    );
+
+    write(&crate_dir.join("Cargo.toml"), &manifest)?;
+    write(
+        &crate_dir.join("README.md"),
+        &format!(
+            "# แบบฝึกหัดที่ {}: {}\n\n{}\n",
+            exercise.number, exercise.title, exercise.description
+        ),
+    )?;
+    write(&crate_dir.join("src/main.rs"), &main)?;
+    write(
+        &hidden_dir.join(format!("{}.rs", practice.dir)),
+        &tests.render(),
+    )?;
+    write(
+        &hidden_dir.join(format!("{}_solution.rs", practice.dir)),
+        &solution_source,
+    )?;
+    if let Some(output) = expected_output {
+        write(&hidden_dir.join(format!("{}.out", practice.dir)), &output)?;
+    }
+    Ok(())
+}
+
+fn write_root(dir: &Path, workspace: &Workspace, checker: &Checker) -> Result<(), ExerciseError> {
+    let members: Vec<String> = workspace
+        .exercises
+        .iter()
+        .map(|practice| format!("    \"{}\",", practice.dir))
+        .collect();
+    write(
+        &dir.join("Cargo.toml"),
+        &format!(
+            "[workspace]\nmembers = [\n{}\n]\nresolver = \"3\"\n",
+            members.join("\n")
+        ),
+    )?;
+
+    let list: Vec<String> = workspace
+        .exercises
+        .iter()
+        .map(|practice| {
+            format!(
+                "- `{}/` แบบฝึกหัดที่ {}: {}",
+                practice.dir, practice.number, practice.title
+            )
+        })
+        .collect();
+    write(
+        &dir.join("README.md"),
+        &format!(
+            "# บทที่ {} - {}\n\n\
+             แก้ `src/main.rs` ของแต่ละข้อจนกว่า `practice check` จะผ่าน\n\n\
+             ```bash\n\
+             practice check       # ตรวจทุกข้อ\n\
+             practice check 2     # ตรวจเฉพาะข้อ 2\n\
+             practice hint 2      # ขอคำใบ้ข้อ 2\n\
+             ```\n\n\
+             {}\n",
+            workspace.chapter,
+            workspace.title,
+            list.join("\n")
+        ),
+    )?;
+
+    // ใช้ version ของ dependency ชุดเดียวกับ project (build offline ได้)
+    if let Some(lockfile) = checker.lockfile() {
+        fs::copy(lockfile, dir.join("Cargo.lock")).map_err(|source| ExerciseError::Io {
+            path: lockfile.to_path_buf(),
+            source,
+        })?;
+    }
+    Ok(())
+}
+
+// -------------------------------------------
+// อ่านผลของ cargo test
+// -------------------------------------------
+
+// เฉพาะก้อน error ของ compiler (ข้าม warning)
+fn compile_errors(stderr: &str) -> String {
+    stderr
+        .split("\n\n")
+        .filter(|block| {
+            let block = block.trim_start();
+            block.starts_with("error") && !block.starts_with("error: could not compile")
+        })
+        .collect::<Vec<_>>()
+        .join("\n\n")
+        .trim_end()
+        .to_string()
+}
+
+// ส่วน "---- test stdout ----" ของ test ที่ไม่ผ่าน
+fn test_failures(stdout: &str) -> String {
+    let Some(start) = stdout.find("failures:\n") else {
+        return stdout.trim_end().to_string();
+    };
+    let failures = &stdout[start + "failures:\n".len()..];
+    let end = failures.find("\nfailures:\n").unwrap_or(failures.len());
+    failures[..end].trim().to_string()
+}
@@ -0,0 +1,71 @@
+// ===========================================
+// circle.rs - วงกลม
+// ===========================================
+
+use super::{GeometryError, Point, Rect, Shape};
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawCircle")]
+pub struct Circle {
+    pub center: Point,
+    pub radius: f64,
+}
+
+#[derive(Deserialize)]
+struct RawCircle {
+    center: Point,
+    radius: f64,
+}
+
+impl TryFrom<RawCircle> for Circle {
+    type Error = GeometryError;
+
+    fn try_from(raw: RawCircle) -> Result<Self, Self::Error> {
+        Circle::new(raw.center, raw.radius)
+    }
+}
+
+impl Circle {
+    pub fn new(center: Point, radius: f64) -> Result<Self, GeometryError> {
+        if !radius.is_finite() {
+            return Err(GeometryError::NotFinite("radius"));
+        }
+        if radius < 0.0 {
+            return Err(GeometryError::Negative {
+                what: "radius",
+                value: radius,
+            });
+        }
+        Ok(Self { center, radius })
+    }
+
+    pub fn intersects(&self, other: &Circle) -> bool {
+        self.center.distance_to(other.center) <= self.radius + other.radius
+    }
+}
+
+impl Shape for Circle {
+    fn area(&self) -> f64 {
+        PI * self.radius * self.radius
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * PI * self.radius
+    }
+
+    fn bounding_box(&self) -> Rect {
+        let diameter = 2.0 * self.radius;
+        Rect::new(
+            self.center.x - self.radius,
+            self.center.y - self.radius,
+            diameter,
+            diameter,
+        )
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        self.center.distance_to(point) <= self.radius
+    }
+}
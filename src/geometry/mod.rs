@@ -0,0 +1,26 @@
+// ===========================================
+// Geometry - รูปทรง 2 มิติ (ต่อยอดจาก Rectangle บทที่ 5 และ Point<f64> บทที่ 10)
+// ===========================================
+//
+// - shape.rs:   trait Shape (พื้นที่, เส้นรอบรูป, กรอบสี่เหลี่ยม, จุดอยู่ข้างในไหม)
+// - point.rs:   Point
+// - rect.rs:    Rect ที่มีตำแหน่ง (ตัดกัน, รวมกัน, อยู่ข้างใน) และ Size
+// - circle.rs:  Circle
+// - polygon.rs: Polygon (จุดเรียงรอบรูป)
+// - pack.rs:    จัดวางสี่เหลี่ยมหลายอันลงในความกว้างที่กำหนด
+//
+// แกน y เพิ่มลงล่างแบบหน้าจอ: Rect เริ่มที่มุมบนซ้าย (x, y)
+
+mod circle;
+mod pack;
+mod point;
+mod polygon;
+mod rect;
+mod shape;
+
+pub use circle::Circle;
+pub use pack::{Packing, pack};
+pub use point::Point;
+pub use polygon::Polygon;
+pub use rect::{Rect, Size};
+pub use shape::{GeometryError, Shape};
@@ -0,0 +1,105 @@
+// ===========================================
+// pack.rs - จัดวางสี่เหลี่ยมลงในความกว้างที่กำหนด (shelf packing)
+// ===========================================
+//
+// เรียงจากสูงไปเตี้ย แล้ววางทีละชั้น (shelf) จากซ้ายไปขวา
+// แต่ละชิ้นลงชั้นแรกที่ยังเหลือที่พอ ไม่มีก็เปิดชั้นใหม่ด้านล่าง
+// ความสูงของชั้น = ชิ้นแรกที่วาง (สูงสุดในชั้น) ชิ้นหลังๆ เตี้ยกว่าจึงใส่ได้เสมอ
+// ไม่ได้ดีที่สุดเสมอ แต่ง่ายและไม่มีชิ้นไหนทับกัน
+
+use super::{GeometryError, Point, Rect, Shape, Size};
+use std::cmp::Ordering;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Packing {
+    // ตำแหน่งของแต่ละชิ้น เรียงตาม input
+    pub placements: Vec<Rect>,
+    pub width: f64,
+    // ความสูงรวมที่ใช้
+    pub height: f64,
+}
+
+impl Packing {
+    pub fn bounds(&self) -> Rect {
+        Rect::new(0.0, 0.0, self.width, self.height)
+    }
+
+    // สัดส่วนพื้นที่ที่ถูกใช้ (0.0 - 1.0)
+    pub fn fill_ratio(&self) -> f64 {
+        let total = self.bounds().area();
+        if total == 0.0 {
+            return 0.0;
+        }
+        self.placements.iter().map(Shape::area).sum::<f64>() / total
+    }
+}
+
+struct Shelf {
+    y: f64,
+    height: f64,
+    // ความกว้างที่ใช้ไปแล้ว
+    used: f64,
+}
+
+pub fn pack(bin_width: f64, items: &[Size]) -> Result<Packing, GeometryError> {
+    if !bin_width.is_finite() {
+        return Err(GeometryError::NotFinite("bin width"));
+    }
+    for (index, item) in items.iter().enumerate() {
+        if !item.width.is_finite() || !item.height.is_finite() {
+            return Err(GeometryError::NotFinite("item size"));
+        }
+        if item.width < 0.0 || item.height < 0.0 {
+            return Err(GeometryError::Negative {
+                what: "item size",
+                value: item.width.min(item.height),
+            });
+        }
+        if item.width > bin_width {
+            return Err(GeometryError::TooWide {
+                index,
+                width: item.width,
+                bin_width,
+            });
+        }
+    }
+
+    // สูงก่อน ถ้าเท่ากันเอากว้างก่อน (sort แบบ stable ลำดับเดิมไม่สลับ)
+    let mut order: Vec<usize> = (0..items.len()).collect();
+    order.sort_by(|&a, &b| {
+        let (a, b) = (items[a], items[b]);
+        b.height
+            .partial_cmp(&a.height)
+            .unwrap_or(Ordering::Equal)
+            .then(b.width.partial_cmp(&a.width).unwrap_or(Ordering::Equal))
+    });
+
+    let mut placements = vec![Rect::default(); items.len()];
+    let mut shelves: Vec<Shelf> = Vec::new();
+    for index in order {
+        let item = items[index];
+        let shelf = match shelves
+            .iter_mut()
+            .position(|shelf| shelf.used + item.width <= bin_width)
+        {
+            Some(found) => &mut shelves[found],
+            None => {
+                let y = shelves.last().map_or(0.0, |last| last.y + last.height);
+                shelves.push(Shelf {
+                    y,
+                    height: item.height,
+                    used: 0.0,
+                });
+                shelves.last_mut().expect("just pushed")
+            }
+        };
+        placements[index] = Rect::at(Point::new(shelf.used, shelf.y), item);
+        shelf.used += item.width;
+    }
+
+    Ok(Packing {
+        placements,
+        width: bin_width,
+        height: shelves.last().map_or(0.0, |last| last.y + last.height),
+    })
+}
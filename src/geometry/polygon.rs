@@ -0,0 +1,100 @@
+// ===========================================
+// polygon.rs - รูปหลายเหลี่ยม (จุดเรียงรอบรูป ตามหรือทวนเข็มก็ได้)
+// ===========================================
+
+use super::{GeometryError, Point, Rect, Shape};
+use serde::{Deserialize, Serialize};
+
+// อ่านจาก JSON ผ่าน Polygon::new เสมอ (จุดไม่ถึง 3 จุดทำให้ bounding_box panic)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "RawPolygon")]
+pub struct Polygon {
+    points: Vec<Point>,
+}
+
+#[derive(Deserialize)]
+struct RawPolygon {
+    points: Vec<Point>,
+}
+
+impl TryFrom<RawPolygon> for Polygon {
+    type Error = GeometryError;
+
+    fn try_from(raw: RawPolygon) -> Result<Self, Self::Error> {
+        Polygon::new(raw.points)
+    }
+}
+
+impl Polygon {
+    pub fn new(points: Vec<Point>) -> Result<Self, GeometryError> {
+        if points.len() < 3 {
+            return Err(GeometryError::TooFewPoints(points.len()));
+        }
+        if points.iter().any(|p| !p.x.is_finite() || !p.y.is_finite()) {
+            return Err(GeometryError::NotFinite("polygon point"));
+        }
+        Ok(Self { points })
+    }
+
+    pub fn points(&self) -> &[Point] {
+        &self.points
+    }
+
+    // ด้านทั้งหมด รวมด้านจากจุดสุดท้ายกลับไปจุดแรก
+    fn edges(&self) -> impl Iterator<Item = (Point, Point)> + '_ {
+        self.points
+            .iter()
+            .copied()
+            .zip(self.points.iter().copied().cycle().skip(1))
+    }
+}
+
+impl Shape for Polygon {
+    // shoelace formula
+    fn area(&self) -> f64 {
+        let twice: f64 = self.edges().map(|(a, b)| a.x * b.y - b.x * a.y).sum();
+        twice.abs() / 2.0
+    }
+
+    fn perimeter(&self) -> f64 {
+        self.edges().map(|(a, b)| a.distance_to(b)).sum()
+    }
+
+    fn bounding_box(&self) -> Rect {
+        let first = self.points[0];
+        let (min, max) = self.points.iter().fold((first, first), |(min, max), p| {
+            (
+                Point::new(min.x.min(p.x), min.y.min(p.y)),
+                Point::new(max.x.max(p.x), max.y.max(p.y)),
+            )
+        });
+        Rect::from_corners(min, max)
+    }
+
+    // ray casting: ลากเส้นจากจุดไปทางขวา ตัดขอบเป็นจำนวนคี่ = อยู่ข้างใน
+    fn contains(&self, point: Point) -> bool {
+        if self.edges().any(|(a, b)| on_segment(point, a, b)) {
+            return true;
+        }
+        let mut inside = false;
+        for (a, b) in self.edges() {
+            if (a.y > point.y) != (b.y > point.y) {
+                let x = a.x + (point.y - a.y) * (b.x - a.x) / (b.y - a.y);
+                if point.x < x {
+                    inside = !inside;
+                }
+            }
+        }
+        inside
+    }
+}
+
+fn on_segment(point: Point, a: Point, b: Point) -> bool {
+    let cross = (b.x - a.x) * (point.y - a.y) - (b.y - a.y) * (point.x - a.x);
+    let tolerance = 1e-9 * a.distance_to(b).max(1.0);
+    cross.abs() <= tolerance
+        && point.x >= a.x.min(b.x) - tolerance
+        && point.x <= a.x.max(b.x) + tolerance
+        && point.y >= a.y.min(b.y) - tolerance
+        && point.y <= a.y.max(b.y) + tolerance
+}
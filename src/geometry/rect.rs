@@ -0,0 +1,162 @@
+// ===========================================
+// rect.rs - สี่เหลี่ยมที่มีตำแหน่ง (ด้านขนานกับแกน)
+// ===========================================
+
+use super::{GeometryError, Point, Shape};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub struct Size {
+    pub width: f64,
+    pub height: f64,
+}
+
+impl Size {
+    pub fn new(width: f64, height: f64) -> Self {
+        Self { width, height }
+    }
+
+    pub fn area(self) -> f64 {
+        self.width * self.height
+    }
+}
+
+// อ่านจาก JSON ผ่าน Rect::new เสมอ (ความกว้าง/สูงติดลบถูกกลับด้าน, NaN ไม่รับ)
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+#[serde(try_from = "RawRect")]
+pub struct Rect {
+    // มุมบนซ้าย
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub height: f64,
+}
+
+#[derive(Deserialize)]
+struct RawRect {
+    x: f64,
+    y: f64,
+    width: f64,
+    height: f64,
+}
+
+impl TryFrom<RawRect> for Rect {
+    type Error = GeometryError;
+
+    fn try_from(raw: RawRect) -> Result<Self, Self::Error> {
+        if [raw.x, raw.y, raw.width, raw.height]
+            .iter()
+            .any(|v| !v.is_finite())
+        {
+            return Err(GeometryError::NotFinite("rect"));
+        }
+        Ok(Rect::new(raw.x, raw.y, raw.width, raw.height))
+    }
+}
+
+impl Rect {
+    // ความกว้าง/สูงติดลบจะกลับด้านให้ (Rect::new(10, 0, -4, 2) = Rect::new(6, 0, 4, 2))
+    pub fn new(x: f64, y: f64, width: f64, height: f64) -> Self {
+        Rect::from_corners(Point::new(x, y), Point::new(x + width, y + height))
+    }
+
+    // มุมตรงข้ามสองมุม (ลำดับไหนก็ได้)
+    pub fn from_corners(a: Point, b: Point) -> Self {
+        Self {
+            x: a.x.min(b.x),
+            y: a.y.min(b.y),
+            width: (a.x - b.x).abs(),
+            height: (a.y - b.y).abs(),
+        }
+    }
+
+    pub fn at(origin: Point, size: Size) -> Self {
+        Rect::new(origin.x, origin.y, size.width, size.height)
+    }
+
+    pub fn min(&self) -> Point {
+        Point::new(self.x, self.y)
+    }
+
+    pub fn max(&self) -> Point {
+        Point::new(self.x + self.width, self.y + self.height)
+    }
+
+    pub fn center(&self) -> Point {
+        Point::new(self.x + self.width / 2.0, self.y + self.height / 2.0)
+    }
+
+    pub fn size(&self) -> Size {
+        Size::new(self.width, self.height)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.width <= 0.0 || self.height <= 0.0
+    }
+
+    // ส่วนที่ทับกัน (แค่ขอบแตะกันไม่นับ)
+    pub fn intersection(&self, other: &Rect) -> Option<Rect> {
+        let min = Point::new(self.x.max(other.x), self.y.max(other.y));
+        let max = Point::new(
+            self.max().x.min(other.max().x),
+            self.max().y.min(other.max().y),
+        );
+        (min.x < max.x && min.y < max.y).then(|| Rect::from_corners(min, max))
+    }
+
+    pub fn intersects(&self, other: &Rect) -> bool {
+        self.intersection(other).is_some()
+    }
+
+    // สี่เหลี่ยมเล็กที่สุดที่ครอบทั้งสองอัน
+    pub fn union(&self, other: &Rect) -> Rect {
+        let min = Point::new(self.x.min(other.x), self.y.min(other.y));
+        let max = Point::new(
+            self.max().x.max(other.max().x),
+            self.max().y.max(other.max().y),
+        );
+        Rect::from_corners(min, max)
+    }
+
+    // other อยู่ข้างในทั้งหมด (ขอบทับกันได้)
+    pub fn contains_rect(&self, other: &Rect) -> bool {
+        self.x <= other.x
+            && self.y <= other.y
+            && other.max().x <= self.max().x
+            && other.max().y <= self.max().y
+    }
+
+    // แบบ can_hold ในบทที่ 5: ใหญ่กว่าทั้งกว้างและสูง (ไม่สนตำแหน่ง)
+    pub fn can_hold(&self, other: &Rect) -> bool {
+        self.width > other.width && self.height > other.height
+    }
+
+    pub fn translate(&self, dx: f64, dy: f64) -> Rect {
+        Rect::new(self.x + dx, self.y + dy, self.width, self.height)
+    }
+}
+
+impl Shape for Rect {
+    fn area(&self) -> f64 {
+        self.width * self.height
+    }
+
+    fn perimeter(&self) -> f64 {
+        2.0 * (self.width + self.height)
+    }
+
+    fn bounding_box(&self) -> Rect {
+        *self
+    }
+
+    fn contains(&self, point: Point) -> bool {
+        (self.x..=self.max().x).contains(&point.x) && (self.y..=self.max().y).contains(&point.y)
+    }
+}
+
+impl fmt::Display for Rect {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}x{} at {}", self.width, self.height, self.min())
+    }
+}
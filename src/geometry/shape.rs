@@ -0,0 +1,34 @@
+// ===========================================
+// shape.rs - สิ่งที่ทุกรูปทรงทำได้
+// ===========================================
+
+use super::{Point, Rect};
+use thiserror::Error;
+
+pub trait Shape {
+    fn area(&self) -> f64;
+    fn perimeter(&self) -> f64;
+    // สี่เหลี่ยมเล็กที่สุดที่ครอบรูปได้
+    fn bounding_box(&self) -> Rect;
+    // จุดบนขอบนับว่าอยู่ข้างใน
+    fn contains(&self, point: Point) -> bool;
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum GeometryError {
+    #[error("{what} must not be negative, got {value}")]
+    Negative { what: &'static str, value: f64 },
+
+    #[error("a polygon needs at least 3 points, got {0}")]
+    TooFewPoints(usize),
+
+    #[error("{0} must be a finite number")]
+    NotFinite(&'static str),
+
+    #[error("item {index} is {width} wide, wider than the bin ({bin_width})")]
+    TooWide {
+        index: usize,
+        width: f64,
+        bin_width: f64,
+    },
+}
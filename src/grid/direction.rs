@@ -0,0 +1,103 @@
+// ===========================================
+// direction.rs - ทิศทางการเดิน 4 ทิศ
+// ===========================================
+
+use super::GridError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Direction {
+    North,
+    South,
+    East,
+    West,
+}
+
+impl Direction {
+    // ลำดับที่ใช้ตอนหาเส้นทาง (เส้นทางยาวเท่ากันจะเลือกตามลำดับนี้)
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::South,
+        Direction::East,
+        Direction::West,
+    ];
+
+    // (dx, dy) - North คือ y ลดลง (ขึ้นไปบนจอ)
+    pub fn offset(self) -> (i32, i32) {
+        match self {
+            Direction::North => (0, -1),
+            Direction::South => (0, 1),
+            Direction::East => (1, 0),
+            Direction::West => (-1, 0),
+        }
+    }
+
+    pub fn opposite(self) -> Direction {
+        match self {
+            Direction::North => Direction::South,
+            Direction::South => Direction::North,
+            Direction::East => Direction::West,
+            Direction::West => Direction::East,
+        }
+    }
+
+    pub fn arrow(self) -> char {
+        match self {
+            Direction::North => '↑',
+            Direction::South => '↓',
+            Direction::East => '→',
+            Direction::West => '←',
+        }
+    }
+
+    pub fn letter(self) -> char {
+        match self {
+            Direction::North => 'N',
+            Direction::South => 'S',
+            Direction::East => 'E',
+            Direction::West => 'W',
+        }
+    }
+
+    // "NNEES" หรือ "N N E E S" หรือ "north,east" → Vec<Direction>
+    pub fn parse_moves(moves: &str) -> Result<Vec<Direction>, GridError> {
+        let words: Vec<&str> = moves
+            .split(|c: char| c.is_whitespace() || c == ',')
+            .filter(|word| !word.is_empty())
+            .collect();
+        if let [word] = words.as_slice()
+            && word.chars().all(|c| "NSEWnsew".contains(c))
+        {
+            return word.chars().map(|c| c.to_string().parse()).collect();
+        }
+        words.into_iter().map(str::parse).collect()
+    }
+}
+
+impl FromStr for Direction {
+    type Err = GridError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "n" | "north" | "up" => Ok(Direction::North),
+            "s" | "south" | "down" => Ok(Direction::South),
+            "e" | "east" | "right" => Ok(Direction::East),
+            "w" | "west" | "left" => Ok(Direction::West),
+            _ => Err(GridError::Direction(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Direction {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let name = match self {
+            Direction::North => "North",
+            Direction::South => "South",
+            Direction::East => "East",
+            Direction::West => "West",
+        };
+        write!(f, "{}", name)
+    }
+}
@@ -0,0 +1,27 @@
+// ===========================================
+// Grid - โลกตาราง 2 มิติที่เดินด้วย enum Direction (จาก examples/structs_enums.rs)
+// ===========================================
+//
+// - direction.rs: Direction (North, South, East, West)
+// - world.rs:     Grid (ขนาดและกำแพง จากแผนที่ ASCII), World (ตำแหน่ง player + การเดิน)
+// - path.rs:      หาเส้นทางที่สั้นที่สุดด้วย BFS และ A*
+// - render.rs:    วาด grid เป็น ASCII (พร้อม player และเส้นทาง)
+//
+// แผนที่:
+//
+//   #######
+//   #@..#.#      # = กำแพง   . = ทางเดิน   @ = player
+//   #.#...#
+//   #######
+//
+// x เพิ่มไปทางขวา (East) y เพิ่มลงล่าง (South) เริ่มที่มุมซ้ายบน (0, 0)
+
+mod direction;
+mod path;
+mod render;
+mod world;
+
+pub use direction::Direction;
+pub use path::{astar, bfs};
+pub use render::{render, render_path};
+pub use world::{Blocked, Grid, GridError, Position, Step, Walk, World};
@@ -0,0 +1,82 @@
+// ===========================================
+// path.rs - หาเส้นทางที่สั้นที่สุดจาก start ไป goal
+// ===========================================
+//
+// ทั้งสองแบบคืนลำดับ Direction (ส่งให้ World::walk ได้เลย) หรือ None ถ้าไปไม่ถึง
+//
+// - bfs:   ค้นแบบกระจายออกทีละชั้น เจอ goal ครั้งแรกคือทางที่สั้นที่สุด
+// - astar: เลือกช่องที่ "เดินมาแล้ว + ระยะ Manhattan ถึง goal" น้อยที่สุดก่อน
+//          ได้ความยาวเท่า bfs แต่ดูช่องน้อยกว่าเมื่อแผนที่ใหญ่
+
+use super::{Direction, Grid, Position};
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, VecDeque};
+
+// ช่องก่อนหน้าและทิศที่เดินมา (ใช้ย้อนหาเส้นทาง)
+type CameFrom = HashMap<Position, (Position, Direction)>;
+
+pub fn bfs(grid: &Grid, start: Position, goal: Position) -> Option<Vec<Direction>> {
+    if !grid.is_walkable(start) || !grid.is_walkable(goal) {
+        return None;
+    }
+    let mut came_from = CameFrom::new();
+    let mut queue = VecDeque::from([start]);
+    while let Some(position) = queue.pop_front() {
+        if position == goal {
+            return Some(trace(&came_from, start, goal));
+        }
+        for (direction, next) in grid.neighbors(position) {
+            if next != start && !came_from.contains_key(&next) {
+                came_from.insert(next, (position, direction));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+pub fn astar(grid: &Grid, start: Position, goal: Position) -> Option<Vec<Direction>> {
+    if !grid.is_walkable(start) || !grid.is_walkable(goal) {
+        return None;
+    }
+    let mut came_from = CameFrom::new();
+    let mut cost = HashMap::from([(start, 0u32)]);
+    // (ค่าประมาณรวม, ระยะที่เหลือ, ลำดับที่ใส่) - น้อยสุดก่อน, เท่ากันเลือกที่ใกล้ goal กว่าแล้วใส่ก่อน
+    let mut open = BinaryHeap::from([Reverse((
+        start.manhattan(goal),
+        start.manhattan(goal),
+        0u64,
+        start,
+    ))]);
+    let mut pushed = 0;
+
+    while let Some(Reverse((_, _, _, position))) = open.pop() {
+        if position == goal {
+            return Some(trace(&came_from, start, goal));
+        }
+        let here = cost[&position];
+        for (direction, next) in grid.neighbors(position) {
+            let through = here + 1;
+            if cost.get(&next).is_none_or(|&known| through < known) {
+                cost.insert(next, through);
+                came_from.insert(next, (position, direction));
+                pushed += 1;
+                let remaining = next.manhattan(goal);
+                open.push(Reverse((through + remaining, remaining, pushed, next)));
+            }
+        }
+    }
+    None
+}
+
+fn trace(came_from: &CameFrom, start: Position, goal: Position) -> Vec<Direction> {
+    let mut path = Vec::new();
+    let mut position = goal;
+    while position != start {
+        let (previous, direction) = came_from[&position];
+        path.push(direction);
+        position = previous;
+    }
+    path.reverse();
+    path
+}
@@ -0,0 +1,46 @@
+// ===========================================
+// render.rs - วาด grid เป็นข้อความ
+// ===========================================
+//
+//   # กำแพง   . ทางเดิน   @ player   ลูกศร = เส้นทาง   X = ปลายทาง
+
+use super::{Direction, Grid, Position};
+use std::collections::HashMap;
+
+// grid กับ player (ถ้ามี)
+pub fn render(grid: &Grid, player: Option<Position>) -> String {
+    draw(grid, |position| (Some(position) == player).then_some('@'))
+}
+
+// เส้นทางจาก start: แต่ละช่องแสดงลูกศรของก้าวที่ออกจากช่องนั้น
+// ก้าวที่ชนกำแพงหรือออกนอกแผนที่จะไม่ถูกวาด (และหยุดวาดต่อจากนั้น)
+pub fn render_path(grid: &Grid, start: Position, path: &[Direction]) -> String {
+    let mut cells = HashMap::new();
+    let mut position = start;
+    for &direction in path {
+        let next = position.step(direction);
+        if !grid.is_walkable(next) {
+            break;
+        }
+        cells.entry(position).or_insert(direction.arrow());
+        position = next;
+    }
+    if position != start {
+        cells.insert(position, 'X');
+    }
+    cells.insert(start, '@');
+    draw(grid, |position| cells.get(&position).copied())
+}
+
+fn draw(grid: &Grid, overlay: impl Fn(Position) -> Option<char>) -> String {
+    let mut out = String::with_capacity(((grid.width() + 1) * grid.height()) as usize);
+    for y in 0..grid.height() {
+        for x in 0..grid.width() {
+            let position = Position::new(x, y);
+            let cell = overlay(position).unwrap_or(if grid.is_wall(position) { '#' } else { '.' });
+            out.push(cell);
+        }
+        out.push('\n');
+    }
+    out
+}
@@ -0,0 +1,279 @@
+// ===========================================
+// world.rs - Grid (กำแพงและขอบ) และ World (player ที่เดินบน grid)
+// ===========================================
+
+use super::Direction;
+use std::fmt;
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum GridError {
+    #[error("map is empty")]
+    Empty,
+
+    #[error("line {line}: expected {expected} cells like the first line, found {found}")]
+    Ragged {
+        line: usize,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("line {line}, column {column}: unknown cell '{cell}' (use # . @)")]
+    Cell {
+        line: usize,
+        column: usize,
+        cell: char,
+    },
+
+    #[error("line {line}: second player '@' (the first is at {first})")]
+    SecondPlayer { line: usize, first: Position },
+
+    #[error("map has no player '@'")]
+    NoPlayer,
+
+    #[error("{0} is a wall or outside the map")]
+    NotWalkable(Position),
+
+    #[error("unknown direction '{0}' (use N S E W or north, south, east, west)")]
+    Direction(String),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Position {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Position {
+    pub fn new(x: i32, y: i32) -> Self {
+        Self { x, y }
+    }
+
+    pub fn step(self, direction: Direction) -> Position {
+        let (dx, dy) = direction.offset();
+        Position::new(self.x + dx, self.y + dy)
+    }
+
+    pub fn manhattan(self, other: Position) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+}
+
+impl fmt::Display for Position {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+// -------------------------------------------
+// Grid
+// -------------------------------------------
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Grid {
+    width: i32,
+    height: i32,
+    // แถวต่อแถว: walls[y * width + x]
+    walls: Vec<bool>,
+}
+
+impl Grid {
+    // grid โล่งๆ ไม่มีกำแพง
+    pub fn new(width: u16, height: u16) -> Self {
+        Self {
+            width: width.into(),
+            height: height.into(),
+            walls: vec![false; usize::from(width) * usize::from(height)],
+        }
+    }
+
+    // แผนที่ ASCII → grid กับตำแหน่ง '@' (ถ้ามี)
+    // ช่องว่างหน้า/หลังบรรทัดไม่นับ (เขียนแผนที่แบบย่อหน้าในโค้ดได้)
+    pub fn parse(map: &str) -> Result<(Grid, Option<Position>), GridError> {
+        let lines: Vec<&str> = map.lines().map(str::trim).collect();
+        let Some(first) = lines.iter().position(|line| !line.is_empty()) else {
+            return Err(GridError::Empty);
+        };
+        let last = lines
+            .iter()
+            .rposition(|line| !line.is_empty())
+            .unwrap_or(first);
+        let rows: Vec<Vec<char>> = lines[first..=last]
+            .iter()
+            .map(|line| line.chars().collect())
+            .collect();
+        // เลขบรรทัดใน error นับบรรทัดว่างข้างบนด้วย (แผนที่ที่เขียนแบบ "\n    ###...")
+        let line = |y: usize| first + y + 1;
+
+        let width = rows[0].len();
+        let mut walls = Vec::with_capacity(width * rows.len());
+        let mut player: Option<Position> = None;
+        for (y, row) in rows.iter().enumerate() {
+            if row.len() != width {
+                return Err(GridError::Ragged {
+                    line: line(y),
+                    expected: width,
+                    found: row.len(),
+                });
+            }
+            for (x, &cell) in row.iter().enumerate() {
+                match cell {
+                    '#' => walls.push(true),
+                    '.' => walls.push(false),
+                    '@' => {
+                        if let Some(first) = player {
+                            return Err(GridError::SecondPlayer {
+                                line: line(y),
+                                first,
+                            });
+                        }
+                        player = Some(Position::new(x as i32, y as i32));
+                        walls.push(false);
+                    }
+                    cell => {
+                        return Err(GridError::Cell {
+                            line: line(y),
+                            column: x + 1,
+                            cell,
+                        });
+                    }
+                }
+            }
+        }
+
+        let grid = Grid {
+            width: width as i32,
+            height: rows.len() as i32,
+            walls,
+        };
+        Ok((grid, player))
+    }
+
+    pub fn width(&self) -> i32 {
+        self.width
+    }
+
+    pub fn height(&self) -> i32 {
+        self.height
+    }
+
+    pub fn in_bounds(&self, position: Position) -> bool {
+        (0..self.width).contains(&position.x) && (0..self.height).contains(&position.y)
+    }
+
+    pub fn is_wall(&self, position: Position) -> bool {
+        self.index(position).is_some_and(|index| self.walls[index])
+    }
+
+    // อยู่ในแผนที่และไม่ใช่กำแพง
+    pub fn is_walkable(&self, position: Position) -> bool {
+        self.index(position).is_some_and(|index| !self.walls[index])
+    }
+
+    pub fn set_wall(&mut self, position: Position, wall: bool) {
+        if let Some(index) = self.index(position) {
+            self.walls[index] = wall;
+        }
+    }
+
+    // ช่องข้างๆ ที่เดินไปได้ ตามลำดับ Direction::ALL
+    pub fn neighbors(
+        &self,
+        position: Position,
+    ) -> impl Iterator<Item = (Direction, Position)> + '_ {
+        Direction::ALL
+            .into_iter()
+            .map(move |direction| (direction, position.step(direction)))
+            .filter(|(_, next)| self.is_walkable(*next))
+    }
+
+    pub(crate) fn index(&self, position: Position) -> Option<usize> {
+        self.in_bounds(position)
+            .then(|| (position.y * self.width + position.x) as usize)
+    }
+}
+
+// -------------------------------------------
+// World - player บน grid
+// -------------------------------------------
+
+// เดินไม่ได้เพราะอะไร
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Blocked {
+    Wall(Position),
+    OutOfBounds(Position),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+    Moved(Position),
+    // ชนแล้วอยู่ที่เดิม
+    Blocked(Blocked),
+}
+
+// ผลของการเดินหลายก้าว
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Walk {
+    pub end: Position,
+    pub moved: usize,
+    // (ก้าวที่เท่าไร เริ่มที่ 0, ทิศ, ชนอะไร)
+    pub blocked: Vec<(usize, Direction, Blocked)>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct World {
+    pub grid: Grid,
+    player: Position,
+}
+
+impl World {
+    pub fn new(grid: Grid, player: Position) -> Result<Self, GridError> {
+        if !grid.is_walkable(player) {
+            return Err(GridError::NotWalkable(player));
+        }
+        Ok(Self { grid, player })
+    }
+
+    // แผนที่ต้องมี '@' หนึ่งตัว
+    pub fn parse(map: &str) -> Result<Self, GridError> {
+        let (grid, player) = Grid::parse(map)?;
+        World::new(grid, player.ok_or(GridError::NoPlayer)?)
+    }
+
+    pub fn player(&self) -> Position {
+        self.player
+    }
+
+    pub fn render(&self) -> String {
+        super::render(&self.grid, Some(self.player))
+    }
+
+    pub fn step(&mut self, direction: Direction) -> Step {
+        let next = self.player.step(direction);
+        if !self.grid.in_bounds(next) {
+            Step::Blocked(Blocked::OutOfBounds(next))
+        } else if self.grid.is_wall(next) {
+            Step::Blocked(Blocked::Wall(next))
+        } else {
+            self.player = next;
+            Step::Moved(next)
+        }
+    }
+
+    // ก้าวที่ชนจะถูกข้าม แล้วเดินก้าวถัดไปต่อ
+    pub fn walk(&mut self, moves: &[Direction]) -> Walk {
+        let mut walk = Walk {
+            end: self.player,
+            moved: 0,
+            blocked: Vec::new(),
+        };
+        for (index, &direction) in moves.iter().enumerate() {
+            match self.step(direction) {
+                Step::Moved(_) => walk.moved += 1,
+                Step::Blocked(blocked) => walk.blocked.push((index, direction, blocked)),
+            }
+        }
+        walk.end = self.player;
+        walk
+    }
+}
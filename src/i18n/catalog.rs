@@ -0,0 +1,164 @@
+// ===========================================
+// catalog.rs - ข้อความของแต่ละภาษา (locales/th.json, locales/en.json)
+// ===========================================
+//
+// ไฟล์ถูกฝังใน binary ตอน compile (example รันจากที่ไหนก็ได้) รูปแบบ:
+//
+//   {
+//     "hello.variables": "📝 ตัวอย่างตัวแปร:",
+//     "files.found": { "one": "Found 1 file", "other": "Found {count} files" }
+//   }
+//
+// แบบ plural ต้องมี "other" เสมอ ส่วน "one" ใส่เฉพาะภาษาที่ใช้
+
+use super::format::{interpolate, placeholders};
+use super::{Locale, Plural};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::Display;
+use std::sync::OnceLock;
+use thiserror::Error;
+
+const SOURCES: [(Locale, &str); 2] = [
+    (Locale::Th, include_str!("../../locales/th.json")),
+    (Locale::En, include_str!("../../locales/en.json")),
+];
+
+#[derive(Error, Debug)]
+pub enum I18nError {
+    #[error("Unknown language '{0}' (supported: th, en)")]
+    UnknownLocale(String),
+
+    #[error("locales/{locale}.json is not valid JSON: {source}")]
+    Json {
+        locale: Locale,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("locales/{locale}.json: '{key}' {message}")]
+    Entry {
+        locale: Locale,
+        key: String,
+        message: String,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum Message {
+    Text(String),
+    Plural { one: Option<String>, other: String },
+}
+
+impl Message {
+    // ชื่อ {name} ที่ใช้ในทุกรูปของข้อความ
+    pub fn placeholders(&self) -> BTreeSet<&str> {
+        match self {
+            Message::Text(text) => placeholders(text).into_iter().collect(),
+            Message::Plural { one, other } => one
+                .iter()
+                .chain([other])
+                .flat_map(|text| placeholders(text))
+                .collect(),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Catalog {
+    pub locale: Locale,
+    messages: BTreeMap<String, Message>,
+}
+
+impl Catalog {
+    pub fn parse(locale: Locale, json: &str) -> Result<Self, I18nError> {
+        let entries: BTreeMap<String, Value> =
+            serde_json::from_str(json).map_err(|source| I18nError::Json { locale, source })?;
+
+        let mut messages = BTreeMap::new();
+        for (key, value) in entries {
+            let entry_error = |message: &str| I18nError::Entry {
+                locale,
+                key: key.clone(),
+                message: message.to_string(),
+            };
+            let message = match value {
+                Value::String(text) => Message::Text(text),
+                Value::Object(forms) => {
+                    if let Some(form) = forms
+                        .keys()
+                        .find(|form| !["one", "other"].contains(&form.as_str()))
+                    {
+                        return Err(entry_error(&format!(
+                            "has unknown plural form '{}' (use one / other)",
+                            form
+                        )));
+                    }
+                    let text =
+                        |form: &str| forms.get(form).and_then(Value::as_str).map(str::to_string);
+                    Message::Plural {
+                        one: text("one"),
+                        other: text("other")
+                            .ok_or_else(|| entry_error("needs an \"other\" text"))?,
+                    }
+                }
+                _ => return Err(entry_error("must be a string or {\"one\", \"other\"}")),
+            };
+            messages.insert(key, message);
+        }
+        Ok(Self { locale, messages })
+    }
+
+    // catalog ที่ฝังไว้ (panic ถ้าไฟล์ผิด - tests/i18n.rs ตรวจไว้แล้ว)
+    pub fn get(locale: Locale) -> &'static Catalog {
+        static CATALOGS: OnceLock<Vec<Catalog>> = OnceLock::new();
+        let catalogs = CATALOGS.get_or_init(|| {
+            SOURCES
+                .iter()
+                .map(|(locale, json)| {
+                    Catalog::parse(*locale, json).unwrap_or_else(|e| panic!("{}", e))
+                })
+                .collect()
+        });
+        // SOURCES เรียงตาม Locale::ALL
+        &catalogs[locale as usize]
+    }
+
+    // ตรวจไฟล์ที่ฝังไว้โดยไม่ panic
+    pub fn embedded() -> Result<Vec<Catalog>, I18nError> {
+        SOURCES
+            .iter()
+            .map(|(locale, json)| Catalog::parse(*locale, json))
+            .collect()
+    }
+
+    // ข้อความของ key ถ้าไม่มี key นี้คืนตัว key เอง
+    // argument ชื่อ count ใช้เลือกรูป plural
+    pub fn translate(&self, key: &str, args: &[(&str, &dyn Display)]) -> String {
+        let Some(message) = self.message(key) else {
+            return key.to_string();
+        };
+        let template = match message {
+            Message::Text(text) => text,
+            Message::Plural { one, other } => {
+                let count = args
+                    .iter()
+                    .find(|(name, _)| *name == "count")
+                    .and_then(|(_, value)| value.to_string().parse::<f64>().ok());
+                match count.map(|count| self.locale.plural(count)) {
+                    Some(Plural::One) => one.as_deref().unwrap_or(other),
+                    _ => other,
+                }
+            }
+        };
+        interpolate(template, args)
+    }
+
+    pub fn message(&self, key: &str) -> Option<&Message> {
+        self.messages.get(key)
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &str> {
+        self.messages.keys().map(String::as_str)
+    }
+}
@@ -0,0 +1,59 @@
+// ===========================================
+// format.rs - แทน {name} ในข้อความ ({{ และ }} คือวงเล็บปีกกาจริง)
+// ===========================================
+
+use std::fmt::Display;
+
+// ชื่อที่ไม่มีใน args คงไว้เป็น {name} ให้เห็นว่าลืมส่งค่า
+pub fn interpolate(template: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find(['{', '}']) {
+        out.push_str(&rest[..start]);
+        let tail = &rest[start..];
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            out.push_str(&tail[..1]);
+            rest = &tail[2..];
+            continue;
+        }
+        let placeholder = tail
+            .strip_prefix('{')
+            .and_then(|inner| inner.find('}').map(|end| &inner[..end]));
+        match placeholder {
+            Some(name) => {
+                match args.iter().find(|(arg, _)| *arg == name) {
+                    Some((_, value)) => out.push_str(&value.to_string()),
+                    None => out.push_str(&tail[..name.len() + 2]),
+                }
+                rest = &tail[name.len() + 2..];
+            }
+            None => {
+                out.push_str(&tail[..1]);
+                rest = &tail[1..];
+            }
+        }
+    }
+    out.push_str(rest);
+    out
+}
+
+// ชื่อ {name} ทั้งหมดในข้อความ (ใช้ตรวจว่าทุกภาษาใช้ชื่อเดียวกัน)
+pub(crate) fn placeholders(template: &str) -> Vec<&str> {
+    let mut names = Vec::new();
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        let tail = &rest[start..];
+        if let Some(escaped) = tail.strip_prefix("{{") {
+            rest = escaped;
+            continue;
+        }
+        match tail[1..].find('}') {
+            Some(end) => {
+                names.push(&tail[1..end + 1]);
+                rest = &tail[end + 2..];
+            }
+            None => break,
+        }
+    }
+    names
+}
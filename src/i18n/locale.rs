@@ -0,0 +1,88 @@
+// ===========================================
+// locale.rs - ภาษาที่รองรับ, การเลือกภาษา และกฎ plural
+// ===========================================
+
+use super::I18nError;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Locale {
+    Th,
+    En,
+}
+
+// รูปของคำตามจำนวน (ภาษาไทยไม่มี plural จึงใช้ Other เสมอ)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Plural {
+    One,
+    Other,
+}
+
+impl Locale {
+    // ลำดับตรงกับค่า as u8
+    pub const ALL: [Locale; 2] = [Locale::Th, Locale::En];
+    pub const DEFAULT: Locale = Locale::Th;
+
+    pub fn code(self) -> &'static str {
+        match self {
+            Locale::Th => "th",
+            Locale::En => "en",
+        }
+    }
+
+    // ค่า $LANG ที่ใช้รันโปรแกรมด้วยภาษานี้
+    pub fn lang(self) -> &'static str {
+        match self {
+            Locale::Th => "th_TH.UTF-8",
+            Locale::En => "en_US.UTF-8",
+        }
+    }
+
+    // ค่าแบบ $LANG: th_TH.UTF-8, en_US.UTF-8, en
+    // C / POSIX / ภาษาอื่นคืน None
+    pub fn from_lang(value: &str) -> Option<Locale> {
+        let language = value.split(['_', '-', '.', '@']).next().unwrap_or_default();
+        language.parse().ok()
+    }
+
+    pub fn plural(self, count: f64) -> Plural {
+        match self {
+            Locale::Th => Plural::Other,
+            Locale::En if count == 1.0 => Plural::One,
+            Locale::En => Plural::Other,
+        }
+    }
+}
+
+impl FromStr for Locale {
+    type Err = I18nError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Locale::ALL
+            .into_iter()
+            .find(|locale| locale.code().eq_ignore_ascii_case(s))
+            .ok_or_else(|| I18nError::UnknownLocale(s.to_string()))
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.code())
+    }
+}
+
+// --lang en / --lang=en ชนะ $LANG, ถ้าไม่มีทั้งคู่ใช้ภาษาไทย
+// --lang ที่ไม่รู้จักเป็น error แต่ $LANG ที่ไม่รู้จัก (เช่น C.UTF-8) ไม่ใช่
+pub fn detect(args: &[String], lang: Option<&str>) -> Result<Locale, I18nError> {
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        let value = match arg.strip_prefix("--lang") {
+            Some("") => args.next().map(String::as_str).unwrap_or_default(),
+            Some(rest) if rest.starts_with('=') => &rest[1..],
+            _ => continue,
+        };
+        return value.parse();
+    }
+    Ok(lang.and_then(Locale::from_lang).unwrap_or(Locale::DEFAULT))
+}
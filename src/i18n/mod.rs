@@ -0,0 +1,66 @@
+// ===========================================
+// i18n - ข้อความภาษาไทย / อังกฤษสำหรับ output ของ examples
+// ===========================================
+//
+// - locale.rs:  Locale (th, en), เลือกจาก --lang หรือ $LANG, กฎ plural
+// - catalog.rs: ข้อความของแต่ละภาษาจาก locales/<code>.json
+// - format.rs:  แทน {name} ในข้อความด้วยค่าจริง
+//
+// ใช้ใน example:
+//
+//   use rust_tutorial::{i18n, t};
+//
+//   i18n::init();                                   // อ่าน --lang / $LANG
+//   println!("{}", t!("hello.variables"));
+//   println!("{}", t!("hello.positive", n = 10));   // แทน {n}
+
+mod catalog;
+mod format;
+mod locale;
+
+pub use catalog::{Catalog, I18nError, Message};
+pub use format::interpolate;
+pub use locale::{Locale, Plural, detect};
+
+use std::env;
+use std::fmt::Display;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+static CURRENT: AtomicU8 = AtomicU8::new(Locale::DEFAULT as u8);
+
+pub fn locale() -> Locale {
+    Locale::ALL[CURRENT.load(Ordering::Relaxed) as usize]
+}
+
+pub fn set_locale(locale: Locale) {
+    CURRENT.store(locale as u8, Ordering::Relaxed);
+}
+
+// เลือกภาษาจาก argument ของโปรแกรมและ $LANG (ค่าที่ไม่รู้จักใช้ภาษาเริ่มต้นพร้อมเตือน)
+pub fn init() -> Locale {
+    let args: Vec<String> = env::args().skip(1).collect();
+    let lang = env::var("LANG").ok();
+    let locale = detect(&args, lang.as_deref()).unwrap_or_else(|e| {
+        eprintln!("⚠️  {} (using {})", e, Locale::DEFAULT);
+        Locale::DEFAULT
+    });
+    set_locale(locale);
+    locale
+}
+
+// ข้อความของ key ในภาษาที่เลือก (ดู Catalog::translate)
+pub fn translate(locale: Locale, key: &str, args: &[(&str, &dyn Display)]) -> String {
+    Catalog::get(locale).translate(key, args)
+}
+
+// t!("key") หรือ t!("key", name = value, count = n) ด้วยภาษาปัจจุบัน
+#[macro_export]
+macro_rules! t {
+    ($key:expr $(, $name:ident = $value:expr)* $(,)?) => {
+        $crate::i18n::translate(
+            $crate::i18n::locale(),
+            $key,
+            &[$((stringify!($name), &$value as &dyn ::std::fmt::Display)),*],
+        )
+    };
+}
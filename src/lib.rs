@@ -0,0 +1,19 @@
+// ===========================================
+// rust_tutorial - โค้ดที่ใช้ร่วมกันระหว่าง binaries และ examples
+// ===========================================
+
+pub mod calc;
+pub mod change;
+pub mod color;
+pub mod config;
+pub mod examples;
+pub mod exercises;
+pub mod geometry;
+pub mod grid;
+pub mod i18n;
+pub mod playground;
+pub mod preprocessor;
+pub mod protocol;
+pub mod quiz;
+pub mod todo;
+pub mod users;
@@ -0,0 +1,12 @@
+// ===========================================
+// Playground - ใช้แทน play.rust-lang.org ในห้องอบรมที่ไม่มี internet
+// ===========================================
+//
+// - sandbox.rs: compile และรัน snippet ด้วย rustc (จำกัดเวลา, memory, ไม่มี network)
+// - server.rs:  HTTP API แบบที่ปุ่ม Run ของ mdBook เรียก + เสิร์ฟหนังสือที่ build แล้ว
+
+mod sandbox;
+mod server;
+
+pub use sandbox::{EvaluateRequest, EvaluateResponse, Limits, PlaygroundError, Sandbox};
+pub use server::{Playground, router};
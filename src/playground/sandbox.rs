@@ -0,0 +1,665 @@
+// ===========================================
+// sandbox.rs - compile และรัน snippet ด้วย rustc แบบจำกัดทรัพยากร
+// ===========================================
+//
+// แต่ละ request ได้โฟลเดอร์ของตัวเองใน work_dir (ลบทิ้งเมื่อเสร็จ):
+//
+//   rustc --edition 2024 -o playground main.rs    (จำกัดเวลา, memory ของ compiler)
+//   ./playground                                  (จำกัดเวลา, memory, ขนาดไฟล์, จำนวน process)
+//
+// บน Linux โปรแกรมถูกรันใน namespace ใหม่ (unshare):
+// - network: เห็นแค่ loopback ที่ยังไม่ได้ตั้งค่า - ต่อ internet หรือเครื่องอื่นในห้องไม่ได้
+// - filesystem: ทั้งเครื่องเป็น read-only, /home, /root และ $HOME ถูกบังด้วย tmpfs ว่างๆ
+//   เขียนได้แค่โฟลเดอร์ของ request เอง
+//
+// ข้อควรรู้: นี่คือการจำกัดความเสียหายของ code ในแบบฝึกหัด ไม่ใช่ sandbox สำหรับ code
+// ที่ไม่น่าไว้ใจ - โปรแกรมยังอ่านไฟล์นอก home ได้ (/etc, /tmp, /proc) และรันด้วยสิทธิ์
+// ของคนที่เปิด server ห้ามเปิด playground ให้คนอื่นเข้าถึงได้ (ดู server.rs)
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::thread;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+const EDITIONS: [&str; 4] = ["2015", "2018", "2021", "2024"];
+
+// โปรแกรมเขียนไฟล์ได้ไม่เกินนี้ (กัน disk เต็ม)
+const FILE_SIZE_LIMIT: u64 = 16 * 1024 * 1024;
+
+#[derive(Error, Debug)]
+pub enum PlaygroundError {
+    #[error("Failed to access {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("Failed to run {program}: {source}")]
+    Command {
+        program: String,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("Cannot isolate snippets from the network or filesystem: {0}")]
+    Isolation(#[source] io::Error),
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Limits {
+    // เวลารันโปรแกรม
+    pub timeout: Duration,
+    // เวลา compile
+    pub compile_timeout: Duration,
+    // address space ของโปรแกรม (bytes)
+    pub memory: u64,
+    // address space ของ rustc (bytes)
+    pub compile_memory: u64,
+    // จำนวน process/thread ของโปรแกรม (กัน fork bomb)
+    pub processes: u64,
+    // stdout/stderr ที่เก็บไว้ (bytes ต่อ stream)
+    pub output: usize,
+    // รันใน network namespace แยก
+    pub isolate_network: bool,
+    // รันใน mount namespace แยก (read-only, ซ่อน home)
+    pub isolate_filesystem: bool,
+}
+
+impl Default for Limits {
+    fn default() -> Self {
+        Self {
+            timeout: Duration::from_secs(5),
+            compile_timeout: Duration::from_secs(30),
+            memory: 256 * 1024 * 1024,
+            compile_memory: 1024 * 1024 * 1024,
+            processes: 64,
+            output: 64 * 1024,
+            isolate_network: true,
+            isolate_filesystem: true,
+        }
+    }
+}
+
+// body ของ POST /evaluate.json แบบที่ปุ่ม Run ของ mdBook ส่งมา
+// {"version": "stable", "optimize": "0", "code": "...", "edition": "2021"}
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct EvaluateRequest {
+    pub code: String,
+    #[serde(default = "default_edition")]
+    pub edition: String,
+    // "0" = debug, อย่างอื่น = optimize
+    #[serde(default)]
+    pub optimize: String,
+    // มีไว้ให้ parse ได้ (ใช้ rustc ที่ติดตั้งไว้เสมอ)
+    #[serde(default)]
+    pub version: String,
+}
+
+// mdBook แสดง `result` ใต้ code block
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct EvaluateResponse {
+    pub result: String,
+    // None = compile และรันจบปกติ
+    pub error: Option<String>,
+}
+
+fn default_edition() -> String {
+    "2021".to_string()
+}
+
+pub struct Sandbox {
+    rustc: PathBuf,
+    work_dir: PathBuf,
+    next_id: AtomicU64,
+    pub limits: Limits,
+}
+
+impl EvaluateResponse {
+    fn error(result: impl Into<String>, error: impl Into<String>) -> Self {
+        Self {
+            result: result.into(),
+            error: Some(error.into()),
+        }
+    }
+}
+
+impl Sandbox {
+    pub fn new(work_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            rustc: PathBuf::from("rustc"),
+            work_dir: work_dir.into(),
+            next_id: AtomicU64::new(0),
+            limits: Limits::default(),
+        }
+    }
+
+    pub fn with_rustc(mut self, rustc: impl Into<PathBuf>) -> Self {
+        self.rustc = rustc.into();
+        self
+    }
+
+    // ตรวจก่อนเปิด server: มี rustc และแยก network/filesystem ได้จริง → คืน "rustc 1.x.y (...)"
+    pub fn probe(&self) -> Result<String, PlaygroundError> {
+        let mut command = Command::new(&self.rustc);
+        command.arg("--version");
+        let confinement = Confinement {
+            memory: Some(self.limits.compile_memory),
+            cpu: self.limits.compile_timeout,
+            file_size: None,
+            processes: None,
+            isolate_network: false,
+            filesystem: None,
+        };
+        let run = run_confined(command, &confinement, self.limits.compile_timeout, 4096)
+            .map_err(|source| self.command_error(source))?;
+
+        // rustc อาจอยู่ใน home ที่ถูกซ่อน จึงลองแยกกับโปรแกรมของระบบแทน
+        if self.limits.isolate_network || self.limits.isolate_filesystem {
+            let dir = Scratch::create(self.work_dir.join(format!("{}-probe", std::process::id())))?;
+            let confinement = Confinement {
+                memory: None,
+                cpu: self.limits.compile_timeout,
+                file_size: None,
+                processes: Some(self.limits.processes),
+                isolate_network: self.limits.isolate_network,
+                filesystem: self.limits.isolate_filesystem.then(|| dir.path.clone()),
+            };
+            run_confined(
+                Command::new("true"),
+                &confinement,
+                self.limits.timeout,
+                4096,
+            )
+            .map_err(PlaygroundError::Isolation)?;
+        }
+        Ok(run.stdout.trim().to_string())
+    }
+
+    pub fn evaluate(&self, request: &EvaluateRequest) -> Result<EvaluateResponse, PlaygroundError> {
+        if !EDITIONS.contains(&request.edition.as_str()) {
+            return Ok(EvaluateResponse::error(
+                format!("unsupported edition '{}'", request.edition),
+                "invalid request",
+            ));
+        }
+
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let dir = Scratch::create(self.work_dir.join(format!("{}-{}", std::process::id(), id)))?;
+        let source = dir.path.join("main.rs");
+        fs::write(&source, &request.code).map_err(|source_error| PlaygroundError::Io {
+            path: source.clone(),
+            source: source_error,
+        })?;
+
+        // compile
+        let opt_level = if matches!(request.optimize.as_str(), "" | "0") {
+            "opt-level=0"
+        } else {
+            "opt-level=3"
+        };
+        let mut rustc = Command::new(&self.rustc);
+        rustc
+            .args(["--edition", &request.edition])
+            .args(["--crate-name", "playground", "--crate-type", "bin"])
+            .args(["-C", opt_level, "--color", "never"])
+            .args(["-o", "playground", "main.rs"])
+            .current_dir(&dir.path);
+        let confinement = Confinement {
+            memory: Some(self.limits.compile_memory),
+            cpu: self.limits.compile_timeout,
+            file_size: None,
+            processes: None,
+            isolate_network: false,
+            filesystem: None,
+        };
+        let build = run_confined(
+            rustc,
+            &confinement,
+            self.limits.compile_timeout,
+            self.limits.output,
+        )
+        .map_err(|source| self.command_error(source))?;
+        let Some(status) = build.status else {
+            return Ok(EvaluateResponse::error(
+                format!(
+                    "compilation timed out after {}s",
+                    self.limits.compile_timeout.as_secs()
+                ),
+                "compile error",
+            ));
+        };
+        if !status.success() {
+            return Ok(EvaluateResponse::error(build.stderr, "compile error"));
+        }
+
+        // run
+        let program = dir.path.join("playground");
+        let mut command = Command::new(&program);
+        command.current_dir(&dir.path).env_clear();
+        let confinement = Confinement {
+            memory: Some(self.limits.memory),
+            cpu: self.limits.timeout,
+            file_size: Some(FILE_SIZE_LIMIT),
+            processes: Some(self.limits.processes),
+            isolate_network: self.limits.isolate_network,
+            filesystem: self.limits.isolate_filesystem.then(|| dir.path.clone()),
+        };
+        let run = run_confined(
+            command,
+            &confinement,
+            self.limits.timeout,
+            self.limits.output,
+        )
+        .map_err(|source| PlaygroundError::Command {
+            program: program.display().to_string(),
+            source,
+        })?;
+
+        let mut result = run.stdout;
+        result.push_str(&run.stderr);
+        if run.truncated {
+            result.push_str(&format!(
+                "\n... output truncated ({} KiB limit)\n",
+                self.limits.output / 1024
+            ));
+        }
+        Ok(match run.status {
+            None => {
+                result.push_str(&format!(
+                    "\ntimed out after {}s\n",
+                    self.limits.timeout.as_secs()
+                ));
+                EvaluateResponse::error(result, "timed out")
+            }
+            Some(status) if status.success() => EvaluateResponse {
+                result,
+                error: None,
+            },
+            Some(status) => EvaluateResponse::error(result, format!("program {}", status)),
+        })
+    }
+
+    fn command_error(&self, source: io::Error) -> PlaygroundError {
+        PlaygroundError::Command {
+            program: self.rustc.display().to_string(),
+            source,
+        }
+    }
+}
+
+// โฟลเดอร์ชั่วคราวของหนึ่ง request
+struct Scratch {
+    path: PathBuf,
+}
+
+impl Scratch {
+    fn create(path: PathBuf) -> Result<Self, PlaygroundError> {
+        fs::create_dir_all(&path).map_err(|source| PlaygroundError::Io {
+            path: path.clone(),
+            source,
+        })?;
+        Ok(Self { path })
+    }
+}
+
+impl Drop for Scratch {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.path);
+    }
+}
+
+// -------------------------------------------
+// รัน process แบบจำกัดทรัพยากร
+// -------------------------------------------
+
+struct Confinement {
+    // address space (None = ไม่จำกัด)
+    memory: Option<u64>,
+    // CPU time (กันกรณีจับเวลาพลาด)
+    cpu: Duration,
+    // ขนาดไฟล์ที่เขียนได้ (None = ไม่จำกัด, rustc ต้องเขียน binary)
+    file_size: Option<u64>,
+    // RLIMIT_NPROC (None = ไม่จำกัด, rustc ต้องเรียก linker)
+    // ถ้าอยู่ใน user namespace ใหม่ จะนับแค่ process ใน namespace นั้น
+    // ไม่อย่างนั้นนับรวมทุก process ของ user
+    processes: Option<u64>,
+    isolate_network: bool,
+    // Some(dir) = แยก filesystem โดยให้เขียนได้แค่ dir
+    filesystem: Option<PathBuf>,
+}
+
+struct Run {
+    // None = หมดเวลา
+    status: Option<ExitStatus>,
+    stdout: String,
+    stderr: String,
+    truncated: bool,
+}
+
+fn run_confined(
+    mut command: Command,
+    confinement: &Confinement,
+    timeout: Duration,
+    output_limit: usize,
+) -> io::Result<Run> {
+    confine(&mut command, confinement)?;
+    let mut child = command
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+
+    // อ่าน output ใน thread แยก ไม่อย่างนั้นโปรแกรมที่พิมพ์เยอะจะค้างเพราะ pipe เต็ม
+    let stdout = read_limited(child.stdout.take(), output_limit);
+    let stderr = read_limited(child.stderr.take(), output_limit);
+
+    let started = Instant::now();
+    let status = loop {
+        if let Some(status) = child.try_wait()? {
+            break Some(status);
+        }
+        if started.elapsed() >= timeout {
+            kill(&mut child)?;
+            break None;
+        }
+        thread::sleep(Duration::from_millis(10));
+    };
+
+    let (stdout, stdout_truncated) = stdout.join().unwrap_or_default();
+    let (stderr, stderr_truncated) = stderr.join().unwrap_or_default();
+    Ok(Run {
+        status,
+        stdout: String::from_utf8_lossy(&stdout).into_owned(),
+        stderr: String::from_utf8_lossy(&stderr).into_owned(),
+        truncated: stdout_truncated || stderr_truncated,
+    })
+}
+
+// เก็บแค่ `limit` bytes แรก ที่เหลืออ่านทิ้ง (โปรแกรมจะได้ไม่ค้าง)
+fn read_limited(
+    pipe: Option<impl Read + Send + 'static>,
+    limit: usize,
+) -> thread::JoinHandle<(Vec<u8>, bool)> {
+    thread::spawn(move || {
+        let mut kept = Vec::new();
+        let mut truncated = false;
+        if let Some(mut pipe) = pipe {
+            let mut buffer = [0; 8192];
+            while let Ok(n) = pipe.read(&mut buffer) {
+                if n == 0 {
+                    break;
+                }
+                let room = limit - kept.len();
+                kept.extend_from_slice(&buffer[..n.min(room)]);
+                truncated |= n > room;
+            }
+        }
+        (kept, truncated)
+    })
+}
+
+#[cfg(unix)]
+fn confine(command: &mut Command, confinement: &Confinement) -> io::Result<()> {
+    use std::os::unix::process::CommandExt;
+
+    #[cfg(not(target_os = "linux"))]
+    if confinement.isolate_network || confinement.filesystem.is_some() {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "namespaces are only available on Linux",
+        ));
+    }
+
+    let memory = confinement.memory;
+    let file_size = confinement.file_size;
+    let processes = confinement.processes;
+    let cpu = confinement.cpu.as_secs() + 1;
+    #[cfg(target_os = "linux")]
+    let namespaces = {
+        let mut flags = 0;
+        if confinement.isolate_network {
+            flags |= libc::CLONE_NEWNET;
+        }
+        if confinement.filesystem.is_some() {
+            flags |= libc::CLONE_NEWNS;
+        }
+        if flags != 0 {
+            flags |= libc::CLONE_NEWUSER;
+        }
+        flags
+    };
+    // เตรียม path ไว้ก่อน fork (ใน child จองหน่วยความจำไม่ได้)
+    #[cfg(target_os = "linux")]
+    let jail = confinement
+        .filesystem
+        .as_deref()
+        .map(Jail::new)
+        .transpose()?;
+
+    // SAFETY: closure ทำงานใน child หลัง fork เรียกแค่ syscall (async-signal-safe) ไม่จอง memory
+    unsafe {
+        command.pre_exec(move || {
+            // process group ของตัวเอง → หมดเวลาแล้ว kill ได้ทั้งกลุ่ม (รวม process ลูก)
+            if libc::setpgid(0, 0) != 0 {
+                return Err(io::Error::last_os_error());
+            }
+            #[cfg(target_os = "linux")]
+            {
+                if namespaces != 0 && libc::unshare(namespaces) != 0 {
+                    return Err(io::Error::last_os_error());
+                }
+                if let Some(jail) = &jail {
+                    jail.enter()?;
+                }
+            }
+            // ตั้งหลัง unshare: จำนวน process จะนับใน user namespace ใหม่
+            if let Some(processes) = processes {
+                set_limit(libc::RLIMIT_NPROC, processes)?;
+            }
+            if let Some(memory) = memory {
+                set_limit(libc::RLIMIT_AS, memory)?;
+            }
+            set_limit(libc::RLIMIT_CPU, cpu)?;
+            if let Some(file_size) = file_size {
+                set_limit(libc::RLIMIT_FSIZE, file_size)?;
+            }
+            set_limit(libc::RLIMIT_CORE, 0)?;
+            Ok(())
+        });
+    }
+    Ok(())
+}
+
+// filesystem ที่โปรแกรมเห็น (อยู่ใน mount namespace ของตัวเอง ไม่กระทบเครื่องจริง):
+// - ทุก mount เป็น read-only ยกเว้นโฟลเดอร์ของ request
+// - /home, /root, $HOME ถูกบังด้วย tmpfs ว่าง (ยกเว้นโฟลเดอร์ที่มีโฟลเดอร์ของ request อยู่)
+#[cfg(target_os = "linux")]
+struct Jail {
+    root: std::ffi::CString,
+    scratch: std::ffi::CString,
+    tmpfs: std::ffi::CString,
+    hidden: Vec<std::ffi::CString>,
+}
+
+// struct mount_attr ของ mount_setattr(2) (Linux 5.12+)
+#[cfg(target_os = "linux")]
+#[repr(C)]
+struct MountAttr {
+    attr_set: u64,
+    attr_clr: u64,
+    propagation: u64,
+    userns_fd: u64,
+}
+
+#[cfg(target_os = "linux")]
+const MOUNT_ATTR_RDONLY: u64 = 0x1;
+
+#[cfg(target_os = "linux")]
+impl Jail {
+    fn new(scratch: &std::path::Path) -> io::Result<Self> {
+        use std::os::unix::ffi::OsStrExt;
+        use std::path::Path;
+
+        let c_path = |path: &Path| {
+            std::ffi::CString::new(path.as_os_str().as_bytes())
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))
+        };
+        let scratch = fs::canonicalize(scratch)?;
+        let home = std::env::var_os("HOME").map(PathBuf::from);
+        let mut hidden = Vec::new();
+        for dir in [
+            Some(PathBuf::from("/home")),
+            Some(PathBuf::from("/root")),
+            home,
+        ]
+        .into_iter()
+        .flatten()
+        {
+            let Ok(dir) = fs::canonicalize(&dir) else {
+                continue;
+            };
+            if dir.is_dir() && dir != Path::new("/") && !scratch.starts_with(&dir) {
+                hidden.push(c_path(&dir)?);
+            }
+        }
+        Ok(Self {
+            root: c_path(Path::new("/"))?,
+            scratch: c_path(&scratch)?,
+            tmpfs: c_path(Path::new("tmpfs"))?,
+            hidden,
+        })
+    }
+
+    // เรียกใน child หลัง unshare(CLONE_NEWUSER | CLONE_NEWNS) - ห้ามจอง memory
+    fn enter(&self) -> io::Result<()> {
+        // mount ใน namespace นี้ต้องไม่ย้อนกลับไปที่เครื่องจริง
+        mount(None, &self.root, None, libc::MS_REC | libc::MS_PRIVATE)?;
+        // bind ไว้เป็น mount ของตัวเอง จะได้เปิดให้เขียนได้แยกจากส่วนอื่น
+        mount(
+            Some(&self.scratch),
+            &self.scratch,
+            None,
+            libc::MS_BIND | libc::MS_REC,
+        )?;
+        for dir in &self.hidden {
+            mount(
+                Some(&self.tmpfs),
+                dir,
+                Some(&self.tmpfs),
+                libc::MS_NOSUID | libc::MS_NODEV | libc::MS_NOEXEC,
+            )?;
+        }
+        set_readonly(&self.root, libc::AT_RECURSIVE as libc::c_uint, true)?;
+        set_readonly(&self.scratch, 0, false)?;
+
+        // cwd เดิมชี้ไปที่โฟลเดอร์ใต้ bind mount (read-only) ต้อง chdir ใหม่
+        // SAFETY: path เป็น C string ที่เตรียมไว้ก่อน fork
+        if unsafe { libc::chdir(self.scratch.as_ptr()) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+}
+
+#[cfg(target_os = "linux")]
+fn mount(
+    source: Option<&std::ffi::CStr>,
+    target: &std::ffi::CStr,
+    fstype: Option<&std::ffi::CStr>,
+    flags: libc::c_ulong,
+) -> io::Result<()> {
+    let ptr = |s: Option<&std::ffi::CStr>| s.map_or(std::ptr::null(), |s| s.as_ptr());
+    // SAFETY: ทุก pointer เป็น C string ที่มีชีวิตอยู่ตลอดการเรียก หรือ null
+    let result = unsafe {
+        libc::mount(
+            ptr(source),
+            target.as_ptr(),
+            ptr(fstype),
+            flags,
+            std::ptr::null(),
+        )
+    };
+    if result != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(target_os = "linux")]
+fn set_readonly(path: &std::ffi::CStr, flags: libc::c_uint, readonly: bool) -> io::Result<()> {
+    let attr = MountAttr {
+        attr_set: if readonly { MOUNT_ATTR_RDONLY } else { 0 },
+        attr_clr: if readonly { 0 } else { MOUNT_ATTR_RDONLY },
+        propagation: 0,
+        userns_fd: 0,
+    };
+    // SAFETY: ส่ง pointer ไปยัง C string และ struct บน stack พร้อมขนาดของ struct
+    let result = unsafe {
+        libc::syscall(
+            libc::SYS_mount_setattr,
+            libc::AT_FDCWD,
+            path.as_ptr(),
+            flags,
+            &attr as *const MountAttr,
+            std::mem::size_of::<MountAttr>(),
+        )
+    };
+    if result != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+// setrlimit รับ resource เป็น type ต่างกันใน glibc กับ libc อื่น
+#[cfg(all(target_os = "linux", target_env = "gnu"))]
+type Resource = libc::__rlimit_resource_t;
+#[cfg(all(unix, not(all(target_os = "linux", target_env = "gnu"))))]
+type Resource = libc::c_int;
+
+#[cfg(unix)]
+fn set_limit(resource: Resource, value: u64) -> io::Result<()> {
+    let limit = libc::rlimit {
+        rlim_cur: value as libc::rlim_t,
+        rlim_max: value as libc::rlim_t,
+    };
+    // SAFETY: ส่ง pointer ไปยัง struct ที่อยู่บน stack
+    if unsafe { libc::setrlimit(resource, &limit) } != 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(())
+}
+
+#[cfg(unix)]
+fn kill(child: &mut Child) -> io::Result<()> {
+    // SAFETY: ส่ง SIGKILL ไปที่ process group ที่ child สร้างไว้ใน pre_exec
+    unsafe {
+        libc::kill(-(child.id() as libc::pid_t), libc::SIGKILL);
+    }
+    child.wait()?;
+    Ok(())
+}
+
+// ระบบอื่นจำกัดได้แค่เวลา
+#[cfg(not(unix))]
+fn confine(_command: &mut Command, confinement: &Confinement) -> io::Result<()> {
+    if confinement.isolate_network || confinement.filesystem.is_some() {
+        return Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "network and filesystem isolation are only available on Linux",
+        ));
+    }
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn kill(child: &mut Child) -> io::Result<()> {
+    child.kill()?;
+    child.wait()?;
+    Ok(())
+}
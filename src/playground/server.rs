@@ -0,0 +1,234 @@
+// ===========================================
+// server.rs - HTTP API แบบเดียวกับ play.rust-lang.org ที่ปุ่ม Run ของ mdBook ใช้
+// ===========================================
+//
+//   POST /evaluate.json   {"code", "edition", ...} → {"result", "error"}
+//   GET  /meta/crates     crate ภายนอกที่ใช้ได้ (ไม่มี - ใช้ได้แค่ std)
+//   GET  /*               ไฟล์ของหนังสือที่ build แล้ว (ถ้าระบุ book_dir)
+//
+// /evaluate.json รัน code ได้ จึงต้องไม่ให้เว็บอื่นที่เปิดอยู่ใน browser เรียกได้:
+// request ที่มี Origin header (มาจาก browser) ต้องมี origin อยู่ใน allowed_origins
+// ไม่อย่างนั้นได้ 403 - CORS header ส่งกลับเฉพาะ origin ที่อนุญาตเท่านั้น
+// (ใช้กับ mdbook serve ได้โดยเพิ่ม origin ของมัน เช่น http://localhost:3000)
+
+use super::sandbox::{EvaluateRequest, EvaluateResponse, Sandbox};
+use axum::{
+    Json, Router,
+    body::Body,
+    extract::{Request, State},
+    http::{HeaderValue, StatusCode, Uri, header},
+    middleware::{self, Next},
+    response::{IntoResponse, Response},
+    routing::{get, post},
+};
+use serde_json::json;
+use std::path::{Component, Path, PathBuf};
+use std::sync::Arc;
+use std::time::Instant;
+use tokio::sync::Semaphore;
+
+// code ที่ยาวกว่านี้ไม่รับ
+const MAX_CODE_BYTES: usize = 100 * 1024;
+
+type ApiError = (StatusCode, String);
+
+pub struct Playground {
+    sandbox: Sandbox,
+    // จำนวน snippet ที่ compile พร้อมกันได้
+    jobs: Semaphore,
+    book_dir: Option<PathBuf>,
+    // origin ที่เรียก API จาก browser ได้ เช่น "http://localhost:3030"
+    allowed_origins: Vec<String>,
+}
+
+impl Playground {
+    pub fn new(sandbox: Sandbox, jobs: usize, book_dir: Option<PathBuf>) -> Self {
+        Self {
+            sandbox,
+            jobs: Semaphore::new(jobs.max(1)),
+            book_dir,
+            allowed_origins: Vec::new(),
+        }
+    }
+
+    pub fn with_allowed_origins(mut self, origins: impl IntoIterator<Item = String>) -> Self {
+        self.allowed_origins.extend(
+            origins
+                .into_iter()
+                .map(|o| o.trim_end_matches('/').to_string()),
+        );
+        self
+    }
+}
+
+pub fn router(playground: Arc<Playground>) -> Router {
+    Router::new()
+        .route("/evaluate.json", post(evaluate).options(preflight))
+        .route("/meta/crates", get(crates).post(crates).options(preflight))
+        .fallback(book_file)
+        .layer(middleware::from_fn_with_state(
+            playground.clone(),
+            check_origin,
+        ))
+        .with_state(playground)
+}
+
+async fn evaluate(
+    State(playground): State<Arc<Playground>>,
+    Json(request): Json<EvaluateRequest>,
+) -> Result<Json<EvaluateResponse>, ApiError> {
+    if request.code.len() > MAX_CODE_BYTES {
+        return Err((
+            StatusCode::PAYLOAD_TOO_LARGE,
+            format!("code is longer than {} KiB", MAX_CODE_BYTES / 1024),
+        ));
+    }
+
+    // รอคิวถ้ามี snippet ที่กำลัง compile อยู่ครบจำนวนแล้ว
+    let _permit = playground
+        .jobs
+        .acquire()
+        .await
+        .map_err(|e| (StatusCode::SERVICE_UNAVAILABLE, e.to_string()))?;
+
+    let started = Instant::now();
+    let worker = playground.clone();
+    let response = tokio::task::spawn_blocking(move || worker.sandbox.evaluate(&request))
+        .await
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?
+        .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, e.to_string()))?;
+
+    println!(
+        "{} {:.1}s",
+        match &response.error {
+            None => "✅ ok".to_string(),
+            Some(error) => format!("❌ {}", error),
+        },
+        started.elapsed().as_secs_f64()
+    );
+    Ok(Json(response))
+}
+
+// ปุ่ม Run ของ mdBook ซ่อนตัวเองถ้า snippet ใช้ crate ที่ไม่อยู่ในรายการนี้
+async fn crates() -> Json<serde_json::Value> {
+    Json(json!({ "crates": [] }))
+}
+
+async fn preflight() -> StatusCode {
+    StatusCode::NO_CONTENT
+}
+
+// ไม่มี Origin = ไม่ได้มาจาก browser (curl, test) ผ่านได้เลย
+async fn check_origin(
+    State(playground): State<Arc<Playground>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(origin) = request.headers().get(header::ORIGIN).cloned() else {
+        return next.run(request).await;
+    };
+    let allowed = origin.to_str().is_ok_and(|origin| {
+        playground
+            .allowed_origins
+            .iter()
+            .any(|allowed| allowed == origin)
+    });
+    if !allowed {
+        return (
+            StatusCode::FORBIDDEN,
+            "origin not allowed (start the playground with --allow-origin <url>)",
+        )
+            .into_response();
+    }
+
+    let mut response = next.run(request).await;
+    let headers = response.headers_mut();
+    headers.insert(header::ACCESS_CONTROL_ALLOW_ORIGIN, origin);
+    headers.insert(header::VARY, HeaderValue::from_static("origin"));
+    headers.insert(
+        header::ACCESS_CONTROL_ALLOW_METHODS,
+        HeaderValue::from_static("GET, POST, OPTIONS"),
+    );
+    headers.insert(
+        header::ACCESS_CONTROL_ALLOW_HEADERS,
+        HeaderValue::from_static("content-type"),
+    );
+    response
+}
+
+// -------------------------------------------
+// ไฟล์ของหนังสือ (docs/book)
+// -------------------------------------------
+
+async fn book_file(State(playground): State<Arc<Playground>>, uri: Uri) -> Response {
+    let Some(book_dir) = &playground.book_dir else {
+        return not_found();
+    };
+    let Some(relative) = book_path(uri.path()) else {
+        return not_found();
+    };
+
+    let mut path = book_dir.join(relative);
+    if path.is_dir() {
+        path.push("index.html");
+    }
+    match tokio::fs::read(&path).await {
+        Ok(content) => ([(header::CONTENT_TYPE, content_type(&path))], content).into_response(),
+        Err(_) => match tokio::fs::read(book_dir.join("404.html")).await {
+            Ok(page) => (
+                StatusCode::NOT_FOUND,
+                [(header::CONTENT_TYPE, "text/html; charset=utf-8")],
+                page,
+            )
+                .into_response(),
+            Err(_) => not_found(),
+        },
+    }
+}
+
+fn not_found() -> Response {
+    (StatusCode::NOT_FOUND, Body::from("Not found")).into_response()
+}
+
+// "/ch01/hello%20world.html" → "ch01/hello world.html"
+// None ถ้า path พยายามออกนอกโฟลเดอร์ (.., path เต็ม)
+fn book_path(uri_path: &str) -> Option<PathBuf> {
+    let decoded = percent_decode(uri_path.trim_start_matches('/'))?;
+    let path = PathBuf::from(decoded);
+    path.components()
+        .all(|component| matches!(component, Component::Normal(_)))
+        .then_some(path)
+}
+
+fn percent_decode(text: &str) -> Option<String> {
+    let mut bytes = Vec::with_capacity(text.len());
+    let mut input = text.bytes();
+    while let Some(byte) = input.next() {
+        if byte == b'%' {
+            let hex = [input.next()?, input.next()?];
+            let hex = std::str::from_utf8(&hex).ok()?;
+            bytes.push(u8::from_str_radix(hex, 16).ok()?);
+        } else {
+            bytes.push(byte);
+        }
+    }
+    String::from_utf8(bytes).ok()
+}
+
+fn content_type(path: &Path) -> &'static str {
+    match path.extension().and_then(|e| e.to_str()) {
+        Some("html") => "text/html; charset=utf-8",
+        Some("css") => "text/css; charset=utf-8",
+        Some("js") => "text/javascript; charset=utf-8",
+        Some("json") => "application/json",
+        Some("svg") => "image/svg+xml",
+        Some("png") => "image/png",
+        Some("jpg" | "jpeg") => "image/jpeg",
+        Some("ico") => "image/x-icon",
+        Some("woff") => "font/woff",
+        Some("woff2") => "font/woff2",
+        Some("ttf") => "font/ttf",
+        Some("txt" | "md") => "text/plain; charset=utf-8",
+        _ => "application/octet-stream",
+    }
+}
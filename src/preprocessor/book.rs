@@ -0,0 +1,276 @@
+// ===========================================
+// book.rs - แทนที่ {{#example ...}} ในทุกบทของหนังสือ
+// ===========================================
+//
+// mdBook ส่ง [context, book] เป็น JSON มาทาง stdin แล้วรอ book ที่แก้แล้วทาง stdout
+// (ดู https://rust-lang.github.io/mdBook/for_developers/preprocessors.html)
+//
+// ตั้งค่าได้ใน docs/book.toml (path นับจาก docs/):
+//
+//   [preprocessor.examples]
+//   examples-dir = "../examples"
+//   project-dir = ".."
+//   output-timeout = 30
+//   locale = "th"            # ภาษาของ {{#example_output}} (th หรือ en)
+
+use super::directive::{self, Directive, Found, Kind};
+use super::region::{self, RegionError};
+use crate::examples::{self, ExampleError};
+use crate::i18n::Locale;
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
+use std::time::Duration;
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum PreprocessError {
+    #[error("{chapter}:{line}: {message}")]
+    Directive {
+        chapter: String,
+        line: usize,
+        message: String,
+    },
+
+    #[error("Invalid input from mdbook: {0}")]
+    Input(#[from] serde_json::Error),
+}
+
+pub struct Preprocessor {
+    examples_dir: PathBuf,
+    // ที่อยู่ของ Cargo.toml (ใช้ build example ตอนเก็บ output)
+    project_dir: PathBuf,
+    pub output_timeout: Duration,
+    // ภาษาของ output ที่เก็บมาใส่หนังสือ
+    pub locale: Locale,
+    // false = ไม่รันโปรแกรม (ตรวจแค่ว่ามี example และ region)
+    pub capture_output: bool,
+    sources: HashMap<String, Source>,
+    outputs: HashMap<String, String>,
+}
+
+struct Source {
+    // เช่น "examples/ownership.rs" (ใช้แสดงในหนังสือ)
+    display: String,
+    content: String,
+}
+
+impl Preprocessor {
+    pub fn new(examples_dir: impl Into<PathBuf>, project_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            examples_dir: examples_dir.into(),
+            project_dir: project_dir.into(),
+            output_timeout: Duration::from_secs(30),
+            locale: Locale::DEFAULT,
+            capture_output: true,
+            sources: HashMap::new(),
+            outputs: HashMap::new(),
+        }
+    }
+
+    // context ที่ mdBook ส่งมา: {"root": "/path/docs", "config": {...}, ...}
+    pub fn from_context(context: &Value) -> Self {
+        let root = PathBuf::from(context["root"].as_str().unwrap_or("."));
+        let config = &context["config"]["preprocessor"]["examples"];
+        let dir = |key: &str, default: &str| root.join(config[key].as_str().unwrap_or(default));
+
+        let mut preprocessor =
+            Self::new(dir("examples-dir", "../examples"), dir("project-dir", ".."));
+        if let Some(secs) = config["output-timeout"].as_u64() {
+            preprocessor.output_timeout = Duration::from_secs(secs);
+        }
+        if let Some(code) = config["locale"].as_str() {
+            match code.parse() {
+                Ok(locale) => preprocessor.locale = locale,
+                Err(e) => eprintln!("⚠️  {} (using {})", e, preprocessor.locale),
+            }
+        }
+        preprocessor
+    }
+
+    // แทนที่ directive ทั้งหมดใน `content` (chapter ใช้แค่ตอนรายงาน error)
+    pub fn process(&mut self, chapter: &str, content: &str) -> Result<String, PreprocessError> {
+        let found =
+            directive::find(content).map_err(|(line, message)| PreprocessError::Directive {
+                chapter: chapter.to_string(),
+                line,
+                message,
+            })?;
+
+        let mut result = String::with_capacity(content.len());
+        let mut copied = 0;
+        for item in found {
+            match item {
+                Found::Escaped { backslash } => {
+                    result.push_str(&content[copied..backslash]);
+                    copied = backslash + 1;
+                }
+                Found::Directive(directive) => {
+                    let text =
+                        self.render(&directive)
+                            .map_err(|message| PreprocessError::Directive {
+                                chapter: chapter.to_string(),
+                                line: directive.line,
+                                message,
+                            })?;
+                    result.push_str(&content[copied..directive.span.start]);
+                    result.push_str(&text);
+                    copied = directive.span.end;
+                }
+            }
+        }
+        result.push_str(&content[copied..]);
+        Ok(result)
+    }
+
+    // sections ของ book (แก้ content ของทุกบท รวมบทย่อย)
+    pub fn process_book(&mut self, book: &mut Value) -> Result<(), PreprocessError> {
+        // mdBook 0.4 ใช้ "sections", รุ่นเก่าใช้ "items"
+        let items = match book.get_mut("sections") {
+            Some(sections) => sections,
+            None => &mut book["items"],
+        };
+        self.process_items(items)
+    }
+
+    fn process_items(&mut self, items: &mut Value) -> Result<(), PreprocessError> {
+        let Some(items) = items.as_array_mut() else {
+            return Ok(());
+        };
+        for item in items {
+            let Some(chapter) = item.get_mut("Chapter") else {
+                continue;
+            };
+            let name = ["source_path", "path", "name"]
+                .iter()
+                .find_map(|key| chapter[*key].as_str())
+                .unwrap_or("?")
+                .to_string();
+            if let Some(content) = chapter["content"].as_str() {
+                let processed = self.process(&name, content)?;
+                chapter["content"] = Value::String(processed);
+            }
+            self.process_items(&mut chapter["sub_items"])?;
+        }
+        Ok(())
+    }
+
+    fn render(&mut self, directive: &Directive) -> Result<String, String> {
+        let name = &directive.example;
+        let (display, code) = {
+            let source = self.source(name)?;
+            let code = match &directive.region {
+                None => region::without_markers(&source.content),
+                Some(region) => region::region(&source.content, region)
+                    .map_err(|e| region_error(e, region, &source.display))?,
+            };
+            (source.display.clone(), code)
+        };
+
+        if directive.in_code_block {
+            return match directive.kind {
+                Kind::Code if directive.with_output => Err(
+                    "output cannot be added inside a code block, use {{#example_output}} in its own block"
+                        .to_string(),
+                ),
+                Kind::Code => Ok(code),
+                Kind::Output => self.output(name),
+            };
+        }
+
+        let output = match (directive.kind, directive.with_output) {
+            (Kind::Output, _) | (Kind::Code, true) => Some(self.output(name)?),
+            (Kind::Code, false) => None,
+        };
+        // ใช้ ignore เพราะ example อาจใช้ crate ภายนอก (tokio, axum) หรือเป็นแค่บางส่วนของฟังก์ชัน
+        // ซึ่ง mdbook test และ play.rust-lang.org compile ไม่ได้ (รันจริงด้วย cargo run --example)
+        let code_block = format!(
+            "📄 `{}` · รัน: `cargo run --example {}`\n\n```rust,ignore\n{}\n```",
+            display, name, code
+        );
+        let output_block = output.map(|output| format!("```text\n{}\n```", output));
+        Ok(match (directive.kind, output_block) {
+            (Kind::Code, None) => code_block,
+            (Kind::Code, Some(output)) => format!("{}\n\nผลลัพธ์:\n\n{}", code_block, output),
+            (Kind::Output, output) => output.unwrap_or_default(),
+        })
+    }
+
+    // examples/<name>.rs หรือ examples/<name>/main.rs
+    fn source(&mut self, name: &str) -> Result<&Source, String> {
+        if !self.sources.contains_key(name) {
+            let candidates = [format!("{}.rs", name), format!("{}/main.rs", name)];
+            let Some(file) = candidates
+                .iter()
+                .find(|file| self.examples_dir.join(file).is_file())
+            else {
+                return Err(format!(
+                    "example '{}' not found in {}",
+                    name,
+                    self.examples_dir.display()
+                ));
+            };
+            let path = self.examples_dir.join(file);
+            let content = fs::read_to_string(&path)
+                .map_err(|e| format!("failed to read {}: {}", path.display(), e))?;
+            let source = Source {
+                display: format!("examples/{}", file),
+                content,
+            };
+            self.sources.insert(name.to_string(), source);
+        }
+        Ok(&self.sources[name])
+    }
+
+    fn output(&mut self, name: &str) -> Result<String, String> {
+        if !self.capture_output {
+            return Ok(format!("(output of {} is not captured)", name));
+        }
+        if let Some(output) = self.outputs.get(name) {
+            return Ok(output.clone());
+        }
+        let run = examples::run(&self.project_dir, name, self.locale, self.output_timeout)
+            .map_err(|e| match e {
+                ExampleError::Timeout { .. } => format!("{} (servers cannot be captured)", e),
+                other => other.to_string(),
+            })?;
+        if !run.success() {
+            return Err(format!(
+                "example '{}' exited with {}:\n{}",
+                name,
+                run.status,
+                run.stderr.trim_end()
+            ));
+        }
+        let output = run.stdout.trim_end().to_string();
+        self.outputs.insert(name.to_string(), output.clone());
+        Ok(output)
+    }
+}
+
+fn region_error(error: RegionError, region: &str, file: &str) -> String {
+    match error {
+        RegionError::Missing { available } if available.is_empty() => format!(
+            "region '{}' not found in {} (the file has no // ANCHOR: markers)",
+            region, file
+        ),
+        RegionError::Missing { available } => format!(
+            "region '{}' not found in {} (available: {})",
+            region,
+            file,
+            available.join(", ")
+        ),
+        RegionError::Unclosed { line } => format!(
+            "region '{}' in {} starts at line {} but has no // ANCHOR_END: {}",
+            region, file, line, region
+        ),
+    }
+}
+
+// อ่าน [context, book] จาก mdBook แล้วคืน book ที่แก้แล้วเป็น JSON
+pub fn preprocess(input: &str) -> Result<String, PreprocessError> {
+    let (context, mut book): (Value, Value) = serde_json::from_str(input)?;
+    Preprocessor::from_context(&context).process_book(&mut book)?;
+    Ok(serde_json::to_string(&book)?)
+}
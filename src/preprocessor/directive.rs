@@ -0,0 +1,128 @@
+// ===========================================
+// directive.rs - หา {{#example ...}} ในไฟล์ markdown ของหนังสือ
+// ===========================================
+//
+//   {{#example ownership}}                  ทั้งไฟล์ examples/ownership.rs
+//   {{#example ownership:borrowing}}        เฉพาะ region "borrowing"
+//   {{#example ownership:borrowing output}} ตามด้วย output จริงของโปรแกรม
+//   {{#example_output ownership}}           เฉพาะ output
+//   \{{#example ownership}}                 ไม่แทนที่ (แสดงตามตัวอักษร)
+//
+// ถ้าอยู่นอก code block จะได้ code block ใหม่ ถ้าอยู่ใน ``` อยู่แล้วจะแทรกแค่เนื้อหา
+// (เลือก attribute ของ code block เองได้ เหมือน {{#include}})
+
+use std::ops::Range;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Kind {
+    Code,
+    Output,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Directive {
+    pub kind: Kind,
+    pub example: String,
+    pub region: Option<String>,
+    // ต่อท้าย code ด้วย output (เฉพาะ Kind::Code)
+    pub with_output: bool,
+    // ตำแหน่ง (byte) ของ {{...}} ใน content และเลขบรรทัด (เริ่มที่ 1)
+    pub span: Range<usize>,
+    pub line: usize,
+    pub in_code_block: bool,
+}
+
+// สิ่งที่เจอใน markdown: directive จริง หรือ \{{#example}} ที่ต้องเอา \ ออก
+#[derive(Debug, Clone, PartialEq)]
+pub enum Found {
+    Directive(Directive),
+    Escaped { backslash: usize },
+}
+
+// Err((บรรทัด, ข้อความ)) ถ้าเขียน directive ผิดรูปแบบ
+pub fn find(content: &str) -> Result<Vec<Found>, (usize, String)> {
+    let mut found = Vec::new();
+    let mut in_code_block = false;
+    let mut offset = 0;
+
+    for (index, line) in content.split_inclusive('\n').enumerate() {
+        let trimmed = line.trim_start();
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            in_code_block = !in_code_block;
+        }
+
+        let mut search = 0;
+        while let Some(position) = line[search..].find("{{#example") {
+            let start = search + position;
+            let Some(length) = line[start..].find("}}") else {
+                return Err((index + 1, "directive is missing closing }}".to_string()));
+            };
+            let end = start + length + 2;
+            search = end;
+
+            if line[..start].ends_with('\\') {
+                found.push(Found::Escaped {
+                    backslash: offset + start - 1,
+                });
+                continue;
+            }
+            let mut directive =
+                parse(&line[start + 3..end - 2]).map_err(|message| (index + 1, message))?;
+            directive.span = offset + start..offset + end;
+            directive.line = index + 1;
+            directive.in_code_block = in_code_block;
+            found.push(Found::Directive(directive));
+        }
+        offset += line.len();
+    }
+    Ok(found)
+}
+
+// "example ownership:borrowing output" (ไม่มี {{# และ }})
+fn parse(text: &str) -> Result<Directive, String> {
+    let mut words = text.split_whitespace();
+    let kind = match words.next() {
+        Some("example") => Kind::Code,
+        Some("example_output") => Kind::Output,
+        Some(other) => return Err(format!("unknown directive '{{{{#{}}}}}'", other)),
+        None => return Err("empty directive".to_string()),
+    };
+    let target = words
+        .next()
+        .ok_or_else(|| "missing example name, e.g. {{#example ownership:borrowing}}".to_string())?;
+    let (example, region) = match target.split_once(':') {
+        Some((example, region)) => (example, Some(region)),
+        None => (target, None),
+    };
+    if example.is_empty()
+        || !example
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_')
+    {
+        return Err(format!("invalid example name '{}'", example));
+    }
+    if region.is_some_and(str::is_empty) {
+        return Err(format!("empty region name after '{}:'", example));
+    }
+    if kind == Kind::Output && region.is_some() {
+        return Err("example_output captures the whole program, not a region".to_string());
+    }
+
+    let mut with_output = false;
+    for flag in words {
+        match (kind, flag) {
+            (Kind::Code, "output") => with_output = true,
+            _ => return Err(format!("unknown option '{}'", flag)),
+        }
+    }
+
+    Ok(Directive {
+        kind,
+        example: example.to_string(),
+        region: region.map(str::to_string),
+        with_output,
+        span: 0..0,
+        line: 0,
+        in_code_block: false,
+    })
+}
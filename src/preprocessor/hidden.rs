@@ -0,0 +1,27 @@
+// ===========================================
+// hidden.rs - บรรทัดที่ซ่อนไว้ใน code block ของ mdBook
+// ===========================================
+//
+// ```rust
+// # fn main() {
+// let x = 5;
+// # }
+// ```
+//
+// หนังสือแสดงแค่ `let x = 5;` แต่โปรแกรมเต็มมีบรรทัดที่ขึ้นต้นด้วย "# " ด้วย
+// ใช้ทั้งตอนตรวจ code ในคลังคำถาม (quiz) และตอน compile แบบฝึกหัด (exercises)
+
+// บรรทัดที่ขึ้นต้นด้วย "# " เป็นโค้ดที่ซ่อนไว้ใน mdBook แต่ยังเป็นส่วนหนึ่งของโปรแกรม
+pub(crate) fn strip_hidden_lines(code: &str) -> String {
+    code.lines()
+        .map(|line| {
+            let trimmed = line.trim_start();
+            match trimmed.strip_prefix('#') {
+                Some("") => "",
+                Some(rest) if rest.starts_with(' ') => &rest[1..],
+                _ => line,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
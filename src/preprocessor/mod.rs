@@ -0,0 +1,21 @@
+// ===========================================
+// Preprocessor - ดึงโค้ดจาก examples/*.rs เข้าหนังสือ (mdBook preprocessor)
+// ===========================================
+//
+// - directive.rs: หา {{#example name:region}} และ {{#example_output name}} ใน markdown
+// - region.rs:    ตัดส่วนที่อยู่ระหว่าง // ANCHOR: name กับ // ANCHOR_END: name
+// - book.rs:      แทนที่ directive ในทุกบท + รัน example เพื่อเก็บ output จริง
+// - hidden.rs:    บรรทัด "# " ที่ซ่อนไว้ใน code block (ใช้ร่วมกับ quiz และ exercises)
+//
+// ถ้าหา example หรือ region ไม่เจอ mdbook build จะล้มเหลว หนังสือจึงไม่ห่างจากโค้ดจริง
+
+mod book;
+mod directive;
+mod hidden;
+mod region;
+
+pub use book::{PreprocessError, Preprocessor, preprocess};
+pub use directive::{Directive, Found, Kind, find};
+pub use region::{RegionError, names, region, without_markers};
+
+pub(crate) use hidden::strip_hidden_lines;
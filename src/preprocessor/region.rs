@@ -0,0 +1,100 @@
+// ===========================================
+// region.rs - ตัดส่วนที่มีชื่อออกจากไฟล์ examples/*.rs
+// ===========================================
+//
+// ใช้ marker แบบเดียวกับ {{#include file.rs:name}} ของ mdBook:
+//
+//   // ANCHOR: borrowing
+//   let len = calculate_length(&s5);
+//   // ANCHOR_END: borrowing
+//
+// region ซ้อนกันได้ และบรรทัด marker ทุกบรรทัดจะไม่ถูกนำไปแสดงในหนังสือ
+
+use std::collections::BTreeSet;
+
+const START: &str = "ANCHOR:";
+const END: &str = "ANCHOR_END:";
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum RegionError {
+    // available = ชื่อ region ที่มีในไฟล์ (ใช้ช่วยบอกว่าพิมพ์ผิดหรือเปล่า)
+    Missing { available: Vec<String> },
+    Unclosed { line: usize },
+}
+
+enum Marker<'a> {
+    Start(&'a str),
+    End(&'a str),
+}
+
+fn marker(line: &str) -> Option<Marker<'_>> {
+    let comment = line.trim().strip_prefix("//")?.trim_start();
+    if let Some(name) = comment.strip_prefix(END) {
+        Some(Marker::End(name.trim()))
+    } else {
+        comment
+            .strip_prefix(START)
+            .map(|name| Marker::Start(name.trim()))
+    }
+}
+
+// ชื่อ region ทั้งหมดในไฟล์ เรียงตามตัวอักษร
+pub fn names(source: &str) -> Vec<String> {
+    source
+        .lines()
+        .filter_map(|line| match marker(line) {
+            Some(Marker::Start(name)) => Some(name.to_string()),
+            _ => None,
+        })
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect()
+}
+
+// โค้ดใน region (เอา marker ออกและลด indent ให้ชิดซ้าย)
+pub fn region(source: &str, name: &str) -> Result<String, RegionError> {
+    let mut lines = Vec::new();
+    let mut start = None;
+    for (index, line) in source.lines().enumerate() {
+        match marker(line) {
+            Some(Marker::Start(found)) if found == name && start.is_none() => {
+                start = Some(index + 1);
+            }
+            Some(Marker::End(found)) if found == name && start.is_some() => {
+                return Ok(dedent(&lines));
+            }
+            Some(_) => {}
+            None if start.is_some() => lines.push(line),
+            None => {}
+        }
+    }
+    match start {
+        Some(line) => Err(RegionError::Unclosed { line }),
+        None => Err(RegionError::Missing {
+            available: names(source),
+        }),
+    }
+}
+
+// ทั้งไฟล์ (ไม่มี marker)
+pub fn without_markers(source: &str) -> String {
+    let lines: Vec<&str> = source
+        .lines()
+        .filter(|line| marker(line).is_none())
+        .collect();
+    lines.join("\n")
+}
+
+fn dedent(lines: &[&str]) -> String {
+    let indent = lines
+        .iter()
+        .filter(|line| !line.trim().is_empty())
+        .map(|line| line.len() - line.trim_start().len())
+        .min()
+        .unwrap_or(0);
+    lines
+        .iter()
+        .map(|line| line.get(indent..).unwrap_or("").trim_end())
+        .collect::<Vec<_>>()
+        .join("\n")
+}
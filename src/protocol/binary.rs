@@ -0,0 +1,146 @@
+// ===========================================
+// binary.rs - encoding แบบ binary
+// ===========================================
+//
+// byte แรกเป็น tag บอกชนิด ตามด้วยข้อมูลของชนิดนั้น:
+//
+//   0x00 Quit
+//   0x01 Move         x, y          (varint)
+//   0x02 Write        ความยาว, UTF-8 (varint + bytes)
+//   0x03 ChangeColor  r, g, b       (varint)
+//
+// ตัวเลขใช้ zigzag varint (LEB128): ค่าใกล้ 0 ทั้งบวกและลบใช้แค่ 1 byte
+// เช่น Move { x: 10, y: -20 } = [0x01, 0x14, 0x27] (3 byte แทน 9)
+
+use super::{DecodeError, Message};
+
+const QUIT: u8 = 0x00;
+const MOVE: u8 = 0x01;
+const WRITE: u8 = 0x02;
+const CHANGE_COLOR: u8 = 0x03;
+
+// varint ของ u32 ยาวไม่เกิน 5 byte (7 bit ต่อ byte)
+const MAX_VARINT_LEN: usize = 5;
+
+pub fn encode(message: &Message) -> Vec<u8> {
+    let mut bytes = Vec::new();
+    encode_into(message, &mut bytes);
+    bytes
+}
+
+// ต่อท้าย buffer ที่มีอยู่ (ไม่ต้อง allocate ใหม่ทุก message)
+pub fn encode_into(message: &Message, out: &mut Vec<u8>) {
+    match message {
+        Message::Quit => out.push(QUIT),
+        Message::Move { x, y } => {
+            out.push(MOVE);
+            write_varint(out, zigzag(*x));
+            write_varint(out, zigzag(*y));
+        }
+        Message::Write(text) => {
+            out.push(WRITE);
+            write_varint(out, text.len() as u32);
+            out.extend_from_slice(text.as_bytes());
+        }
+        Message::ChangeColor(r, g, b) => {
+            out.push(CHANGE_COLOR);
+            for value in [r, g, b] {
+                write_varint(out, zigzag(*value));
+            }
+        }
+    }
+}
+
+// bytes ต้องเป็น message เดียวพอดี (เกินมาถือว่าผิด)
+pub fn decode(bytes: &[u8]) -> Result<Message, DecodeError> {
+    let mut reader = Reader { bytes, offset: 0 };
+    let tag = *bytes.first().ok_or(DecodeError::Empty)?;
+    reader.offset = 1;
+
+    let message = match tag {
+        QUIT => Message::Quit,
+        MOVE => Message::Move {
+            x: reader.int("x")?,
+            y: reader.int("y")?,
+        },
+        WRITE => Message::Write(reader.text()?),
+        CHANGE_COLOR => Message::ChangeColor(
+            reader.int("red")?,
+            reader.int("green")?,
+            reader.int("blue")?,
+        ),
+        tag => return Err(DecodeError::UnknownTag { tag }),
+    };
+
+    match bytes.len() - reader.offset {
+        0 => Ok(message),
+        count => Err(DecodeError::TrailingBytes { count }),
+    }
+}
+
+struct Reader<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+}
+
+impl Reader<'_> {
+    fn varint(&mut self, field: &'static str) -> Result<u32, DecodeError> {
+        let start = self.offset;
+        let mut value: u64 = 0;
+        for index in 0..MAX_VARINT_LEN {
+            let byte = *self.bytes.get(self.offset).ok_or(DecodeError::Truncated {
+                field,
+                offset: self.offset,
+            })?;
+            self.offset += 1;
+            value |= u64::from(byte & 0x7f) << (7 * index);
+            if byte & 0x80 == 0 {
+                return u32::try_from(value).map_err(|_| DecodeError::InvalidVarint {
+                    field,
+                    offset: start,
+                });
+            }
+        }
+        Err(DecodeError::InvalidVarint {
+            field,
+            offset: start,
+        })
+    }
+
+    fn int(&mut self, field: &'static str) -> Result<i32, DecodeError> {
+        self.varint(field).map(unzigzag)
+    }
+
+    fn text(&mut self) -> Result<String, DecodeError> {
+        let len = self.varint("text length")? as usize;
+        let start = self.offset;
+        let bytes =
+            self.bytes
+                .get(start..start.saturating_add(len))
+                .ok_or(DecodeError::Truncated {
+                    field: "text",
+                    offset: self.bytes.len(),
+                })?;
+        self.offset += len;
+        String::from_utf8(bytes.to_vec()).map_err(|e| DecodeError::InvalidUtf8 {
+            offset: start + e.utf8_error().valid_up_to(),
+        })
+    }
+}
+
+fn write_varint(out: &mut Vec<u8>, mut value: u32) {
+    while value >= 0x80 {
+        out.push((value as u8 & 0x7f) | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+// 0 → 0, -1 → 1, 1 → 2, -2 → 3, ...
+fn zigzag(value: i32) -> u32 {
+    ((value << 1) ^ (value >> 31)) as u32
+}
+
+fn unzigzag(value: u32) -> i32 {
+    ((value >> 1) as i32) ^ -((value & 1) as i32)
+}
@@ -0,0 +1,58 @@
+// ===========================================
+// connection.rs - รับส่ง Message แบบ frame ผ่าน TCP (tokio)
+// ===========================================
+
+use super::{FrameDecoder, FrameError, Message, encode, frame};
+use std::io;
+use std::net::SocketAddr;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::{TcpStream, ToSocketAddrs};
+
+const READ_CHUNK: usize = 4096;
+
+pub struct Connection {
+    stream: TcpStream,
+    decoder: FrameDecoder,
+}
+
+impl Connection {
+    pub fn new(stream: TcpStream) -> Self {
+        Self {
+            stream,
+            decoder: FrameDecoder::new(),
+        }
+    }
+
+    pub async fn connect(addr: impl ToSocketAddrs) -> io::Result<Self> {
+        Ok(Self::new(TcpStream::connect(addr).await?))
+    }
+
+    pub fn peer_addr(&self) -> io::Result<SocketAddr> {
+        self.stream.peer_addr()
+    }
+
+    pub async fn send(&mut self, message: &Message) -> Result<(), FrameError> {
+        self.stream.write_all(&frame(&encode(message))?).await?;
+        Ok(())
+    }
+
+    // None = อีกฝั่งปิด connection ระหว่าง frame (ปกติ)
+    // ใช้ใน tokio::select! ได้: ถ้าถูกยกเลิกกลางทาง byte ที่อ่านแล้วยังอยู่ใน decoder
+    //
+    // FrameError::Decode = frame ครบแต่ message ผิด (อ่าน frame ถัดไปต่อได้)
+    // error อื่น = stream ใช้ต่อไม่ได้แล้ว
+    pub async fn recv(&mut self) -> Result<Option<Message>, FrameError> {
+        let mut chunk = [0; READ_CHUNK];
+        loop {
+            if let Some(message) = self.decoder.next_message()? {
+                return Ok(Some(message));
+            }
+            let n = self.stream.read(&mut chunk).await?;
+            if n == 0 {
+                self.decoder.finish()?;
+                return Ok(None);
+            }
+            self.decoder.extend(&chunk[..n]);
+        }
+    }
+}
@@ -0,0 +1,65 @@
+// ===========================================
+// error.rs - error ตอน decode message และอ่าน frame
+// ===========================================
+//
+// ข้อความบอกตำแหน่ง (byte ที่เท่าไร) และสิ่งที่คาดไว้ เพื่อ debug protocol ได้จาก log
+
+use std::io;
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum DecodeError {
+    // -------- binary --------
+    #[error("empty message (expected a tag byte)")]
+    Empty,
+
+    #[error("unknown message tag {tag:#04x} (expected 0x00-0x03)")]
+    UnknownTag { tag: u8 },
+
+    #[error("message ends at byte {offset} while reading {field}")]
+    Truncated { field: &'static str, offset: usize },
+
+    #[error("{field} at byte {offset} is not a valid 32-bit varint")]
+    InvalidVarint { field: &'static str, offset: usize },
+
+    #[error("text at byte {offset} is not valid UTF-8")]
+    InvalidUtf8 { offset: usize },
+
+    #[error("{count} unexpected byte(s) after the end of the message")]
+    TrailingBytes { count: usize },
+
+    // -------- text --------
+    #[error("unknown command '{0}' (expected QUIT, MOVE, WRITE or COLOR)")]
+    UnknownCommand(String),
+
+    #[error("{command} takes {expected} argument(s), found {found}")]
+    Arguments {
+        command: &'static str,
+        expected: usize,
+        found: usize,
+    },
+
+    #[error("{command}: '{value}' is not a 32-bit integer")]
+    InvalidNumber {
+        command: &'static str,
+        value: String,
+    },
+
+    #[error("unknown escape '\\{0}' in WRITE text (use \\n, \\r or \\\\)")]
+    InvalidEscape(String),
+}
+
+#[derive(Error, Debug)]
+pub enum FrameError {
+    #[error("frame of {len} bytes is larger than the limit of {max} bytes")]
+    TooLarge { len: usize, max: usize },
+
+    #[error("stream ended after {received} of {expected} bytes of a frame")]
+    Truncated { expected: usize, received: usize },
+
+    #[error("invalid message in frame: {0}")]
+    Decode(#[from] DecodeError),
+
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+}
@@ -0,0 +1,165 @@
+// ===========================================
+// frame.rs - แบ่ง byte stream (เช่น TCP) เป็น frame
+// ===========================================
+//
+// TCP ไม่รู้ว่า message หนึ่งจบตรงไหน จึงนำหน้าแต่ละ frame ด้วยความยาว:
+//
+//   [ความยาว: u32 big-endian][payload ตามความยาวนั้น]
+//
+// ฝั่งรับจำกัดขนาด frame (MAX_FRAME_LEN) ไม่ให้ peer ส่งความยาวมั่วๆ มาจอง memory
+
+use super::{FrameError, Message, decode, encode_into};
+use std::io::{self, Read, Write};
+
+pub const MAX_FRAME_LEN: usize = 1024 * 1024;
+
+const HEADER_LEN: usize = 4;
+
+// payload → frame ที่พร้อมส่ง
+pub fn frame(payload: &[u8]) -> Result<Vec<u8>, FrameError> {
+    check_len(payload.len(), MAX_FRAME_LEN)?;
+    let mut bytes = Vec::with_capacity(HEADER_LEN + payload.len());
+    bytes.extend_from_slice(&(payload.len() as u32).to_be_bytes());
+    bytes.extend_from_slice(payload);
+    Ok(bytes)
+}
+
+pub fn write_frame(writer: &mut impl Write, payload: &[u8]) -> Result<(), FrameError> {
+    writer.write_all(&frame(payload)?)?;
+    Ok(())
+}
+
+// None = stream จบพอดีระหว่าง frame (ปิด connection ตามปกติ)
+pub fn read_frame(reader: &mut impl Read) -> Result<Option<Vec<u8>>, FrameError> {
+    let mut header = [0; HEADER_LEN];
+    match read_full(reader, &mut header)? {
+        0 => return Ok(None),
+        HEADER_LEN => {}
+        received => {
+            return Err(FrameError::Truncated {
+                expected: HEADER_LEN,
+                received,
+            });
+        }
+    }
+    let len = u32::from_be_bytes(header) as usize;
+    check_len(len, MAX_FRAME_LEN)?;
+
+    let mut payload = vec![0; len];
+    let received = read_full(reader, &mut payload)?;
+    if received < len {
+        return Err(FrameError::Truncated {
+            expected: HEADER_LEN + len,
+            received: HEADER_LEN + received,
+        });
+    }
+    Ok(Some(payload))
+}
+
+pub fn write_message(writer: &mut impl Write, message: &Message) -> Result<(), FrameError> {
+    let mut payload = Vec::new();
+    encode_into(message, &mut payload);
+    write_frame(writer, &payload)
+}
+
+pub fn read_message(reader: &mut impl Read) -> Result<Option<Message>, FrameError> {
+    match read_frame(reader)? {
+        Some(payload) => Ok(Some(decode(&payload)?)),
+        None => Ok(None),
+    }
+}
+
+// อ่านจนเต็ม buffer หรือ EOF แล้วคืนจำนวน byte ที่ได้
+fn read_full(reader: &mut impl Read, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match reader.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+fn check_len(len: usize, max: usize) -> Result<(), FrameError> {
+    if len > max {
+        return Err(FrameError::TooLarge { len, max });
+    }
+    Ok(())
+}
+
+// -------------------------------------------
+// FrameDecoder - สำหรับข้อมูลที่มาเป็นก้อนๆ (เช่น async read)
+// -------------------------------------------
+// ใส่ byte ที่ได้มาด้วย extend แล้วเรียก next_frame จนได้ None
+
+#[derive(Debug)]
+pub struct FrameDecoder {
+    buffer: Vec<u8>,
+    max_len: usize,
+}
+
+impl Default for FrameDecoder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FrameDecoder {
+    pub fn new() -> Self {
+        Self::with_max_len(MAX_FRAME_LEN)
+    }
+
+    pub fn with_max_len(max_len: usize) -> Self {
+        Self {
+            buffer: Vec::new(),
+            max_len,
+        }
+    }
+
+    pub fn extend(&mut self, bytes: &[u8]) {
+        self.buffer.extend_from_slice(bytes);
+    }
+
+    // None = ยังได้ไม่ครบ frame รอข้อมูลเพิ่ม
+    pub fn next_frame(&mut self) -> Result<Option<Vec<u8>>, FrameError> {
+        let Some(header) = self.buffer.first_chunk::<HEADER_LEN>() else {
+            return Ok(None);
+        };
+        let len = u32::from_be_bytes(*header) as usize;
+        check_len(len, self.max_len)?;
+        if self.buffer.len() < HEADER_LEN + len {
+            return Ok(None);
+        }
+        let payload = self.buffer[HEADER_LEN..HEADER_LEN + len].to_vec();
+        self.buffer.drain(..HEADER_LEN + len);
+        Ok(Some(payload))
+    }
+
+    pub fn next_message(&mut self) -> Result<Option<Message>, FrameError> {
+        match self.next_frame()? {
+            Some(payload) => Ok(Some(decode(&payload)?)),
+            None => Ok(None),
+        }
+    }
+
+    // byte ที่ค้างอยู่ (ยังไม่ครบ frame)
+    pub fn buffered(&self) -> usize {
+        self.buffer.len()
+    }
+
+    // เรียกตอน stream จบ: ถ้ายังมี frame ค้างครึ่งๆ กลางๆ เป็น error
+    pub fn finish(&self) -> Result<(), FrameError> {
+        let received = self.buffer.len();
+        if received == 0 {
+            return Ok(());
+        }
+        let expected = match self.buffer.first_chunk::<HEADER_LEN>() {
+            Some(header) => HEADER_LEN + u32::from_be_bytes(*header) as usize,
+            None => HEADER_LEN,
+        };
+        Err(FrameError::Truncated { expected, received })
+    }
+}
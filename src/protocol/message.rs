@@ -0,0 +1,14 @@
+// ===========================================
+// message.rs - Message ที่ส่งระหว่างโปรแกรม
+// ===========================================
+
+use serde::{Deserialize, Serialize};
+
+// เหมือน enum Message ในบทที่ 6 แต่ clone / เทียบ / serialize ได้
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Message {
+    Quit,
+    Move { x: i32, y: i32 },
+    Write(String),
+    ChangeColor(i32, i32, i32),
+}
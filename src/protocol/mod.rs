@@ -0,0 +1,29 @@
+// ===========================================
+// Protocol - ส่ง Message (จาก examples/structs_enums.rs) ผ่าน network
+// ===========================================
+//
+// - message.rs: enum Message (serde ได้ด้วย)
+// - binary.rs:  encoding แบบ binary ขนาดเล็ก (tag + varint)
+// - text.rs:    encoding แบบบรรทัดละ message ที่คนอ่าน/พิมพ์เองได้ (เช่นผ่าน nc)
+// - frame.rs:   แบ่ง stream เป็น frame ด้วย length prefix 4 byte
+// - error.rs:   error ตอน decode / อ่าน frame
+// - connection.rs: รับส่ง Message ผ่าน TCP (tokio)
+// - server.rs:  server หลาย client พร้อม state ของแต่ละคนและ broadcast
+
+mod binary;
+mod connection;
+mod error;
+mod frame;
+mod message;
+mod server;
+mod text;
+
+pub use binary::{decode, encode, encode_into};
+pub use connection::Connection;
+pub use error::{DecodeError, FrameError};
+pub use frame::{
+    FrameDecoder, MAX_FRAME_LEN, frame, read_frame, read_message, write_frame, write_message,
+};
+pub use message::Message;
+pub use server::{ClientId, ClientState, Lobby, Outcome, Server};
+pub use text::{from_line, to_line};
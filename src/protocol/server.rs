@@ -0,0 +1,301 @@
+// ===========================================
+// server.rs - TCP server ที่รับ Message จากหลาย client
+// ===========================================
+//
+// แต่ละ client มี state ของตัวเอง (ClientState):
+//
+//   Move { x, y }        → ย้ายตำแหน่ง
+//   ChangeColor(r, g, b) → เปลี่ยนสี (0-255)
+//   Write(text)          → เก็บลง chat log ("/who" = ดูทุกคนที่ online)
+//   Quit                 → ออกจาก server
+//
+// การเปลี่ยนแปลงถูกส่งให้ทุก client (รวมคนส่ง) เป็น Message::Write
+// เช่น "client 2 moved to (10, 20)"
+//
+// Lobby เก็บ state และตัดสินว่าต้องตอบอะไร (ไม่มี I/O จึง test ได้ง่าย)
+// Server รับ connection แล้วส่งต่อ message ให้ Lobby
+
+use super::{Connection, FrameError, Message};
+use std::collections::BTreeMap;
+use std::io;
+use std::sync::{Arc, Mutex, PoisonError};
+use tokio::net::TcpListener;
+use tokio::sync::broadcast;
+
+pub type ClientId = u64;
+
+// chat log ต่อ client เก็บแค่ข้อความล่าสุด
+const MAX_LOG: usize = 100;
+
+// update ที่ค้างส่งได้ต่อ client ก่อนจะถูกข้าม (client ที่อ่านช้า)
+const UPDATE_BUFFER: usize = 256;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct ClientState {
+    pub id: ClientId,
+    pub position: (i32, i32),
+    pub color: (i32, i32, i32),
+    pub log: Vec<String>,
+}
+
+impl ClientState {
+    fn new(id: ClientId) -> Self {
+        Self {
+            id,
+            position: (0, 0),
+            color: (255, 255, 255),
+            log: Vec::new(),
+        }
+    }
+}
+
+// ผลของ message หนึ่งตัว
+#[derive(Debug, Default, PartialEq)]
+pub struct Outcome {
+    // ส่งให้ทุก client
+    pub broadcast: Option<String>,
+    // ส่งให้คนส่งคนเดียว
+    pub reply: Option<String>,
+    pub disconnect: bool,
+}
+
+#[derive(Debug, Default)]
+pub struct Lobby {
+    clients: BTreeMap<ClientId, ClientState>,
+    next_id: ClientId,
+}
+
+impl Lobby {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn join(&mut self) -> (ClientId, Outcome) {
+        self.next_id += 1;
+        let id = self.next_id;
+        self.clients.insert(id, ClientState::new(id));
+        let outcome = Outcome {
+            broadcast: Some(format!("client {} joined", id)),
+            reply: Some(format!(
+                "welcome, you are client {} ({} online)",
+                id,
+                self.clients.len()
+            )),
+            disconnect: false,
+        };
+        (id, outcome)
+    }
+
+    // หลุดไปโดยไม่ส่ง Quit (ถ้าออกด้วย Quit แล้วไม่มีอะไรต้องทำ)
+    pub fn leave(&mut self, id: ClientId) -> Outcome {
+        match self.clients.remove(&id) {
+            Some(_) => broadcast(format!("client {} disconnected", id)),
+            None => Outcome::default(),
+        }
+    }
+
+    pub fn process_message(&mut self, id: ClientId, message: Message) -> Outcome {
+        if message == Message::Quit {
+            self.clients.remove(&id);
+            return Outcome {
+                broadcast: Some(format!("client {} left", id)),
+                reply: Some("bye 👋".to_string()),
+                disconnect: true,
+            };
+        }
+        if matches!(&message, Message::Write(text) if text.trim() == "/who") {
+            return reply(self.who());
+        }
+        let Some(client) = self.clients.get_mut(&id) else {
+            return Outcome {
+                disconnect: true,
+                ..Outcome::default()
+            };
+        };
+
+        match message {
+            Message::Quit => unreachable!("handled above"),
+            Message::Move { x, y } => {
+                client.position = (x, y);
+                broadcast(format!("client {} moved to ({}, {})", id, x, y))
+            }
+            Message::ChangeColor(r, g, b) => {
+                if ![r, g, b].iter().all(|value| (0..=255).contains(value)) {
+                    return reply(format!(
+                        "error: color values must be 0-255, got ({}, {}, {})",
+                        r, g, b
+                    ));
+                }
+                client.color = (r, g, b);
+                broadcast(format!(
+                    "client {} changed color to RGB({}, {}, {})",
+                    id, r, g, b
+                ))
+            }
+            Message::Write(text) => {
+                if client.log.len() == MAX_LOG {
+                    client.log.remove(0);
+                }
+                client.log.push(text.clone());
+                broadcast(format!("client {}: {}", id, text))
+            }
+        }
+    }
+
+    pub fn client(&self, id: ClientId) -> Option<&ClientState> {
+        self.clients.get(&id)
+    }
+
+    pub fn clients(&self) -> impl Iterator<Item = &ClientState> {
+        self.clients.values()
+    }
+
+    fn who(&self) -> String {
+        let mut lines = vec![format!("{} online:", self.clients.len())];
+        for client in self.clients.values() {
+            let (x, y) = client.position;
+            let (r, g, b) = client.color;
+            lines.push(format!(
+                "  client {} at ({}, {}) RGB({}, {}, {}), {} message(s)",
+                client.id,
+                x,
+                y,
+                r,
+                g,
+                b,
+                client.log.len()
+            ));
+        }
+        lines.join("\n")
+    }
+}
+
+fn broadcast(text: String) -> Outcome {
+    Outcome {
+        broadcast: Some(text),
+        ..Outcome::default()
+    }
+}
+
+fn reply(text: String) -> Outcome {
+    Outcome {
+        reply: Some(text),
+        ..Outcome::default()
+    }
+}
+
+// -------------------------------------------
+// Server - รับ connection แล้วรัน Lobby
+// -------------------------------------------
+
+pub struct Server {
+    lobby: Mutex<Lobby>,
+    updates: broadcast::Sender<String>,
+}
+
+impl Default for Server {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Server {
+    pub fn new() -> Self {
+        let (updates, _) = broadcast::channel(UPDATE_BUFFER);
+        Self {
+            lobby: Mutex::new(Lobby::new()),
+            updates,
+        }
+    }
+
+    // update ทุกอย่างที่ส่งให้ client (ใช้พิมพ์ log ฝั่ง server)
+    pub fn subscribe(&self) -> broadcast::Receiver<String> {
+        self.updates.subscribe()
+    }
+
+    pub fn clients(&self) -> Vec<ClientState> {
+        self.lobby().clients().cloned().collect()
+    }
+
+    // รับ connection ไปเรื่อยๆ แต่ละ client ทำงานใน task ของตัวเอง
+    pub async fn run(self: Arc<Self>, listener: TcpListener) -> io::Result<()> {
+        loop {
+            let (stream, _) = listener.accept().await?;
+            let server = self.clone();
+            tokio::spawn(async move {
+                server.handle(Connection::new(stream)).await;
+            });
+        }
+    }
+
+    async fn handle(&self, mut connection: Connection) {
+        // subscribe ก่อน join เพื่อให้เห็นประกาศว่าตัวเองเข้ามาด้วย
+        let mut updates = self.updates.subscribe();
+        let (id, outcome) = self.lobby().join();
+        if !self.deliver(&mut connection, outcome).await {
+            self.disconnect(id);
+            return;
+        }
+
+        loop {
+            let open = tokio::select! {
+                received = connection.recv() => match received {
+                    Ok(Some(message)) => {
+                        let outcome = self.lobby().process_message(id, message);
+                        if outcome.disconnect {
+                            // ส่ง update ที่ค้างอยู่ก่อนปิด (เช่น ข้อความที่เพิ่งพิมพ์ก่อน Quit)
+                            while let Ok(text) = updates.try_recv() {
+                                if connection.send(&Message::Write(text)).await.is_err() {
+                                    break;
+                                }
+                            }
+                        }
+                        self.deliver(&mut connection, outcome).await
+                    }
+                    // frame ครบแต่ message ผิด: บอก client แล้วรับต่อ
+                    Err(FrameError::Decode(e)) => {
+                        self.deliver(&mut connection, reply(format!("error: {}", e))).await
+                    }
+                    Ok(None) | Err(_) => false,
+                },
+                update = updates.recv() => match update {
+                    Ok(text) => connection.send(&Message::Write(text)).await.is_ok(),
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        let notice = format!("({} update(s) skipped, reading too slowly)", skipped);
+                        connection.send(&Message::Write(notice)).await.is_ok()
+                    }
+                    Err(broadcast::error::RecvError::Closed) => false,
+                },
+            };
+            if !open {
+                break;
+            }
+        }
+        self.disconnect(id);
+    }
+
+    // คืน false ถ้าต้องปิด connection
+    async fn deliver(&self, connection: &mut Connection, outcome: Outcome) -> bool {
+        if let Some(text) = outcome.reply
+            && connection.send(&Message::Write(text)).await.is_err()
+        {
+            return false;
+        }
+        if let Some(text) = outcome.broadcast {
+            // ไม่มีใครฟังอยู่ก็ไม่เป็นไร
+            let _ = self.updates.send(text);
+        }
+        !outcome.disconnect
+    }
+
+    fn disconnect(&self, id: ClientId) {
+        let outcome = self.lobby().leave(id);
+        if let Some(text) = outcome.broadcast {
+            let _ = self.updates.send(text);
+        }
+    }
+
+    fn lobby(&self) -> std::sync::MutexGuard<'_, Lobby> {
+        self.lobby.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
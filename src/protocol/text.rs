@@ -0,0 +1,101 @@
+// ===========================================
+// text.rs - encoding แบบข้อความ บรรทัดละ 1 message
+// ===========================================
+//
+//   QUIT
+//   MOVE 10 -20
+//   WRITE hello world        (ข้อความทั้งหมดหลังช่องว่างแรก)
+//   COLOR 255 0 0
+//
+// ใน WRITE ขึ้นบรรทัดใหม่เขียนเป็น \n, \r เป็น \r และ \ เป็น \\
+// ชื่อคำสั่งพิมพ์เล็กได้ (สะดวกตอนทดลองด้วย nc / telnet)
+
+use super::{DecodeError, Message};
+
+// ไม่มี \n ท้าย (ผู้ส่งต่อ \n เอง)
+pub fn to_line(message: &Message) -> String {
+    match message {
+        Message::Quit => "QUIT".to_string(),
+        Message::Move { x, y } => format!("MOVE {} {}", x, y),
+        Message::Write(text) => format!("WRITE {}", escape(text)),
+        Message::ChangeColor(r, g, b) => format!("COLOR {} {} {}", r, g, b),
+    }
+}
+
+// รับบรรทัดที่มีหรือไม่มี \n / \r\n ท้ายก็ได้
+pub fn from_line(line: &str) -> Result<Message, DecodeError> {
+    let line = line
+        .strip_suffix('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+        .unwrap_or(line);
+    let (command, rest) = line.split_once(' ').unwrap_or((line, ""));
+
+    match command.to_ascii_uppercase().as_str() {
+        "QUIT" => {
+            numbers("QUIT", rest, 0)?;
+            Ok(Message::Quit)
+        }
+        "MOVE" => match numbers("MOVE", rest, 2)?.as_slice() {
+            [x, y] => Ok(Message::Move { x: *x, y: *y }),
+            _ => unreachable!("numbers checks the count"),
+        },
+        "WRITE" => Ok(Message::Write(unescape(rest)?)),
+        "COLOR" => match numbers("COLOR", rest, 3)?.as_slice() {
+            [r, g, b] => Ok(Message::ChangeColor(*r, *g, *b)),
+            _ => unreachable!("numbers checks the count"),
+        },
+        _ => Err(DecodeError::UnknownCommand(command.to_string())),
+    }
+}
+
+fn numbers(command: &'static str, rest: &str, expected: usize) -> Result<Vec<i32>, DecodeError> {
+    let values: Vec<&str> = rest.split_whitespace().collect();
+    if values.len() != expected {
+        return Err(DecodeError::Arguments {
+            command,
+            expected,
+            found: values.len(),
+        });
+    }
+    values
+        .into_iter()
+        .map(|value| {
+            value.parse().map_err(|_| DecodeError::InvalidNumber {
+                command,
+                value: value.to_string(),
+            })
+        })
+        .collect()
+}
+
+fn escape(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    out
+}
+
+fn unescape(text: &str) -> Result<String, DecodeError> {
+    let mut out = String::with_capacity(text.len());
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('\\') => out.push('\\'),
+            Some('n') => out.push('\n'),
+            Some('r') => out.push('\r'),
+            Some(other) => return Err(DecodeError::InvalidEscape(other.to_string())),
+            None => return Err(DecodeError::InvalidEscape(String::new())),
+        }
+    }
+    Ok(out)
+}
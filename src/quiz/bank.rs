@@ -0,0 +1,471 @@
+// ===========================================
+// bank.rs - อ่านไฟล์ quiz (docs/src/appendix/quiz/chNN-quiz.md)
+// ===========================================
+//
+// รูปแบบของแต่ละไฟล์:
+//
+//   # Quiz: บทที่ 5 - Ownership
+//
+//   ## คำถามที่ 1          (หรือ "## คำถาม 7.1")
+//   <คำถาม + code block>
+//   A. ...
+//   B. ...
+//   <details>
+//   <summary>ดูเฉลย</summary>
+//   **B. ...**             ← คำตอบที่ถูก
+//   <คำอธิบาย>
+//   </details>
+
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serde::{Serialize, Serializer};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+const TITLE_PREFIX: &str = "# Quiz: บทที่ ";
+const QUESTION_PREFIXES: [&str; 2] = ["## คำถามที่ ", "## คำถาม "];
+
+#[derive(Error, Debug)]
+pub enum QuizError {
+    #[error("Failed to read {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("{path}:{line}: {message}")]
+    Parse {
+        path: PathBuf,
+        line: usize,
+        message: String,
+    },
+}
+
+// Quiz ของหนึ่งบท
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Quiz {
+    pub chapter: u32,
+    pub title: String,
+    #[serde(rename = "file", serialize_with = "file_name")]
+    pub path: PathBuf,
+    pub questions: Vec<Question>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Question {
+    // ลำดับในไฟล์ (เริ่มที่ 1)
+    pub number: u32,
+    // ข้อความหัวข้อ เช่น "คำถามที่ 1" หรือ "คำถาม 7.1"
+    pub heading: String,
+    pub line: usize,
+    // Markdown ของตัวคำถาม (รวม code block)
+    pub prompt: String,
+    pub choices: Vec<Choice>,
+    pub answer: Answer,
+    pub explanation: String,
+    // code block ทั้งหมดในคำถามและเฉลย
+    pub snippets: Vec<Snippet>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Choice {
+    pub letter: char,
+    pub text: String,
+    pub line: usize,
+}
+
+// คำตอบตามที่เขียนในเฉลย (**B. ...**)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Answer {
+    pub letter: char,
+    pub text: String,
+    pub line: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Snippet {
+    // ข้อความหลัง ``` เช่น "rust,ignore"
+    pub info: String,
+    // บรรทัดแรกของโค้ด
+    pub line: usize,
+    pub code: String,
+}
+
+impl Quiz {
+    pub fn load(path: impl AsRef<Path>) -> Result<Self, QuizError> {
+        let path = path.as_ref();
+        let content = fs::read_to_string(path).map_err(|source| QuizError::Io {
+            path: path.to_path_buf(),
+            source,
+        })?;
+        Self::parse(path, &content)
+    }
+
+    // `path` ใช้ในข้อความ error เท่านั้น
+    pub fn parse(path: impl AsRef<Path>, content: &str) -> Result<Self, QuizError> {
+        match Self::parse_partial(path, content) {
+            (Some(quiz), errors) if errors.is_empty() => Ok(quiz),
+            (_, errors) => Err(errors.into_iter().next().expect("at least one error")),
+        }
+    }
+
+    // อ่านต่อแม้เจอคำถามที่ผิดรูปแบบ: คืนคำถามที่อ่านได้ + error ทั้งหมด
+    // (None ถ้าบรรทัดแรกไม่ใช่หัวข้อ quiz)
+    pub fn parse_partial(path: impl AsRef<Path>, content: &str) -> (Option<Self>, Vec<QuizError>) {
+        Parser::new(path.as_ref()).parse(content)
+    }
+
+    // สลับลำดับคำถาม (ตัวเลือก A-D คงเดิมเพราะคำอธิบายอ้างถึงตัวอักษร)
+    pub fn shuffle(&mut self, rng: &mut impl Rng) {
+        self.questions.shuffle(rng);
+    }
+}
+
+impl Question {
+    pub fn choice(&self, letter: char) -> Option<&Choice> {
+        let letter = letter.to_ascii_uppercase();
+        self.choices.iter().find(|c| c.letter == letter)
+    }
+
+    pub fn is_correct(&self, letter: char) -> bool {
+        letter.to_ascii_uppercase() == self.answer.letter
+    }
+}
+
+impl Snippet {
+    // ```rust, ```rust,ignore, ```rust,compile_fail ...
+    pub fn is_rust(&self) -> bool {
+        self.info.split(',').next().map(str::trim) == Some("rust")
+    }
+}
+
+// อ่านทุกไฟล์ chNN-quiz.md ในโฟลเดอร์ เรียงตามบท
+pub fn load_bank(dir: impl AsRef<Path>) -> Result<Vec<Quiz>, QuizError> {
+    let mut quizzes = quiz_files(dir.as_ref())?
+        .iter()
+        .map(Quiz::load)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    quizzes.sort_by_key(|quiz| quiz.chapter);
+    Ok(quizzes)
+}
+
+// ไฟล์ chNN-quiz.md ทั้งหมด เรียงตามชื่อ
+pub(super) fn quiz_files(dir: &Path) -> Result<Vec<PathBuf>, QuizError> {
+    let io_error = |source| QuizError::Io {
+        path: dir.to_path_buf(),
+        source,
+    };
+
+    let mut files = Vec::new();
+    for entry in fs::read_dir(dir).map_err(io_error)? {
+        let path = entry.map_err(io_error)?.path();
+        let is_quiz = path
+            .file_name()
+            .and_then(|name| name.to_str())
+            .is_some_and(|name| name.starts_with("ch") && name.ends_with("-quiz.md"));
+        if is_quiz {
+            files.push(path);
+        }
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+// export เฉพาะชื่อไฟล์ (path เต็มขึ้นกับเครื่องที่รัน)
+fn file_name<S: Serializer>(path: &Path, serializer: S) -> Result<S::Ok, S::Error> {
+    let name = path.file_name().unwrap_or(path.as_os_str());
+    serializer.serialize_str(&name.to_string_lossy())
+}
+
+// -------------------------------------------
+// Parser
+// -------------------------------------------
+
+#[derive(Clone, Copy, PartialEq)]
+enum Section {
+    // นอกคำถาม (หรือข้ามคำถามที่ผิดรูปแบบไปจนถึงหัวข้อถัดไป)
+    Outside,
+    Prompt,
+    Choices,
+    Details,
+    Explanation,
+    Closed,
+}
+
+// คำถามที่กำลังอ่านอยู่
+struct Draft {
+    number: u32,
+    heading: String,
+    line: usize,
+    prompt: Vec<String>,
+    choices: Vec<Choice>,
+    answer: Option<Answer>,
+    explanation: Vec<String>,
+    snippets: Vec<Snippet>,
+}
+
+// code block ที่เปิดอยู่
+struct Fence {
+    ticks: usize,
+    info: String,
+    line: usize,
+    code: Vec<String>,
+}
+
+struct Parser<'a> {
+    path: &'a Path,
+    section: Section,
+    fence: Option<Fence>,
+    headings: u32,
+    draft: Option<Draft>,
+    questions: Vec<Question>,
+    errors: Vec<QuizError>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(path: &'a Path) -> Self {
+        Self {
+            path,
+            section: Section::Outside,
+            fence: None,
+            headings: 0,
+            draft: None,
+            questions: Vec::new(),
+            errors: Vec::new(),
+        }
+    }
+
+    // บันทึก error แล้วข้ามคำถามนี้ไป (อ่านคำถามถัดไปต่อได้)
+    fn fail(&mut self, line: usize, message: impl Into<String>) {
+        let message = match self.draft.take() {
+            Some(draft) => format!("{}: {}", draft.heading, message.into()),
+            None => message.into(),
+        };
+        self.errors.push(parse_error(self.path, line, message));
+        self.section = Section::Outside;
+    }
+
+    fn parse(mut self, content: &str) -> (Option<Quiz>, Vec<QuizError>) {
+        let mut lines = content.lines().enumerate().map(|(i, line)| (i + 1, line));
+        let last_line = content.lines().count().max(1);
+
+        let title = lines.next().and_then(|(_, first)| parse_title(first));
+        if title.is_none() {
+            self.fail(1, "expected '# Quiz: บทที่ N - Title' on the first line");
+        }
+
+        for (number, line) in lines {
+            self.line(number, line);
+        }
+
+        if let Some(fence) = self.fence.take() {
+            self.fail(
+                fence.line,
+                format!(
+                    "code block opened with {} backticks is never closed",
+                    fence.ticks
+                ),
+            );
+        }
+        self.finish_question(last_line);
+
+        if self.headings == 0 {
+            self.fail(1, "no questions found");
+        }
+
+        let quiz = title.map(|(chapter, title)| Quiz {
+            chapter,
+            title,
+            path: self.path.to_path_buf(),
+            questions: self.questions,
+        });
+        (quiz, self.errors)
+    }
+
+    fn line(&mut self, number: usize, line: &str) {
+        let in_code = self.fence.is_some();
+        self.track_fence(number, line);
+
+        if !in_code && let Some(heading) = parse_heading(line) {
+            self.finish_question(number - 1);
+            self.headings += 1;
+            self.draft = Some(Draft {
+                number: self.headings,
+                heading: heading.to_string(),
+                line: number,
+                prompt: Vec::new(),
+                choices: Vec::new(),
+                answer: None,
+                explanation: Vec::new(),
+                snippets: Vec::new(),
+            });
+            self.section = Section::Prompt;
+            return;
+        }
+
+        let section = self.section;
+        let Some(draft) = self.draft.as_mut() else {
+            return;
+        };
+        let trimmed = line.trim();
+
+        match section {
+            Section::Outside | Section::Closed => {}
+            Section::Prompt | Section::Choices if !in_code && trimmed == "<details>" => {
+                if draft.choices.is_empty() {
+                    self.fail(number, "<details> before any A.-D. options");
+                } else {
+                    self.section = Section::Details;
+                }
+            }
+            Section::Prompt | Section::Choices => match parse_choice(line, number) {
+                Some(choice) if !in_code => {
+                    draft.choices.push(choice);
+                    self.section = Section::Choices;
+                }
+                _ if section == Section::Prompt => draft.prompt.push(line.to_string()),
+                _ if trimmed.is_empty() => {}
+                _ => self.fail(number, "unexpected text between options"),
+            },
+            Section::Details if trimmed.is_empty() || trimmed.starts_with("<summary>") => {}
+            Section::Details if trimmed == "</details>" => {
+                self.fail(number, "no answer inside <details>");
+            }
+            Section::Details => match parse_answer(trimmed, number) {
+                Some(answer) => {
+                    draft.answer = Some(answer);
+                    self.section = Section::Explanation;
+                }
+                None => self.fail(number, "expected the answer in bold, e.g. **B. ...**"),
+            },
+            Section::Explanation if !in_code && trimmed == "</details>" => {
+                self.section = Section::Closed;
+            }
+            Section::Explanation if !in_code && parse_answer(trimmed, number).is_some() => {
+                self.fail(number, format!("more than one answer: {}", trimmed));
+            }
+            Section::Explanation => draft.explanation.push(line.to_string()),
+        }
+    }
+
+    // เปิด/ปิด code block (``` ปิดได้ด้วย backticks ที่ยาวอย่างน้อยเท่ากันและไม่มีข้อความต่อท้าย)
+    fn track_fence(&mut self, number: usize, line: &str) {
+        let trimmed = line.trim_start();
+        let ticks = trimmed.chars().take_while(|&c| c == '`').count();
+        let rest = &trimmed[ticks..];
+
+        match self.fence.as_mut() {
+            None if ticks >= 3 => {
+                self.fence = Some(Fence {
+                    ticks,
+                    info: rest.trim().to_string(),
+                    line: number,
+                    code: Vec::new(),
+                });
+            }
+            None => {}
+            Some(fence) if ticks >= fence.ticks && rest.trim().is_empty() => {
+                let fence = self.fence.take().expect("fence is open");
+                if let Some(draft) = self.draft.as_mut() {
+                    draft.snippets.push(Snippet {
+                        info: fence.info,
+                        line: fence.line + 1,
+                        code: fence.code.join("\n"),
+                    });
+                }
+            }
+            Some(fence) => fence.code.push(line.to_string()),
+        }
+    }
+
+    fn finish_question(&mut self, last_line: usize) {
+        if self.draft.is_none() {
+            return;
+        }
+
+        let section = self.section;
+        let draft = self.draft.as_ref().expect("draft exists");
+        if draft.choices.is_empty() {
+            return self.fail(draft.line, "no A.-D. options");
+        }
+        if draft.answer.is_none() {
+            return self.fail(draft.line, "no answer inside <details>");
+        }
+        if section != Section::Closed {
+            return self.fail(last_line, "missing </details>");
+        }
+
+        let draft = self.draft.take().expect("draft exists");
+        self.questions.push(Question {
+            number: draft.number,
+            heading: draft.heading,
+            line: draft.line,
+            prompt: join_trimmed(&draft.prompt),
+            choices: draft.choices,
+            answer: draft.answer.expect("answer checked above"),
+            explanation: join_trimmed(&draft.explanation),
+            snippets: draft.snippets,
+        });
+    }
+}
+
+fn parse_error(path: &Path, line: usize, message: impl Into<String>) -> QuizError {
+    QuizError::Parse {
+        path: path.to_path_buf(),
+        line,
+        message: message.into(),
+    }
+}
+
+// "# Quiz: บทที่ 5 - Ownership" → (5, "Ownership")
+fn parse_title(line: &str) -> Option<(u32, String)> {
+    let rest = line.strip_prefix(TITLE_PREFIX)?;
+    let (chapter, title) = rest.split_once(" - ")?;
+    Some((chapter.trim().parse().ok()?, title.trim().to_string()))
+}
+
+// "## คำถามที่ 1" → "คำถามที่ 1"
+fn parse_heading(line: &str) -> Option<&str> {
+    QUESTION_PREFIXES
+        .iter()
+        .any(|prefix| line.starts_with(prefix))
+        .then(|| line.trim_start_matches('#').trim())
+}
+
+// "B. ข้อความ  " → Choice { letter: 'B', text: "ข้อความ" }
+fn parse_choice(line: &str, number: usize) -> Option<Choice> {
+    let (letter, text) = split_letter(line)?;
+    Some(Choice {
+        letter,
+        text: text.trim().to_string(),
+        line: number,
+    })
+}
+
+// "**B. ข้อความ**" → Answer { letter: 'B', text: "ข้อความ" }
+fn parse_answer(line: &str, number: usize) -> Option<Answer> {
+    let inner = line.strip_prefix("**")?.strip_suffix("**")?;
+    let (letter, text) = split_letter(inner)?;
+    Some(Answer {
+        letter,
+        text: text.trim().to_string(),
+        line: number,
+    })
+}
+
+fn split_letter(text: &str) -> Option<(char, &str)> {
+    let mut chars = text.chars();
+    let letter = chars.next().filter(char::is_ascii_uppercase)?;
+    let rest = chars.as_str().strip_prefix(". ")?;
+    Some((letter, rest))
+}
+
+// ต่อบรรทัดและตัดบรรทัดว่างหัวท้าย
+fn join_trimmed(lines: &[String]) -> String {
+    lines.join("\n").trim_matches('\n').trim_end().to_string()
+}
@@ -0,0 +1,15 @@
+// ===========================================
+// Quiz - แบบทดสอบท้ายบทจาก docs/src/appendix/quiz
+// ===========================================
+//
+// - bank.rs:     อ่านไฟล์ markdown เป็น Quiz / Question
+// - progress.rs: คะแนนของผู้เรียนแต่ละคน
+// - validate.rs: ตรวจเฉลยและ code ในคลังคำถาม
+
+mod bank;
+mod progress;
+mod validate;
+
+pub use bank::{Answer, Choice, Question, Quiz, QuizError, Snippet, load_bank};
+pub use progress::{ChapterProgress, Progress, ProgressError, ProgressStore};
+pub use validate::{Issue, IssueKind, Report, check_question, check_rust, validate, validate_bank};
@@ -0,0 +1,161 @@
+// ===========================================
+// progress.rs - คะแนนของผู้เรียนแต่ละคน (เก็บเป็นไฟล์ JSON ในเครื่อง)
+// ===========================================
+//
+// .quiz-progress/<learner>.json
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum ProgressError {
+    #[error("Invalid learner name '{0}' (use letters, digits, '-' or '_')")]
+    InvalidLearner(String),
+
+    #[error("Failed to access {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("Failed to parse {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ChapterProgress {
+    pub attempts: u32,
+    pub best_score: usize,
+    pub last_score: usize,
+    pub total: usize,
+    pub last_taken: DateTime<Utc>,
+    // หัวข้อคำถามที่ตอบผิดในครั้งล่าสุด
+    #[serde(default)]
+    pub missed: Vec<String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Progress {
+    pub learner: String,
+    pub chapters: BTreeMap<u32, ChapterProgress>,
+}
+
+impl Progress {
+    pub fn new(learner: impl Into<String>) -> Self {
+        Self {
+            learner: learner.into(),
+            chapters: BTreeMap::new(),
+        }
+    }
+
+    // บันทึกผลการทำ quiz หนึ่งครั้ง (ทำครบทุกข้อแล้วเท่านั้น)
+    pub fn record(
+        &mut self,
+        chapter: u32,
+        score: usize,
+        total: usize,
+        missed: Vec<String>,
+        at: DateTime<Utc>,
+    ) -> &ChapterProgress {
+        let entry = self
+            .chapters
+            .entry(chapter)
+            .or_insert_with(|| ChapterProgress {
+                attempts: 0,
+                best_score: 0,
+                last_score: 0,
+                total,
+                last_taken: at,
+                missed: Vec::new(),
+            });
+
+        // จำนวนข้ออาจเปลี่ยนถ้าไฟล์ quiz ถูกแก้ - เทียบคะแนนเก่าไม่ได้แล้ว
+        if entry.total != total {
+            entry.best_score = 0;
+            entry.total = total;
+        }
+        entry.attempts += 1;
+        entry.best_score = entry.best_score.max(score);
+        entry.last_score = score;
+        entry.last_taken = at;
+        entry.missed = missed;
+        entry
+    }
+
+    pub fn chapter(&self, chapter: u32) -> Option<&ChapterProgress> {
+        self.chapters.get(&chapter)
+    }
+
+    pub fn reset(&mut self, chapter: Option<u32>) {
+        match chapter {
+            Some(chapter) => {
+                self.chapters.remove(&chapter);
+            }
+            None => self.chapters.clear(),
+        }
+    }
+}
+
+pub struct ProgressStore {
+    dir: PathBuf,
+}
+
+impl ProgressStore {
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    // ชื่อผู้เรียนใช้เป็นชื่อไฟล์ จึงรับเฉพาะตัวอักษรที่ปลอดภัย
+    pub fn path_for(&self, learner: &str) -> Result<PathBuf, ProgressError> {
+        let valid = !learner.is_empty()
+            && learner
+                .chars()
+                .all(|c| c.is_alphanumeric() || c == '-' || c == '_');
+        if !valid {
+            return Err(ProgressError::InvalidLearner(learner.to_string()));
+        }
+        Ok(self.dir.join(format!("{}.json", learner)))
+    }
+
+    // ผู้เรียนใหม่ = ยังไม่มีคะแนน
+    pub fn load(&self, learner: &str) -> Result<Progress, ProgressError> {
+        let path = self.path_for(learner)?;
+        let content = match fs::read_to_string(&path) {
+            Ok(content) => content,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Progress::new(learner)),
+            Err(source) => return Err(ProgressError::Io { path, source }),
+        };
+
+        serde_json::from_str(&content).map_err(|source| ProgressError::Parse { path, source })
+    }
+
+    pub fn save(&self, progress: &Progress) -> Result<(), ProgressError> {
+        let path = self.path_for(&progress.learner)?;
+        let io_error = |source| ProgressError::Io {
+            path: path.clone(),
+            source,
+        };
+
+        fs::create_dir_all(&self.dir).map_err(io_error)?;
+        let content =
+            serde_json::to_string_pretty(progress).map_err(|source| ProgressError::Parse {
+                path: path.clone(),
+                source,
+            })?;
+        fs::write(&path, content).map_err(io_error)
+    }
+}
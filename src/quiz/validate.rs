@@ -0,0 +1,221 @@
+// ===========================================
+// validate.rs - ตรวจความถูกต้องของคลังคำถาม
+// ===========================================
+//
+// - รูปแบบ markdown ถูกต้อง (ทุกคำถามที่ผิด ไม่ใช่แค่ข้อแรก)
+// - ตัวเลือกเรียง A, B, C, ... ไม่ซ้ำ
+// - เฉลยมีข้อเดียว ตรงกับตัวเลือกที่มีอยู่จริง ทั้งตัวอักษรและข้อความ
+// - ทุก ```rust snippet parse ได้ (ใช้ syn)
+
+use super::bank::{Question, Quiz, QuizError, Snippet, quiz_files};
+use crate::preprocessor::strip_hidden_lines;
+use serde::Serialize;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Issue {
+    pub path: PathBuf,
+    pub line: usize,
+    pub kind: IssueKind,
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum IssueKind {
+    #[error("{message}")]
+    Malformed { message: String },
+
+    #[error("{question}: options must be A, B, C, ... in order (found {found})")]
+    OptionOrder { question: String, found: String },
+
+    #[error("{question}: answer {letter} is not one of the options ({options})")]
+    AnswerNotAnOption {
+        question: String,
+        letter: char,
+        options: String,
+    },
+
+    #[error("{question}: answer \"{answer}\" does not match option {letter} \"{option}\"")]
+    AnswerMismatch {
+        question: String,
+        letter: char,
+        answer: String,
+        option: String,
+    },
+
+    #[error("{question}: rust snippet does not parse: {message}")]
+    Syntax { question: String, message: String },
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}", self.path.display(), self.line, self.kind)
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct Report {
+    // quiz ที่อ่านได้ (คำถามที่ผิดรูปแบบจะไม่อยู่ในนี้)
+    pub quizzes: Vec<Quiz>,
+    pub issues: Vec<Issue>,
+}
+
+impl Report {
+    pub fn is_ok(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    pub fn question_count(&self) -> usize {
+        self.quizzes.iter().map(|q| q.questions.len()).sum()
+    }
+}
+
+// ตรวจทุกไฟล์ในโฟลเดอร์ - Err เฉพาะตอนอ่านไฟล์ไม่ได้
+pub fn validate_bank(dir: impl AsRef<Path>) -> Result<Report, QuizError> {
+    let mut report = Report::default();
+
+    for path in quiz_files(dir.as_ref())? {
+        let content = fs::read_to_string(&path).map_err(|source| QuizError::Io {
+            path: path.clone(),
+            source,
+        })?;
+        let (quiz, issues) = validate(&path, &content);
+        report.quizzes.extend(quiz);
+        report.issues.extend(issues);
+    }
+
+    report.quizzes.sort_by_key(|quiz| quiz.chapter);
+    Ok(report)
+}
+
+// ตรวจไฟล์เดียว
+pub fn validate(path: impl AsRef<Path>, content: &str) -> (Option<Quiz>, Vec<Issue>) {
+    let path = path.as_ref();
+    let (quiz, errors) = Quiz::parse_partial(path, content);
+
+    let mut issues: Vec<Issue> = errors.into_iter().filter_map(malformed).collect();
+    if let Some(quiz) = &quiz {
+        for question in &quiz.questions {
+            issues.extend(check_question(path, question));
+        }
+    }
+
+    issues.sort_by_key(|issue| issue.line);
+    (quiz, issues)
+}
+
+fn malformed(error: QuizError) -> Option<Issue> {
+    match error {
+        QuizError::Parse {
+            path,
+            line,
+            message,
+        } => Some(Issue {
+            path,
+            line,
+            kind: IssueKind::Malformed { message },
+        }),
+        QuizError::Io { .. } => None,
+    }
+}
+
+pub fn check_question(path: &Path, question: &Question) -> Vec<Issue> {
+    let issue = |line, kind| Issue {
+        path: path.to_path_buf(),
+        line,
+        kind,
+    };
+    let mut issues = Vec::new();
+    let heading = || question.heading.clone();
+
+    let letters: String = question.choices.iter().map(|c| c.letter).collect();
+    let expected: String = ('A'..='Z').take(letters.chars().count()).collect();
+    if letters != expected {
+        issues.push(issue(
+            question.choices[0].line,
+            IssueKind::OptionOrder {
+                question: heading(),
+                found: letters.clone(),
+            },
+        ));
+    }
+
+    let answer = &question.answer;
+    match question.choice(answer.letter) {
+        None => issues.push(issue(
+            answer.line,
+            IssueKind::AnswerNotAnOption {
+                question: heading(),
+                letter: answer.letter,
+                options: letters,
+            },
+        )),
+        Some(choice) if normalize(&choice.text) != normalize(&answer.text) => issues.push(issue(
+            answer.line,
+            IssueKind::AnswerMismatch {
+                question: heading(),
+                letter: answer.letter,
+                answer: answer.text.clone(),
+                option: choice.text.clone(),
+            },
+        )),
+        Some(_) => {}
+    }
+
+    for snippet in question.snippets.iter().filter(|s| s.is_rust()) {
+        if let Err((line, message)) = check_rust(snippet) {
+            issues.push(issue(
+                snippet.line + line - 1,
+                IssueKind::Syntax {
+                    question: heading(),
+                    message,
+                },
+            ));
+        }
+    }
+
+    issues
+}
+
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+// -------------------------------------------
+// ตรวจ syntax ของ Rust
+// -------------------------------------------
+//
+// snippet ในหนังสือมีทั้งแบบไฟล์เต็ม (fn, struct, ...) และแบบ statements
+// ที่ไม่มี fn main จึงลองทั้งสองแบบ
+// คืน (บรรทัดใน snippet, ข้อความ) ของ error ที่อ่านไปได้ไกลกว่า
+
+pub fn check_rust(snippet: &Snippet) -> Result<(), (usize, String)> {
+    let code = strip_hidden_lines(&snippet.code);
+
+    let file_error = match syn::parse_file(&code) {
+        Ok(_) => return Ok(()),
+        Err(e) => e,
+    };
+    let block_error = match syn::parse_str::<syn::Block>(&format!("{{\n{}\n}}", code)) {
+        Ok(_) => return Ok(()),
+        Err(e) => e,
+    };
+
+    let file_at = file_error.span().start();
+    let block_at = block_error.span().start();
+    // block มีบรรทัด "{" เพิ่มขึ้นมาหนึ่งบรรทัด
+    let block_line = block_at.line.saturating_sub(1);
+
+    let (line, error) = if (block_line, block_at.column) > (file_at.line, file_at.column) {
+        (block_line, block_error)
+    } else {
+        (file_at.line, file_error)
+    };
+    Err((
+        line.clamp(1, code.lines().count().max(1)),
+        error.to_string(),
+    ))
+}
@@ -0,0 +1,104 @@
+// ===========================================
+// due.rs - แปลงข้อความเป็นวันครบกำหนด
+// ===========================================
+//
+// รองรับ:
+//   today, tomorrow, yesterday
+//   in 3 days, in 2 weeks, +3d, +2w
+//   monday ... sunday (วันนั้นที่จะถึง), next monday (สัปดาห์ถัดไป)
+//   next week (อีก 7 วัน)
+//   2026-01-31
+
+use chrono::{Datelike, Days, NaiveDate, Weekday};
+use thiserror::Error;
+
+#[derive(Error, Debug, PartialEq)]
+pub enum DueDateError {
+    #[error("unrecognised due date '{0}' (try 'tomorrow', 'in 3 days', 'friday' or YYYY-MM-DD)")]
+    Unrecognised(String),
+
+    #[error("due date '{0}' is out of range")]
+    OutOfRange(String),
+}
+
+// `today` ส่งเข้ามาเพื่อให้ทดสอบได้ (ไม่ผูกกับนาฬิกาจริง)
+pub fn parse_due(input: &str, today: NaiveDate) -> Result<NaiveDate, DueDateError> {
+    let text = input.trim().to_lowercase();
+    let words: Vec<&str> = text.split_whitespace().collect();
+    let unrecognised = || DueDateError::Unrecognised(input.to_string());
+    let out_of_range = || DueDateError::OutOfRange(input.to_string());
+
+    let offset_days = match words.as_slice() {
+        ["today"] => Some(0),
+        ["tomorrow"] => Some(1),
+        ["yesterday"] => Some(-1),
+        ["next", "week"] => Some(7),
+        ["in", amount, unit] => Some(relative_days(amount, unit).ok_or_else(unrecognised)?),
+        [word] if word.starts_with('+') => {
+            let rest = &word[1..];
+            let split = rest
+                .find(|c: char| !c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let (amount, unit) = rest.split_at(split);
+            Some(relative_days(amount, unit).ok_or_else(unrecognised)?)
+        }
+        _ => None,
+    };
+    if let Some(days) = offset_days {
+        return shift(today, days).ok_or_else(out_of_range);
+    }
+
+    match words.as_slice() {
+        [day] if let Some(weekday) = parse_weekday(day) => {
+            shift(today, days_until(today, weekday)).ok_or_else(out_of_range)
+        }
+        ["next", day] => {
+            let weekday = parse_weekday(day).ok_or_else(unrecognised)?;
+            shift(today, days_until(today, weekday) + 7).ok_or_else(out_of_range)
+        }
+        _ => NaiveDate::parse_from_str(&text, "%Y-%m-%d").map_err(|_| unrecognised()),
+    }
+}
+
+// "3" + "days" / "3" + "d" → 3, "2" + "weeks" / "2" + "w" → 14
+fn relative_days(amount: &str, unit: &str) -> Option<i64> {
+    let amount: i64 = amount.parse().ok()?;
+    let per_unit = match unit {
+        "d" | "day" | "days" => 1,
+        "w" | "week" | "weeks" => 7,
+        _ => return None,
+    };
+    amount.checked_mul(per_unit)
+}
+
+fn parse_weekday(word: &str) -> Option<Weekday> {
+    let weekday = match word {
+        "mon" | "monday" => Weekday::Mon,
+        "tue" | "tuesday" => Weekday::Tue,
+        "wed" | "wednesday" => Weekday::Wed,
+        "thu" | "thursday" => Weekday::Thu,
+        "fri" | "friday" => Weekday::Fri,
+        "sat" | "saturday" => Weekday::Sat,
+        "sun" | "sunday" => Weekday::Sun,
+        _ => return None,
+    };
+    Some(weekday)
+}
+
+// จำนวนวันจนถึง weekday ที่จะถึง (1..=7, ไม่นับวันนี้)
+fn days_until(today: NaiveDate, weekday: Weekday) -> i64 {
+    let from = today.weekday().num_days_from_monday() as i64;
+    let to = weekday.num_days_from_monday() as i64;
+    match (to - from).rem_euclid(7) {
+        0 => 7,
+        days => days,
+    }
+}
+
+fn shift(date: NaiveDate, days: i64) -> Option<NaiveDate> {
+    if days >= 0 {
+        date.checked_add_days(Days::new(days as u64))
+    } else {
+        date.checked_sub_days(Days::new(days.unsigned_abs()))
+    }
+}
@@ -0,0 +1,158 @@
+// ===========================================
+// item.rs - Todo data model
+// ===========================================
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+use thiserror::Error;
+
+// ลำดับ variant = ลำดับความสำคัญ (Low < Medium < High)
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Priority {
+    Low,
+    #[default]
+    Medium,
+    High,
+}
+
+#[derive(Error, Debug, PartialEq)]
+#[error("invalid priority '{0}' (expected low, medium or high)")]
+pub struct ParsePriorityError(String);
+
+impl FromStr for Priority {
+    type Err = ParsePriorityError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "low" | "l" => Ok(Priority::Low),
+            "medium" | "med" | "m" => Ok(Priority::Medium),
+            "high" | "h" => Ok(Priority::High),
+            _ => Err(ParsePriorityError(s.to_string())),
+        }
+    }
+}
+
+impl fmt::Display for Priority {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let name = match self {
+            Priority::Low => "low",
+            Priority::Medium => "medium",
+            Priority::High => "high",
+        };
+        f.write_str(name)
+    }
+}
+
+// ฟิลด์ใหม่มี #[serde(default)] เพื่อให้อ่าน todos.json รุ่นเก่าได้
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Todo {
+    pub id: u32,
+    pub title: String,
+    pub completed: bool,
+    pub created_at: DateTime<Utc>,
+    pub completed_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    pub priority: Priority,
+    #[serde(default)]
+    pub due: Option<NaiveDate>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl Todo {
+    pub fn new(id: u32, title: String) -> Self {
+        Self {
+            id,
+            title,
+            completed: false,
+            created_at: Utc::now(),
+            completed_at: None,
+            priority: Priority::default(),
+            due: None,
+            tags: Vec::new(),
+        }
+    }
+
+    pub fn with_priority(mut self, priority: Priority) -> Self {
+        self.priority = priority;
+        self
+    }
+
+    pub fn with_due(mut self, due: NaiveDate) -> Self {
+        self.due = Some(due);
+        self
+    }
+
+    pub fn with_tag(mut self, tag: &str) -> Self {
+        self.add_tag(tag);
+        self
+    }
+
+    pub fn complete(&mut self) {
+        self.complete_at(Utc::now());
+    }
+
+    // เวลาที่ทำเสร็จมาจากผู้เรียก (เช่น sync ที่ต้องได้ค่าเดียวกันทุกเครื่อง)
+    // ถ้าเสร็จไปแล้วคงเวลาเดิมไว้
+    pub fn complete_at(&mut self, at: DateTime<Utc>) {
+        if !self.completed {
+            self.completed = true;
+            self.completed_at = Some(at);
+        }
+    }
+
+    pub fn uncomplete(&mut self) {
+        self.completed = false;
+        self.completed_at = None;
+    }
+
+    // tag เก็บเป็นตัวพิมพ์เล็กและไม่ซ้ำ ("#Work" กับ "work" คือ tag เดียวกัน)
+    pub fn add_tag(&mut self, tag: &str) {
+        let tag = normalize_tag(tag);
+        if !tag.is_empty() && !self.tags.contains(&tag) {
+            self.tags.push(tag);
+        }
+    }
+
+    pub fn has_tag(&self, tag: &str) -> bool {
+        let tag = normalize_tag(tag);
+        self.tags.contains(&tag)
+    }
+
+    // ยังไม่เสร็จและเลยวันครบกำหนดแล้ว
+    pub fn is_overdue(&self, today: NaiveDate) -> bool {
+        !self.completed && self.due.is_some_and(|due| due < today)
+    }
+}
+
+pub(crate) fn normalize_tag(tag: &str) -> String {
+    tag.trim().trim_start_matches('#').to_lowercase()
+}
+
+impl fmt::Display for Todo {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = if self.completed { "✓" } else { " " };
+        write!(f, "[{}] {}. {}", status, self.id, self.title)?;
+
+        if self.priority != Priority::default() {
+            write!(f, " !{}", self.priority)?;
+        }
+        if let Some(due) = self.due {
+            write!(f, " (due {})", due)?;
+        }
+        for tag in &self.tags {
+            write!(f, " #{}", tag)?;
+        }
+        Ok(())
+    }
+}
+
+// ID ถัดไป = ID สูงสุด + 1
+pub fn next_id(todos: &[Todo]) -> u32 {
+    todos.iter().map(|t| t.id).max().unwrap_or(0) + 1
+}
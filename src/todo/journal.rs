@@ -0,0 +1,607 @@
+// ===========================================
+// journal.rs - ที่เก็บ todos แบบบันทึกทุกการเปลี่ยนแปลง (undo/redo ได้)
+// ===========================================
+//
+// แทนที่จะเขียนทั้งไฟล์ใหม่ทุกครั้ง ทุกการเปลี่ยนแปลงถูกต่อท้าย journal
+// (append-only) เป็น Entry หนึ่งรายการ:
+//
+//   Snapshot (seq 120) ─┬─ Entry 121: Do(Add)
+//                       ├─ Entry 122: Do(Remove)
+//                       └─ Entry 123: Undo
+//
+// ตอนเปิดจะอ่าน snapshot แล้ว replay entries ที่ตามหลัง
+// compact() รวมทุกอย่างเป็น snapshot ใหม่และล้าง journal
+//
+// Backend มี 2 แบบ: JsonJournal (ไฟล์) และ SqliteJournal (sqlx)
+
+use super::item::{Todo, next_id};
+use super::storage::{StorageError, with_suffix, write_atomic};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePool, SqlitePoolOptions};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+
+// จำนวน undo ย้อนหลังสูงสุดที่เก็บไว้
+const MAX_HISTORY: usize = 100;
+
+// compact อัตโนมัติเมื่อ journal ยาวเกินนี้
+const DEFAULT_COMPACT_EVERY: usize = 500;
+
+// การเปลี่ยนแปลงหนึ่งครั้ง - เก็บข้อมูลพอที่จะย้อนกลับได้
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum Operation {
+    Add { todo: Todo },
+    Update { before: Todo, after: Todo },
+    Remove { index: usize, todo: Todo },
+    Clear { todos: Vec<Todo> },
+}
+
+impl Operation {
+    // id สูงสุดที่ operation นี้อ้างถึง
+    fn max_id(&self) -> u32 {
+        match self {
+            Operation::Add { todo } | Operation::Remove { todo, .. } => todo.id,
+            Operation::Update { before, after } => before.id.max(after.id),
+            Operation::Clear { todos } => todos.iter().map(|t| t.id).max().unwrap_or(0),
+        }
+    }
+
+    fn apply(&self, todos: &mut Vec<Todo>) {
+        match self {
+            Operation::Add { todo } => todos.push(todo.clone()),
+            Operation::Update { after, .. } => replace(todos, after),
+            Operation::Remove { todo, .. } => todos.retain(|t| t.id != todo.id),
+            Operation::Clear { .. } => todos.clear(),
+        }
+    }
+
+    fn revert(&self, todos: &mut Vec<Todo>) {
+        match self {
+            Operation::Add { todo } => todos.retain(|t| t.id != todo.id),
+            Operation::Update { before, .. } => replace(todos, before),
+            Operation::Remove { index, todo } => {
+                todos.insert((*index).min(todos.len()), todo.clone());
+            }
+            Operation::Clear { todos: old } => *todos = old.clone(),
+        }
+    }
+}
+
+fn replace(todos: &mut [Todo], todo: &Todo) {
+    if let Some(slot) = todos.iter_mut().find(|t| t.id == todo.id) {
+        *slot = todo.clone();
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum Action {
+    Do { operation: Operation },
+    Undo,
+    Redo,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Entry {
+    pub seq: u64,
+    pub at: DateTime<Utc>,
+    #[serde(flatten)]
+    pub action: Action,
+}
+
+// สถานะทั้งหมด ณ entry `seq` (รวมประวัติ undo/redo)
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Snapshot {
+    pub seq: u64,
+    pub todos: Vec<Todo>,
+    #[serde(default)]
+    pub undo: Vec<Operation>,
+    #[serde(default)]
+    pub redo: Vec<Operation>,
+    // id ที่จะให้ todo ตัวถัดไป - เพิ่มขึ้นอย่างเดียว ไม่ใช้ id ของ todo ที่ถูกลบซ้ำ
+    // (ไม่อย่างนั้น undo/redo ที่อ้าง id เก่าจะไปโดนรายการใหม่)
+    // 0 = snapshot รุ่นเก่าที่ยังไม่มีค่านี้
+    #[serde(default)]
+    pub next_id: u32,
+}
+
+pub trait JournalBackend {
+    // snapshot ล่าสุดและ entries ที่ตามหลัง (เรียงตาม seq)
+    fn load(&mut self) -> Result<(Snapshot, Vec<Entry>), StorageError>;
+
+    fn append(&mut self, entry: &Entry) -> Result<(), StorageError>;
+
+    // แทน snapshot เดิมและทิ้ง entries ที่ seq <= snapshot.seq
+    fn compact(&mut self, snapshot: &Snapshot) -> Result<(), StorageError>;
+}
+
+impl<B: JournalBackend + ?Sized> JournalBackend for Box<B> {
+    fn load(&mut self) -> Result<(Snapshot, Vec<Entry>), StorageError> {
+        (**self).load()
+    }
+
+    fn append(&mut self, entry: &Entry) -> Result<(), StorageError> {
+        (**self).append(entry)
+    }
+
+    fn compact(&mut self, snapshot: &Snapshot) -> Result<(), StorageError> {
+        (**self).compact(snapshot)
+    }
+}
+
+pub struct JournaledStore<B: JournalBackend> {
+    backend: B,
+    todos: Vec<Todo>,
+    undo: Vec<Operation>,
+    redo: Vec<Operation>,
+    seq: u64,
+    next_id: u32,
+    journal_len: usize,
+    compact_every: usize,
+}
+
+impl<B: JournalBackend> JournaledStore<B> {
+    pub fn open(mut backend: B) -> Result<Self, StorageError> {
+        let (snapshot, entries) = backend.load()?;
+        let seen = snapshot
+            .undo
+            .iter()
+            .chain(&snapshot.redo)
+            .map(|op| op.max_id() + 1)
+            .chain([next_id(&snapshot.todos), snapshot.next_id])
+            .max()
+            .unwrap_or(1);
+        let mut store = Self {
+            backend,
+            todos: snapshot.todos,
+            undo: snapshot.undo,
+            redo: snapshot.redo,
+            seq: snapshot.seq,
+            next_id: seen,
+            journal_len: 0,
+            compact_every: DEFAULT_COMPACT_EVERY,
+        };
+
+        for entry in entries.into_iter().filter(|e| e.seq > snapshot.seq) {
+            store.replay(&entry.action);
+            store.seq = entry.seq;
+            store.journal_len += 1;
+        }
+        Ok(store)
+    }
+
+    pub fn with_compact_every(mut self, entries: usize) -> Self {
+        self.compact_every = entries.max(1);
+        self
+    }
+
+    pub fn todos(&self) -> &[Todo] {
+        &self.todos
+    }
+
+    pub fn get(&self, id: u32) -> Option<&Todo> {
+        self.todos.iter().find(|t| t.id == id)
+    }
+
+    // จำนวน entries ที่ยังไม่ถูก compact
+    pub fn journal_len(&self) -> usize {
+        self.journal_len
+    }
+
+    pub fn can_undo(&self) -> bool {
+        !self.undo.is_empty()
+    }
+
+    pub fn can_redo(&self) -> bool {
+        !self.redo.is_empty()
+    }
+
+    // กำหนด id ใหม่ให้ todo แล้วเพิ่มเข้าไป
+    pub fn add(&mut self, mut todo: Todo) -> Result<&Todo, StorageError> {
+        todo.id = self.next_id;
+        let id = todo.id;
+        self.record(Action::Do {
+            operation: Operation::Add { todo },
+        })?;
+        Ok(self.get(id).expect("todo was just added"))
+    }
+
+    pub fn update(
+        &mut self,
+        id: u32,
+        change: impl FnOnce(&mut Todo),
+    ) -> Result<Option<&Todo>, StorageError> {
+        let Some(before) = self.get(id).cloned() else {
+            return Ok(None);
+        };
+        let mut after = before.clone();
+        change(&mut after);
+        after.id = id;
+
+        if after != before {
+            self.record(Action::Do {
+                operation: Operation::Update { before, after },
+            })?;
+        }
+        Ok(self.get(id))
+    }
+
+    pub fn remove(&mut self, id: u32) -> Result<Option<Todo>, StorageError> {
+        let Some(index) = self.todos.iter().position(|t| t.id == id) else {
+            return Ok(None);
+        };
+        let todo = self.todos[index].clone();
+        self.record(Action::Do {
+            operation: Operation::Remove {
+                index,
+                todo: todo.clone(),
+            },
+        })?;
+        Ok(Some(todo))
+    }
+
+    // คืนจำนวนรายการที่ถูกลบ
+    pub fn clear(&mut self) -> Result<usize, StorageError> {
+        let count = self.todos.len();
+        if count > 0 {
+            self.record(Action::Do {
+                operation: Operation::Clear {
+                    todos: self.todos.clone(),
+                },
+            })?;
+        }
+        Ok(count)
+    }
+
+    // คืน operation ที่ถูกย้อนกลับ (None = ไม่มีอะไรให้ undo)
+    pub fn undo(&mut self) -> Result<Option<Operation>, StorageError> {
+        if !self.can_undo() {
+            return Ok(None);
+        }
+        self.record(Action::Undo)?;
+        Ok(self.redo.last().cloned())
+    }
+
+    // คืน operation ที่ถูกทำซ้ำ (None = ไม่มีอะไรให้ redo)
+    pub fn redo(&mut self) -> Result<Option<Operation>, StorageError> {
+        if !self.can_redo() {
+            return Ok(None);
+        }
+        self.record(Action::Redo)?;
+        Ok(self.undo.last().cloned())
+    }
+
+    pub fn compact(&mut self) -> Result<(), StorageError> {
+        let snapshot = Snapshot {
+            seq: self.seq,
+            todos: self.todos.clone(),
+            undo: self.undo.clone(),
+            redo: self.redo.clone(),
+            next_id: self.next_id,
+        };
+        self.backend.compact(&snapshot)?;
+        self.journal_len = 0;
+        Ok(())
+    }
+
+    // เขียนลง journal ก่อน แล้วค่อยเปลี่ยนข้อมูลในหน่วยความจำ
+    // ถ้าเขียนไม่สำเร็จ สถานะจะไม่เปลี่ยน
+    // เขียนสำเร็จแล้ว = สำเร็จ แม้ compact อัตโนมัติจะล้มเหลว (ไม่อย่างนั้นผู้ใช้จะสั่งซ้ำ)
+    fn record(&mut self, action: Action) -> Result<(), StorageError> {
+        let entry = Entry {
+            seq: self.seq + 1,
+            at: Utc::now(),
+            action,
+        };
+        self.backend.append(&entry)?;
+
+        self.seq = entry.seq;
+        self.replay(&entry.action);
+        self.journal_len += 1;
+
+        // compact ไม่สำเร็จ journal_len ยังเกินอยู่ จึงลองใหม่ตอน record ครั้งถัดไป
+        if self.journal_len >= self.compact_every {
+            let _ = self.compact();
+        }
+        Ok(())
+    }
+
+    fn replay(&mut self, action: &Action) {
+        match action {
+            Action::Do { operation } => {
+                self.next_id = self.next_id.max(operation.max_id() + 1);
+                operation.apply(&mut self.todos);
+                push_history(&mut self.undo, operation.clone());
+                self.redo.clear();
+            }
+            Action::Undo => {
+                if let Some(operation) = self.undo.pop() {
+                    operation.revert(&mut self.todos);
+                    self.redo.push(operation);
+                }
+            }
+            Action::Redo => {
+                if let Some(operation) = self.redo.pop() {
+                    operation.apply(&mut self.todos);
+                    push_history(&mut self.undo, operation);
+                }
+            }
+        }
+    }
+}
+
+fn push_history(history: &mut Vec<Operation>, operation: Operation) {
+    history.push(operation);
+    if history.len() > MAX_HISTORY {
+        history.remove(0);
+    }
+}
+
+fn io_error(path: &Path, source: io::Error) -> StorageError {
+    StorageError::Io {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+fn parse_error(path: &Path, source: serde_json::Error) -> StorageError {
+    StorageError::Parse {
+        path: path.to_path_buf(),
+        source,
+    }
+}
+
+fn read_optional(path: &Path) -> Result<Option<String>, StorageError> {
+    match fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(io_error(path, e)),
+    }
+}
+
+// -------------------------------------------
+// JSON backend
+// -------------------------------------------
+//
+// todos.json          ← snapshot (อ่าน todos.json แบบ array เดิมได้)
+// todos.json.journal  ← JSON Lines หนึ่ง entry ต่อบรรทัด
+
+pub struct JsonJournal {
+    snapshot_path: PathBuf,
+    journal_path: PathBuf,
+}
+
+// todos.json รุ่นก่อนเป็น array ของ Todo
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum SnapshotFile {
+    Snapshot(Snapshot),
+    Legacy(Vec<Todo>),
+}
+
+impl JsonJournal {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        let snapshot_path = path.into();
+        let journal_path = with_suffix(&snapshot_path, ".journal");
+        Self {
+            snapshot_path,
+            journal_path,
+        }
+    }
+
+    pub fn journal_path(&self) -> &Path {
+        &self.journal_path
+    }
+}
+
+impl JournalBackend for JsonJournal {
+    fn load(&mut self) -> Result<(Snapshot, Vec<Entry>), StorageError> {
+        let snapshot = match read_optional(&self.snapshot_path)? {
+            Some(content) => match serde_json::from_str(&content)
+                .map_err(|e| parse_error(&self.snapshot_path, e))?
+            {
+                SnapshotFile::Snapshot(snapshot) => snapshot,
+                SnapshotFile::Legacy(todos) => Snapshot {
+                    todos,
+                    ..Snapshot::default()
+                },
+            },
+            None => Snapshot::default(),
+        };
+
+        let mut entries = Vec::new();
+        if let Some(content) = read_optional(&self.journal_path)? {
+            let lines: Vec<&str> = content.lines().filter(|l| !l.trim().is_empty()).collect();
+            let torn = !content.is_empty() && !content.ends_with('\n');
+            for (i, line) in lines.iter().enumerate() {
+                match serde_json::from_str::<Entry>(line) {
+                    Ok(entry) => entries.push(entry),
+                    // บรรทัดสุดท้ายที่เขียนไม่จบ (โปรแกรมตายกลางคัน) ข้ามได้
+                    Err(_) if i + 1 == lines.len() && torn => {}
+                    Err(e) => return Err(parse_error(&self.journal_path, e)),
+                }
+            }
+
+            // ต้องซ่อมท้ายไฟล์ก่อน ไม่อย่างนั้น append ครั้งถัดไปจะต่อท้ายบรรทัดที่ขาด
+            // แล้ว journal จะอ่านไม่ได้อีกเลย
+            if torn {
+                let tail_parsed = lines
+                    .last()
+                    .is_some_and(|l| serde_json::from_str::<Entry>(l).is_ok());
+                repair_tail(&self.journal_path, &content, tail_parsed)
+                    .map_err(|e| io_error(&self.journal_path, e))?;
+            }
+        }
+
+        Ok((snapshot, entries))
+    }
+
+    fn append(&mut self, entry: &Entry) -> Result<(), StorageError> {
+        let mut line =
+            serde_json::to_string(entry).map_err(|e| parse_error(&self.journal_path, e))?;
+        line.push('\n');
+
+        let write = || -> io::Result<()> {
+            let mut file = OpenOptions::new()
+                .create(true)
+                .append(true)
+                .open(&self.journal_path)?;
+            file.write_all(line.as_bytes())?;
+            file.sync_data()
+        };
+        write().map_err(|e| io_error(&self.journal_path, e))
+    }
+
+    fn compact(&mut self, snapshot: &Snapshot) -> Result<(), StorageError> {
+        let content = serde_json::to_string_pretty(snapshot)
+            .map_err(|e| parse_error(&self.snapshot_path, e))?;
+        write_atomic(&self.snapshot_path, content.as_bytes())
+            .map_err(|e| io_error(&self.snapshot_path, e))?;
+
+        // ถ้าตายก่อนบรรทัดนี้ entries เก่าจะถูกข้ามตอนเปิด (seq <= snapshot.seq)
+        File::create(&self.journal_path)
+            .map(drop)
+            .map_err(|e| io_error(&self.journal_path, e))
+    }
+}
+
+// บรรทัดสุดท้ายไม่มี '\n' ปิด:
+// - ถ้าอ่านได้ครบ (ขาดแค่ '\n') เติม '\n' ให้
+// - ถ้าขาดกลางคัน ตัดไฟล์กลับไปที่ '\n' ตัวสุดท้าย
+fn repair_tail(path: &Path, content: &str, tail_parsed: bool) -> io::Result<()> {
+    let mut file = OpenOptions::new().write(true).open(path)?;
+    if tail_parsed {
+        file.seek(SeekFrom::End(0))?;
+        file.write_all(b"\n")?;
+    } else {
+        let keep = content.rfind('\n').map_or(0, |i| i + 1);
+        file.set_len(keep as u64)?;
+    }
+    file.sync_data()
+}
+
+// -------------------------------------------
+// SQLite backend
+// -------------------------------------------
+//
+// ใช้ sqlx (async) ผ่าน runtime ของตัวเอง เพื่อให้ API เป็นแบบ sync เหมือน JsonJournal
+// จึงห้ามเรียกจากภายใน async runtime อื่น
+
+pub struct SqliteJournal {
+    runtime: tokio::runtime::Runtime,
+    pool: SqlitePool,
+    path: PathBuf,
+}
+
+impl SqliteJournal {
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self, StorageError> {
+        let path = path.into();
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()
+            .map_err(|e| io_error(&path, e))?;
+
+        let options = SqliteConnectOptions::new()
+            .filename(&path)
+            .create_if_missing(true);
+        let pool = runtime.block_on(async {
+            let pool = SqlitePoolOptions::new()
+                .max_connections(1)
+                .connect_with(options)
+                .await?;
+
+            sqlx::query(
+                r#"
+                CREATE TABLE IF NOT EXISTS todo_snapshot (
+                    id INTEGER PRIMARY KEY CHECK (id = 1),
+                    seq INTEGER NOT NULL,
+                    data TEXT NOT NULL
+                )
+                "#,
+            )
+            .execute(&pool)
+            .await?;
+
+            sqlx::query(
+                r#"
+                CREATE TABLE IF NOT EXISTS todo_journal (
+                    seq INTEGER PRIMARY KEY,
+                    entry TEXT NOT NULL
+                )
+                "#,
+            )
+            .execute(&pool)
+            .await?;
+
+            Ok::<_, sqlx::Error>(pool)
+        })?;
+
+        Ok(Self {
+            runtime,
+            pool,
+            path,
+        })
+    }
+}
+
+impl JournalBackend for SqliteJournal {
+    fn load(&mut self) -> Result<(Snapshot, Vec<Entry>), StorageError> {
+        let (snapshot, rows) = self.runtime.block_on(async {
+            let snapshot: Option<String> =
+                sqlx::query_scalar("SELECT data FROM todo_snapshot WHERE id = 1")
+                    .fetch_optional(&self.pool)
+                    .await?;
+            let rows: Vec<String> =
+                sqlx::query_scalar("SELECT entry FROM todo_journal ORDER BY seq")
+                    .fetch_all(&self.pool)
+                    .await?;
+            Ok::<_, sqlx::Error>((snapshot, rows))
+        })?;
+
+        let parse_error = |e| parse_error(&self.path, e);
+        let snapshot = match snapshot {
+            Some(data) => serde_json::from_str(&data).map_err(parse_error)?,
+            None => Snapshot::default(),
+        };
+        let entries = rows
+            .iter()
+            .map(|row| serde_json::from_str(row).map_err(parse_error))
+            .collect::<Result<_, _>>()?;
+
+        Ok((snapshot, entries))
+    }
+
+    fn append(&mut self, entry: &Entry) -> Result<(), StorageError> {
+        let data = serde_json::to_string(entry).map_err(|e| parse_error(&self.path, e))?;
+
+        self.runtime.block_on(
+            sqlx::query("INSERT INTO todo_journal (seq, entry) VALUES (?, ?)")
+                .bind(entry.seq as i64)
+                .bind(data)
+                .execute(&self.pool),
+        )?;
+        Ok(())
+    }
+
+    fn compact(&mut self, snapshot: &Snapshot) -> Result<(), StorageError> {
+        let data = serde_json::to_string(snapshot).map_err(|e| parse_error(&self.path, e))?;
+
+        self.runtime.block_on(async {
+            let mut tx = self.pool.begin().await?;
+            sqlx::query("INSERT OR REPLACE INTO todo_snapshot (id, seq, data) VALUES (1, ?, ?)")
+                .bind(snapshot.seq as i64)
+                .bind(data)
+                .execute(&mut *tx)
+                .await?;
+            sqlx::query("DELETE FROM todo_journal WHERE seq <= ?")
+                .bind(snapshot.seq as i64)
+                .execute(&mut *tx)
+                .await?;
+            tx.commit().await
+        })?;
+        Ok(())
+    }
+}
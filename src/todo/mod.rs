@@ -0,0 +1,29 @@
+// ===========================================
+// Todo - โมเดลและที่เก็บข้อมูลของ Final Project (บทที่ 20)
+// ===========================================
+//
+// - item.rs:    Todo, Priority
+// - due.rs:     แปลงวันครบกำหนด ("tomorrow", "in 3 days", "2026-01-31")
+// - query.rs:   กรองและเรียงลำดับ todos
+// - storage.rs: StorageError และการเขียนไฟล์แบบ atomic
+// - journal.rs: ที่เก็บแบบ journal (undo/redo) บน JSON หรือ SQLite
+// - sync.rs:    ซิงค์ todos หลายเครื่องผ่าน server (version + revision)
+
+mod due;
+mod item;
+mod journal;
+mod query;
+mod storage;
+mod sync;
+
+pub use due::{DueDateError, parse_due};
+pub use item::{ParsePriorityError, Priority, Todo, next_id};
+pub use journal::{
+    Action, Entry, JournalBackend, JournaledStore, JsonJournal, Operation, Snapshot, SqliteJournal,
+};
+pub use query::{Query, SortOrder, Status};
+pub use storage::StorageError;
+pub use sync::{
+    Change, Conflict, Created, Resolution, SyncRequest, SyncResponse, SyncStore, SyncedTodo,
+    TodoFields,
+};
@@ -0,0 +1,154 @@
+// ===========================================
+// query.rs - กรองและเรียงลำดับ todos
+// ===========================================
+//
+// let today = Local::now().date_naive();
+// let urgent = Query::new()
+//     .tag("work")
+//     .overdue(today)
+//     .sort_by(SortOrder::Priority)
+//     .apply(&todos);
+
+use super::item::{Priority, Todo, normalize_tag};
+use chrono::NaiveDate;
+use std::cmp::Ordering;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Status {
+    #[default]
+    All,
+    Pending,
+    Completed,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SortOrder {
+    #[default]
+    Id,
+    // วันครบกำหนดใกล้สุดก่อน (ไม่มีวันครบกำหนดไว้ท้าย)
+    Due,
+    // สำคัญสุดก่อน
+    Priority,
+    Created,
+    Title,
+}
+
+impl FromStr for SortOrder {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "id" => Ok(SortOrder::Id),
+            "due" => Ok(SortOrder::Due),
+            "priority" => Ok(SortOrder::Priority),
+            "created" => Ok(SortOrder::Created),
+            "title" => Ok(SortOrder::Title),
+            _ => Err(format!(
+                "invalid sort order '{}' (expected id, due, priority, created or title)",
+                s
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Query {
+    tags: Vec<String>,
+    status: Status,
+    overdue_on: Option<NaiveDate>,
+    due_by: Option<NaiveDate>,
+    min_priority: Option<Priority>,
+    sort: SortOrder,
+    reverse: bool,
+}
+
+impl Query {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // ต้องมีทุก tag ที่ระบุ
+    pub fn tag(mut self, tag: &str) -> Self {
+        self.tags.push(normalize_tag(tag));
+        self
+    }
+
+    pub fn status(mut self, status: Status) -> Self {
+        self.status = status;
+        self
+    }
+
+    // เฉพาะรายการที่เลยกำหนดแล้ว ณ วัน `today`
+    pub fn overdue(mut self, today: NaiveDate) -> Self {
+        self.overdue_on = Some(today);
+        self
+    }
+
+    // เฉพาะรายการที่ครบกำหนดภายในวัน `date` (รวมวันนั้น)
+    pub fn due_by(mut self, date: NaiveDate) -> Self {
+        self.due_by = Some(date);
+        self
+    }
+
+    pub fn min_priority(mut self, priority: Priority) -> Self {
+        self.min_priority = Some(priority);
+        self
+    }
+
+    pub fn sort_by(mut self, sort: SortOrder) -> Self {
+        self.sort = sort;
+        self
+    }
+
+    pub fn reversed(mut self) -> Self {
+        self.reverse = !self.reverse;
+        self
+    }
+
+    pub fn matches(&self, todo: &Todo) -> bool {
+        let status_ok = match self.status {
+            Status::All => true,
+            Status::Pending => !todo.completed,
+            Status::Completed => todo.completed,
+        };
+
+        status_ok
+            && self.tags.iter().all(|tag| todo.tags.contains(tag))
+            && self.overdue_on.is_none_or(|today| todo.is_overdue(today))
+            && self
+                .due_by
+                .is_none_or(|date| todo.due.is_some_and(|due| due <= date))
+            && self.min_priority.is_none_or(|p| todo.priority >= p)
+    }
+
+    pub fn apply<'a>(&self, todos: &'a [Todo]) -> Vec<&'a Todo> {
+        let mut result: Vec<&Todo> = todos.iter().filter(|t| self.matches(t)).collect();
+
+        result.sort_by(|a, b| {
+            let order = compare(self.sort, a, b).then(a.id.cmp(&b.id));
+            if self.reverse { order.reverse() } else { order }
+        });
+        result
+    }
+}
+
+fn compare(sort: SortOrder, a: &Todo, b: &Todo) -> Ordering {
+    match sort {
+        SortOrder::Id => Ordering::Equal,
+        SortOrder::Due => compare_due(a, b).then(b.priority.cmp(&a.priority)),
+        SortOrder::Priority => b.priority.cmp(&a.priority).then(compare_due(a, b)),
+        SortOrder::Created => a.created_at.cmp(&b.created_at),
+        SortOrder::Title => a.title.to_lowercase().cmp(&b.title.to_lowercase()),
+    }
+}
+
+// None (ไม่มีวันครบกำหนด) อยู่หลังสุด
+fn compare_due(a: &Todo, b: &Todo) -> Ordering {
+    match (a.due, b.due) {
+        (Some(a), Some(b)) => a.cmp(&b),
+        (Some(_), None) => Ordering::Less,
+        (None, Some(_)) => Ordering::Greater,
+        (None, None) => Ordering::Equal,
+    }
+}
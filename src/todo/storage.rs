@@ -0,0 +1,54 @@
+// ===========================================
+// storage.rs - error และ helper สำหรับเขียนไฟล์ของ todo
+// ===========================================
+
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use thiserror::Error;
+
+#[derive(Error, Debug)]
+pub enum StorageError {
+    #[error("Failed to access {path}: {source}")]
+    Io {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
+    #[error("Failed to parse {path}: {source}")]
+    Parse {
+        path: PathBuf,
+        #[source]
+        source: serde_json::Error,
+    },
+
+    #[error("Database error: {0}")]
+    Database(#[from] sqlx::Error),
+}
+
+// เขียนไฟล์แบบ atomic: เขียนลงไฟล์ชั่วคราวแล้ว rename ทับ
+pub(crate) fn write_atomic(path: &Path, content: &[u8]) -> io::Result<()> {
+    let tmp_path = with_suffix(path, ".tmp");
+    let write = || -> io::Result<()> {
+        let mut file = File::create(&tmp_path)?;
+        file.write_all(content)?;
+        file.write_all(b"\n")?;
+        file.sync_all()?;
+        fs::rename(&tmp_path, path)
+    };
+
+    write().inspect_err(|_| {
+        let _ = fs::remove_file(&tmp_path);
+    })
+}
+
+// todos.json + ".tmp" → todos.json.tmp
+pub(crate) fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name = path
+        .file_name()
+        .map(|n| n.to_os_string())
+        .unwrap_or_else(|| "todos.json".into());
+    name.push(suffix);
+    path.with_file_name(name)
+}
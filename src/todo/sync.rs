@@ -0,0 +1,306 @@
+// ===========================================
+// sync.rs - ซิงค์ todos ระหว่างหลายเครื่อง
+// ===========================================
+//
+// Server เก็บ SyncStore ต่อ 1 รายการ todos
+// - revision: ตัวนับของทั้ง store เพิ่มทุกครั้งที่มีการเปลี่ยนแปลง
+// - version:  ตัวนับของแต่ละรายการ ใช้ตรวจ conflict
+//
+// Client ส่ง SyncRequest { since, changes } โดย since คือ revision ล่าสุดที่เห็น
+// และแต่ละ change บอก base_version ที่แก้ต่อมา
+// ถ้า base_version ไม่ตรงกับ version บน server = conflict
+// ตัดสินด้วย updated_at (ใครแก้หลังสุดชนะ) แล้วรายงานไว้ใน SyncResponse
+//
+// การสร้างใหม่จำ client_ref ไว้ ถ้า client ส่ง request เดิมซ้ำ (เช่น response หายระหว่างทาง)
+// จะได้ id เดิมกลับไปแทนการสร้างรายการซ้ำ client_ref จึงต้องไม่ซ้ำกันใน store เดียวกัน
+
+use super::item::{Priority, Todo, next_id};
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+// ข้อมูลที่ client แก้ไขได้
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TodoFields {
+    pub title: String,
+    #[serde(default)]
+    pub completed: bool,
+    #[serde(default)]
+    pub priority: Priority,
+    #[serde(default)]
+    pub due: Option<NaiveDate>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+impl TodoFields {
+    // now = เวลาของการเปลี่ยนแปลง (ใช้เป็น completed_at ถ้าเพิ่งทำเสร็จ)
+    fn apply_to(self, todo: &mut Todo, now: DateTime<Utc>) {
+        todo.title = self.title;
+        todo.priority = self.priority;
+        todo.due = self.due;
+        todo.tags.clear();
+        for tag in &self.tags {
+            todo.add_tag(tag);
+        }
+        if self.completed {
+            todo.complete_at(now);
+        } else {
+            todo.uncomplete();
+        }
+    }
+}
+
+// Todo พร้อมข้อมูลสำหรับซิงค์ (deleted = tombstone ให้ client รู้ว่าถูกลบ)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncedTodo {
+    #[serde(flatten)]
+    pub todo: Todo,
+    pub version: u64,
+    pub revision: u64,
+    pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub deleted: bool,
+}
+
+// การเปลี่ยนแปลงหนึ่งรายการจาก client
+//
+// - สร้างใหม่: id = None และมี client_ref ไว้จับคู่กับ id ที่ server ให้
+// - แก้ไข:    id + base_version + fields
+// - ลบ:       id + base_version + deleted = true
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Change {
+    #[serde(default)]
+    pub id: Option<u32>,
+    #[serde(default)]
+    pub client_ref: Option<String>,
+    #[serde(default)]
+    pub base_version: u64,
+    pub updated_at: DateTime<Utc>,
+    #[serde(default)]
+    pub deleted: bool,
+    #[serde(default)]
+    pub fields: Option<TodoFields>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncRequest {
+    #[serde(default)]
+    pub since: u64,
+    #[serde(default)]
+    pub changes: Vec<Change>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Created {
+    pub client_ref: String,
+    pub id: u32,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Resolution {
+    // การแก้ไขของ client ใหม่กว่า จึงถูกนำไปใช้
+    ClientWins,
+    // ข้อมูลบน server ใหม่กว่า การแก้ไขของ client ถูกทิ้ง
+    ServerWins,
+    // change ใช้ไม่ได้ (ไม่พบ id หรือข้อมูลไม่ครบ)
+    Rejected,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Conflict {
+    pub id: Option<u32>,
+    pub client_ref: Option<String>,
+    pub base_version: u64,
+    pub server_version: Option<u64>,
+    pub resolution: Resolution,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SyncResponse {
+    // revision ที่ client ควรส่งมาเป็น since ครั้งถัดไป
+    pub revision: u64,
+    // ทุกรายการที่เปลี่ยนหลัง since (รวมที่เพิ่ง sync, tombstones
+    // และรายการที่ server ชนะ conflict)
+    pub changes: Vec<SyncedTodo>,
+    pub created: Vec<Created>,
+    pub conflicts: Vec<Conflict>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SyncStore {
+    revision: u64,
+    items: BTreeMap<u32, SyncedTodo>,
+    // client_ref → id ที่สร้างไปแล้ว
+    #[serde(default)]
+    client_refs: BTreeMap<String, u32>,
+}
+
+impl SyncStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn revision(&self) -> u64 {
+        self.revision
+    }
+
+    // รายการที่ยังไม่ถูกลบ เรียงตาม id
+    pub fn todos(&self) -> impl Iterator<Item = &SyncedTodo> {
+        self.items.values().filter(|item| !item.deleted)
+    }
+
+    pub fn get(&self, id: u32) -> Option<&SyncedTodo> {
+        self.items.get(&id).filter(|item| !item.deleted)
+    }
+
+    pub fn create(&mut self, fields: TodoFields, now: DateTime<Utc>) -> &SyncedTodo {
+        // นับ tombstones ด้วย เพื่อไม่ให้ id ซ้ำกับรายการที่ถูกลบ
+        let all: Vec<Todo> = self.items.values().map(|i| i.todo.clone()).collect();
+        let mut todo = Todo::new(next_id(&all), String::new());
+        todo.created_at = now;
+        fields.apply_to(&mut todo, now);
+
+        self.revision += 1;
+        let item = SyncedTodo {
+            todo,
+            version: 1,
+            revision: self.revision,
+            updated_at: now,
+            deleted: false,
+        };
+        self.items.entry(item.todo.id).or_insert(item)
+    }
+
+    pub fn update(
+        &mut self,
+        id: u32,
+        fields: TodoFields,
+        now: DateTime<Utc>,
+    ) -> Option<&SyncedTodo> {
+        self.get(id)?;
+        self.write(id, Some(fields), false, now);
+        self.items.get(&id)
+    }
+
+    pub fn delete(&mut self, id: u32, now: DateTime<Utc>) -> bool {
+        if self.get(id).is_none() {
+            return false;
+        }
+        self.write(id, None, true, now);
+        true
+    }
+
+    // รายการที่เปลี่ยนหลัง revision `since`
+    pub fn changes_since(&self, since: u64) -> Vec<SyncedTodo> {
+        let mut changes: Vec<SyncedTodo> = self
+            .items
+            .values()
+            .filter(|item| item.revision > since)
+            .cloned()
+            .collect();
+        changes.sort_by_key(|item| item.revision);
+        changes
+    }
+
+    pub fn sync(&mut self, request: SyncRequest, now: DateTime<Utc>) -> SyncResponse {
+        let mut created = Vec::new();
+        let mut conflicts = Vec::new();
+        let mut server_wins = Vec::new();
+
+        for change in request.changes {
+            // นาฬิกาของ client ที่เดินเร็วเกินไม่ควรชนะทุก conflict
+            let updated_at = change.updated_at.min(now);
+            let rejected = |server_version| Conflict {
+                id: change.id,
+                client_ref: change.client_ref.clone(),
+                base_version: change.base_version,
+                server_version,
+                resolution: Resolution::Rejected,
+            };
+
+            let Some(id) = change.id else {
+                match (change.client_ref.clone(), change.fields, change.deleted) {
+                    (Some(client_ref), Some(fields), false) => {
+                        // ส่งซ้ำ: ตอบ id เดิม ไม่สร้างใหม่
+                        let id = match self.client_refs.get(&client_ref) {
+                            Some(&id) => id,
+                            None => {
+                                let id = self.create(fields, updated_at).todo.id;
+                                self.client_refs.insert(client_ref.clone(), id);
+                                id
+                            }
+                        };
+                        created.push(Created { client_ref, id });
+                    }
+                    // สร้างแล้วลบก่อน sync = ไม่ต้องทำอะไร
+                    (_, _, true) => {}
+                    _ => conflicts.push(rejected(None)),
+                }
+                continue;
+            };
+
+            let Some(server) = self.items.get(&id) else {
+                conflicts.push(rejected(None));
+                continue;
+            };
+            if !change.deleted && change.fields.is_none() {
+                conflicts.push(rejected(Some(server.version)));
+                continue;
+            }
+
+            if server.version != change.base_version {
+                let resolution = if updated_at > server.updated_at {
+                    Resolution::ClientWins
+                } else {
+                    Resolution::ServerWins
+                };
+                conflicts.push(Conflict {
+                    id: Some(id),
+                    client_ref: change.client_ref.clone(),
+                    base_version: change.base_version,
+                    server_version: Some(server.version),
+                    resolution,
+                });
+                if resolution == Resolution::ServerWins {
+                    server_wins.push(id);
+                    continue;
+                }
+            }
+
+            self.write(id, change.fields, change.deleted, updated_at);
+        }
+
+        // client ต้องได้ข้อมูลของ server กลับไปทับการแก้ไขที่แพ้ แม้จะเก่ากว่า since
+        let mut changes = self.changes_since(request.since);
+        for id in server_wins {
+            if !changes.iter().any(|item| item.todo.id == id) {
+                changes.extend(self.items.get(&id).cloned());
+            }
+        }
+
+        SyncResponse {
+            revision: self.revision,
+            changes,
+            created,
+            conflicts,
+        }
+    }
+
+    // บันทึกการเปลี่ยนแปลงของรายการที่มีอยู่แล้ว (version และ revision เพิ่ม)
+    fn write(&mut self, id: u32, fields: Option<TodoFields>, deleted: bool, now: DateTime<Utc>) {
+        self.revision += 1;
+        let revision = self.revision;
+
+        if let Some(item) = self.items.get_mut(&id) {
+            if let Some(fields) = fields {
+                fields.apply_to(&mut item.todo, now);
+            }
+            item.deleted = deleted;
+            item.version += 1;
+            item.revision = revision;
+            item.updated_at = now;
+        }
+    }
+}
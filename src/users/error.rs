@@ -0,0 +1,23 @@
+// ===========================================
+// error.rs - error ของการตรวจข้อมูล user
+// ===========================================
+
+use thiserror::Error;
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum UserError {
+    #[error("name must not be empty")]
+    EmptyName,
+
+    #[error("name is {len} characters long, the limit is {max}")]
+    NameTooLong { len: usize, max: usize },
+
+    #[error("name must not contain control characters")]
+    NameControlCharacter,
+
+    #[error("'{email}' is not a valid email: {reason}")]
+    InvalidEmail { email: String, reason: &'static str },
+
+    #[error("age {age} is out of range (0-{max})")]
+    AgeOutOfRange { age: i64, max: u8 },
+}
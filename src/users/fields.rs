@@ -0,0 +1,227 @@
+// ===========================================
+// fields.rs - UserName, Email, Age
+// ===========================================
+//
+// ค่าข้างในเป็น private: ได้มาทาง new() (หรือ serde) ที่ตรวจแล้วเท่านั้น
+
+use super::UserError;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::str::FromStr;
+
+// -------------------------------------------
+// UserName
+// -------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct UserName(String);
+
+impl UserName {
+    // นับเป็นตัวอักษร ไม่ใช่ byte (ชื่อไทยตัวละ 3 byte)
+    pub const MAX_LEN: usize = 50;
+
+    // ตัดช่องว่างหัวท้ายให้
+    pub fn new(name: impl Into<String>) -> Result<Self, UserError> {
+        let name = name.into();
+        let name = name.trim();
+        if name.is_empty() {
+            return Err(UserError::EmptyName);
+        }
+        let len = name.chars().count();
+        if len > Self::MAX_LEN {
+            return Err(UserError::NameTooLong {
+                len,
+                max: Self::MAX_LEN,
+            });
+        }
+        if name.chars().any(char::is_control) {
+            return Err(UserError::NameControlCharacter);
+        }
+        Ok(Self(name.to_string()))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+// -------------------------------------------
+// Email
+// -------------------------------------------
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub struct Email(String);
+
+// ตัวอักษรพิเศษที่ใช้หน้า @ ได้ (RFC 5322 แบบไม่มี quote)
+const LOCAL_SPECIALS: &str = ".!#$%&'*+/=?^_`{|}~-";
+
+impl Email {
+    pub const MAX_LEN: usize = 254;
+    const MAX_LOCAL_LEN: usize = 64;
+    const MAX_LABEL_LEN: usize = 63;
+
+    // ตรวจแบบใช้งานจริง (ไม่รับ quoted local part หรือ IP address)
+    // domain เก็บเป็นตัวพิมพ์เล็ก: Somchai@Example.COM → Somchai@example.com
+    pub fn new(email: impl Into<String>) -> Result<Self, UserError> {
+        let email = email.into();
+        let email = email.trim();
+        let invalid = |reason| UserError::InvalidEmail {
+            email: email.to_string(),
+            reason,
+        };
+
+        if email.len() > Self::MAX_LEN {
+            return Err(invalid("too long"));
+        }
+        let (local, domain) = email
+            .rsplit_once('@')
+            .ok_or_else(|| invalid("missing '@'"))?;
+
+        if local.is_empty() || local.len() > Self::MAX_LOCAL_LEN {
+            return Err(invalid("the part before '@' must be 1-64 characters"));
+        }
+        if !local
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || LOCAL_SPECIALS.contains(c))
+        {
+            return Err(invalid("unsupported character before '@'"));
+        }
+        if local.starts_with('.') || local.ends_with('.') || local.contains("..") {
+            return Err(invalid("misplaced '.' before '@'"));
+        }
+
+        let labels: Vec<&str> = domain.split('.').collect();
+        if labels.len() < 2 {
+            return Err(invalid("domain needs a dot, e.g. example.com"));
+        }
+        for label in &labels {
+            let valid = (1..=Self::MAX_LABEL_LEN).contains(&label.len())
+                && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+                && !label.starts_with('-')
+                && !label.ends_with('-');
+            if !valid {
+                return Err(invalid("invalid domain"));
+            }
+        }
+        let tld = labels[labels.len() - 1];
+        if tld.len() < 2 || !tld.chars().all(|c| c.is_ascii_alphabetic()) {
+            return Err(invalid("invalid top-level domain"));
+        }
+
+        Ok(Self(format!("{}@{}", local, domain.to_ascii_lowercase())))
+    }
+
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    pub fn local_part(&self) -> &str {
+        self.split().0
+    }
+
+    pub fn domain(&self) -> &str {
+        self.split().1
+    }
+
+    fn split(&self) -> (&str, &str) {
+        self.0.rsplit_once('@').expect("validated email has '@'")
+    }
+}
+
+// -------------------------------------------
+// Age
+// -------------------------------------------
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(try_from = "i64", into = "u8")]
+pub struct Age(u8);
+
+impl Age {
+    pub const MAX: u8 = 150;
+
+    // รับ i64 เพื่อให้ค่าติดลบได้ error ของเราเอง (ไม่ใช่ error ของ serde)
+    pub fn new(age: i64) -> Result<Self, UserError> {
+        match u8::try_from(age) {
+            Ok(age) if age <= Self::MAX => Ok(Self(age)),
+            _ => Err(UserError::AgeOutOfRange {
+                age,
+                max: Self::MAX,
+            }),
+        }
+    }
+
+    pub fn get(self) -> u8 {
+        self.0
+    }
+
+    pub fn is_adult(self) -> bool {
+        self.0 >= 18
+    }
+}
+
+// -------------------------------------------
+// Conversions ที่ใช้ร่วมกัน
+// -------------------------------------------
+
+macro_rules! text_field {
+    ($name:ident) => {
+        impl TryFrom<String> for $name {
+            type Error = UserError;
+
+            fn try_from(text: String) -> Result<Self, Self::Error> {
+                $name::new(text)
+            }
+        }
+
+        impl FromStr for $name {
+            type Err = UserError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                $name::new(s)
+            }
+        }
+
+        impl From<$name> for String {
+            fn from(value: $name) -> Self {
+                value.0
+            }
+        }
+
+        impl AsRef<str> for $name {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+    };
+}
+
+text_field!(UserName);
+text_field!(Email);
+
+impl TryFrom<i64> for Age {
+    type Error = UserError;
+
+    fn try_from(age: i64) -> Result<Self, Self::Error> {
+        Age::new(age)
+    }
+}
+
+impl From<Age> for u8 {
+    fn from(age: Age) -> Self {
+        age.0
+    }
+}
+
+impl fmt::Display for Age {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
@@ -0,0 +1,18 @@
+// ===========================================
+// Users - ข้อมูล user ที่ตรวจสอบแล้ว (ต่อยอดจาก struct User บทที่ 5)
+// ===========================================
+//
+// - fields.rs: newtype UserName, Email, Age (สร้างได้ต้องผ่านการตรวจเท่านั้น)
+// - user.rs:   User ที่ประกอบจาก newtype ข้างบน
+// - error.rs:  UserError
+//
+// serde ก็ผ่านการตรวจเดียวกัน: JSON ที่ผิดจะ deserialize ไม่ได้เลย
+// จึงไม่มีทางที่ข้อมูลผิดรูปแบบจะหลุดไปถึง storage
+
+mod error;
+mod fields;
+mod user;
+
+pub use error::UserError;
+pub use fields::{Age, Email, UserName};
+pub use user::User;
@@ -0,0 +1,32 @@
+// ===========================================
+// user.rs - User ที่ทุกฟิลด์ถูกตรวจแล้ว
+// ===========================================
+
+use super::{Age, Email, UserName};
+use serde::{Deserialize, Serialize};
+
+// ฟิลด์เป็น pub ได้เพราะแต่ละ type ตรวจตัวเองแล้ว
+// แก้ทีละฟิลด์ก็ต้องสร้างค่าใหม่ผ่าน new() อยู่ดี
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct User {
+    pub name: UserName,
+    pub email: Email,
+    // ไม่บังคับ (JSON ไม่มี "age" ก็ได้)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub age: Option<Age>,
+}
+
+impl User {
+    pub fn new(name: UserName, email: Email) -> Self {
+        Self {
+            name,
+            email,
+            age: None,
+        }
+    }
+
+    pub fn with_age(mut self, age: Age) -> Self {
+        self.age = Some(age);
+        self
+    }
+}
@@ -0,0 +1,235 @@
+// ===========================================
+// Tests สำหรับ rust_tutorial::calc (tokenize, parse, evaluate, error span)
+// รัน: cargo test --test calc
+// ===========================================
+
+use proptest::prelude::*;
+use rust_tutorial::calc::{
+    CalcError, Calculator, ErrorKind, ExprKind, MAX_DEPTH, Span, TokenKind, evaluate, parse,
+    tokenize,
+};
+
+fn value(source: &str) -> f64 {
+    evaluate(source).unwrap_or_else(|e| panic!("{}: {}", source, e))
+}
+
+fn error(source: &str) -> CalcError {
+    evaluate(source).expect_err(source)
+}
+
+#[test]
+fn tokenizes_with_char_spans() {
+    let tokens = tokenize("x1 = 2.5e3*(3)").unwrap();
+    let kinds: Vec<TokenKind> = tokens.iter().map(|t| t.kind.clone()).collect();
+    assert_eq!(
+        kinds,
+        [
+            TokenKind::Ident("x1".into()),
+            TokenKind::Equals,
+            TokenKind::Number(2500.0),
+            TokenKind::Star,
+            TokenKind::LParen,
+            TokenKind::Number(3.0),
+            TokenKind::RParen,
+        ]
+    );
+    assert_eq!(tokens[2].span, Span::new(5, 10));
+
+    // column นับเป็นตัวอักษร: "ราคา" 4 ตัว (12 byte)
+    let tokens = tokenize("ราคา × 2").unwrap();
+    assert_eq!(tokens[1].kind, TokenKind::Star);
+    assert_eq!(tokens[1].span, Span::new(5, 6));
+}
+
+#[test]
+fn precedence_and_associativity() {
+    assert_eq!(value("1 + 2 * 3"), 7.0);
+    assert_eq!(value("(1 + 2) * 3"), 9.0);
+    assert_eq!(value("10 - 4 - 3"), 3.0);
+    assert_eq!(value("100 / 10 / 5"), 2.0);
+    assert_eq!(value("2 ^ 3 ^ 2"), 512.0);
+    assert_eq!(value("-2 ^ 2"), -4.0);
+    assert_eq!(value("2 ^ -1"), 0.5);
+    assert_eq!(value("--3"), 3.0);
+    assert_eq!(value("+4 - -4"), 8.0);
+    assert_eq!(value("7 % 4 * 2"), 6.0);
+    assert_eq!(value(".5 + 1e-1"), 0.6);
+}
+
+#[test]
+fn functions_and_constants() {
+    assert_eq!(value("sqrt(16)"), 4.0);
+    assert_eq!(value("min(4, -2, 9)"), -2.0);
+    assert_eq!(value("max(1)"), 1.0);
+    assert_eq!(value("floor(2.7) + ceil(2.1) + round(2.5)"), 8.0);
+    assert_eq!(value("ln(e)"), 1.0);
+    assert_eq!(value("abs(cos(pi))"), 1.0);
+    assert_eq!(value("tau / pi"), 2.0);
+}
+
+#[test]
+fn variables_and_assignment() {
+    let mut calc = Calculator::new();
+    assert_eq!(calc.run("rate = 0.07").unwrap(), 0.07);
+    calc.set("price", 200.0).unwrap();
+    assert_eq!(calc.eval("price * (1 + rate)").unwrap(), 214.0);
+    assert_eq!(calc.run("price = price / 2").unwrap(), 100.0);
+    assert_eq!(calc.get("price"), Some(100.0));
+    assert_eq!(
+        calc.variables().collect::<Vec<_>>(),
+        [("price", 100.0), ("rate", 0.07)]
+    );
+
+    let e = calc.run("pi = 3").unwrap_err();
+    assert_eq!(e.kind, ErrorKind::ReadOnly("pi".into()));
+    assert_eq!(e.span, Span::new(0, 2));
+    assert_eq!(calc.set("e", 1.0), Err(ErrorKind::ReadOnly("e".into())));
+
+    // eval ไม่รับการกำหนดค่า
+    assert_eq!(
+        calc.eval("x = 1").unwrap_err().kind,
+        ErrorKind::UnexpectedToken("'='".into())
+    );
+}
+
+#[test]
+fn parse_keeps_spans_including_parens() {
+    let expr = parse(" (1 + 2) * x").unwrap();
+    assert_eq!(expr.span, Span::new(1, 12));
+    let ExprKind::Binary { lhs, rhs, .. } = expr.kind else {
+        panic!("expected a binary expression");
+    };
+    assert_eq!(lhs.span, Span::new(1, 8));
+    assert_eq!(rhs.kind, ExprKind::Variable("x".into()));
+}
+
+#[test]
+fn syntax_errors_point_at_the_problem() {
+    let cases = [
+        ("1 + * 2", ErrorKind::UnexpectedToken("'*'".into()), (4, 5)),
+        ("2 * (3 + 4", ErrorKind::UnclosedParen, (4, 5)),
+        ("1 +", ErrorKind::UnexpectedEnd("a number"), (3, 4)),
+        ("", ErrorKind::UnexpectedEnd("a number"), (0, 1)),
+        ("(1))", ErrorKind::UnexpectedToken("')'".into()), (3, 4)),
+        ("3 $ 4", ErrorKind::UnexpectedChar('$'), (2, 3)),
+        (
+            "1.2.3 + 1",
+            ErrorKind::InvalidNumber("1.2.3".into()),
+            (0, 5),
+        ),
+        ("1e999", ErrorKind::InvalidNumber("1e999".into()), (0, 5)),
+        ("2 3", ErrorKind::UnexpectedToken("number 3".into()), (2, 3)),
+        ("max(1,)", ErrorKind::UnexpectedToken("')'".into()), (6, 7)),
+    ];
+    for (source, kind, (start, end)) in cases {
+        assert_eq!(
+            error(source),
+            CalcError::new(kind, Span::new(start, end)),
+            "{}",
+            source
+        );
+    }
+}
+
+#[test]
+fn evaluation_errors_are_checked() {
+    let e = error("1 + 10 / (5 - 5)");
+    assert_eq!(e.kind, ErrorKind::DivisionByZero);
+    assert_eq!(e.span, Span::new(9, 16));
+
+    assert_eq!(error("5 % 0").kind, ErrorKind::DivisionByZero);
+    assert_eq!(error("0 ^ -1").kind, ErrorKind::DivisionByZero);
+    assert_eq!(error("10 ^ 400").kind, ErrorKind::Overflow);
+    assert_eq!(error("exp(1000)").kind, ErrorKind::Overflow);
+    assert_eq!(
+        error("1e308 * 10"),
+        CalcError::new(ErrorKind::Overflow, Span::new(0, 10))
+    );
+    assert_eq!(
+        error("(-8) ^ 0.5").kind,
+        ErrorKind::Domain("fractional power of a negative number")
+    );
+    assert_eq!(value("(-2) ^ 3"), -8.0);
+    assert_eq!(
+        error("2 + sqrt(-1)"),
+        CalcError::new(
+            ErrorKind::Domain("sqrt of a negative number"),
+            Span::new(4, 12)
+        )
+    );
+    assert!(matches!(error("ln(0)").kind, ErrorKind::Domain(_)));
+    assert_eq!(
+        error("y * 2"),
+        CalcError::new(ErrorKind::UnknownVariable("y".into()), Span::new(0, 1))
+    );
+    assert_eq!(
+        error("foo(1)").kind,
+        ErrorKind::UnknownFunction("foo".into())
+    );
+    assert_eq!(
+        error("sqrt(1, 2)").to_string(),
+        "sqrt() takes 1 argument, got 2 at columns 1-10"
+    );
+}
+
+#[test]
+fn render_draws_carets_under_span() {
+    let source = "price * (qty + bonus)";
+    let mut calc = Calculator::new();
+    calc.set("price", 1.0).unwrap();
+    calc.set("qty", 1.0).unwrap();
+    let e = calc.eval(source).unwrap_err();
+    assert_eq!(
+        e.render(source),
+        "price * (qty + bonus)\n               ^^^^^ unknown variable 'bonus'"
+    );
+
+    // error ท้ายบรรทัดชี้ถัดจากตัวสุดท้าย, tab คงไว้ให้ตรงคอลัมน์
+    assert_eq!(
+        error("\t1 -").render("\t1 -"),
+        "\t1 -\n\t   ^ unexpected end of input, expected a number"
+    );
+}
+
+#[test]
+fn deep_nesting_is_an_error_not_a_crash() {
+    let source = format!(
+        "{}1{}",
+        "(".repeat(MAX_DEPTH + 1),
+        ")".repeat(MAX_DEPTH + 1)
+    );
+    assert_eq!(error(&source).kind, ErrorKind::TooDeep);
+    assert_eq!(error(&"-".repeat(10_000)).kind, ErrorKind::TooDeep);
+
+    let fine = format!("{}1{}", "(".repeat(50), ")".repeat(50));
+    assert_eq!(value(&fine), 1.0);
+}
+
+proptest! {
+    #[test]
+    fn integer_arithmetic_matches_rust(
+        a in -1000i64..1000, b in -1000i64..1000, c in 1i64..100
+    ) {
+        let source = format!("{} + {} * {} - ({}) % {}", a, b, c, a, c);
+        prop_assert_eq!(value(&source), (a + b * c - a % c) as f64);
+    }
+
+    #[test]
+    fn never_panics_and_spans_stay_in_bounds(source in "[0-9a-z+*/%^(),. =-]{0,30}") {
+        let mut calc = Calculator::new();
+        if let Err(e) = calc.run(&source) {
+            let len = source.chars().count();
+            prop_assert!(e.span.start <= len && e.span.end <= len + 1, "{:?}", e);
+            prop_assert!(e.span.start < e.span.end);
+            let rendered = e.render(&source);
+            prop_assert!(rendered.contains('^'));
+        }
+    }
+
+    #[test]
+    fn results_are_always_finite(source in "[0-9.+*/^()-]{1,20}") {
+        if let Ok(value) = evaluate(&source) {
+            prop_assert!(value.is_finite());
+        }
+    }
+}
@@ -0,0 +1,232 @@
+// ===========================================
+// Tests สำหรับ rust_tutorial::change (ชุดเหรียญ, DP, จำนวนวิธี, เหรียญจำกัด)
+// รัน: cargo test --test change
+// ===========================================
+
+use proptest::prelude::*;
+use rust_tutorial::change::{
+    ChangeError, Coin, CoinSet, Inventory, MAX_AMOUNT, count_ways, min_coins,
+};
+
+// ค่าที่ใช้ในคำตอบ เช่น [(25, 1), (10, 1)]
+fn values(set: &CoinSet, amount: u32) -> Vec<(u32, u32)> {
+    min_coins(set, amount)
+        .unwrap()
+        .coins
+        .iter()
+        .map(|(coin, count)| (coin.value, *count))
+        .collect()
+}
+
+// นับวิธีแบบตรงไปตรงมา (ใช้เทียบกับ DP)
+fn brute_force_ways(coins: &[u32], amount: u32) -> u128 {
+    match coins.split_first() {
+        None => u128::from(amount == 0),
+        Some((&coin, rest)) => (0..=amount / coin)
+            .map(|count| brute_force_ways(rest, amount - count * coin))
+            .sum(),
+    }
+}
+
+#[test]
+fn us_coins_match_value_in_cents() {
+    let us = CoinSet::us();
+    let values: Vec<u32> = us.coins().iter().map(|coin| coin.value).collect();
+    assert_eq!(values, [25, 10, 5, 1]);
+    assert_eq!(us.get(25).unwrap().name, "Quarter");
+    assert_eq!(Coin::Dime.value_in_cents(), 10);
+}
+
+#[test]
+fn minimal_breakdowns() {
+    let us = CoinSet::us();
+    assert_eq!(values(&us, 41), [(25, 1), (10, 1), (5, 1), (1, 1)]);
+    assert_eq!(values(&us, 30), [(25, 1), (5, 1)]);
+    assert_eq!(min_coins(&us, 0).unwrap().to_string(), "no coins");
+    assert_eq!(
+        min_coins(&us, 66).unwrap().to_string(),
+        "2 × Quarter + 1 × Dime + 1 × Nickel + 1 × Penny (5 coins)"
+    );
+
+    // 37.75 บาท
+    let baht = CoinSet::thai_baht();
+    let change = min_coins(&baht, 3775).unwrap();
+    assert_eq!(change.count_of(1000), 3);
+    assert_eq!(change.count_of(500), 1);
+    assert_eq!(change.count_of(200), 1);
+    assert_eq!(change.count_of(50), 1);
+    assert_eq!(change.count_of(25), 1);
+    assert_eq!(change.coin_count(), 7);
+}
+
+#[test]
+fn dynamic_programming_beats_greedy() {
+    // greedy: 4 + 1 + 1, ที่ดีที่สุด: 3 + 3
+    let set = CoinSet::custom(&[1, 3, 4]).unwrap();
+    assert_eq!(values(&set, 6), [(3, 2)]);
+}
+
+#[test]
+fn impossible_and_invalid_amounts() {
+    let baht = CoinSet::thai_baht();
+    assert_eq!(
+        min_coins(&baht, 1010),
+        Err(ChangeError::NoExactChange { amount: 1010 })
+    );
+    assert_eq!(count_ways(&baht, 10).unwrap(), 0);
+    assert!(matches!(
+        min_coins(&baht, MAX_AMOUNT + 1),
+        Err(ChangeError::TooLarge { .. })
+    ));
+}
+
+#[test]
+fn coin_sets_are_validated() {
+    assert_eq!(CoinSet::custom(&[]), Err(ChangeError::EmptySet));
+    assert_eq!(CoinSet::custom(&[5, 0]), Err(ChangeError::ZeroCoin));
+    assert_eq!(
+        CoinSet::custom(&[5, 1, 5]),
+        Err(ChangeError::DuplicateCoin(5))
+    );
+    let set = CoinSet::custom(&[2, 7]).unwrap();
+    assert_eq!(set.coins()[0].name, "7");
+}
+
+#[test]
+fn deserialized_coin_sets_are_validated() {
+    let json = r#"{"name":"broken","coins":[{"name":"1","value":1},{"name":"0","value":0}]}"#;
+    let error = serde_json::from_str::<CoinSet>(json).unwrap_err();
+    assert!(error.to_string().contains("greater than 0"), "{}", error);
+
+    let json = r#"{"name":"empty","coins":[]}"#;
+    assert!(serde_json::from_str::<CoinSet>(json).is_err());
+
+    // ผ่าน new จึงเรียงจากค่ามากไปน้อยเสมอ และ serialize กลับได้
+    let json = r#"{"name":"thb","coins":[{"name":"1","value":1},{"name":"10","value":10}]}"#;
+    let set: CoinSet = serde_json::from_str(json).unwrap();
+    assert_eq!(set.coins()[0].value, 10);
+    let again: CoinSet = serde_json::from_str(&serde_json::to_string(&set).unwrap()).unwrap();
+    assert_eq!(again, set);
+}
+
+#[test]
+fn counts_ways() {
+    let us = CoinSet::us();
+    assert_eq!(count_ways(&us, 0).unwrap(), 1);
+    assert_eq!(count_ways(&us, 10).unwrap(), 4);
+    assert_eq!(count_ways(&us, 100).unwrap(), 242);
+
+    let many = CoinSet::custom(&(1..=200).collect::<Vec<_>>()).unwrap();
+    assert_eq!(
+        count_ways(&many, 100_000),
+        Err(ChangeError::TooManyWays { amount: 100_000 })
+    );
+}
+
+#[test]
+fn vending_machine_uses_only_what_it_has() {
+    let mut machine = Inventory::new(CoinSet::thai_baht());
+    machine.add(500, 1).unwrap();
+    machine.add(200, 3).unwrap();
+    machine.add(100, 10).unwrap();
+    assert_eq!(machine.total(), 500 + 600 + 1000);
+
+    // ไม่จำกัดจะใช้ 10 + 5, แต่ไม่มีเหรียญ 10 บาท
+    let change = machine.dispense(1500).unwrap();
+    assert_eq!(change.count_of(500), 1);
+    assert_eq!(change.count_of(200), 3);
+    assert_eq!(change.count_of(100), 4);
+    assert_eq!(machine.count(500), 0);
+    assert_eq!(machine.count(100), 6);
+
+    // ทอนไม่ได้ = ไม่หักเหรียญ
+    assert_eq!(
+        machine.dispense(650),
+        Err(ChangeError::NoExactChange { amount: 650 })
+    );
+    assert_eq!(machine.count(100), 6);
+    assert_eq!(machine.add(300, 1), Err(ChangeError::UnknownCoin(300)));
+}
+
+#[test]
+fn limited_coins_can_force_more_coins() {
+    // มี 25 อันเดียว: 30 = 10 + 10 + 10 แทน 25 + 5
+    let mut till = Inventory::new(CoinSet::us());
+    till.add(25, 1).unwrap();
+    till.add(10, 3).unwrap();
+    let change = till.make_change(30).unwrap();
+    assert_eq!(change.count_of(10), 3);
+    assert_eq!(till.count_ways(30).unwrap(), 1);
+    assert_eq!(till.count_ways(35).unwrap(), 1);
+    assert_eq!(till.count_ways(45).unwrap(), 1);
+}
+
+fn any_coin_set() -> impl Strategy<Value = CoinSet> {
+    proptest::collection::btree_set(1u32..30, 1..5)
+        .prop_map(|values| CoinSet::custom(&values.into_iter().collect::<Vec<_>>()).unwrap())
+}
+
+proptest! {
+    #[test]
+    fn breakdown_adds_up_and_is_no_worse_than_greedy(set in any_coin_set(), amount in 0u32..200) {
+        let Ok(change) = min_coins(&set, amount) else {
+            return Ok(());
+        };
+        let total: u32 = change.coins.iter().map(|(coin, count)| coin.value * count).sum();
+        prop_assert_eq!(total, amount);
+
+        let mut greedy = 0;
+        let mut left = amount;
+        for coin in set.coins() {
+            greedy += left / coin.value;
+            left %= coin.value;
+        }
+        if left == 0 {
+            prop_assert!(change.coin_count() <= greedy);
+        }
+    }
+
+    #[test]
+    fn ways_match_brute_force(set in any_coin_set(), amount in 0u32..60) {
+        let coins: Vec<u32> = set.coins().iter().map(|coin| coin.value).collect();
+        prop_assert_eq!(count_ways(&set, amount).unwrap(), brute_force_ways(&coins, amount));
+    }
+
+    // เหรียญมากพอ = ได้คำตอบเหมือนแบบไม่จำกัด
+    #[test]
+    fn plenty_of_coins_behaves_like_unlimited(set in any_coin_set(), amount in 0u32..150) {
+        let mut inventory = Inventory::new(set.clone());
+        for coin in set.coins() {
+            inventory.add(coin.value, 200).unwrap();
+        }
+        let limited = inventory.make_change(amount).map(|change| change.coin_count());
+        let unlimited = min_coins(&set, amount).map(|change| change.coin_count());
+        prop_assert_eq!(limited, unlimited);
+        prop_assert_eq!(inventory.count_ways(amount), count_ways(&set, amount));
+    }
+
+    #[test]
+    fn dispense_removes_exactly_the_change(
+        set in any_coin_set(),
+        counts in proptest::collection::vec(0u32..4, 5),
+        amount in 0u32..100,
+    ) {
+        let mut inventory = Inventory::new(set.clone());
+        for (coin, count) in set.coins().iter().zip(&counts) {
+            inventory.add(coin.value, *count).unwrap();
+        }
+        let before = inventory.total();
+        let ways = inventory.count_ways(amount).unwrap();
+        match inventory.dispense(amount) {
+            Ok(_) => {
+                prop_assert!(ways > 0);
+                prop_assert_eq!(inventory.total(), before - u64::from(amount));
+            }
+            Err(ChangeError::NoExactChange { .. }) => {
+                prop_assert_eq!(ways, 0);
+                prop_assert_eq!(inventory.total(), before);
+            }
+            Err(e) => prop_assert!(false, "unexpected error {}", e),
+        }
+    }
+}
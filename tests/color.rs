@@ -0,0 +1,165 @@
+// ===========================================
+// Tests สำหรับ rust_tutorial::color (hex, ชื่อ CSS, HSL/HSV, alpha, WCAG)
+// รัน: cargo test --test color
+// ===========================================
+
+use proptest::prelude::*;
+use rust_tutorial::color::{Color, ColorError, Hsl, Hsv, TextSize, WcagLevel};
+
+fn color(text: &str) -> Color {
+    text.parse().unwrap()
+}
+
+#[test]
+fn parses_hex_forms() {
+    assert_eq!(color("#ff8800"), Color::rgb(255, 136, 0));
+    assert_eq!(color("#F80"), Color::rgb(255, 136, 0));
+    assert_eq!(color("#ff880080"), Color::rgba(255, 136, 0, 128));
+    assert_eq!(color("#f808"), Color::rgba(255, 136, 0, 136));
+    assert_eq!(color("  #000000 "), Color::BLACK);
+}
+
+#[test]
+fn rejects_bad_input() {
+    assert_eq!("".parse::<Color>(), Err(ColorError::Empty));
+    assert_eq!(
+        "#12345".parse::<Color>(),
+        Err(ColorError::InvalidHex("#12345".into()))
+    );
+    assert_eq!(
+        "#gg0000".parse::<Color>(),
+        Err(ColorError::InvalidHex("#gg0000".into()))
+    );
+    // ตัวอักษรหลายไบต์ต้องไม่ทำให้ panic
+    assert!("#ก12".parse::<Color>().is_err());
+    assert_eq!(
+        "reddish".parse::<Color>(),
+        Err(ColorError::UnknownName("reddish".into()))
+    );
+}
+
+#[test]
+fn css_names() {
+    assert_eq!(color("rebeccapurple"), Color::rgb(0x66, 0x33, 0x99));
+    assert_eq!(color("Red"), Color::rgb(255, 0, 0));
+    assert_eq!(color("transparent"), Color::TRANSPARENT);
+    assert_eq!(color("grey"), color("gray"));
+
+    assert_eq!(Color::rgb(255, 165, 0).name(), Some("orange"));
+    assert_eq!(Color::rgb(0, 255, 255).name(), Some("aqua"));
+    assert_eq!(Color::rgb(1, 2, 3).name(), None);
+    assert_eq!(Color::rgba(255, 0, 0, 10).name(), None);
+}
+
+#[test]
+fn display_is_lowercase_hex() {
+    assert_eq!(Color::rgb(255, 136, 0).to_string(), "#ff8800");
+    assert_eq!(Color::rgba(255, 136, 0, 128).to_string(), "#ff880080");
+    assert_eq!(color("navy").to_hex(), "#000080");
+}
+
+#[test]
+fn serde_uses_text_form() {
+    let json = serde_json::to_string(&Color::rgb(255, 136, 0)).unwrap();
+    assert_eq!(json, "\"#ff8800\"");
+    assert_eq!(
+        serde_json::from_str::<Color>("\"teal\"").unwrap(),
+        Color::rgb(0, 128, 128)
+    );
+
+    let error = serde_json::from_str::<Color>("\"#xyz\"").unwrap_err();
+    assert!(error.to_string().contains("not a hex color"), "{}", error);
+}
+
+#[test]
+fn hsl_and_hsv_known_values() {
+    let orange = Color::rgb(255, 136, 0);
+    let hsl = orange.to_hsl();
+    assert!((hsl.h - 32.0).abs() < 0.01, "{:?}", hsl);
+    assert_eq!((hsl.s, hsl.l), (1.0, 0.5));
+
+    assert_eq!(
+        Color::from_hsl(Hsl::new(120.0, 1.0, 0.25)),
+        Color::rgb(0, 128, 0)
+    );
+    assert_eq!(
+        Color::from_hsl(Hsl::new(-120.0, 1.0, 0.5)),
+        Color::rgb(0, 0, 255)
+    );
+    assert_eq!(Color::from_hsv(Hsv::new(0.0, 0.0, 1.0)), Color::WHITE);
+    assert_eq!(Color::rgb(0, 0, 255).to_hsv(), Hsv::new(240.0, 1.0, 1.0));
+
+    assert_eq!(Color::rgb(0, 0, 255).lighten(0.5), Color::WHITE);
+    assert_eq!(Color::WHITE.darken(1.0), Color::BLACK);
+}
+
+#[test]
+fn alpha_blending() {
+    let half_red = Color::rgba(255, 0, 0, 128);
+    assert_eq!(half_red.over(Color::WHITE), Color::rgb(255, 127, 127));
+    assert_eq!(Color::rgb(1, 2, 3).over(Color::WHITE), Color::rgb(1, 2, 3));
+    assert_eq!(
+        Color::TRANSPARENT.over(Color::TRANSPARENT),
+        Color::TRANSPARENT
+    );
+
+    assert_eq!(
+        Color::BLACK.mix(Color::WHITE, 0.5),
+        Color::rgb(128, 128, 128)
+    );
+    assert_eq!(Color::BLACK.mix(Color::WHITE, 2.0), Color::WHITE);
+}
+
+#[test]
+fn wcag_contrast() {
+    assert!((Color::BLACK.contrast_ratio(Color::WHITE) - 21.0).abs() < 1e-9);
+    assert_eq!(Color::WHITE.contrast_ratio(Color::WHITE), 1.0);
+
+    // #767676 บนขาว ≈ 4.54:1 ผ่าน AA พอดี
+    let gray = color("#767676");
+    assert_eq!(
+        gray.wcag_level(Color::WHITE, TextSize::Normal),
+        Some(WcagLevel::AA)
+    );
+    assert_eq!(
+        gray.wcag_level(Color::WHITE, TextSize::Large),
+        Some(WcagLevel::AAA)
+    );
+    assert_eq!(
+        color("#999999").wcag_level(Color::WHITE, TextSize::Normal),
+        None
+    );
+
+    assert_eq!(color("navy").readable_text_color(), Color::WHITE);
+    assert_eq!(color("yellow").readable_text_color(), Color::BLACK);
+}
+
+fn any_color() -> impl Strategy<Value = Color> {
+    any::<[u8; 4]>().prop_map(|[r, g, b, a]| Color::rgba(r, g, b, a))
+}
+
+proptest! {
+    #[test]
+    fn display_parse_roundtrip(c in any_color()) {
+        prop_assert_eq!(c.to_string().parse::<Color>(), Ok(c));
+    }
+
+    #[test]
+    fn hsl_hsv_roundtrip(c in any_color()) {
+        let opaque = c.with_alpha(255);
+        prop_assert_eq!(Color::from_hsl(opaque.to_hsl()), opaque);
+        prop_assert_eq!(Color::from_hsv(opaque.to_hsv()), opaque);
+    }
+
+    #[test]
+    fn contrast_is_symmetric_and_bounded(a in any_color(), b in any_color()) {
+        let ratio = a.contrast_ratio(b);
+        prop_assert!((1.0..=21.0 + 1e-9).contains(&ratio));
+        prop_assert_eq!(ratio, b.contrast_ratio(a));
+    }
+
+    #[test]
+    fn over_opaque_background_is_opaque(top in any_color(), bottom in any_color()) {
+        prop_assert!(top.over(bottom.with_alpha(255)).is_opaque());
+    }
+}
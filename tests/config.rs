@@ -0,0 +1,297 @@
+// ===========================================
+// Tests สำหรับ rust_tutorial::config (defaults → ไฟล์ → env → CLI)
+// รัน: cargo test --test config
+// ===========================================
+
+use rust_tutorial::config::{ConfigError, ConfigLoader, Loaded, Source};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct AppConfig {
+    username: String,
+    debug: bool,
+    server: Server,
+    tags: Vec<String>,
+    // ไม่บังคับ: None ใน defaults
+    log_file: Option<PathBuf>,
+    // key อะไรก็ได้
+    limits: BTreeMap<String, u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct Server {
+    host: String,
+    port: u16,
+}
+
+impl Default for AppConfig {
+    fn default() -> Self {
+        Self {
+            username: "default_user".into(),
+            debug: false,
+            server: Server {
+                host: "127.0.0.1".into(),
+                port: 3000,
+            },
+            tags: vec!["web".into()],
+            log_file: None,
+            limits: BTreeMap::new(),
+        }
+    }
+}
+
+fn write(dir: &TempDir, name: &str, content: &str) -> PathBuf {
+    let path = dir.path().join(name);
+    fs::write(&path, content).unwrap();
+    path
+}
+
+fn env(vars: &[(&str, &str)]) -> Vec<(String, String)> {
+    vars.iter()
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect()
+}
+
+fn loader() -> ConfigLoader {
+    ConfigLoader::new().defaults(&AppConfig::default())
+}
+
+fn load(loader: ConfigLoader) -> Loaded<AppConfig> {
+    loader.load().unwrap_or_else(|e| panic!("{}", e))
+}
+
+#[test]
+fn defaults_only() {
+    let config = load(loader());
+    assert_eq!(config.value, AppConfig::default());
+    assert_eq!(config.source("server.port"), Some(&Source::Default));
+    assert_eq!(config.source("nope"), None);
+}
+
+#[test]
+fn layers_override_in_order() {
+    let dir = TempDir::new().unwrap();
+    let file = write(
+        &dir,
+        "app.toml",
+        r#"
+username = "somchai"
+tags = ["web", "api"]
+
+[server]
+port = 8080
+"#,
+    );
+
+    let config = load(
+        loader()
+            .file(&file)
+            .env_vars(
+                "APP",
+                env(&[
+                    ("APP_SERVER__PORT", "9000"),
+                    ("APP_DEBUG", "true"),
+                    ("OTHER_DEBUG", "false"),
+                ]),
+            )
+            .overrides(["server.port=9100", "tags=a, b"]),
+    );
+
+    assert_eq!(config.value.username, "somchai");
+    assert!(config.value.debug);
+    assert_eq!(config.value.server.port, 9100);
+    assert_eq!(config.value.server.host, "127.0.0.1");
+    assert_eq!(config.value.tags, ["a", "b"]);
+
+    assert_eq!(config.source("username"), Some(&Source::File(file.clone())));
+    assert_eq!(
+        config.source("debug"),
+        Some(&Source::Env("APP_DEBUG".into()))
+    );
+    assert_eq!(
+        config.source("server.port"),
+        Some(&Source::Cli("server.port=9100".into()))
+    );
+    assert_eq!(config.source("server.host"), Some(&Source::Default));
+}
+
+#[test]
+fn json_file_and_open_sections() {
+    let dir = TempDir::new().unwrap();
+    let file = write(
+        &dir,
+        "app.json",
+        r#"{"log_file": "/var/log/app.log", "limits": {"upload": 10}}"#,
+    );
+    let config = load(
+        loader()
+            .file(&file)
+            .env_vars("APP", env(&[("APP_LIMITS__DOWNLOAD", "20")])),
+    );
+    assert_eq!(
+        config.value.log_file.as_deref(),
+        Some(Path::new("/var/log/app.log"))
+    );
+    assert_eq!(
+        config.value.limits,
+        BTreeMap::from([("download".into(), 20), ("upload".into(), 10)])
+    );
+}
+
+#[test]
+fn missing_is_not_the_same_as_invalid() {
+    let dir = TempDir::new().unwrap();
+    let absent = dir.path().join("absent.toml");
+
+    // optional: ไม่มีไฟล์ก็ใช้ค่าเดิม และบอกว่าไม่พบ
+    let config = load(loader().optional_file(&absent));
+    assert_eq!(config.value, AppConfig::default());
+    assert_eq!(config.missing_files(), std::slice::from_ref(&absent));
+
+    // required: ไม่มีไฟล์เป็น error
+    assert!(matches!(
+        loader().file(&absent).load::<AppConfig>(),
+        Err(ConfigError::Missing(path)) if path == absent
+    ));
+
+    // มีอยู่แต่อ่านไม่ได้ (เป็น directory) ไม่ถูกกลบแม้เป็น optional
+    let directory = dir.path().join("dir.toml");
+    fs::create_dir(&directory).unwrap();
+    assert!(matches!(
+        loader().optional_file(&directory).load::<AppConfig>(),
+        Err(ConfigError::Unreadable { .. })
+    ));
+
+    // ผิดรูปแบบ
+    let broken = write(&dir, "broken.toml", "username = ");
+    let error = loader()
+        .optional_file(&broken)
+        .load::<AppConfig>()
+        .unwrap_err();
+    assert!(matches!(error, ConfigError::Parse { format: "TOML", .. }));
+    assert!(error.to_string().contains("broken.toml is not valid TOML"));
+
+    let broken = write(&dir, "broken.json", "[1, 2]");
+    assert!(matches!(
+        loader().file(&broken).load::<AppConfig>(),
+        Err(ConfigError::Parse { format: "JSON", .. })
+    ));
+
+    let yaml = write(&dir, "app.yaml", "username: x");
+    assert!(matches!(
+        loader().file(&yaml).load::<AppConfig>(),
+        Err(ConfigError::UnsupportedFormat(_))
+    ));
+}
+
+#[test]
+fn typos_are_reported_with_their_source() {
+    let dir = TempDir::new().unwrap();
+    let file = write(&dir, "app.toml", "[server]\nprot = 80\n");
+    let error = loader().file(&file).load::<AppConfig>().unwrap_err();
+    assert!(
+        matches!(&error, ConfigError::UnknownKey { key, origin: Source::File(_) } if key == "server.prot"),
+        "{}",
+        error
+    );
+
+    let error = loader()
+        .env_vars("APP", env(&[("APP_USERNAM", "x")]))
+        .load::<AppConfig>()
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "unknown key 'usernam' (from env APP_USERNAM)"
+    );
+}
+
+#[test]
+fn wrong_types_name_the_key_and_source() {
+    let error = loader()
+        .env_vars("APP", env(&[("APP_SERVER__PORT", "eighty")]))
+        .load::<AppConfig>()
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid value for 'server.port' (from env APP_SERVER__PORT): expected a number, got 'eighty'"
+    );
+
+    let dir = TempDir::new().unwrap();
+    let file = write(&dir, "app.toml", "debug = \"yes\"\n");
+    let error = loader().file(&file).load::<AppConfig>().unwrap_err();
+    assert!(
+        error
+            .to_string()
+            .ends_with("expected a boolean, got a string"),
+        "{}",
+        error
+    );
+
+    let error = loader()
+        .overrides(["server=1"])
+        .load::<AppConfig>()
+        .unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "invalid value for 'server' (from argument 'server=1'): this is a section, set one of its keys instead"
+    );
+    assert!(matches!(
+        loader().overrides(["debug"]).load::<AppConfig>(),
+        Err(ConfigError::InvalidOverride(text)) if text == "debug"
+    ));
+
+    // ชนิดถูกแต่ค่าเกิน u16 ตรวจตอนแปลงเป็น struct
+    assert!(matches!(
+        loader()
+            .overrides(["server.port=70000"])
+            .load::<AppConfig>(),
+        Err(ConfigError::Invalid(_))
+    ));
+}
+
+#[test]
+fn without_defaults_values_are_inferred() {
+    #[derive(Debug, Deserialize)]
+    struct Small {
+        port: u16,
+        name: String,
+    }
+    let config: Loaded<Small> = ConfigLoader::new()
+        .env_vars("APP", env(&[("APP_PORT", "80"), ("APP_NAME", "web")]))
+        .load()
+        .unwrap();
+    assert_eq!((config.value.port, config.value.name.as_str()), (80, "web"));
+
+    let error = ConfigLoader::new().load::<Small>().unwrap_err();
+    assert!(
+        error.to_string().contains("missing field `port`"),
+        "{}",
+        error
+    );
+}
+
+#[test]
+fn report_lists_every_value_with_its_source() {
+    let dir = TempDir::new().unwrap();
+    let config = load(
+        loader()
+            .optional_file(dir.path().join("app.toml"))
+            .env_vars("APP", env(&[("APP_SERVER__PORT", "9000")])),
+    );
+    let report = config.report();
+    assert!(
+        report.contains("server.port = 9000         (env APP_SERVER__PORT)"),
+        "{}",
+        report
+    );
+    assert!(
+        report.contains("username = \"default_user\"  (default)"),
+        "{}",
+        report
+    );
+    assert!(report.ends_with("app.toml"), "{}", report);
+}
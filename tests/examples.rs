@@ -0,0 +1,243 @@
+// ===========================================
+// Snapshot tests: output ของทุกโปรแกรมใน examples/ ต้องตรงกับ tests/snapshots/<name>.txt
+// รัน: cargo test --test examples
+// อัปเดต snapshot หลังแก้ example: UPDATE_SNAPSHOTS=1 cargo test --test examples
+// ===========================================
+//
+// output บางส่วนขึ้นกับเวลาและการจัดลำดับ thread จึงปรับก่อนเทียบ:
+// - "Time: 201.3ms" → "Time: <elapsed>"
+// - ส่วนที่อยู่ใน UNORDERED เทียบแบบไม่สนลำดับบรรทัด (thread / task ที่ทำงานพร้อมกัน)
+
+use rust_tutorial::examples;
+use rust_tutorial::i18n::Locale;
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const PROJECT_DIR: &str = env!("CARGO_MANIFEST_DIR");
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+// example ที่ไม่มี snapshot พร้อมเหตุผล
+const SKIPPED: &[(&str, &str)] = &[("web_server", "HTTP server ที่ไม่จบเอง")];
+
+// (example, หัวข้อ) - บรรทัดในหัวข้อนั้น (จนถึงบรรทัดว่าง) ลำดับไม่แน่นอน
+const UNORDERED: &[(&str, &str)] = &[
+    ("concurrency", "1️⃣ Basic Thread:"),
+    ("concurrency", "5️⃣ Multiple Producers:"),
+    ("concurrency", "8️⃣ Parallel Work:"),
+    ("async_await", "3️⃣ Concurrent with join!:"),
+    ("async_await", "4️⃣ Spawn Task:"),
+    ("async_await", "6️⃣ Select (Race):"),
+];
+
+fn snapshot_path(name: &str) -> PathBuf {
+    Path::new(PROJECT_DIR)
+        .join("tests/snapshots")
+        .join(format!("{}.txt", name))
+}
+
+// เวลาที่วัดได้เปลี่ยนทุกครั้ง
+fn normalize_time(output: &str) -> String {
+    output
+        .lines()
+        .map(|line| match line.split_once("Time: ") {
+            Some((before, _)) => format!("{}Time: <elapsed>", before),
+            None => line.to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+// เรียงบรรทัดในหัวข้อที่ลำดับไม่แน่นอน (ใช้ตอนเทียบเท่านั้น snapshot เก็บลำดับจริง)
+fn sort_unordered(name: &str, output: &str) -> String {
+    let headings: Vec<&str> = UNORDERED
+        .iter()
+        .filter(|(example, _)| *example == name)
+        .map(|(_, heading)| *heading)
+        .collect();
+
+    let mut lines: Vec<&str> = Vec::new();
+    let mut section: Option<Vec<&str>> = None;
+    for line in output.lines() {
+        match section.as_mut() {
+            Some(body) if !line.trim().is_empty() => body.push(line),
+            Some(body) => {
+                body.sort_unstable();
+                lines.append(body);
+                section = None;
+                lines.push(line);
+            }
+            None => {
+                lines.push(line);
+                if headings.contains(&line.trim()) {
+                    section = Some(Vec::new());
+                }
+            }
+        }
+    }
+    if let Some(mut body) = section {
+        body.sort_unstable();
+        lines.append(&mut body);
+    }
+    lines.join("\n")
+}
+
+fn check(name: &str) {
+    // snapshot เก็บ output ภาษาไทย (ภาษาของหนังสือ)
+    let run = examples::run(Path::new(PROJECT_DIR), name, Locale::Th, TIMEOUT).unwrap();
+    assert!(
+        run.success(),
+        "{} exited with {}:\n{}",
+        name,
+        run.status,
+        run.stderr
+    );
+    let actual = normalize_time(run.stdout.trim_end());
+
+    let path = snapshot_path(name);
+    let expected = fs::read_to_string(&path).unwrap_or_default();
+    let matches = sort_unordered(name, &actual) == sort_unordered(name, expected.trim_end());
+    if matches {
+        return;
+    }
+
+    if env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(&path, format!("{}\n", actual)).unwrap();
+        eprintln!("📝 updated {}", path.display());
+        return;
+    }
+
+    let first_difference = actual
+        .lines()
+        .zip(expected.lines())
+        .position(|(a, e)| a != e)
+        .map(|index| index + 1)
+        .unwrap_or_else(|| actual.lines().count().min(expected.lines().count()) + 1);
+    panic!(
+        "output of examples/{} does not match {} (first difference at line {})\n\
+         run `UPDATE_SNAPSHOTS=1 cargo test --test examples` if the change is intended\n\n\
+         --- expected ---\n{}\n--- actual ---\n{}\n",
+        name,
+        path.display(),
+        first_difference,
+        expected.trim_end(),
+        actual
+    );
+}
+
+#[test]
+fn hello_world() {
+    check("hello_world");
+}
+
+// ไม่ว่า $LANG ของเครื่องที่รัน test จะเป็นอะไร output ต้องเป็นภาษาที่ขอ
+#[test]
+fn hello_world_in_each_locale() {
+    for (locale, greeting, positive) in [
+        (Locale::Th, "🦀 สวัสดี Rust!", "10 เป็นจำนวนบวก"),
+        (Locale::En, "🦀 Hello, Rust!", "10 is positive"),
+    ] {
+        let run = examples::run(Path::new(PROJECT_DIR), "hello_world", locale, TIMEOUT).unwrap();
+        assert!(run.success(), "{}", run.stderr);
+        assert!(
+            run.stdout.starts_with(greeting),
+            "{}:\n{}",
+            locale,
+            run.stdout
+        );
+        assert!(run.stdout.contains(positive), "{}:\n{}", locale, run.stdout);
+    }
+}
+
+#[test]
+fn ownership() {
+    check("ownership");
+}
+
+#[test]
+fn structs_enums() {
+    check("structs_enums");
+}
+
+#[test]
+fn grid_world() {
+    check("grid_world");
+}
+
+#[test]
+fn collections() {
+    check("collections");
+}
+
+#[test]
+fn calculator() {
+    check("calculator");
+}
+
+#[test]
+fn error_handling() {
+    check("error_handling");
+}
+
+#[test]
+fn generics_traits() {
+    check("generics_traits");
+}
+
+#[test]
+fn iterators() {
+    check("iterators");
+}
+
+#[test]
+fn smart_pointers() {
+    check("smart_pointers");
+}
+
+#[test]
+fn concurrency() {
+    check("concurrency");
+}
+
+#[test]
+fn async_await() {
+    check("async_await");
+}
+
+// ทุก [[example]] ใน Cargo.toml ต้องมี snapshot หรืออยู่ใน SKIPPED
+#[test]
+fn every_example_has_a_snapshot() {
+    let manifest = fs::read_to_string(Path::new(PROJECT_DIR).join("Cargo.toml")).unwrap();
+    let mut in_example = false;
+    let mut names = Vec::new();
+    for line in manifest.lines().map(str::trim) {
+        if line.starts_with('[') {
+            in_example = line == "[[example]]";
+        } else if in_example && let Some(value) = line.strip_prefix("name = ") {
+            names.push(value.trim_matches('"').to_string());
+        }
+    }
+    assert!(names.len() >= 11, "{:?}", names);
+
+    let missing: Vec<&String> = names
+        .iter()
+        .filter(|name| !SKIPPED.iter().any(|(skipped, _)| skipped == name))
+        .filter(|name| !snapshot_path(name).is_file())
+        .collect();
+    assert!(missing.is_empty(), "no snapshot for {:?}", missing);
+}
+
+#[test]
+fn normalizes_time_and_unordered_sections() {
+    assert_eq!(
+        normalize_time("  Time: 201.53ms\n  Results: 1"),
+        "  Time: <elapsed>\n  Results: 1"
+    );
+    let output = "1️⃣ Basic Thread:\n  Spawned 1\n  Main 1\n\n2️⃣ Move Closure:\n  b\n  a";
+    assert_eq!(
+        sort_unordered("concurrency", output),
+        "1️⃣ Basic Thread:\n  Main 1\n  Spawned 1\n\n2️⃣ Move Closure:\n  b\n  a"
+    );
+}
@@ -0,0 +1,363 @@
+// ===========================================
+// Tests สำหรับ rust_tutorial::exercises (parser, program, checker) และ exercises CLI
+// รัน: cargo test --test exercises_check
+// ===========================================
+
+use rust_tutorial::exercises::{
+    Chapter, Checker, ExerciseError, Expectation, Outcome, Place, Snippet, expectation, load_book,
+    program,
+};
+use std::path::Path;
+use std::process::{Command, Output};
+use std::time::Duration;
+
+const EXERCISES_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/docs/src/appendix/exercises");
+
+const SAMPLE: &str = "\
+# แบบฝึกหัด: บทที่ 5 - Ownership
+
+## แบบฝึกหัดที่ 1: Move
+
+```rust,compile_fail,E0382
+fn main() {
+    let s1 = String::from(\"hello\");
+    let s2 = s1;
+    println!(\"{}\", s1);
+}
+```
+
+<details>
+<summary>ดูเฉลย</summary>
+
+```rust
+let s1 = String::from(\"hello\");
+let s2 = s1.clone();
+println!(\"{}\", s1);
+```
+
+```text
+hello
+```
+
+</details>
+
+## แบบฝึกหัดที่ 2: Panic
+
+<details>
+<summary>ดูเฉลย</summary>
+
+```rust,should_panic
+fn main() {
+    let v: Vec<i32> = Vec::new();
+    v[0];
+}
+```
+
+```rust,ignore
+fn main() {
+    let x = ____;
+}
+```
+
+</details>
+";
+
+fn parse(content: &str) -> Result<Chapter, ExerciseError> {
+    Chapter::parse("ch05-exercises.md", content)
+}
+
+fn parse_error_line(content: &str) -> (usize, String) {
+    match parse(content) {
+        Err(ExerciseError::Parse { line, message, .. }) => (line, message),
+        other => panic!("expected parse error, got {:?}", other),
+    }
+}
+
+fn snippet(info: &str, code: &str) -> Snippet {
+    Snippet {
+        place: Place::Solution,
+        info: info.to_string(),
+        line: 1,
+        code: code.to_string(),
+    }
+}
+
+fn checker() -> (tempfile::TempDir, Checker) {
+    let dir = tempfile::tempdir().unwrap();
+    let checker = Checker::new(dir.path().join("work"));
+    (dir, checker)
+}
+
+fn reason(outcome: &Outcome) -> &str {
+    match outcome {
+        Outcome::Failed { reason, .. } => reason,
+        other => panic!("expected a failure, got {:?}", other),
+    }
+}
+
+#[test]
+fn parses_every_chapter_in_the_book() {
+    let book = load_book(EXERCISES_DIR).unwrap();
+
+    assert_eq!(book.len(), 20);
+    for (i, chapter) in book.iter().enumerate() {
+        assert_eq!(chapter.chapter, i as u32 + 1);
+        let numbers: Vec<u32> = chapter.exercises.iter().map(|e| e.number).collect();
+        assert_eq!(numbers, [1, 2, 3, 4, 5], "{}", chapter.path.display());
+    }
+
+    // ch05 ข้อ 1: โจทย์ compile_fail + เฉลยสองวิธี
+    let first = &book[4].exercises[0];
+    let places: Vec<Place> = first.snippets.iter().map(|s| s.place).collect();
+    assert_eq!(places, [Place::Problem, Place::Solution, Place::Solution]);
+    assert!(first.snippets[0].attributes().compile_fail);
+}
+
+#[test]
+fn parses_sample() {
+    let chapter = parse(SAMPLE).unwrap();
+    assert_eq!(chapter.chapter, 5);
+    assert_eq!(chapter.title, "Ownership");
+    assert_eq!(chapter.exercises.len(), 2);
+    assert_eq!(chapter.snippet_count(), 5);
+
+    let first = &chapter.exercises[0];
+    assert_eq!(first.number, 1);
+    assert_eq!(first.title, "Move");
+    assert_eq!(first.line, 3);
+
+    let problem = &first.snippets[0];
+    assert_eq!(problem.place, Place::Problem);
+    assert_eq!(problem.line, 6);
+    assert!(problem.code.starts_with("fn main() {"));
+    let attributes = problem.attributes();
+    assert!(attributes.compile_fail);
+    assert_eq!(attributes.error_codes, ["E0382"]);
+
+    let text = &first.snippets[2];
+    assert_eq!(text.place, Place::Solution);
+    assert!(!text.is_rust());
+
+    let second = &chapter.exercises[1];
+    assert!(second.snippets[0].attributes().should_panic);
+    assert!(second.snippets[1].attributes().ignore);
+}
+
+#[test]
+fn annotations_decide_what_to_check() {
+    let check = |info| expectation(&snippet(info, ""), false);
+    assert_eq!(check("rust"), Some(Expectation::Run));
+    assert_eq!(check("rust,compile_fail"), Some(Expectation::CompileFail));
+    assert_eq!(check("rust,no_run"), Some(Expectation::Compile));
+    assert_eq!(check("rust,should_panic"), Some(Expectation::Panic));
+    assert_eq!(check("rust,ignore"), None);
+
+    assert_eq!(
+        expectation(&snippet("rust,ignore", ""), true),
+        Some(Expectation::Run)
+    );
+    assert_eq!(
+        snippet("rust,edition2021", "")
+            .attributes()
+            .edition
+            .as_deref(),
+        Some("2021")
+    );
+}
+
+#[test]
+fn malformed_files_report_line() {
+    let (line, message) = parse_error_line("# แบบฝึกหัด\n");
+    assert_eq!(line, 1);
+    assert!(message.contains("# แบบฝึกหัด: บทที่ N - Title"));
+
+    let (line, message) =
+        parse_error_line(&SAMPLE.replace("</details>\n\n## แบบฝึกหัดที่ 2", "## แบบฝึกหัดที่ 2"));
+    assert_eq!(line, 13);
+    assert_eq!(message, "missing </details>");
+
+    let (line, message) = parse_error_line(&format!("{}\n```rust\nfn main() {{}}\n", SAMPLE));
+    assert_eq!(line, 48);
+    assert!(message.contains("never closed"), "{}", message);
+
+    let (line, message) =
+        parse_error_line(&SAMPLE.replace("## แบบฝึกหัดที่ 2: Panic", "## แบบฝึกหัดที่ สอง"));
+    assert_eq!(line, 28);
+    assert!(message.contains("## แบบฝึกหัดที่ N: Title"));
+}
+
+#[test]
+fn program_wraps_statements_in_main() {
+    let wrapped = program("let x = 1;\nprintln!(\"{}\", x);");
+    assert_eq!(
+        wrapped,
+        "#![allow(unused)]\nfn main() {\nlet x = 1;\nprintln!(\"{}\", x);\n}\n"
+    );
+
+    // มี fn main อยู่แล้ว และบรรทัดที่ซ่อนไว้ใน mdBook
+    let full = program("#![allow(dead_code)]\n# use std::fmt;\nfn main() {}");
+    assert_eq!(
+        full,
+        "#![allow(unused)]\n#![allow(dead_code)]\nuse std::fmt;\nfn main() {}\n"
+    );
+}
+
+#[test]
+fn compile_fail_must_fail_with_the_right_error() {
+    let (_dir, checker) = checker();
+    let chapter = parse(SAMPLE).unwrap();
+    let problem = &chapter.exercises[0].snippets[0];
+
+    let outcome = checker.check(problem, Expectation::CompileFail).unwrap();
+    assert_eq!(outcome, Outcome::Passed);
+
+    let wrong_code = snippet("rust,compile_fail,E0308", &problem.code);
+    let outcome = checker
+        .check(&wrong_code, Expectation::CompileFail)
+        .unwrap();
+    assert_eq!(reason(&outcome), "failed to compile, but without E0308");
+
+    let compiles = snippet("rust,compile_fail", "fn main() {}");
+    let outcome = checker.check(&compiles, Expectation::CompileFail).unwrap();
+    assert_eq!(reason(&outcome), "compiled, but it should not");
+}
+
+#[test]
+fn solutions_must_compile_and_run() {
+    let (_dir, checker) = checker();
+
+    let broken = snippet("rust", "let x: i32 = \"five\";");
+    match checker.check(&broken, Expectation::Run).unwrap() {
+        Outcome::Failed { reason, output } => {
+            assert_eq!(reason, "does not compile");
+            assert!(output.contains("error[E0308]"), "{}", output);
+        }
+        other => panic!("expected a failure, got {:?}", other),
+    }
+
+    let panics = snippet("rust", "panic!(\"boom\");");
+    match checker.check(&panics, Expectation::Run).unwrap() {
+        Outcome::Failed { reason, output } => {
+            assert!(reason.starts_with("exited with"), "{}", reason);
+            assert!(output.contains("boom"), "{}", output);
+        }
+        other => panic!("expected a failure, got {:?}", other),
+    }
+    assert_eq!(
+        checker.check(&panics, Expectation::Panic).unwrap(),
+        Outcome::Passed
+    );
+
+    let fine = snippet("rust", "println!(\"hi\");");
+    let outcome = checker.check(&fine, Expectation::Panic).unwrap();
+    assert_eq!(reason(&outcome), "ran to the end, but it should panic");
+}
+
+#[test]
+fn programs_that_never_finish_time_out() {
+    let (_dir, mut checker) = checker();
+    checker.timeout = Duration::from_secs(1);
+
+    let forever = snippet(
+        "rust",
+        "loop { std::thread::sleep(std::time::Duration::from_millis(50)); }",
+    );
+    let outcome = checker.check(&forever, Expectation::Run).unwrap();
+    assert_eq!(reason(&outcome), "still running after 1s");
+
+    // no_run ไม่รัน จึงไม่หมดเวลา
+    assert_eq!(
+        checker.check(&forever, Expectation::Compile).unwrap(),
+        Outcome::Passed
+    );
+}
+
+#[test]
+fn chapter_report_counts_results() {
+    let (_dir, checker) = checker();
+    let chapter = parse(SAMPLE).unwrap();
+    let report = checker.check_chapter(&chapter).unwrap();
+
+    // text block ไม่นับ
+    assert_eq!(report.results.len(), 4);
+    assert_eq!(
+        (report.passed(), report.failed(), report.ignored()),
+        (3, 0, 1)
+    );
+    assert!(report.is_ok());
+    assert_eq!(report.results[1].place, Place::Solution);
+    assert_eq!(report.results[1].line, 17);
+}
+
+// -------------------------------------------
+// CLI
+// -------------------------------------------
+
+fn exercises(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_exercises"))
+        .args(args)
+        .env("EXERCISES_DIR", dir.join("exercises"))
+        .env("EXERCISES_WORK_DIR", dir.join("work"))
+        .output()
+        .expect("failed to run exercises binary")
+}
+
+fn fixture(content: &str) -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::create_dir(dir.path().join("exercises")).unwrap();
+    std::fs::write(dir.path().join("exercises/ch05-exercises.md"), content).unwrap();
+    dir
+}
+
+#[test]
+fn cli_reports_per_chapter() {
+    let dir = fixture(SAMPLE);
+
+    let output = exercises(dir.path(), &["check"]);
+    let out = String::from_utf8_lossy(&output.stdout);
+    assert!(output.status.success(), "{}", out);
+    assert!(out.contains("📘 บทที่ 5 - Ownership"));
+    assert!(out.contains("  ✅ 5.1 problem  fails to compile"));
+    assert!(out.contains("  ✅ 5.2 solution compiles and panics"));
+    assert!(out.contains("   3 passed, 0 failed, 1 ignored"));
+    assert!(out.contains("🏁 1 chapters: 3 passed, 0 failed, 1 ignored"));
+
+    let list = exercises(dir.path(), &["list"]);
+    assert_eq!(
+        String::from_utf8_lossy(&list.stdout),
+        " 5. Ownership                    2 exercises  5 snippets\n"
+    );
+}
+
+#[test]
+fn cli_fails_when_a_solution_rots() {
+    let dir = fixture(&SAMPLE.replace("s1.clone()", "s1.clone_it()"));
+
+    let output = exercises(dir.path(), &["check", "5"]);
+    let out = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        out.contains(
+            "  ❌ 5.1 solution should compile and run: does not compile (ch05-exercises.md:17)"
+        ),
+        "{}",
+        out
+    );
+    assert!(out.contains("     │ error[E0599]"), "{}", out);
+    assert!(String::from_utf8_lossy(&output.stderr).contains("1 snippet(s) failed"));
+}
+
+#[test]
+fn cli_rejects_unknown_chapter_and_bad_arguments() {
+    let dir = fixture(SAMPLE);
+
+    let output = exercises(dir.path(), &["check", "9"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No exercises for chapter 9"));
+
+    for args in [&["frobnicate"][..], &["check", "five"]] {
+        let output = exercises(dir.path(), args);
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        assert!(String::from_utf8_lossy(&output.stderr).contains("Usage: exercises"));
+    }
+}
@@ -0,0 +1,266 @@
+// ===========================================
+// Tests สำหรับ rust_tutorial::geometry (Rect, Circle, Polygon, packing)
+// รัน: cargo test --test geometry
+// ===========================================
+
+use proptest::prelude::*;
+use rust_tutorial::geometry::{Circle, GeometryError, Point, Polygon, Rect, Shape, Size, pack};
+use std::f64::consts::PI;
+
+fn close(a: f64, b: f64) -> bool {
+    (a - b).abs() < 1e-9
+}
+
+fn square() -> Polygon {
+    Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(4.0, 0.0),
+        Point::new(4.0, 4.0),
+        Point::new(0.0, 4.0),
+    ])
+    .unwrap()
+}
+
+#[test]
+fn point_distance_matches_chapter_10() {
+    assert_eq!(Point::new(3.0, 4.0).distance_from_origin(), 5.0);
+    assert_eq!(Point::new(1.0, 1.0).distance_to(Point::new(4.0, 5.0)), 5.0);
+}
+
+#[test]
+fn rect_normalizes_negative_size() {
+    assert_eq!(
+        Rect::new(10.0, 0.0, -4.0, 2.0),
+        Rect::new(6.0, 0.0, 4.0, 2.0)
+    );
+    assert_eq!(
+        Rect::from_corners(Point::new(5.0, 5.0), Point::new(1.0, 2.0)),
+        Rect::new(1.0, 2.0, 4.0, 3.0)
+    );
+}
+
+#[test]
+fn rect_intersection_and_union() {
+    let a = Rect::new(0.0, 0.0, 10.0, 10.0);
+    let b = Rect::new(5.0, 5.0, 10.0, 10.0);
+    assert_eq!(a.intersection(&b), Some(Rect::new(5.0, 5.0, 5.0, 5.0)));
+    assert_eq!(a.union(&b), Rect::new(0.0, 0.0, 15.0, 15.0));
+
+    // แค่ขอบแตะกันไม่นับว่าทับ
+    let touching = Rect::new(10.0, 0.0, 5.0, 5.0);
+    assert_eq!(a.intersection(&touching), None);
+    assert!(!a.intersects(&touching));
+}
+
+#[test]
+fn rect_containment() {
+    let outer = Rect::new(0.0, 0.0, 10.0, 10.0);
+    assert!(outer.contains_rect(&Rect::new(2.0, 2.0, 3.0, 3.0)));
+    assert!(outer.contains_rect(&outer));
+    assert!(!outer.contains_rect(&Rect::new(8.0, 8.0, 3.0, 3.0)));
+
+    assert!(outer.contains(Point::new(10.0, 0.0)));
+    assert!(!outer.contains(Point::new(10.1, 5.0)));
+
+    // can_hold แบบบทที่ 5: ต้องใหญ่กว่าจริงทั้งสองด้าน ไม่สนตำแหน่ง
+    assert!(outer.can_hold(&Rect::new(50.0, 50.0, 5.0, 5.0)));
+    assert!(!outer.can_hold(&outer));
+}
+
+#[test]
+fn circle_shape() {
+    let circle = Circle::new(Point::new(1.0, 2.0), 2.0).unwrap();
+    assert!(close(circle.area(), 4.0 * PI));
+    assert!(close(circle.perimeter(), 4.0 * PI));
+    assert_eq!(circle.bounding_box(), Rect::new(-1.0, 0.0, 4.0, 4.0));
+    assert!(circle.contains(Point::new(3.0, 2.0)));
+    assert!(!circle.contains(Point::new(3.0, 4.0)));
+
+    assert_eq!(
+        Circle::new(Point::ORIGIN, -1.0),
+        Err(GeometryError::Negative {
+            what: "radius",
+            value: -1.0
+        })
+    );
+    assert!(Circle::new(Point::ORIGIN, f64::NAN).is_err());
+}
+
+#[test]
+fn polygon_shape() {
+    let square = square();
+    assert_eq!(square.area(), 16.0);
+    assert_eq!(square.perimeter(), 16.0);
+    assert_eq!(square.bounding_box(), Rect::new(0.0, 0.0, 4.0, 4.0));
+
+    // สามเหลี่ยมเรียงตามเข็มก็ได้พื้นที่บวก
+    let triangle = Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(0.0, 3.0),
+        Point::new(4.0, 0.0),
+    ])
+    .unwrap();
+    assert_eq!(triangle.area(), 6.0);
+    assert_eq!(triangle.perimeter(), 12.0);
+
+    assert_eq!(
+        Polygon::new(vec![Point::ORIGIN, Point::new(1.0, 1.0)]),
+        Err(GeometryError::TooFewPoints(2))
+    );
+}
+
+#[test]
+fn polygon_contains_concave() {
+    // รูปตัว L
+    let l_shape = Polygon::new(vec![
+        Point::new(0.0, 0.0),
+        Point::new(2.0, 0.0),
+        Point::new(2.0, 2.0),
+        Point::new(4.0, 2.0),
+        Point::new(4.0, 4.0),
+        Point::new(0.0, 4.0),
+    ])
+    .unwrap();
+    assert_eq!(l_shape.area(), 12.0);
+    assert!(l_shape.contains(Point::new(1.0, 1.0)));
+    assert!(l_shape.contains(Point::new(3.0, 3.0)));
+    assert!(!l_shape.contains(Point::new(3.0, 1.0)));
+    // บนขอบ / มุม
+    assert!(l_shape.contains(Point::new(2.0, 1.0)));
+    assert!(l_shape.contains(Point::new(4.0, 4.0)));
+}
+
+#[test]
+fn shapes_work_as_trait_objects() {
+    let shapes: Vec<Box<dyn Shape>> = vec![
+        Box::new(Rect::new(0.0, 0.0, 2.0, 3.0)),
+        Box::new(Circle::new(Point::ORIGIN, 1.0).unwrap()),
+        Box::new(square()),
+    ];
+    let total: f64 = shapes.iter().map(|shape| shape.area()).sum();
+    assert!(close(total, 6.0 + PI + 16.0));
+}
+
+#[test]
+fn pack_into_shelves() {
+    let items = [
+        Size::new(4.0, 2.0),
+        Size::new(6.0, 5.0),
+        Size::new(5.0, 3.0),
+        Size::new(3.0, 3.0),
+    ];
+    let packing = pack(10.0, &items).unwrap();
+    // สูงสุดวางก่อน: 6x5 แล้ว 5x3 ไม่พอ เปิดชั้นใหม่ที่ y = 5
+    // 3x3 กลับไปลงที่ว่างในชั้นแรก ส่วน 4x2 ชั้นแรกไม่พอแล้วจึงลงชั้นที่สอง
+    assert_eq!(
+        packing.placements,
+        vec![
+            Rect::new(5.0, 5.0, 4.0, 2.0),
+            Rect::new(0.0, 0.0, 6.0, 5.0),
+            Rect::new(0.0, 5.0, 5.0, 3.0),
+            Rect::new(6.0, 0.0, 3.0, 3.0),
+        ]
+    );
+    assert_eq!(packing.height, 8.0);
+    assert_eq!(packing.bounds(), Rect::new(0.0, 0.0, 10.0, 8.0));
+}
+
+#[test]
+fn pack_rejects_items_wider_than_bin() {
+    assert_eq!(
+        pack(5.0, &[Size::new(2.0, 2.0), Size::new(6.0, 1.0)]),
+        Err(GeometryError::TooWide {
+            index: 1,
+            width: 6.0,
+            bin_width: 5.0
+        })
+    );
+    assert!(pack(5.0, &[Size::new(-1.0, 1.0)]).is_err());
+
+    let empty = pack(5.0, &[]).unwrap();
+    assert_eq!(empty.height, 0.0);
+    assert_eq!(empty.fill_ratio(), 0.0);
+}
+
+#[test]
+fn deserialized_shapes_are_validated() {
+    let error = serde_json::from_str::<Polygon>(r#"{"points":[]}"#).unwrap_err();
+    assert!(error.to_string().contains("at least 3 points"), "{}", error);
+
+    let error =
+        serde_json::from_str::<Circle>(r#"{"center":{"x":0,"y":0},"radius":-1}"#).unwrap_err();
+    assert!(
+        error.to_string().contains("must not be negative"),
+        "{}",
+        error
+    );
+
+    // ผ่าน Rect::new จึงกลับด้านความกว้างติดลบเหมือนกัน
+    let rect: Rect = serde_json::from_str(r#"{"x":10,"y":0,"width":-4,"height":2}"#).unwrap();
+    assert_eq!(rect, Rect::new(6.0, 0.0, 4.0, 2.0));
+
+    let json = serde_json::to_string(&square()).unwrap();
+    assert_eq!(serde_json::from_str::<Polygon>(&json).unwrap(), square());
+}
+
+fn rect_strategy() -> impl Strategy<Value = Rect> {
+    (-50i32..50, -50i32..50, 0i32..40, 0i32..40)
+        .prop_map(|(x, y, w, h)| Rect::new(x as f64, y as f64, w as f64, h as f64))
+}
+
+proptest! {
+    #[test]
+    fn intersection_is_inside_both(a in rect_strategy(), b in rect_strategy()) {
+        match a.intersection(&b) {
+            Some(i) => {
+                prop_assert!(a.contains_rect(&i) && b.contains_rect(&i));
+                prop_assert!(i.area() > 0.0);
+                prop_assert_eq!(b.intersection(&a), Some(i));
+            }
+            None => prop_assert!(!b.intersects(&a)),
+        }
+    }
+
+    #[test]
+    fn union_contains_both(a in rect_strategy(), b in rect_strategy()) {
+        let u = a.union(&b);
+        prop_assert!(u.contains_rect(&a) && u.contains_rect(&b));
+        prop_assert_eq!(u, b.union(&a));
+    }
+
+    #[test]
+    fn rect_as_polygon_agrees(r in rect_strategy(), px in -60i32..100, py in -60i32..100) {
+        prop_assume!(!r.is_empty());
+        let polygon = Polygon::new(vec![
+            r.min(),
+            Point::new(r.max().x, r.y),
+            r.max(),
+            Point::new(r.x, r.max().y),
+        ]).unwrap();
+        let point = Point::new(px as f64, py as f64);
+        prop_assert_eq!(polygon.area(), r.area());
+        prop_assert_eq!(polygon.perimeter(), r.perimeter());
+        prop_assert_eq!(polygon.bounding_box(), r);
+        prop_assert_eq!(polygon.contains(point), r.contains(point));
+    }
+
+    #[test]
+    fn packed_items_never_overlap(
+        sizes in prop::collection::vec((1u8..20, 1u8..20), 0..30)
+    ) {
+        let items: Vec<Size> = sizes
+            .iter()
+            .map(|&(w, h)| Size::new(w as f64, h as f64))
+            .collect();
+        let packing = pack(20.0, &items).unwrap();
+        let bounds = packing.bounds();
+        for (i, placed) in packing.placements.iter().enumerate() {
+            prop_assert_eq!(placed.size(), items[i]);
+            prop_assert!(bounds.contains_rect(placed));
+            for other in &packing.placements[i + 1..] {
+                prop_assert!(!placed.intersects(other));
+            }
+        }
+        prop_assert!(packing.fill_ratio() <= 1.0);
+    }
+}
@@ -0,0 +1,232 @@
+// ===========================================
+// Tests สำหรับ rust_tutorial::grid (แผนที่, การเดิน, หาเส้นทาง, render)
+// รัน: cargo test --test grid
+// ===========================================
+
+use proptest::prelude::*;
+use rust_tutorial::grid::{
+    self, Blocked, Direction, Grid, GridError, Position, Step, World, astar, bfs,
+};
+
+const MAZE: &str = "
+    #######
+    #@..#.#
+    #.#...#
+    #...#.#
+    #######
+";
+
+fn maze() -> World {
+    World::parse(MAZE).unwrap()
+}
+
+#[test]
+fn parses_ascii_maps() {
+    let world = maze();
+    assert_eq!(world.player(), Position::new(1, 1));
+    assert_eq!((world.grid.width(), world.grid.height()), (7, 5));
+    assert!(world.grid.is_wall(Position::new(4, 1)));
+    assert!(world.grid.is_walkable(Position::new(5, 1)));
+    assert!(!world.grid.is_walkable(Position::new(-1, 1)));
+    // render ได้แผนที่เดิม
+    assert_eq!(
+        world.render(),
+        "#######\n#@..#.#\n#.#...#\n#...#.#\n#######\n"
+    );
+}
+
+#[test]
+fn map_errors_point_at_the_problem() {
+    let cases = [
+        ("\n  \n", GridError::Empty),
+        (
+            "###\n#.\n###",
+            GridError::Ragged {
+                line: 2,
+                expected: 3,
+                found: 2,
+            },
+        ),
+        (
+            "#?#",
+            GridError::Cell {
+                line: 1,
+                column: 2,
+                cell: '?',
+            },
+        ),
+        (
+            "@.\n.@",
+            GridError::SecondPlayer {
+                line: 2,
+                first: Position::new(0, 0),
+            },
+        ),
+        // บรรทัดว่างข้างบนนับด้วย (แผนที่แบบย่อหน้าในโค้ด)
+        (
+            "\n    ###\n    #.\n",
+            GridError::Ragged {
+                line: 3,
+                expected: 3,
+                found: 2,
+            },
+        ),
+        (
+            "\n\n    #?#\n",
+            GridError::Cell {
+                line: 3,
+                column: 2,
+                cell: '?',
+            },
+        ),
+        (
+            "\n  @.\n  .@",
+            GridError::SecondPlayer {
+                line: 3,
+                first: Position::new(0, 0),
+            },
+        ),
+    ];
+    for (map, expected) in cases {
+        assert_eq!(Grid::parse(map).unwrap_err(), expected, "{:?}", map);
+    }
+    assert_eq!(World::parse("...").unwrap_err(), GridError::NoPlayer);
+    assert_eq!(
+        World::new(Grid::parse("#.").unwrap().0, Position::new(0, 0)).unwrap_err(),
+        GridError::NotWalkable(Position::new(0, 0))
+    );
+    assert_eq!(
+        GridError::Ragged {
+            line: 2,
+            expected: 3,
+            found: 2
+        }
+        .to_string(),
+        "line 2: expected 3 cells like the first line, found 2"
+    );
+}
+
+#[test]
+fn steps_collide_with_walls_and_bounds() {
+    let mut world = maze();
+    assert_eq!(
+        world.step(Direction::North),
+        Step::Blocked(Blocked::Wall(Position::new(1, 0)))
+    );
+    assert_eq!(
+        world.step(Direction::East),
+        Step::Moved(Position::new(2, 1))
+    );
+    assert_eq!(world.player(), Position::new(2, 1));
+
+    let mut open = World::new(Grid::new(2, 1), Position::new(0, 0)).unwrap();
+    assert_eq!(
+        open.step(Direction::West),
+        Step::Blocked(Blocked::OutOfBounds(Position::new(-1, 0)))
+    );
+    assert_eq!(open.player(), Position::new(0, 0));
+}
+
+#[test]
+fn walk_skips_blocked_steps() {
+    let mut world = maze();
+    let moves = Direction::parse_moves("E E E S S").unwrap();
+    let walk = world.walk(&moves);
+    assert_eq!(walk.end, Position::new(3, 3));
+    assert_eq!(walk.moved, 4);
+    assert_eq!(
+        walk.blocked,
+        [(2, Direction::East, Blocked::Wall(Position::new(4, 1)))]
+    );
+}
+
+#[test]
+fn parses_move_lists() {
+    use Direction::*;
+    assert_eq!(
+        Direction::parse_moves("NnEsW").unwrap(),
+        [North, North, East, South, West]
+    );
+    assert_eq!(
+        Direction::parse_moves("north, east up").unwrap(),
+        [North, East, North]
+    );
+    assert_eq!(Direction::parse_moves("").unwrap(), []);
+    assert_eq!(
+        Direction::parse_moves("N X").unwrap_err(),
+        GridError::Direction("X".to_string())
+    );
+    assert_eq!("West".parse::<Direction>().unwrap().opposite(), East);
+}
+
+#[test]
+fn finds_shortest_paths() {
+    let world = maze();
+    let start = world.player();
+    let goal = Position::new(5, 1);
+    let path = bfs(&world.grid, start, goal).unwrap();
+    assert_eq!(path.len(), 6);
+    assert_eq!(astar(&world.grid, start, goal).unwrap().len(), 6);
+
+    assert_eq!(bfs(&world.grid, start, start), Some(vec![]));
+    // ปลายทางเป็นกำแพง / ไปไม่ถึง
+    assert_eq!(bfs(&world.grid, start, Position::new(0, 0)), None);
+    let (walled, _) = Grid::parse(".#.").unwrap();
+    assert_eq!(bfs(&walled, Position::new(0, 0), Position::new(2, 0)), None);
+    assert_eq!(
+        astar(&walled, Position::new(0, 0), Position::new(2, 0)),
+        None
+    );
+}
+
+#[test]
+fn renders_paths_with_arrows() {
+    let world = maze();
+    let path = bfs(&world.grid, world.player(), Position::new(5, 1)).unwrap();
+    assert_eq!(
+        grid::render_path(&world.grid, world.player(), &path),
+        "#######\n#@→↓#X#\n#.#→→↑#\n#...#.#\n#######\n"
+    );
+}
+
+// -------------------------------------------
+// property tests: แผนที่สุ่ม
+// -------------------------------------------
+
+fn any_grid() -> impl Strategy<Value = Grid> {
+    (2u16..8, 2u16..8).prop_flat_map(|(width, height)| {
+        proptest::collection::vec(proptest::bool::weighted(0.3), usize::from(width * height))
+            .prop_map(move |walls| {
+                let mut grid = Grid::new(width, height);
+                for (index, wall) in walls.into_iter().enumerate() {
+                    let x = (index % usize::from(width)) as i32;
+                    let y = (index / usize::from(width)) as i32;
+                    grid.set_wall(Position::new(x, y), wall);
+                }
+                grid
+            })
+    })
+}
+
+proptest! {
+    #[test]
+    fn astar_and_bfs_agree_and_paths_are_walkable(
+        grid in any_grid(),
+        start in (0i32..8, 0i32..8),
+        goal in (0i32..8, 0i32..8),
+    ) {
+        let start = Position::new(start.0, start.1);
+        let goal = Position::new(goal.0, goal.1);
+        let by_bfs = bfs(&grid, start, goal);
+        let by_astar = astar(&grid, start, goal);
+        prop_assert_eq!(by_bfs.as_ref().map(Vec::len), by_astar.as_ref().map(Vec::len));
+
+        if let Some(path) = by_astar {
+            prop_assert!(path.len() as u32 >= start.manhattan(goal));
+            let mut world = World::new(grid, start).unwrap();
+            let walk = world.walk(&path);
+            prop_assert!(walk.blocked.is_empty());
+            prop_assert_eq!(walk.end, goal);
+        }
+    }
+}
@@ -0,0 +1,164 @@
+// ===========================================
+// Tests สำหรับ rust_tutorial::i18n (catalog, t!, การเลือกภาษา)
+// รัน: cargo test --test i18n
+// ===========================================
+
+use rust_tutorial::i18n::{self, Catalog, I18nError, Locale, Message, Plural, detect, interpolate};
+use rust_tutorial::t;
+use std::collections::BTreeSet;
+
+fn args(values: &[&str]) -> Vec<String> {
+    values.iter().map(|s| s.to_string()).collect()
+}
+
+// key ต้องครบทุกภาษา ไม่อย่างนั้น workshop ภาษานั้นจะเห็นชื่อ key แทนข้อความ
+#[test]
+fn every_key_exists_in_every_catalog() {
+    let catalogs = Catalog::embedded().unwrap();
+    assert_eq!(catalogs.len(), Locale::ALL.len());
+
+    let all_keys: BTreeSet<&str> = catalogs.iter().flat_map(Catalog::keys).collect();
+    assert!(!all_keys.is_empty());
+    for catalog in &catalogs {
+        let keys: BTreeSet<&str> = catalog.keys().collect();
+        let missing: Vec<&&str> = all_keys.difference(&keys).collect();
+        assert!(
+            missing.is_empty(),
+            "locales/{}.json is missing {:?}",
+            catalog.locale,
+            missing
+        );
+    }
+}
+
+// {count} ไม่นับ เพราะรูป "one" อาจไม่ใช้ (เช่น "Found 1 file")
+fn named_placeholders(message: &Message) -> BTreeSet<&str> {
+    let mut names = message.placeholders();
+    names.remove("count");
+    names
+}
+
+#[test]
+fn every_translation_uses_the_same_placeholders() {
+    let catalogs = Catalog::embedded().unwrap();
+    let (first, rest) = catalogs.split_first().unwrap();
+    for key in first.keys() {
+        let expected = named_placeholders(first.message(key).unwrap());
+        for catalog in rest {
+            let actual = named_placeholders(catalog.message(key).unwrap());
+            assert_eq!(
+                actual, expected,
+                "'{}' in locales/{}.json",
+                key, catalog.locale
+            );
+        }
+    }
+}
+
+#[test]
+fn translates_with_named_arguments_and_plurals() {
+    let en = Catalog::parse(
+        Locale::En,
+        r#"{"files.found": {"one": "Found 1 file", "other": "Found {count} files"}}"#,
+    )
+    .unwrap();
+    let th = Catalog::parse(
+        Locale::Th,
+        r#"{"files.found": {"other": "พบ {count} ไฟล์"}}"#,
+    )
+    .unwrap();
+    let translate =
+        |catalog: &Catalog, count: i32| catalog.translate("files.found", &[("count", &count)]);
+    assert_eq!(translate(&en, 1), "Found 1 file");
+    assert_eq!(translate(&en, 5), "Found 5 files");
+    assert_eq!(translate(&en, 0), "Found 0 files");
+    assert_eq!(translate(&th, 1), "พบ 1 ไฟล์");
+
+    assert_eq!(
+        i18n::translate(Locale::Th, "hello.positive", &[("n", &10)]),
+        "10 เป็นจำนวนบวก"
+    );
+    // key ที่ไม่มีคืนชื่อ key
+    assert_eq!(
+        i18n::translate(Locale::En, "no.such.key", &[]),
+        "no.such.key"
+    );
+}
+
+#[test]
+fn macro_uses_the_current_locale() {
+    // test เดียวที่เปลี่ยนภาษาปัจจุบัน (test อื่นระบุ locale เอง)
+    i18n::set_locale(Locale::En);
+    assert_eq!(i18n::locale(), Locale::En);
+    assert_eq!(t!("hello.negative", n = -3), "-3 is negative");
+    assert_eq!(t!("hello.positive", n = 2,), "2 is positive");
+
+    i18n::set_locale(Locale::Th);
+    assert_eq!(t!("hello.zero", n = 0), "0 เป็นศูนย์");
+    assert_eq!(t!("hello.variables"), "📝 ตัวอย่างตัวแปร:");
+}
+
+#[test]
+fn interpolates_named_placeholders() {
+    assert_eq!(
+        interpolate("{name} is {age}", &[("name", &"Ferris"), ("age", &7)]),
+        "Ferris is 7"
+    );
+    // {{ }} เป็นวงเล็บจริง, ชื่อที่ไม่ได้ส่งมาคงไว้
+    assert_eq!(interpolate("{{x}} {missing}", &[]), "{x} {missing}");
+    assert_eq!(interpolate("open { and }", &[]), "open { and }");
+}
+
+#[test]
+fn plural_rules() {
+    assert_eq!(Locale::En.plural(1.0), Plural::One);
+    assert_eq!(Locale::En.plural(2.0), Plural::Other);
+    assert_eq!(Locale::En.plural(1.5), Plural::Other);
+    assert_eq!(Locale::Th.plural(1.0), Plural::Other);
+}
+
+#[test]
+fn detects_locale_from_flag_then_lang() {
+    assert_eq!(detect(&[], None).unwrap(), Locale::Th);
+    assert_eq!(detect(&[], Some("en_US.UTF-8")).unwrap(), Locale::En);
+    assert_eq!(detect(&[], Some("th_TH.UTF-8")).unwrap(), Locale::Th);
+    assert_eq!(detect(&[], Some("C.UTF-8")).unwrap(), Locale::Th);
+
+    let lang = Some("th_TH.UTF-8");
+    assert_eq!(detect(&args(&["--lang", "en"]), lang).unwrap(), Locale::En);
+    assert_eq!(
+        detect(&args(&["-v", "--lang=EN"]), lang).unwrap(),
+        Locale::En
+    );
+    assert!(matches!(
+        detect(&args(&["--lang", "fr"]), lang),
+        Err(I18nError::UnknownLocale(code)) if code == "fr"
+    ));
+    assert!(detect(&args(&["--lang"]), lang).is_err());
+}
+
+#[test]
+fn rejects_malformed_catalogs() {
+    let plural = Catalog::parse(Locale::En, r#"{"a": {"one": "x", "other": "y"}}"#).unwrap();
+    assert_eq!(
+        plural.message("a"),
+        Some(&Message::Plural {
+            one: Some("x".to_string()),
+            other: "y".to_string()
+        })
+    );
+
+    for (json, expected) in [
+        (r#"{"a": {"one": "x"}}"#, "'a' needs an \"other\" text"),
+        (
+            r#"{"a": {"few": "x", "other": "y"}}"#,
+            "unknown plural form 'few'",
+        ),
+        (r#"{"a": 1}"#, "must be a string"),
+        ("{", "not valid JSON"),
+    ] {
+        let error = Catalog::parse(Locale::En, json).unwrap_err().to_string();
+        assert!(error.contains(expected), "{}: {}", json, error);
+        assert!(error.contains("locales/en.json"), "{}", error);
+    }
+}
@@ -0,0 +1,275 @@
+// ===========================================
+// Tests สำหรับ rust_tutorial::protocol (Lobby, Server, Connection) และ messages CLI
+// รัน: cargo test --test messages
+// ===========================================
+
+use rust_tutorial::protocol::{Connection, Lobby, Message, Outcome, Server, frame};
+use std::io::{BufRead, BufReader, Write};
+use std::net::SocketAddr;
+use std::process::{Command, Stdio};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::AsyncWriteExt;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::time::timeout;
+
+const WAIT: Duration = Duration::from_secs(5);
+
+fn write(text: &str) -> Message {
+    Message::Write(text.to_string())
+}
+
+async fn start_server() -> (Arc<Server>, SocketAddr) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let server = Arc::new(Server::new());
+    tokio::spawn(server.clone().run(listener));
+    (server, addr)
+}
+
+async fn recv(connection: &mut Connection) -> Message {
+    timeout(WAIT, connection.recv())
+        .await
+        .expect("no message from server")
+        .unwrap()
+        .expect("server closed the connection")
+}
+
+// รับข้อความจนเจอบรรทัดที่ขึ้นต้นด้วย expected (ข้าม update อื่นที่มาก่อน)
+async fn recv_until(connection: &mut Connection, expected: &str) -> String {
+    loop {
+        if let Message::Write(text) = recv(connection).await
+            && text.starts_with(expected)
+        {
+            return text;
+        }
+    }
+}
+
+async fn join(addr: SocketAddr) -> Connection {
+    let mut connection = Connection::connect(addr).await.unwrap();
+    recv_until(&mut connection, "welcome").await;
+    connection
+}
+
+// -------------------------------------------
+// Lobby
+// -------------------------------------------
+
+#[test]
+fn lobby_keeps_state_per_client() {
+    let mut lobby = Lobby::new();
+    let (one, welcome) = lobby.join();
+    let (two, _) = lobby.join();
+    assert_eq!(
+        welcome.reply.as_deref(),
+        Some("welcome, you are client 1 (1 online)")
+    );
+
+    let moved = lobby.process_message(one, Message::Move { x: 10, y: -20 });
+    assert_eq!(
+        moved.broadcast.as_deref(),
+        Some("client 1 moved to (10, -20)")
+    );
+    lobby.process_message(two, Message::ChangeColor(255, 0, 0));
+    lobby.process_message(two, write("hello"));
+    lobby.process_message(two, write("again"));
+
+    let one = lobby.client(one).unwrap();
+    assert_eq!(one.position, (10, -20));
+    assert!(one.log.is_empty());
+    let two = lobby.client(two).unwrap();
+    assert_eq!(two.color, (255, 0, 0));
+    assert_eq!(two.log, ["hello", "again"]);
+}
+
+#[test]
+fn lobby_rejects_invalid_colors_and_answers_who() {
+    let mut lobby = Lobby::new();
+    let (id, _) = lobby.join();
+    let outcome = lobby.process_message(id, Message::ChangeColor(0, 256, 0));
+    assert_eq!(outcome.broadcast, None);
+    assert!(outcome.reply.unwrap().contains("must be 0-255"));
+    assert_eq!(lobby.client(id).unwrap().color, (255, 255, 255));
+
+    let who = lobby.process_message(id, write("/who")).reply.unwrap();
+    assert!(who.starts_with("1 online:"), "{}", who);
+    assert!(who.contains("client 1 at (0, 0)"));
+    // /who ไม่ใช่ข้อความ chat
+    assert!(lobby.client(id).unwrap().log.is_empty());
+}
+
+#[test]
+fn lobby_quit_and_disconnect() {
+    let mut lobby = Lobby::new();
+    let (one, _) = lobby.join();
+    let (two, _) = lobby.join();
+
+    let quit = lobby.process_message(one, Message::Quit);
+    assert!(quit.disconnect);
+    assert_eq!(quit.broadcast.as_deref(), Some("client 1 left"));
+    // ออกด้วย Quit แล้ว leave ไม่ประกาศซ้ำ
+    assert_eq!(lobby.leave(one), Outcome::default());
+
+    let dropped = lobby.leave(two);
+    assert_eq!(dropped.broadcast.as_deref(), Some("client 2 disconnected"));
+    assert_eq!(lobby.clients().count(), 0);
+}
+
+// -------------------------------------------
+// Server ผ่าน TCP จริง
+// -------------------------------------------
+
+#[tokio::test]
+async fn server_broadcasts_to_every_client() {
+    let (server, addr) = start_server().await;
+    let mut alice = join(addr).await;
+    let mut bob = join(addr).await;
+    recv_until(&mut alice, "client 2 joined").await;
+
+    alice.send(&Message::Move { x: 3, y: 4 }).await.unwrap();
+    assert_eq!(
+        recv_until(&mut bob, "client 1 moved").await,
+        "client 1 moved to (3, 4)"
+    );
+    assert_eq!(
+        recv_until(&mut alice, "client 1 moved").await,
+        "client 1 moved to (3, 4)"
+    );
+
+    bob.send(&write("hi alice")).await.unwrap();
+    assert_eq!(
+        recv_until(&mut alice, "client 2:").await,
+        "client 2: hi alice"
+    );
+
+    let clients = server.clients();
+    assert_eq!(clients.len(), 2);
+    assert_eq!(clients[0].position, (3, 4));
+    assert_eq!(clients[1].log, ["hi alice"]);
+}
+
+#[tokio::test]
+async fn quit_closes_the_connection_and_tells_the_others() {
+    let (server, addr) = start_server().await;
+    let mut alice = join(addr).await;
+    let mut bob = join(addr).await;
+    let mut carol = join(addr).await;
+
+    alice.send(&Message::Quit).await.unwrap();
+    recv_until(&mut alice, "bye").await;
+    let closed = timeout(WAIT, async {
+        while let Ok(Some(_)) = alice.recv().await {}
+    });
+    closed.await.expect("server did not close the connection");
+
+    recv_until(&mut bob, "client 1 left").await;
+    recv_until(&mut carol, "client 1 left").await;
+    assert_eq!(server.clients().len(), 2);
+
+    // หลุดโดยไม่ส่ง Quit
+    drop(bob);
+    recv_until(&mut carol, "client 2 disconnected").await;
+    assert_eq!(server.clients().len(), 1);
+}
+
+#[tokio::test]
+async fn invalid_frames_get_an_error_reply() {
+    let (_server, addr) = start_server().await;
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+    stream.write_all(&frame(&[0x09]).unwrap()).await.unwrap();
+    let mut connection = Connection::new(stream);
+    recv_until(&mut connection, "welcome").await;
+    assert_eq!(
+        recv_until(&mut connection, "error:").await,
+        "error: unknown message tag 0x09 (expected 0x00-0x03)"
+    );
+
+    // connection ยังใช้ต่อได้
+    connection.send(&write("still here")).await.unwrap();
+    recv_until(&mut connection, "client 1: still here").await;
+}
+
+// -------------------------------------------
+// CLI
+// -------------------------------------------
+
+#[test]
+fn cli_client_talks_to_cli_server() {
+    let mut server = Command::new(env!("CARGO_BIN_EXE_messages"))
+        .args(["serve", "--port", "0"])
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut server_out = BufReader::new(server.stdout.take().unwrap());
+    let mut first_line = String::new();
+    server_out.read_line(&mut first_line).unwrap();
+    let addr = first_line
+        .trim()
+        .strip_prefix("📡 Listening on ")
+        .unwrap()
+        .to_string();
+
+    let mut client = Command::new(env!("CARGO_BIN_EXE_messages"))
+        .args(["connect", &addr])
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .spawn()
+        .unwrap();
+    client
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(b"MOVE 5 6\nhello everyone\nMOVE x\n/who\nQUIT\n")
+        .unwrap();
+    let output = client.wait_with_output().unwrap();
+
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    for expected in [
+        "🔌 Connected to",
+        "welcome, you are client 1",
+        "❌ MOVE takes 2 argument(s), found 1",
+        "client 1: hello everyone",
+        "client 1 at (5, 6)",
+        "👋 Disconnected",
+    ] {
+        assert!(stdout.contains(expected), "{:?} in\n{}", expected, stdout);
+    }
+
+    let mut log = String::new();
+    for _ in 0..3 {
+        server_out.read_line(&mut log).unwrap();
+    }
+    server.kill().unwrap();
+    server.wait().unwrap();
+    assert!(log.contains("📝 client 1 joined"), "{}", log);
+}
+
+#[test]
+fn cli_usage_errors() {
+    for args in [
+        &["serve", "--port", "x"][..],
+        &["connect", "a", "b"],
+        &["chat"],
+    ] {
+        let output = Command::new(env!("CARGO_BIN_EXE_messages"))
+            .args(args)
+            .output()
+            .unwrap();
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        assert!(String::from_utf8_lossy(&output.stderr).contains("Usage: messages"));
+    }
+
+    // ไม่มี server
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap().to_string();
+    drop(listener);
+    let output = Command::new(env!("CARGO_BIN_EXE_messages"))
+        .args(["connect", &addr])
+        .stdin(Stdio::null())
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Cannot connect"));
+}
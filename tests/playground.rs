@@ -0,0 +1,387 @@
+// ===========================================
+// Tests สำหรับ rust_tutorial::playground (sandbox, HTTP API) และ playground CLI
+// รัน: cargo test --test playground
+// ===========================================
+
+use rust_tutorial::playground::{self, EvaluateRequest, Limits, Playground, Sandbox};
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::path::Path;
+use std::process::Command;
+use std::sync::Arc;
+use std::time::Duration;
+
+fn sandbox(dir: &Path) -> Sandbox {
+    let mut sandbox = Sandbox::new(dir);
+    sandbox.limits = Limits {
+        timeout: Duration::from_secs(2),
+        isolate_network: false,
+        isolate_filesystem: false,
+        ..Limits::default()
+    };
+    sandbox
+}
+
+fn request(code: &str) -> EvaluateRequest {
+    serde_json::from_value(serde_json::json!({
+        "version": "stable",
+        "optimize": "0",
+        "code": code,
+        "edition": "2021",
+    }))
+    .unwrap()
+}
+
+#[test]
+fn runs_snippet_and_returns_output() {
+    let dir = tempfile::tempdir().unwrap();
+    let response = sandbox(dir.path())
+        .evaluate(&request(
+            "fn main() { println!(\"สวัสดี\"); eprintln!(\"warn\"); }",
+        ))
+        .unwrap();
+    assert_eq!(response.error, None);
+    assert!(response.result.contains("สวัสดี\n"), "{}", response.result);
+    assert!(response.result.contains("warn"), "{}", response.result);
+}
+
+#[test]
+fn compile_error_returns_compiler_message() {
+    let dir = tempfile::tempdir().unwrap();
+    let response = sandbox(dir.path())
+        .evaluate(&request("fn main() { let x: i32 = \"no\"; }"))
+        .unwrap();
+    assert_eq!(response.error.as_deref(), Some("compile error"));
+    assert!(
+        response.result.contains("error[E0308]"),
+        "{}",
+        response.result
+    );
+}
+
+#[test]
+fn panic_reports_exit_status() {
+    let dir = tempfile::tempdir().unwrap();
+    let response = sandbox(dir.path())
+        .evaluate(&request("fn main() { panic!(\"boom\"); }"))
+        .unwrap();
+    assert!(response.error.unwrap().contains("101"));
+    assert!(response.result.contains("boom"));
+}
+
+#[test]
+fn endless_loop_times_out() {
+    let dir = tempfile::tempdir().unwrap();
+    let response = sandbox(dir.path())
+        .evaluate(&request("fn main() { loop {} }"))
+        .unwrap();
+    assert_eq!(response.error.as_deref(), Some("timed out"));
+}
+
+#[test]
+fn memory_limit_stops_large_allocation() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut sandbox = sandbox(dir.path());
+    sandbox.limits.memory = 64 * 1024 * 1024;
+    let response = sandbox
+        .evaluate(&request(
+            "fn main() { let v = vec![1u8; 512 * 1024 * 1024]; println!(\"{}\", v[v.len() - 1]); }",
+        ))
+        .unwrap();
+    assert!(response.error.is_some(), "{:?}", response);
+    assert!(!response.result.starts_with('1'));
+}
+
+#[test]
+fn long_output_is_truncated() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut sandbox = sandbox(dir.path());
+    sandbox.limits.output = 1024;
+    let response = sandbox
+        .evaluate(&request(
+            "fn main() { for i in 0..100_000 { println!(\"line {}\", i); } }",
+        ))
+        .unwrap();
+    assert!(response.result.len() < 2048, "{}", response.result.len());
+}
+
+#[test]
+fn unknown_edition_is_rejected() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut request = request("fn main() {}");
+    request.edition = "2030".to_string();
+    let response = sandbox(dir.path()).evaluate(&request).unwrap();
+    assert_eq!(response.error.as_deref(), Some("invalid request"));
+    assert!(response.result.contains("2030"));
+}
+
+#[test]
+fn scratch_files_are_removed() {
+    let dir = tempfile::tempdir().unwrap();
+    sandbox(dir.path())
+        .evaluate(&request("fn main() {}"))
+        .unwrap();
+    let left: Vec<_> = std::fs::read_dir(dir.path()).unwrap().collect();
+    assert!(left.is_empty(), "{:?}", left);
+}
+
+#[test]
+fn isolated_snippet_has_no_network() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut sandbox = sandbox(dir.path());
+    sandbox.limits.isolate_network = true;
+    if let Err(e) = sandbox.probe() {
+        // เครื่องที่ไม่มี user namespace (เช่น container บางแบบ) ทดสอบไม่ได้
+        eprintln!("skipped: {}", e);
+        return;
+    }
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let code = format!(
+        "fn main() {{ std::net::TcpStream::connect(\"{}\").unwrap(); println!(\"connected\"); }}",
+        listener.local_addr().unwrap()
+    );
+    let response = sandbox.evaluate(&request(&code)).unwrap();
+    assert!(response.error.is_some());
+    assert!(!response.result.contains("connected"));
+}
+
+#[test]
+fn isolated_snippet_cannot_touch_files_outside_its_dir() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut sandbox = sandbox(dir.path());
+    sandbox.limits.isolate_filesystem = true;
+    if let Err(e) = sandbox.probe() {
+        eprintln!("skipped: {}", e);
+        return;
+    }
+
+    // ไฟล์ข้างนอก (ที่ไม่ใช่ home) อ่านได้แต่เขียนไม่ได้
+    let outside = tempfile::tempdir().unwrap();
+    let target = outside.path().join("victim.txt");
+    std::fs::write(&target, "original").unwrap();
+    let code = format!(
+        r#"fn main() {{
+            std::fs::write("mine.txt", "ok").unwrap();
+            println!("own dir: {{}}", std::fs::read_to_string("mine.txt").unwrap());
+            println!("outside: {{:?}}", std::fs::write({:?}, "changed").is_ok());
+            println!("home: {{:?}}", std::fs::read_dir("/root").map(|d| d.count()).unwrap_or(0));
+        }}"#,
+        target
+    );
+    let response = sandbox.evaluate(&request(&code)).unwrap();
+    assert_eq!(response.error, None, "{}", response.result);
+    assert!(
+        response.result.contains("own dir: ok"),
+        "{}",
+        response.result
+    );
+    assert!(
+        response.result.contains("outside: false"),
+        "{}",
+        response.result
+    );
+    assert!(response.result.contains("home: 0"), "{}", response.result);
+    assert_eq!(std::fs::read_to_string(&target).unwrap(), "original");
+}
+
+#[test]
+fn compiler_memory_is_limited() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut sandbox = sandbox(dir.path());
+    sandbox.limits.compile_memory = 16 * 1024 * 1024;
+    let response = sandbox.evaluate(&request("fn main() {}")).unwrap();
+    assert_eq!(response.error.as_deref(), Some("compile error"));
+}
+
+// -------------------------------------------
+// HTTP API
+// -------------------------------------------
+
+fn start_server(book_dir: Option<&Path>, work_dir: &Path) -> SocketAddr {
+    let playground = Arc::new(
+        Playground::new(sandbox(work_dir), 2, book_dir.map(Path::to_path_buf))
+            .with_allowed_origins([BOOK_ORIGIN.to_string()]),
+    );
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    listener.set_nonblocking(true).unwrap();
+    let addr = listener.local_addr().unwrap();
+    std::thread::spawn(move || {
+        let runtime = tokio::runtime::Runtime::new().unwrap();
+        runtime.block_on(async move {
+            let listener = tokio::net::TcpListener::from_std(listener).unwrap();
+            axum::serve(listener, playground::router(playground))
+                .await
+                .unwrap();
+        });
+    });
+    addr
+}
+
+const BOOK_ORIGIN: &str = "http://localhost:3000";
+
+// ส่ง HTTP/1.1 request แบบง่ายๆ แล้วคืน (status, headers, body)
+fn http(addr: SocketAddr, method: &str, path: &str, body: &str) -> (u16, String, String) {
+    http_from(None, addr, method, path, body)
+}
+
+// เหมือน http() แต่ส่ง Origin header แบบที่ browser ส่ง
+fn http_from(
+    origin: Option<&str>,
+    addr: SocketAddr,
+    method: &str,
+    path: &str,
+    body: &str,
+) -> (u16, String, String) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    let origin = origin
+        .map(|o| format!("Origin: {}\r\n", o))
+        .unwrap_or_default();
+    write!(
+        stream,
+        "{} {} HTTP/1.1\r\nHost: localhost\r\n{}Content-Type: application/json\r\n\
+         Content-Length: {}\r\nConnection: close\r\n\r\n{}",
+        method,
+        path,
+        origin,
+        body.len(),
+        body
+    )
+    .unwrap();
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+    (status, head.to_lowercase(), body.to_string())
+}
+
+#[test]
+fn evaluate_json_matches_mdbook_protocol() {
+    let work = tempfile::tempdir().unwrap();
+    let addr = start_server(None, work.path());
+
+    let body = serde_json::json!({
+        "version": "stable",
+        "optimize": "0",
+        "code": "fn main() { println!(\"{}\", 6 * 7); }",
+        "edition": "2024",
+    });
+    let (status, headers, body) = http_from(
+        Some(BOOK_ORIGIN),
+        addr,
+        "POST",
+        "/evaluate.json",
+        &body.to_string(),
+    );
+    assert_eq!(status, 200);
+    assert!(headers.contains("access-control-allow-origin: http://localhost:3000"));
+    let json: serde_json::Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(json["result"], "42\n");
+    assert_eq!(json["error"], serde_json::Value::Null);
+
+    let (status, headers, _) = http_from(Some(BOOK_ORIGIN), addr, "OPTIONS", "/evaluate.json", "");
+    assert_eq!(status, 204);
+    assert!(headers.contains("access-control-allow-headers: content-type"));
+
+    let (status, _, body) = http(addr, "GET", "/meta/crates", "");
+    assert_eq!(status, 200);
+    assert_eq!(body, r#"{"crates":[]}"#);
+}
+
+#[test]
+fn other_origins_cannot_run_code() {
+    let work = tempfile::tempdir().unwrap();
+    let addr = start_server(None, work.path());
+    let body = serde_json::json!({ "code": "fn main() {}" }).to_string();
+
+    for method in ["POST", "OPTIONS"] {
+        let (status, headers, _) = http_from(
+            Some("https://evil.example"),
+            addr,
+            method,
+            "/evaluate.json",
+            &body,
+        );
+        assert_eq!(status, 403, "{}", method);
+        assert!(!headers.contains("access-control-allow-origin"));
+    }
+
+    // request ที่ไม่ได้มาจาก browser ไม่มี CORS header
+    let (status, headers, _) = http(addr, "GET", "/meta/crates", "");
+    assert_eq!(status, 200);
+    assert!(!headers.contains("access-control-allow-origin"));
+}
+
+#[test]
+fn oversized_code_is_rejected() {
+    let work = tempfile::tempdir().unwrap();
+    let addr = start_server(None, work.path());
+    let code = format!("fn main() {{}} // {}", "x".repeat(200 * 1024));
+    let body = serde_json::json!({ "code": code }).to_string();
+    let (status, _, _) = http(addr, "POST", "/evaluate.json", &body);
+    assert_eq!(status, 413);
+}
+
+#[test]
+fn serves_built_book_files() {
+    let work = tempfile::tempdir().unwrap();
+    let book = tempfile::tempdir().unwrap();
+    std::fs::create_dir(book.path().join("ch 01")).unwrap();
+    std::fs::write(book.path().join("index.html"), "<h1>หน้าแรก</h1>").unwrap();
+    std::fs::write(book.path().join("ch 01/index.html"), "บทที่ 1").unwrap();
+    std::fs::write(book.path().join("404.html"), "ไม่พบหน้า").unwrap();
+    let addr = start_server(Some(book.path()), work.path());
+
+    let (status, headers, body) = http(addr, "GET", "/", "");
+    assert_eq!(status, 200);
+    assert!(headers.contains("content-type: text/html"));
+    assert_eq!(body, "<h1>หน้าแรก</h1>");
+
+    let (status, _, body) = http(addr, "GET", "/ch%2001/", "");
+    assert_eq!((status, body.as_str()), (200, "บทที่ 1"));
+
+    let (status, _, body) = http(addr, "GET", "/missing.html", "");
+    assert_eq!((status, body.as_str()), (404, "ไม่พบหน้า"));
+
+    // ห้ามออกนอกโฟลเดอร์หนังสือ
+    let (status, _, body) = http(addr, "GET", "/%2e%2e/Cargo.toml", "");
+    assert_eq!(status, 404);
+    assert!(!body.contains("[package]"));
+}
+
+// -------------------------------------------
+// CLI
+// -------------------------------------------
+
+fn playground_cli(args: &[&str]) -> std::process::Output {
+    Command::new(env!("CARGO_BIN_EXE_playground"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn cli_help_and_usage_errors() {
+    let help = playground_cli(&["--help"]);
+    assert!(help.status.success());
+    assert!(String::from_utf8_lossy(&help.stdout).contains("--allow-network"));
+
+    for args in [
+        &["--port"][..],
+        &["--port", "abc"],
+        &["--timeout", "-1"],
+        &["--bogus"],
+    ] {
+        let output = playground_cli(args);
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        assert!(String::from_utf8_lossy(&output.stderr).contains("Usage:"));
+    }
+}
+
+#[test]
+fn cli_missing_book_dir_fails() {
+    let output = playground_cli(&["--book", "/no/such/book", "--allow-network"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("/no/such/book"));
+}
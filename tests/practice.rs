@@ -0,0 +1,219 @@
+// ===========================================
+// Tests สำหรับ practice workspace (rust_tutorial::exercises::Workspace) และ practice CLI
+// รัน: cargo test --test practice
+// ===========================================
+
+use rust_tutorial::exercises::{Chapter, Checker, ExerciseError, Status, Workspace};
+use std::fs;
+use std::path::Path;
+use std::process::{Command, Output};
+
+const SAMPLE: &str = "\
+# แบบฝึกหัด: บทที่ 3 - Functions
+
+## แบบฝึกหัดที่ 1: Add
+
+เขียน function `add` ที่บวกเลขสองตัว
+
+```rust,compile_fail
+fn main() {
+    println!(\"{}\", add(2, 3));
+}
+```
+
+<details>
+<summary>ดูเฉลย</summary>
+
+**วิธีคิด:** ประกาศ function ที่รับ `i32` สองตัว
+
+```rust
+// บวกแล้วคืนค่า
+fn add(a: i32, b: i32) -> i32 {
+    a + b
+}
+
+fn main() {
+    println!(\"{}\", add(2, 3));
+}
+```
+
+</details>
+
+## แบบฝึกหัดที่ 2: Explain
+
+อธิบายว่า expression ต่างจาก statement อย่างไร
+
+<details>
+<summary>ดูเฉลย</summary>
+
+```text
+expression คืนค่า, statement ไม่คืนค่า
+```
+
+</details>
+";
+
+const WRONG: &str = "\
+fn add(a: i32, b: i32) -> i32 {
+    a - b
+}
+
+fn main() {
+    println!(\"{}\", add(2, 3));
+}
+";
+
+fn generate(dir: &Path) -> Workspace {
+    let chapter = Chapter::parse("ch03-exercises.md", SAMPLE).unwrap();
+    let checker = Checker::new(dir.join("work"));
+    Workspace::generate(&chapter, &checker, dir.join("ch03")).unwrap()
+}
+
+#[test]
+fn generates_one_crate_per_exercise() {
+    let dir = tempfile::tempdir().unwrap();
+    let workspace = generate(dir.path());
+    let root = dir.path().join("ch03");
+
+    assert_eq!(workspace.exercises.len(), 1);
+    let practice = &workspace.exercises[0];
+    assert_eq!(practice.package, "ch03-ex1");
+    assert_eq!(practice.dir, "ex1");
+    assert_eq!(
+        practice.hints,
+        [
+            "วิธีคิด: ประกาศ function ที่รับ `i32` สองตัว",
+            "บวกแล้วคืนค่า",
+            "เฉลยมี: fn add",
+        ]
+    );
+
+    // ข้อที่เฉลยไม่มีโค้ดสร้างไม่ได้
+    assert_eq!(workspace.skipped.len(), 1);
+    assert_eq!(workspace.skipped[0].number, 2);
+
+    let read = |path: &str| fs::read_to_string(root.join(path)).unwrap();
+    assert!(read("Cargo.toml").contains("\"ex1\","));
+    assert!(read("ex1/Cargo.toml").contains("path = \"../.hidden/ex1.rs\""));
+    assert!(read("ex1/README.md").contains("เขียน function `add` ที่บวกเลขสองตัว"));
+    assert!(read("ex1/src/main.rs").contains("    println!(\"{}\", add(2, 3));"));
+    assert_eq!(read(".hidden/ex1.out"), "5\n");
+
+    let tests = read(".hidden/ex1.rs");
+    assert!(tests.contains("const _: fn(i32, i32) -> i32 = add;"));
+    assert!(tests.contains("fn add_matches_the_solution()"));
+    assert!(tests.contains("fn prints_the_same_as_the_solution()"));
+
+    assert_eq!(Workspace::load(&root).unwrap(), workspace);
+}
+
+#[test]
+fn refuses_to_overwrite_a_workspace() {
+    let dir = tempfile::tempdir().unwrap();
+    generate(dir.path());
+
+    let chapter = Chapter::parse("ch03-exercises.md", SAMPLE).unwrap();
+    let checker = Checker::new(dir.path().join("work"));
+    let result = Workspace::generate(&chapter, &checker, dir.path().join("ch03"));
+    assert!(matches!(result, Err(ExerciseError::WorkspaceExists(_))));
+}
+
+#[test]
+fn hidden_tests_compare_with_the_solution() {
+    let dir = tempfile::tempdir().unwrap();
+    let workspace = generate(dir.path());
+    let root = dir.path().join("ch03");
+    let practice = &workspace.exercises[0];
+    let main = root.join("ex1/src/main.rs");
+
+    // โค้ดตั้งต้นยังไม่มี fn add
+    match practice.check(&root).unwrap() {
+        Status::Unfinished { reason, output } => {
+            assert_eq!(reason, "does not compile");
+            assert!(output.contains("error[E0425]"), "{}", output);
+            assert!(output.contains("ex1/src/main.rs"), "{}", output);
+        }
+        Status::Done => panic!("starter code should not pass"),
+    }
+
+    // compile ผ่านแต่ผลไม่ตรงเฉลย
+    fs::write(&main, WRONG).unwrap();
+    match practice.check(&root).unwrap() {
+        Status::Unfinished { reason, output } => {
+            assert_eq!(reason, "tests failed");
+            assert!(output.contains("add(0, 1)"), "{}", output);
+        }
+        Status::Done => panic!("a wrong answer should not pass"),
+    }
+
+    fs::write(&main, WRONG.replace("a - b", "b + a")).unwrap();
+    assert_eq!(practice.check(&root).unwrap(), Status::Done);
+}
+
+// -------------------------------------------
+// CLI
+// -------------------------------------------
+
+fn practice(dir: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_practice"))
+        .args(args)
+        .current_dir(dir)
+        .env("EXERCISES_DIR", dir.join("exercises"))
+        .env("EXERCISES_WORK_DIR", dir.join("work"))
+        .output()
+        .expect("failed to run practice binary")
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+#[test]
+fn cli_init_check_and_hint() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::create_dir(dir.path().join("exercises")).unwrap();
+    fs::write(dir.path().join("exercises/ch03-exercises.md"), SAMPLE).unwrap();
+
+    let init = practice(dir.path(), &["init", "3"]);
+    let out = stdout(&init);
+    assert!(init.status.success(), "{}", out);
+    assert!(out.contains("📘 บทที่ 3 - Functions → practice/ch03"));
+    assert!(out.contains("⏭️  skipped 2. Explain"), "{}", out);
+    assert!(out.contains("🏁 1 exercises ready"));
+
+    let check = practice(dir.path(), &["check", "-d", "practice/ch03"]);
+    let out = stdout(&check);
+    assert_eq!(check.status.code(), Some(1));
+    assert!(out.contains("  ❌ 1. Add - does not compile"), "{}", out);
+    assert!(out.contains("     💡 practice hint 1"));
+    assert!(out.contains("🏁 0/1 exercises done"));
+    assert!(String::from_utf8_lossy(&check.stderr).contains("1 exercise(s) not done yet"));
+
+    let hint = practice(&dir.path().join("practice/ch03"), &["hint", "1"]);
+    let out = stdout(&hint);
+    assert!(out.contains("  2. บวกแล้วคืนค่า"), "{}", out);
+    assert!(out.contains("เฉลยเต็ม: .hidden/ex1_solution.rs"));
+
+    let solution = dir.path().join("practice/ch03/.hidden/ex1_solution.rs");
+    fs::copy(solution, dir.path().join("practice/ch03/ex1/src/main.rs")).unwrap();
+    let check = practice(&dir.path().join("practice/ch03"), &["check", "1"]);
+    let out = stdout(&check);
+    assert!(check.status.success(), "{}", out);
+    assert!(out.contains("  ✅ 1. Add"));
+    assert!(out.contains("🏁 1/1 exercises done"));
+}
+
+#[test]
+fn cli_rejects_missing_workspace_and_bad_arguments() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let output = practice(dir.path(), &["check"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("is not a practice workspace"));
+
+    for args in [&["init"][..], &["hint"], &["check", "one"], &["frobnicate"]] {
+        let output = practice(dir.path(), args);
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        assert!(String::from_utf8_lossy(&output.stderr).contains("Usage: practice"));
+    }
+}
@@ -0,0 +1,353 @@
+// ===========================================
+// Tests สำหรับ rust_tutorial::preprocessor (region, directive, book) และ mdbook-examples CLI
+// รัน: cargo test --test preprocessor
+// ===========================================
+
+use rust_tutorial::i18n::Locale;
+use rust_tutorial::preprocessor::{
+    self, Found, Kind, PreprocessError, Preprocessor, RegionError, region, without_markers,
+};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+const PROJECT_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+const SOURCE: &str = "\
+fn main() {
+    // ANCHOR: all
+    // ANCHOR: greet
+    let name = \"Ferris\";
+    println!(\"hi {}\", name);
+    // ANCHOR_END: greet
+
+    helper();
+    // ANCHOR_END: all
+}
+
+// ANCHOR: broken
+fn helper() {}
+";
+
+fn examples_dir() -> tempfile::TempDir {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("demo.rs"), SOURCE).unwrap();
+    fs::create_dir(dir.path().join("server")).unwrap();
+    fs::write(
+        dir.path().join("server/main.rs"),
+        "// ANCHOR: run\nserve();\n// ANCHOR_END: run\n",
+    )
+    .unwrap();
+    dir
+}
+
+fn preprocessor(dir: &Path) -> Preprocessor {
+    let mut preprocessor = Preprocessor::new(dir, PROJECT_DIR);
+    preprocessor.capture_output = false;
+    preprocessor
+}
+
+fn directive_error(result: Result<String, PreprocessError>) -> (usize, String) {
+    match result {
+        Err(PreprocessError::Directive { line, message, .. }) => (line, message),
+        other => panic!("expected directive error, got {:?}", other),
+    }
+}
+
+#[test]
+fn region_is_dedented_without_markers() {
+    assert_eq!(
+        region(SOURCE, "greet").unwrap(),
+        "let name = \"Ferris\";\nprintln!(\"hi {}\", name);"
+    );
+    // region ซ้อน: marker ของ region ด้านในไม่ถูกแสดง
+    assert_eq!(
+        region(SOURCE, "all").unwrap(),
+        "let name = \"Ferris\";\nprintln!(\"hi {}\", name);\n\nhelper();"
+    );
+    assert!(!without_markers(SOURCE).contains("ANCHOR"));
+}
+
+#[test]
+fn missing_and_unclosed_regions() {
+    assert_eq!(
+        region(SOURCE, "gret"),
+        Err(RegionError::Missing {
+            available: vec!["all".into(), "broken".into(), "greet".into()]
+        })
+    );
+    assert_eq!(
+        region(SOURCE, "broken"),
+        Err(RegionError::Unclosed { line: 12 })
+    );
+}
+
+#[test]
+fn finds_directives_with_lines_and_code_blocks() {
+    let content = "\
+# Title
+{{#example demo:greet output}}
+```rust
+{{#example demo}}
+```
+\\{{#example demo}} and {{#example_output demo}}
+";
+    let found = preprocessor::find(content).unwrap();
+    assert_eq!(found.len(), 4);
+
+    let Found::Directive(first) = &found[0] else {
+        panic!()
+    };
+    assert_eq!((first.kind, first.line), (Kind::Code, 2));
+    assert_eq!(first.region.as_deref(), Some("greet"));
+    assert!(first.with_output && !first.in_code_block);
+    assert_eq!(
+        &content[first.span.clone()],
+        "{{#example demo:greet output}}"
+    );
+
+    let Found::Directive(second) = &found[1] else {
+        panic!()
+    };
+    assert!(second.in_code_block);
+    assert!(matches!(found[2], Found::Escaped { .. }));
+    let Found::Directive(last) = &found[3] else {
+        panic!()
+    };
+    assert_eq!((last.kind, last.line), (Kind::Output, 6));
+}
+
+#[test]
+fn invalid_directives_report_line() {
+    for (content, expected) in [
+        ("\n{{#example}}", "missing example name"),
+        ("\n{{#example demo verbose}}", "unknown option 'verbose'"),
+        ("\n{{#example_output demo:greet}}", "not a region"),
+        ("\n{{#example demo", "missing closing"),
+        ("\n{{#examples demo}}", "unknown directive"),
+    ] {
+        let (line, message) = preprocessor::find(content).unwrap_err();
+        assert_eq!(line, 2, "{}", content);
+        assert!(message.contains(expected), "{}: {}", content, message);
+    }
+}
+
+#[test]
+fn replaces_directives_in_chapter() {
+    let dir = examples_dir();
+    let content = "\
+Intro
+{{#example demo:greet}}
+
+```rust,editable
+{{#example server:run}}
+```
+Literal: \\{{#example demo}}
+";
+    let processed = preprocessor(dir.path())
+        .process("ch01.md", content)
+        .unwrap();
+    assert_eq!(
+        processed,
+        "\
+Intro
+📄 `examples/demo.rs` · รัน: `cargo run --example demo`
+
+```rust,ignore
+let name = \"Ferris\";
+println!(\"hi {}\", name);
+```
+
+```rust,editable
+serve();
+```
+Literal: {{#example demo}}
+"
+    );
+}
+
+#[test]
+fn missing_region_fails_with_chapter_and_line() {
+    let dir = examples_dir();
+    let (line, message) = directive_error(
+        preprocessor(dir.path()).process("ch01.md", "a\nb\n{{#example demo:gret}}\n"),
+    );
+    assert_eq!(line, 3);
+    assert!(message.contains("region 'gret' not found in examples/demo.rs"));
+    assert!(message.contains("available: all, broken, greet"));
+
+    let (_, message) =
+        directive_error(preprocessor(dir.path()).process("ch01.md", "{{#example nope}}"));
+    assert!(message.contains("example 'nope' not found"));
+
+    let (_, message) =
+        directive_error(preprocessor(dir.path()).process("ch01.md", "{{#example demo:broken}}"));
+    assert!(message.contains("no // ANCHOR_END: broken"));
+}
+
+#[test]
+fn output_inside_code_block_is_rejected() {
+    let dir = examples_dir();
+    let (line, message) = directive_error(
+        preprocessor(dir.path()).process("ch01.md", "```text\n{{#example demo output}}\n```"),
+    );
+    assert_eq!(line, 2);
+    assert!(message.contains("example_output"));
+}
+
+#[test]
+fn processes_nested_chapters_in_mdbook_json() {
+    let dir = examples_dir();
+    let input = serde_json::json!([
+        {
+            "root": dir.path().join("docs"),
+            "config": { "preprocessor": { "examples": { "examples-dir": dir.path() } } },
+            "renderer": "html",
+            "mdbook_version": "0.4.40"
+        },
+        {
+            "sections": [
+                { "PartTitle": "Part 1" },
+                { "Chapter": {
+                    "name": "One",
+                    "content": "# One",
+                    "number": [1],
+                    "path": "one.md",
+                    "source_path": "one.md",
+                    "parent_names": [],
+                    "sub_items": [
+                        { "Chapter": {
+                            "name": "Two",
+                            "content": "```rust\n{{#example server:run}}\n```",
+                            "number": [1, 1],
+                            "path": "two.md",
+                            "source_path": "two.md",
+                            "parent_names": ["One"],
+                            "sub_items": []
+                        } }
+                    ]
+                } },
+                "Separator"
+            ],
+            "__non_exhaustive": null
+        }
+    ]);
+
+    let output = preprocessor::preprocess(&input.to_string()).unwrap();
+    let book: serde_json::Value = serde_json::from_str(&output).unwrap();
+    assert_eq!(book["sections"][0]["PartTitle"], "Part 1");
+    assert_eq!(book["sections"][2], "Separator");
+    let two = &book["sections"][1]["Chapter"]["sub_items"][0]["Chapter"];
+    assert_eq!(two["content"], "```rust\nserve();\n```");
+    assert_eq!(two["number"], serde_json::json!([1, 1]));
+}
+
+#[test]
+fn captures_real_example_output() {
+    let mut preprocessor = Preprocessor::new(Path::new(PROJECT_DIR).join("examples"), PROJECT_DIR);
+    let processed = preprocessor
+        .process("examples.md", "{{#example_output hello_world}}")
+        .unwrap();
+    assert!(processed.starts_with("```text\n🦀 สวัสดี Rust!\nHello, World!\n"));
+    assert!(processed.ends_with("Loop: 5\n```"));
+
+    // หนังสือภาษาอังกฤษ: locale = "en" ใน [preprocessor.examples]
+    let context = serde_json::json!({
+        "root": Path::new(PROJECT_DIR).join("docs"),
+        "config": { "preprocessor": { "examples": { "locale": "en" } } },
+    });
+    let mut preprocessor = Preprocessor::from_context(&context);
+    assert_eq!(preprocessor.locale, Locale::En);
+    let processed = preprocessor
+        .process("examples.md", "{{#example_output hello_world}}")
+        .unwrap();
+    assert!(processed.starts_with("```text\n🦀 Hello, Rust!\nHello, World!\n"));
+}
+
+// -------------------------------------------
+// CLI
+// -------------------------------------------
+
+fn cli(args: &[&str], book: &Path, examples: &Path, stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_mdbook-examples"))
+        .args(args)
+        .env("BOOK_SRC_DIR", book)
+        .env("EXAMPLES_DIR", examples)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+#[test]
+fn cli_check_reports_every_broken_directive() {
+    let examples = examples_dir();
+    let book = tempfile::tempdir().unwrap();
+    fs::create_dir(book.path().join("ch01")).unwrap();
+    fs::write(book.path().join("ch01/ok.md"), "{{#example demo:greet}}").unwrap();
+    fs::write(book.path().join("ch01/bad.md"), "x\n{{#example demo:nope}}").unwrap();
+    fs::write(book.path().join("plain.md"), "no directives").unwrap();
+
+    let output = cli(&["check"], book.path(), examples.path(), "");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert_eq!(output.status.code(), Some(1), "{}", stdout);
+    assert!(
+        stdout.contains("✅ ch01/ok.md (1 directive(s))"),
+        "{}",
+        stdout
+    );
+    assert!(
+        stdout.contains("❌ ch01/bad.md:2: region 'nope'"),
+        "{}",
+        stdout
+    );
+    assert!(!stdout.contains("plain.md"));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("1 directive error(s)"));
+
+    fs::remove_file(book.path().join("ch01/bad.md")).unwrap();
+    let output = cli(&["check"], book.path(), examples.path(), "");
+    assert!(output.status.success());
+}
+
+#[test]
+fn cli_supports_list_and_usage() {
+    let examples = examples_dir();
+    let book = tempfile::tempdir().unwrap();
+
+    let output = cli(&["supports", "html"], book.path(), examples.path(), "");
+    assert!(output.status.success());
+
+    let output = cli(&["list"], book.path(), examples.path(), "");
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("📘 demo: all, broken, greet"), "{}", stdout);
+    assert!(stdout.contains("📘 server: run"), "{}", stdout);
+
+    let output = cli(&["check", "--fast"], book.path(), examples.path(), "");
+    assert_eq!(output.status.code(), Some(2));
+
+    // input ที่ไม่ใช่ JSON ของ mdbook
+    let output = cli(&[], book.path(), examples.path(), "not json");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("Invalid input from mdbook"));
+}
+
+#[test]
+fn book_directives_are_valid() {
+    let book = Path::new(PROJECT_DIR).join("docs/src");
+    let examples = Path::new(PROJECT_DIR).join("examples");
+    let output = cli(&["check"], &book, &examples, "");
+    assert!(
+        output.status.success(),
+        "{}",
+        String::from_utf8_lossy(&output.stdout)
+    );
+}
@@ -0,0 +1,247 @@
+// ===========================================
+// Tests สำหรับ rust_tutorial::protocol (binary, text, framing)
+// รัน: cargo test --test protocol
+// ===========================================
+//
+// property tests (proptest) สุ่ม Message หลายร้อยแบบแล้วตรวจว่า encode → decode ได้ค่าเดิม
+// ถ้าเจอกรณีที่ล้มเหลว proptest จะย่อ input ให้เล็กที่สุดก่อนรายงาน
+
+use proptest::prelude::*;
+use rust_tutorial::protocol::{
+    self, DecodeError, FrameDecoder, FrameError, MAX_FRAME_LEN, Message, from_line, to_line,
+};
+use std::io::Cursor;
+
+fn any_message() -> impl Strategy<Value = Message> {
+    prop_oneof![
+        Just(Message::Quit),
+        (any::<i32>(), any::<i32>()).prop_map(|(x, y)| Message::Move { x, y }),
+        // ข้อความทั่วไป + ตัวที่ต้อง escape ในแบบ text
+        any::<String>().prop_map(Message::Write),
+        "[a-z \\\\\n\r]{0,20}".prop_map(Message::Write),
+        (any::<i32>(), any::<i32>(), any::<i32>())
+            .prop_map(|(r, g, b)| Message::ChangeColor(r, g, b)),
+    ]
+}
+
+fn framed(messages: &[Message]) -> Vec<u8> {
+    let mut stream = Vec::new();
+    for message in messages {
+        protocol::write_message(&mut stream, message).unwrap();
+    }
+    stream
+}
+
+proptest! {
+    #[test]
+    fn binary_round_trip(message in any_message()) {
+        prop_assert_eq!(protocol::decode(&protocol::encode(&message)), Ok(message));
+    }
+
+    #[test]
+    fn text_round_trip_stays_on_one_line(message in any_message()) {
+        let line = to_line(&message);
+        prop_assert!(!line.contains('\n') && !line.contains('\r'), "{:?}", line);
+        prop_assert_eq!(from_line(&format!("{}\r\n", line)), Ok(message));
+    }
+
+    #[test]
+    fn truncated_binary_is_an_error(message in any_message()) {
+        let bytes = protocol::encode(&message);
+        for len in 0..bytes.len() {
+            prop_assert!(protocol::decode(&bytes[..len]).is_err());
+        }
+    }
+
+    // bytes มั่วๆ ต้องได้ Err ไม่ใช่ panic
+    #[test]
+    fn decoding_garbage_never_panics(bytes in proptest::collection::vec(any::<u8>(), 0..32)) {
+        let _ = protocol::decode(&bytes);
+        let _ = from_line(&String::from_utf8_lossy(&bytes));
+    }
+
+    #[test]
+    fn framed_stream_round_trip(messages in proptest::collection::vec(any_message(), 0..10)) {
+        let mut reader = Cursor::new(framed(&messages));
+        let mut decoded = Vec::new();
+        while let Some(message) = protocol::read_message(&mut reader).unwrap() {
+            decoded.push(message);
+        }
+        prop_assert_eq!(decoded, messages);
+    }
+
+    // TCP ส่งข้อมูลมาเป็นก้อนขนาดเท่าไรก็ได้
+    #[test]
+    fn frame_decoder_handles_any_chunking(
+        messages in proptest::collection::vec(any_message(), 0..10),
+        chunk in 1usize..16,
+    ) {
+        let mut decoder = FrameDecoder::new();
+        let mut decoded = Vec::new();
+        for bytes in framed(&messages).chunks(chunk) {
+            decoder.extend(bytes);
+            while let Some(message) = decoder.next_message().unwrap() {
+                decoded.push(message);
+            }
+        }
+        prop_assert_eq!(decoded, messages);
+        prop_assert_eq!(decoder.buffered(), 0);
+    }
+}
+
+#[test]
+fn binary_encoding_is_compact() {
+    assert_eq!(protocol::encode(&Message::Quit), [0x00]);
+    assert_eq!(
+        protocol::encode(&Message::Move { x: 10, y: -20 }),
+        [0x01, 0x14, 0x27]
+    );
+    assert_eq!(
+        protocol::encode(&Message::Write("hi".to_string())),
+        [0x02, 0x02, b'h', b'i']
+    );
+    assert_eq!(
+        protocol::encode(&Message::ChangeColor(255, 0, 0)),
+        [0x03, 0xfe, 0x03, 0x00, 0x00]
+    );
+    assert_eq!(
+        protocol::encode(&Message::Move { x: i32::MIN, y: 0 }).len(),
+        7
+    );
+}
+
+#[test]
+fn binary_errors_say_what_went_wrong() {
+    let cases: &[(&[u8], DecodeError, &str)] = &[
+        (&[], DecodeError::Empty, "empty message"),
+        (
+            &[0x09],
+            DecodeError::UnknownTag { tag: 0x09 },
+            "unknown message tag 0x09",
+        ),
+        (
+            &[0x01, 0x14],
+            DecodeError::Truncated {
+                field: "y",
+                offset: 2,
+            },
+            "message ends at byte 2 while reading y",
+        ),
+        (
+            &[0x01, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01, 0x00],
+            DecodeError::InvalidVarint {
+                field: "x",
+                offset: 1,
+            },
+            "x at byte 1 is not a valid 32-bit varint",
+        ),
+        (
+            &[0x02, 0x05, b'h', b'i'],
+            DecodeError::Truncated {
+                field: "text",
+                offset: 4,
+            },
+            "while reading text",
+        ),
+        (
+            &[0x02, 0x02, b'h', 0xff],
+            DecodeError::InvalidUtf8 { offset: 3 },
+            "text at byte 3 is not valid UTF-8",
+        ),
+        (
+            &[0x00, 0x00, 0x00],
+            DecodeError::TrailingBytes { count: 2 },
+            "2 unexpected byte(s)",
+        ),
+    ];
+    for (bytes, expected, message) in cases {
+        let error = protocol::decode(bytes).unwrap_err();
+        assert_eq!(&error, expected, "{:?}", bytes);
+        assert!(error.to_string().contains(message), "{}", error);
+    }
+}
+
+#[test]
+fn text_encoding() {
+    assert_eq!(to_line(&Message::Move { x: 10, y: -20 }), "MOVE 10 -20");
+    assert_eq!(
+        to_line(&Message::Write("a\\b\nc".to_string())),
+        "WRITE a\\\\b\\nc"
+    );
+    assert_eq!(to_line(&Message::ChangeColor(255, 0, 0)), "COLOR 255 0 0");
+    // พิมพ์เองผ่าน nc: ตัวพิมพ์เล็กและช่องว่างหลายช่องได้
+    assert_eq!(from_line("move  3   4\n"), Ok(Message::Move { x: 3, y: 4 }));
+    assert_eq!(
+        from_line("WRITE  two spaces"),
+        Ok(Message::Write(" two spaces".to_string()))
+    );
+    assert_eq!(from_line("WRITE"), Ok(Message::Write(String::new())));
+}
+
+#[test]
+fn text_errors_say_what_went_wrong() {
+    for (line, message) in [
+        ("", "unknown command ''"),
+        ("JUMP 1 2", "unknown command 'JUMP'"),
+        ("MOVE 1", "MOVE takes 2 argument(s), found 1"),
+        ("QUIT now", "QUIT takes 0 argument(s), found 1"),
+        ("COLOR 1 2 x", "COLOR: 'x' is not a 32-bit integer"),
+        (
+            "MOVE 1 99999999999",
+            "'99999999999' is not a 32-bit integer",
+        ),
+        ("WRITE tab\\t", "unknown escape '\\t'"),
+    ] {
+        let error = from_line(line).unwrap_err();
+        assert!(error.to_string().contains(message), "{:?}: {}", line, error);
+    }
+}
+
+#[test]
+fn frame_errors() {
+    // ความยาวเกิน limit ไม่ต้องรอรับ payload
+    let mut huge = Cursor::new((MAX_FRAME_LEN as u32 + 1).to_be_bytes().to_vec());
+    assert!(matches!(
+        protocol::read_frame(&mut huge),
+        Err(FrameError::TooLarge { len, max }) if len == MAX_FRAME_LEN + 1 && max == MAX_FRAME_LEN
+    ));
+    assert!(protocol::frame(&vec![0; MAX_FRAME_LEN + 1]).is_err());
+
+    let mut cut_header = Cursor::new(vec![0, 0]);
+    assert!(matches!(
+        protocol::read_frame(&mut cut_header),
+        Err(FrameError::Truncated {
+            expected: 4,
+            received: 2
+        })
+    ));
+
+    let mut cut_payload = Cursor::new(vec![0, 0, 0, 3, 0x01]);
+    let error = protocol::read_frame(&mut cut_payload).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        "stream ended after 5 of 7 bytes of a frame"
+    );
+
+    let mut bad_message = Cursor::new(protocol::frame(&[0x07]).unwrap());
+    let error = protocol::read_message(&mut bad_message).unwrap_err();
+    assert!(matches!(
+        error,
+        FrameError::Decode(DecodeError::UnknownTag { tag: 7 })
+    ));
+
+    let mut decoder = FrameDecoder::with_max_len(8);
+    decoder.extend(&[0, 0, 0, 9]);
+    assert!(matches!(
+        decoder.next_frame(),
+        Err(FrameError::TooLarge { len: 9, max: 8 })
+    ));
+}
+
+#[test]
+fn message_serializes_with_serde() {
+    let json = serde_json::to_string(&Message::Move { x: 1, y: 2 }).unwrap();
+    assert_eq!(json, r#"{"Move":{"x":1,"y":2}}"#);
+    let back: Message = serde_json::from_str(r#"{"ChangeColor":[1,2,3]}"#).unwrap();
+    assert_eq!(back, Message::ChangeColor(1, 2, 3));
+}
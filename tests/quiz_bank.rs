@@ -0,0 +1,223 @@
+// ===========================================
+// Tests สำหรับ rust_tutorial::quiz (parser และ progress)
+// รัน: cargo test --test quiz_bank
+// ===========================================
+
+use chrono::Utc;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use rust_tutorial::quiz::{Progress, ProgressError, ProgressStore, Quiz, QuizError, load_bank};
+
+const QUIZ_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/docs/src/appendix/quiz");
+
+const SAMPLE: &str = "\
+# Quiz: บทที่ 5 - Ownership
+
+## คำถามที่ 1
+
+code นี้ถูกหรือผิด?
+
+```rust,ignore
+let s1 = String::from(\"hello\");
+// A. not an option inside code
+```
+
+A. ถูก
+B. ผิด - s1 ถูก move
+
+<details>
+<summary>ดูเฉลย</summary>
+
+**B. ผิด - s1 ถูก move**
+
+```rust,ignore
+## not a heading
+```
+
+</details>
+
+---
+
+## คำถาม 5.2
+
+`&` หมายถึงอะไร?
+
+A. Pointer
+B. Reference (borrow)
+C. Copy
+
+<details>
+<summary>ดูเฉลย</summary>
+
+**B. Reference (borrow)**
+
+</details>
+";
+
+fn parse(content: &str) -> Result<Quiz, QuizError> {
+    Quiz::parse("ch05-quiz.md", content)
+}
+
+fn parse_error_line(content: &str) -> (usize, String) {
+    match parse(content) {
+        Err(QuizError::Parse { line, message, .. }) => (line, message),
+        other => panic!("expected parse error, got {:?}", other),
+    }
+}
+
+#[test]
+fn parses_every_quiz_in_the_book() {
+    let bank = load_bank(QUIZ_DIR).unwrap();
+
+    assert_eq!(bank.len(), 20);
+    for (i, quiz) in bank.iter().enumerate() {
+        assert_eq!(quiz.chapter, i as u32 + 1);
+        assert_eq!(quiz.questions.len(), 5, "{}", quiz.path.display());
+        for question in &quiz.questions {
+            let letters: Vec<char> = question.choices.iter().map(|c| c.letter).collect();
+            assert_eq!(letters, ['A', 'B', 'C', 'D'], "{}", question.heading);
+            assert!(!question.prompt.is_empty());
+        }
+    }
+}
+
+#[test]
+fn parses_sample() {
+    let quiz = parse(SAMPLE).unwrap();
+    assert_eq!(quiz.chapter, 5);
+    assert_eq!(quiz.title, "Ownership");
+    assert_eq!(quiz.questions.len(), 2);
+
+    let first = &quiz.questions[0];
+    assert_eq!(first.number, 1);
+    assert_eq!(first.heading, "คำถามที่ 1");
+    assert_eq!(first.line, 3);
+    assert!(first.prompt.starts_with("code นี้ถูกหรือผิด?"));
+    assert!(first.prompt.contains("// A. not an option inside code"));
+    assert_eq!(first.choices.len(), 2);
+    assert_eq!(first.choices[1].text, "ผิด - s1 ถูก move");
+    assert_eq!(first.choices[1].line, 13);
+    assert_eq!(first.answer.letter, 'B');
+    assert_eq!(first.answer.line, 18);
+    assert!(first.explanation.contains("## not a heading"));
+
+    let second = &quiz.questions[1];
+    assert_eq!(second.number, 2);
+    assert_eq!(second.heading, "คำถาม 5.2");
+    assert_eq!(second.choices.len(), 3);
+    assert_eq!(second.explanation, "");
+}
+
+#[test]
+fn answer_check_ignores_case() {
+    let quiz = parse(SAMPLE).unwrap();
+    let question = &quiz.questions[0];
+
+    assert!(question.is_correct('b'));
+    assert!(question.is_correct('B'));
+    assert!(!question.is_correct('A'));
+    assert!(question.choice('a').is_some());
+    assert!(question.choice('D').is_none());
+}
+
+#[test]
+fn shuffle_is_repeatable_with_seed() {
+    let order = |seed| {
+        let mut quiz = load_bank(QUIZ_DIR).unwrap().remove(0);
+        quiz.shuffle(&mut StdRng::seed_from_u64(seed));
+        quiz.questions.iter().map(|q| q.number).collect::<Vec<_>>()
+    };
+
+    assert_eq!(order(42), order(42));
+    let mut sorted = order(42);
+    sorted.sort();
+    assert_eq!(sorted, [1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn missing_title_is_an_error() {
+    let (line, message) = parse_error_line("# Quiz\n");
+    assert_eq!(line, 1);
+    assert!(message.contains("# Quiz: บทที่ N - Title"));
+}
+
+#[test]
+fn missing_answer_reports_line() {
+    let content = SAMPLE.replace("**B. Reference (borrow)**", "B. Reference (borrow)");
+    let (line, message) = parse_error_line(&content);
+    assert_eq!(line, 39);
+    assert!(message.contains("expected the answer in bold"));
+}
+
+#[test]
+fn missing_options_reports_question() {
+    let content = SAMPLE.replace("A. Pointer\nB. Reference (borrow)\nC. Copy\n", "");
+    let (line, message) = parse_error_line(&content);
+    assert_eq!(line, 33);
+    assert!(message.contains("<details> before any A.-D. options"));
+}
+
+#[test]
+fn unclosed_details_is_an_error() {
+    let content = SAMPLE.replace("</details>\n\n---", "---");
+    let (line, message) = parse_error_line(&content);
+    assert_eq!(line, 25);
+    assert!(message.contains("คำถามที่ 1: missing </details>"));
+}
+
+#[test]
+fn unclosed_code_block_is_an_error() {
+    // ```text ปิด code block ไม่ได้ตาม CommonMark
+    // ตัวเลือกและเฉลยจึงกลายเป็นส่วนหนึ่งของ code
+    let content = SAMPLE.replace("// A. not an option inside code\n```", "```text");
+    let (line, message) = parse_error_line(&content);
+    assert_eq!(line, 3);
+    assert_eq!(message, "คำถามที่ 1: no A.-D. options");
+
+    let (line, message) = parse_error_line(&format!("{}\n```rust\nfn main() {{}}\n", SAMPLE));
+    assert_eq!(line, 43);
+    assert!(message.contains("never closed"), "{}", message);
+}
+
+#[test]
+fn progress_keeps_best_score() {
+    let mut progress = Progress::new("alice");
+    progress.record(3, 2, 5, vec!["คำถามที่ 1".into()], Utc::now());
+    let chapter = progress.record(3, 1, 5, vec![], Utc::now());
+
+    assert_eq!(chapter.attempts, 2);
+    assert_eq!(chapter.best_score, 2);
+    assert_eq!(chapter.last_score, 1);
+    assert!(chapter.missed.is_empty());
+
+    // จำนวนข้อเปลี่ยน = เริ่มนับ best ใหม่
+    let chapter = progress.record(3, 1, 6, vec![], Utc::now());
+    assert_eq!(chapter.best_score, 1);
+    assert_eq!(chapter.total, 6);
+}
+
+#[test]
+fn progress_store_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let store = ProgressStore::new(dir.path().join("progress"));
+
+    assert_eq!(store.load("bob").unwrap(), Progress::new("bob"));
+
+    let mut progress = Progress::new("bob");
+    progress.record(1, 4, 5, vec![], Utc::now());
+    store.save(&progress).unwrap();
+
+    assert_eq!(store.load("bob").unwrap(), progress);
+    assert!(dir.path().join("progress/bob.json").exists());
+}
+
+#[test]
+fn learner_name_must_be_safe_for_file_names() {
+    let store = ProgressStore::new("unused");
+    for name in ["", "../etc", "a/b", "a b"] {
+        assert!(matches!(
+            store.load(name),
+            Err(ProgressError::InvalidLearner(_))
+        ));
+    }
+}
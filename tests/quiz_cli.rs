@@ -0,0 +1,271 @@
+// ===========================================
+// Integration tests สำหรับ quiz CLI
+// รัน: cargo test --test quiz_cli
+// ===========================================
+
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+use tempfile::TempDir;
+
+// ทั้งสองข้อมีเฉลยเป็น B จึงตรวจคะแนนได้ไม่ว่าลำดับจะสุ่มออกมาแบบไหน
+const FIXTURE: &str = "\
+# Quiz: บทที่ 1 - Getting Started
+
+## คำถามที่ 1
+
+Cargo คืออะไร?
+
+A. Text editor
+B. Package manager และ build tool
+
+<details>
+<summary>ดูเฉลย</summary>
+
+**B. Package manager และ build tool**
+
+Cargo จัดการ dependencies
+
+</details>
+
+---
+
+## คำถามที่ 2
+
+คำสั่งใดตรวจสอบเวอร์ชัน compiler?
+
+A. `rust --version`
+B. `rustc --version`
+
+<details>
+<summary>ดูเฉลย</summary>
+
+**B. `rustc --version`**
+
+</details>
+";
+
+struct Env {
+    dir: TempDir,
+}
+
+impl Env {
+    fn new() -> Self {
+        let dir = tempfile::tempdir().unwrap();
+        std::fs::create_dir(dir.path().join("quiz")).unwrap();
+        std::fs::write(dir.path().join("quiz/ch01-quiz.md"), FIXTURE).unwrap();
+        Self { dir }
+    }
+
+    fn progress_dir(&self) -> std::path::PathBuf {
+        self.dir.path().join("progress")
+    }
+
+    fn quiz(&self, args: &[&str], input: &str) -> Output {
+        run(
+            &self.dir.path().join("quiz"),
+            &self.progress_dir(),
+            args,
+            input,
+        )
+    }
+}
+
+fn run(quiz_dir: &Path, progress_dir: &Path, args: &[&str], input: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_quiz"))
+        .args(args)
+        .env("QUIZ_DIR", quiz_dir)
+        .env("QUIZ_PROGRESS_DIR", progress_dir)
+        .env("QUIZ_LEARNER", "tester")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .expect("failed to run quiz binary");
+
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(input.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+#[test]
+fn all_correct() {
+    let env = Env::new();
+    let output = env.quiz(&["run", "1"], "b\nB\n");
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    let out = stdout(&output);
+    assert!(out.contains("📝 Quiz: บทที่ 1 - Getting Started (2 questions)"));
+    assert_eq!(out.matches("✅ Correct!").count(), 2);
+    assert!(out.contains("Cargo จัดการ dependencies"));
+    assert!(out.contains("🏁 Score: 2/2 (100%)  best: 2/2  attempts: 1"));
+}
+
+#[test]
+fn wrong_answer_shows_solution_and_is_remembered() {
+    let env = Env::new();
+    let output = env.quiz(&["1", "--seed", "3"], "a\na\n");
+
+    let out = stdout(&output);
+    assert!(out.contains("❌ Wrong - the answer is B. `rustc --version`"));
+    assert!(out.contains("🏁 Score: 0/2 (0%)"));
+
+    let progress = stdout(&env.quiz(&["progress"], ""));
+    assert!(progress.contains(" 1. best 0/2  last 0/2  attempts 1"));
+    assert!(progress.contains("missed: คำถามที่ 1, คำถามที่ 2"));
+}
+
+#[test]
+fn invalid_answer_asks_again() {
+    let env = Env::new();
+    let output = env.quiz(&["run", "1"], "x\nC\nb\nb\n");
+
+    let out = stdout(&output);
+    assert_eq!(out.matches("Please answer A/B (or q to quit)").count(), 2);
+    assert!(out.contains("🏁 Score: 2/2"));
+}
+
+#[test]
+fn quitting_saves_nothing() {
+    let env = Env::new();
+    let output = env.quiz(&["run", "1"], "b\nq\n");
+
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("👋 Quiz stopped - nothing was saved"));
+    assert!(!env.progress_dir().exists());
+
+    // EOF ก็ถือว่าออก
+    let output = env.quiz(&["run", "1"], "b\n");
+    assert!(stdout(&output).contains("👋 Quiz stopped"));
+}
+
+#[test]
+fn progress_is_per_learner() {
+    let env = Env::new();
+    env.quiz(&["run", "1"], "b\nb\n");
+    env.quiz(&["--learner", "other", "run", "1"], "a\na\n");
+
+    assert!(stdout(&env.quiz(&["list"], "")).contains("best 2/2"));
+    assert!(stdout(&env.quiz(&["-l", "other", "list"], "")).contains("best 0/2"));
+    assert!(env.progress_dir().join("tester.json").exists());
+    assert!(env.progress_dir().join("other.json").exists());
+}
+
+#[test]
+fn best_score_survives_worse_attempt_and_reset_clears_it() {
+    let env = Env::new();
+    env.quiz(&["run", "1"], "b\nb\n");
+    let output = env.quiz(&["run", "1"], "a\nb\n");
+    assert!(stdout(&output).contains("🏁 Score: 1/2 (50%)  best: 2/2  attempts: 2"));
+
+    assert_eq!(
+        stdout(&env.quiz(&["reset", "1"], "")),
+        "🗑️ Reset chapter 1\n"
+    );
+    assert!(stdout(&env.quiz(&["list"], "")).contains("not taken"));
+}
+
+#[test]
+fn list_shows_the_real_book() {
+    let dir = tempfile::tempdir().unwrap();
+    let quiz_dir = Path::new(env!("CARGO_MANIFEST_DIR")).join("docs/src/appendix/quiz");
+    let output = run(&quiz_dir, dir.path(), &["list"], "");
+
+    let out = stdout(&output);
+    assert_eq!(out.lines().count(), 20);
+    assert!(out.starts_with(" 1. Getting Started"));
+    assert!(out.contains("20. Final Project"));
+}
+
+#[test]
+fn unknown_chapter_exits_with_error() {
+    let env = Env::new();
+    let output = env.quiz(&["run", "99"], "");
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("No quiz for chapter 99"));
+}
+
+#[test]
+fn bad_arguments_exit_with_usage() {
+    let env = Env::new();
+
+    for args in [
+        &["frobnicate"][..],
+        &["run"],
+        &["run", "1", "--seed"],
+        &["-l"],
+    ] {
+        let output = env.quiz(args, "");
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        assert!(stderr(&output).contains("Usage: quiz"));
+    }
+}
+
+#[test]
+fn invalid_learner_name_is_rejected() {
+    let env = Env::new();
+    let output = env.quiz(&["-l", "../x", "progress"], "");
+
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("Invalid learner name '../x'"));
+}
+
+#[test]
+fn validate_reports_problems_and_fails() {
+    let env = Env::new();
+    assert!(
+        stdout(&env.quiz(&["validate"], ""))
+            .contains("✅ 1 quizzes, 2 questions - no problems found")
+    );
+
+    let broken = FIXTURE.replace("**B. `rustc --version`**", "**E. `rustc --version`**");
+    std::fs::write(env.dir.path().join("quiz/ch01-quiz.md"), broken).unwrap();
+
+    let output = env.quiz(&["validate"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(
+        stdout(&output)
+            .contains("ch01-quiz.md:31: คำถามที่ 2: answer E is not one of the options (AB)")
+    );
+    assert!(stderr(&output).contains("Quiz bank has 1 problem(s)"));
+
+    // export ไม่ยอมเขียนคลังที่มีปัญหา
+    let output = env.quiz(&["export"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout(&output), "");
+}
+
+#[test]
+fn export_writes_json() {
+    let env = Env::new();
+    let file = env.dir.path().join("bank.json");
+    let output = env.quiz(&["export", "-o", file.to_str().unwrap()], "");
+
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert!(stdout(&output).contains("📦 Exported 2 questions to"));
+
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&file).unwrap()).unwrap();
+    assert_eq!(json[0]["file"], "ch01-quiz.md");
+    assert_eq!(
+        json[0]["questions"][1]["answer"]["text"],
+        "`rustc --version`"
+    );
+
+    let json: serde_json::Value =
+        serde_json::from_str(&stdout(&env.quiz(&["export"], ""))).unwrap();
+    assert_eq!(json[0]["questions"].as_array().unwrap().len(), 2);
+}
@@ -0,0 +1,246 @@
+// ===========================================
+// Tests สำหรับการตรวจคลังคำถาม (validate) และ JSON export
+// รัน: cargo test --test quiz_validate
+// ===========================================
+
+use rust_tutorial::quiz::{IssueKind, Snippet, check_rust, validate, validate_bank};
+
+const QUIZ_DIR: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/docs/src/appendix/quiz");
+
+// แต่ละข้อผิดคนละแบบ
+const BROKEN: &str = "\
+# Quiz: บทที่ 3 - Functions
+
+## คำถาม 3.1
+
+ข้อใดถูก?
+
+A. หนึ่ง
+B. สอง
+
+<details>
+<summary>ดูเฉลย</summary>
+
+**E. ห้า**
+
+</details>
+
+## คำถาม 3.2
+
+ข้อใดถูก?
+
+A. หนึ่ง
+B. สอง
+
+<details>
+<summary>ดูเฉลย</summary>
+
+**B. สาม**
+
+</details>
+
+## คำถาม 3.3
+
+ไม่มีเฉลย
+
+A. หนึ่ง
+B. สอง
+
+<details>
+<summary>ดูเฉลย</summary>
+
+</details>
+
+## คำถาม 3.4
+
+ข้อใดถูก?
+
+A. หนึ่ง
+C. สาม
+
+<details>
+<summary>ดูเฉลย</summary>
+
+**A. หนึ่ง**
+
+```rust,ignore
+fn main() {
+    let x = ;
+}
+```
+
+</details>
+
+## คำถาม 3.5
+
+ข้อใดถูก?
+
+A. หนึ่ง
+B. สอง
+
+<details>
+<summary>ดูเฉลย</summary>
+
+**A. หนึ่ง**
+
+**B. สอง**
+
+</details>
+";
+
+fn snippet(code: &str) -> Snippet {
+    Snippet {
+        info: "rust".to_string(),
+        line: 10,
+        code: code.to_string(),
+    }
+}
+
+#[test]
+fn the_book_has_no_problems() {
+    let report = validate_bank(QUIZ_DIR).unwrap();
+
+    for issue in &report.issues {
+        eprintln!("{}", issue);
+    }
+    assert!(report.is_ok());
+    assert_eq!(report.quizzes.len(), 20);
+    assert_eq!(report.question_count(), 100);
+}
+
+#[test]
+fn reports_every_problem_with_line() {
+    let (quiz, issues) = validate("ch03-quiz.md", BROKEN);
+    let found: Vec<(usize, &IssueKind)> = issues.iter().map(|i| (i.line, &i.kind)).collect();
+
+    assert_eq!(found.len(), 6, "{:#?}", found);
+
+    // 3.1: ตัวอักษรเฉลยไม่มีในตัวเลือก
+    assert_eq!(
+        found[0],
+        (
+            13,
+            &IssueKind::AnswerNotAnOption {
+                question: "คำถาม 3.1".into(),
+                letter: 'E',
+                options: "AB".into(),
+            }
+        )
+    );
+
+    // 3.2: ตัวอักษรตรงแต่ข้อความไม่ตรง
+    assert_eq!(
+        found[1],
+        (
+            27,
+            &IssueKind::AnswerMismatch {
+                question: "คำถาม 3.2".into(),
+                letter: 'B',
+                answer: "สาม".into(),
+                option: "สอง".into(),
+            }
+        )
+    );
+
+    // 3.3: ไม่มีเฉลยเลย
+    assert_eq!(found[2].0, 41);
+    assert!(matches!(found[2].1, IssueKind::Malformed { message }
+        if message == "คำถาม 3.3: no answer inside <details>"));
+
+    // 3.4: ตัวเลือกข้าม B และ code parse ไม่ได้
+    assert_eq!(
+        found[3],
+        (
+            47,
+            &IssueKind::OptionOrder {
+                question: "คำถาม 3.4".into(),
+                found: "AC".into(),
+            }
+        )
+    );
+    assert_eq!(found[4].0, 57);
+    assert!(matches!(found[4].1, IssueKind::Syntax { question, .. } if question == "คำถาม 3.4"));
+
+    // 3.5: เฉลยสองข้อ
+    assert_eq!(found[5].0, 75);
+    assert!(matches!(found[5].1, IssueKind::Malformed { message }
+        if message == "คำถาม 3.5: more than one answer: **B. สอง**"));
+
+    // คำถามที่อ่านได้ยังอยู่ใน quiz
+    let numbers: Vec<u32> = quiz.unwrap().questions.iter().map(|q| q.number).collect();
+    assert_eq!(numbers, [1, 2, 4]);
+}
+
+#[test]
+fn issue_display_has_file_and_line() {
+    let (_, issues) = validate("docs/ch03-quiz.md", BROKEN);
+    assert_eq!(
+        issues[0].to_string(),
+        "docs/ch03-quiz.md:13: คำถาม 3.1: answer E is not one of the options (AB)"
+    );
+}
+
+#[test]
+fn rust_snippets_may_be_items_or_statements() {
+    assert_eq!(
+        check_rust(&snippet("fn main() {}\nstruct Point { x: i32 }")),
+        Ok(())
+    );
+    assert_eq!(check_rust(&snippet("let v = vec![1, 2];\nv.len()")), Ok(()));
+    assert_eq!(check_rust(&snippet("use std::fmt;\nlet x = 5;")), Ok(()));
+    // บรรทัดที่ซ่อนใน mdBook
+    assert_eq!(
+        check_rust(&snippet("# fn main() {\nlet x = 1;\n# }")),
+        Ok(())
+    );
+}
+
+#[test]
+fn rust_syntax_error_points_at_line() {
+    let (line, message) = check_rust(&snippet("let a = 1;\nlet b = ;\n")).unwrap_err();
+    assert_eq!(line, 2);
+    assert!(!message.is_empty());
+
+    let (line, _) = check_rust(&snippet("fn f() {\n    1 +\n}\n\nfn g( {}")).unwrap_err();
+    assert_eq!(line, 5);
+}
+
+#[test]
+fn only_rust_snippets_are_checked() {
+    let content = BROKEN.replace("```rust,ignore", "```text");
+    let (_, issues) = validate("ch03-quiz.md", &content);
+    assert!(
+        !issues
+            .iter()
+            .any(|i| matches!(i.kind, IssueKind::Syntax { .. }))
+    );
+}
+
+#[test]
+fn exports_typed_json() {
+    let report = validate_bank(QUIZ_DIR).unwrap();
+    let json = serde_json::to_value(&report.quizzes).unwrap();
+
+    let first = &json[0];
+    assert_eq!(first["chapter"], 1);
+    assert_eq!(first["title"], "Getting Started");
+    assert_eq!(first["file"], "ch01-quiz.md");
+
+    let question = &first["questions"][0];
+    assert_eq!(question["number"], 1);
+    assert_eq!(question["heading"], "คำถามที่ 1");
+    assert_eq!(question["choices"][1]["letter"], "B");
+    assert_eq!(question["choices"][1]["text"], "`rustc --version`");
+    assert_eq!(question["answer"]["letter"], "B");
+    assert!(question["line"].as_u64().unwrap() > 0);
+
+    // ch05 ข้อ 2 มี code ในคำถาม
+    let snippet = &json[4]["questions"][1]["snippets"][0];
+    assert_eq!(snippet["info"], "rust,ignore");
+    assert!(
+        snippet["code"]
+            .as_str()
+            .unwrap()
+            .starts_with("let s1 = String::from")
+    );
+}
@@ -0,0 +1,321 @@
+// ===========================================
+// Integration tests สำหรับ todo CLI
+// รัน: cargo test --test todo_cli
+// ===========================================
+
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output};
+use tempfile::TempDir;
+
+// รัน binary todo โดยใช้ไฟล์ todos.json ใน temp dir
+fn todo(file: &Path, args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_todo"))
+        .args(args)
+        .env("TODO_FILE", file)
+        .output()
+        .expect("failed to run todo binary")
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+fn stderr(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stderr).into_owned()
+}
+
+fn setup() -> (TempDir, PathBuf) {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("todos.json");
+    (dir, file)
+}
+
+#[test]
+fn add_and_list() {
+    let (_dir, file) = setup();
+
+    let output = todo(&file, &["add", "Learn", "Rust"]);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "✅ Added: Learn Rust\n");
+
+    todo(&file, &["add", "Read the book"]);
+
+    let output = todo(&file, &["list"]);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "[ ] 1. Learn Rust\n[ ] 2. Read the book\n");
+}
+
+#[test]
+fn list_empty_without_file() {
+    let (_dir, file) = setup();
+
+    let output = todo(&file, &["list"]);
+    assert!(output.status.success());
+    assert!(stdout(&output).contains("No todos"));
+    assert!(!file.exists(), "list must not create the file");
+}
+
+#[test]
+fn complete_and_uncomplete() {
+    let (_dir, file) = setup();
+    todo(&file, &["add", "Learn Rust"]);
+
+    let output = todo(&file, &["complete", "1"]);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "✅ Completed: Learn Rust\n");
+    assert_eq!(stdout(&todo(&file, &["ls"])), "[✓] 1. Learn Rust\n");
+
+    let output = todo(&file, &["uncomplete", "1"]);
+    assert!(output.status.success());
+    assert_eq!(stdout(&todo(&file, &["ls"])), "[ ] 1. Learn Rust\n");
+}
+
+#[test]
+fn remove_does_not_reuse_existing_ids() {
+    let (_dir, file) = setup();
+    todo(&file, &["add", "first"]);
+    todo(&file, &["add", "second"]);
+
+    let output = todo(&file, &["rm", "1"]);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "✅ Removed: first\n");
+
+    todo(&file, &["add", "third"]);
+    assert_eq!(
+        stdout(&todo(&file, &["list"])),
+        "[ ] 2. second\n[ ] 3. third\n"
+    );
+}
+
+#[test]
+fn clear_removes_everything() {
+    let (_dir, file) = setup();
+    todo(&file, &["add", "a"]);
+    todo(&file, &["add", "b"]);
+
+    let output = todo(&file, &["clear"]);
+    assert!(output.status.success());
+    assert!(stdout(&todo(&file, &["list"])).contains("No todos"));
+}
+
+#[test]
+fn unknown_id_exits_with_error() {
+    let (_dir, file) = setup();
+    todo(&file, &["add", "a"]);
+
+    let output = todo(&file, &["complete", "42"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("Todo with id 42 not found"));
+}
+
+#[test]
+fn invalid_usage_exits_with_code_2() {
+    let (_dir, file) = setup();
+
+    for args in [
+        &["add"][..],
+        &["complete"][..],
+        &["remove", "abc"][..],
+        &["frobnicate"][..],
+    ] {
+        let output = todo(&file, args);
+        assert_eq!(output.status.code(), Some(2), "args: {:?}", args);
+        assert!(stderr(&output).contains("Usage: todo"));
+    }
+}
+
+#[test]
+fn extra_ids_are_rejected() {
+    let (_dir, file) = setup();
+    todo(&file, &["add", "a"]);
+    todo(&file, &["add", "b"]);
+
+    for command in ["done", "uncomplete", "rm"] {
+        let output = todo(&file, &[command, "1", "2"]);
+        assert_eq!(output.status.code(), Some(2), "command: {}", command);
+        assert!(stderr(&output).contains("unexpected argument '2'"));
+    }
+    assert_eq!(stdout(&todo(&file, &["ls"])), "[ ] 1. a\n[ ] 2. b\n");
+}
+
+#[test]
+fn help_succeeds() {
+    let (_dir, file) = setup();
+
+    for args in [&[][..], &["help"][..]] {
+        let output = todo(&file, args);
+        assert!(output.status.success());
+        assert!(stdout(&output).starts_with("Usage: todo"));
+    }
+}
+
+#[test]
+fn corrupt_file_is_reported_and_left_untouched() {
+    let (_dir, file) = setup();
+    std::fs::write(&file, "not json").unwrap();
+
+    let output = todo(&file, &["add", "x"]);
+    assert_eq!(output.status.code(), Some(1));
+    assert!(stderr(&output).contains("Failed to parse"));
+    assert_eq!(std::fs::read_to_string(&file).unwrap(), "not json");
+}
+
+#[test]
+fn compact_leaves_only_snapshot() {
+    let (dir, file) = setup();
+    todo(&file, &["add", "x"]);
+    assert!(dir.path().join("todos.json.journal").exists());
+
+    let output = todo(&file, &["compact"]);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "🗜️ Compacted 1 journal entries\n");
+
+    let mut names: Vec<_> = std::fs::read_dir(dir.path())
+        .unwrap()
+        .map(|e| e.unwrap().file_name())
+        .collect();
+    names.sort();
+    assert_eq!(names, vec!["todos.json", "todos.json.journal"]);
+    assert_eq!(
+        std::fs::read_to_string(dir.path().join("todos.json.journal")).unwrap(),
+        ""
+    );
+
+    let json: serde_json::Value =
+        serde_json::from_str(&std::fs::read_to_string(&file).unwrap()).unwrap();
+    assert_eq!(json["todos"][0]["title"], "x");
+    assert_eq!(json["todos"][0]["completed"], false);
+}
+
+#[test]
+fn undo_and_redo_across_runs() {
+    let (_dir, file) = setup();
+    todo(&file, &["add", "keep"]);
+    todo(&file, &["add", "drop"]);
+    todo(&file, &["rm", "2"]);
+    assert_eq!(stdout(&todo(&file, &["ls"])), "[ ] 1. keep\n");
+
+    let output = todo(&file, &["undo"]);
+    assert!(output.status.success());
+    assert_eq!(stdout(&output), "↩️ Undone: remove \"drop\"\n");
+    assert_eq!(stdout(&todo(&file, &["ls"])), "[ ] 1. keep\n[ ] 2. drop\n");
+
+    let output = todo(&file, &["redo"]);
+    assert_eq!(stdout(&output), "↪️ Redone: remove \"drop\"\n");
+    assert_eq!(stdout(&todo(&file, &["ls"])), "[ ] 1. keep\n");
+
+    todo(&file, &["undo"]);
+    todo(&file, &["undo"]);
+    todo(&file, &["undo"]);
+    assert_eq!(stdout(&todo(&file, &["undo"])), "Nothing to undo\n");
+    assert_eq!(
+        stdout(&todo(&file, &["ls"])),
+        "📭 No todos yet. Add one with: todo add <title>\n"
+    );
+}
+
+#[test]
+fn sqlite_file_uses_sqlite_backend() {
+    let dir = tempfile::tempdir().unwrap();
+    let file = dir.path().join("todos.db");
+    todo(&file, &["add", "in sqlite"]);
+    todo(&file, &["done", "1"]);
+    todo(&file, &["undo"]);
+
+    assert_eq!(stdout(&todo(&file, &["ls"])), "[ ] 1. in sqlite\n");
+    assert!(file.exists());
+    assert!(!dir.path().join("todos.db.journal").exists());
+}
+
+#[test]
+fn add_with_priority_due_and_tags() {
+    let (_dir, file) = setup();
+
+    let output = todo(
+        &file,
+        &[
+            "add",
+            "Ship",
+            "release",
+            "-p",
+            "high",
+            "--due",
+            "2030-01-31",
+            "-t",
+            "#Work",
+        ],
+    );
+    assert!(output.status.success(), "{}", stderr(&output));
+    assert_eq!(stdout(&output), "✅ Added: Ship release\n");
+
+    assert_eq!(
+        stdout(&todo(&file, &["list"])),
+        "[ ] 1. Ship release !high (due 2030-01-31) #work\n"
+    );
+}
+
+#[test]
+fn add_rejects_bad_options() {
+    let (_dir, file) = setup();
+
+    for args in [
+        &["add", "x", "-p", "urgent"][..],
+        &["add", "x", "--due", "someday"][..],
+        &["add", "x", "--tag"][..],
+    ] {
+        let output = todo(&file, args);
+        assert_eq!(output.status.code(), Some(2), "args: {:?}", args);
+    }
+    assert!(!file.exists());
+}
+
+#[test]
+fn list_filters_and_sorts() {
+    let (_dir, file) = setup();
+    todo(&file, &["add", "old", "-d", "2000-01-01", "-t", "work"]);
+    todo(
+        &file,
+        &[
+            "add",
+            "later",
+            "-d",
+            "2999-01-01",
+            "-t",
+            "work",
+            "-p",
+            "high",
+        ],
+    );
+    todo(&file, &["add", "home", "-t", "home", "-p", "low"]);
+    todo(&file, &["complete", "3"]);
+
+    let titles = |args: &[&str]| -> Vec<String> {
+        stdout(&todo(&file, args))
+            .lines()
+            .map(|l| {
+                l.split(". ")
+                    .nth(1)
+                    .unwrap()
+                    .split(' ')
+                    .next()
+                    .unwrap()
+                    .to_string()
+            })
+            .collect()
+    };
+
+    assert_eq!(titles(&["list", "--tag", "work"]), ["old", "later"]);
+    assert_eq!(titles(&["list", "--overdue"]), ["old"]);
+    assert_eq!(titles(&["list", "--done"]), ["home"]);
+    assert_eq!(
+        titles(&["list", "--pending", "-s", "priority"]),
+        ["later", "old"]
+    );
+    assert_eq!(
+        titles(&["list", "--sort", "due", "-r"]),
+        ["home", "later", "old"]
+    );
+
+    let output = todo(&file, &["list", "--sort", "size"]);
+    assert_eq!(output.status.code(), Some(2));
+}
@@ -0,0 +1,331 @@
+// ===========================================
+// Tests สำหรับ JournaledStore (undo/redo, compaction, backends)
+// รัน: cargo test --test todo_journal
+// ===========================================
+
+use rust_tutorial::todo::{
+    JournalBackend, JournaledStore, JsonJournal, Operation, SqliteJournal, Todo,
+};
+use std::fs;
+use std::path::Path;
+
+fn todo(title: &str) -> Todo {
+    Todo::new(0, title.to_string())
+}
+
+fn titles<B: JournalBackend>(store: &JournaledStore<B>) -> Vec<&str> {
+    store.todos().iter().map(|t| t.title.as_str()).collect()
+}
+
+fn open_json(path: &Path) -> JournaledStore<JsonJournal> {
+    JournaledStore::open(JsonJournal::new(path)).unwrap()
+}
+
+#[test]
+fn add_assigns_ids_and_persists() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("todos.json");
+
+    let mut store = open_json(&path);
+    assert_eq!(store.add(todo("a")).unwrap().id, 1);
+    assert_eq!(store.add(todo("b")).unwrap().id, 2);
+    drop(store);
+
+    let store = open_json(&path);
+    assert_eq!(titles(&store), ["a", "b"]);
+    assert_eq!(store.journal_len(), 2);
+    assert!(!path.exists(), "snapshot is only written on compaction");
+}
+
+#[test]
+fn ids_of_removed_todos_are_not_reused() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("todos.json");
+
+    let mut store = open_json(&path);
+    store.add(todo("a")).unwrap();
+    store.add(todo("b")).unwrap();
+    store.remove(2).unwrap();
+    assert_eq!(store.add(todo("c")).unwrap().id, 3);
+
+    // undo การเพิ่ม c แล้วเพิ่มใหม่ ต้องไม่ได้ id 3 ซ้ำ
+    store.undo().unwrap();
+    assert_eq!(store.add(todo("d")).unwrap().id, 4);
+    store.clear().unwrap();
+    drop(store);
+
+    // ตัวนับต้องรอดทั้งการ replay journal และการ compact
+    let mut store = open_json(&path);
+    assert_eq!(store.add(todo("e")).unwrap().id, 5);
+    store.compact().unwrap();
+    store.clear().unwrap();
+    store.compact().unwrap();
+    drop(store);
+
+    let mut store = open_json(&path);
+    assert_eq!(store.add(todo("f")).unwrap().id, 6);
+}
+
+#[test]
+fn undo_and_redo_every_operation() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut store = open_json(&dir.path().join("todos.json"));
+
+    store.add(todo("a")).unwrap();
+    store.add(todo("b")).unwrap();
+    store.add(todo("c")).unwrap();
+    store.update(2, Todo::complete).unwrap();
+    store.remove(1).unwrap();
+    store.clear().unwrap();
+    assert!(store.todos().is_empty());
+
+    assert!(matches!(
+        store.undo().unwrap(),
+        Some(Operation::Clear { .. })
+    ));
+    assert_eq!(titles(&store), ["b", "c"]);
+
+    // Remove ต้องคืนตำแหน่งเดิม
+    assert!(matches!(
+        store.undo().unwrap(),
+        Some(Operation::Remove { .. })
+    ));
+    assert_eq!(titles(&store), ["a", "b", "c"]);
+
+    assert!(matches!(
+        store.undo().unwrap(),
+        Some(Operation::Update { .. })
+    ));
+    assert!(!store.get(2).unwrap().completed);
+
+    assert!(matches!(
+        store.redo().unwrap(),
+        Some(Operation::Update { .. })
+    ));
+    assert!(store.get(2).unwrap().completed);
+    assert!(store.can_redo());
+
+    // การเปลี่ยนแปลงใหม่ล้าง redo
+    store.add(todo("d")).unwrap();
+    assert!(!store.can_redo());
+    assert_eq!(store.redo().unwrap(), None);
+}
+
+#[test]
+fn undo_on_empty_history_does_nothing() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut store = open_json(&dir.path().join("todos.json"));
+
+    assert_eq!(store.undo().unwrap(), None);
+    assert_eq!(store.update(1, Todo::complete).unwrap(), None);
+    assert_eq!(store.remove(1).unwrap(), None);
+    assert_eq!(store.clear().unwrap(), 0);
+    assert_eq!(store.journal_len(), 0);
+}
+
+#[test]
+fn unchanged_update_is_not_recorded() {
+    let dir = tempfile::tempdir().unwrap();
+    let mut store = open_json(&dir.path().join("todos.json"));
+
+    store.add(todo("a")).unwrap();
+    store.update(1, |t| t.title = "a".to_string()).unwrap();
+    assert_eq!(store.journal_len(), 1);
+}
+
+#[test]
+fn undo_history_survives_reopen_and_compaction() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("todos.json");
+
+    let mut store = open_json(&path);
+    store.add(todo("a")).unwrap();
+    store.add(todo("b")).unwrap();
+    store.undo().unwrap();
+    store.compact().unwrap();
+    assert_eq!(store.journal_len(), 0);
+    drop(store);
+
+    assert_eq!(
+        fs::read_to_string(dir.path().join("todos.json.journal")).unwrap(),
+        ""
+    );
+
+    let mut store = open_json(&path);
+    assert_eq!(titles(&store), ["a"]);
+    store.redo().unwrap();
+    assert_eq!(titles(&store), ["a", "b"]);
+    store.undo().unwrap();
+    store.undo().unwrap();
+    assert!(store.todos().is_empty());
+    assert!(!store.can_undo());
+}
+
+#[test]
+fn compacts_automatically() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("todos.json");
+
+    let mut store = open_json(&path).with_compact_every(3);
+    for title in ["a", "b", "c", "d"] {
+        store.add(todo(title)).unwrap();
+    }
+    assert_eq!(store.journal_len(), 1);
+    assert!(path.exists());
+    drop(store);
+
+    assert_eq!(titles(&open_json(&path)), ["a", "b", "c", "d"]);
+}
+
+#[test]
+fn failed_auto_compaction_keeps_the_change() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("todos.json");
+
+    let mut store = open_json(&path).with_compact_every(2);
+    // snapshot เขียนไม่ได้ (มีโฟลเดอร์ชื่อเดียวกันขวางอยู่) แต่ journal ยังเขียนได้
+    fs::create_dir(&path).unwrap();
+    for title in ["a", "b", "c"] {
+        store.add(todo(title)).unwrap();
+    }
+    assert_eq!(store.journal_len(), 3);
+
+    // ลอง compact ใหม่ตอนบันทึกครั้งถัดไป
+    fs::remove_dir(&path).unwrap();
+    store.remove(1).unwrap();
+    assert_eq!(store.journal_len(), 0);
+    drop(store);
+
+    assert_eq!(titles(&open_json(&path)), ["b", "c"]);
+}
+
+#[test]
+fn entries_covered_by_snapshot_are_skipped() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("todos.json");
+    let journal = dir.path().join("todos.json.journal");
+
+    let mut store = open_json(&path);
+    store.add(todo("a")).unwrap();
+    let stale = fs::read_to_string(&journal).unwrap();
+    store.compact().unwrap();
+    drop(store);
+
+    // จำลองโปรแกรมตายหลังเขียน snapshot แต่ก่อนล้าง journal
+    fs::write(&journal, stale).unwrap();
+    assert_eq!(titles(&open_json(&path)), ["a"]);
+}
+
+#[test]
+fn torn_last_line_is_ignored() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("todos.json");
+    let journal = dir.path().join("todos.json.journal");
+
+    let mut store = open_json(&path);
+    store.add(todo("a")).unwrap();
+    store.add(todo("b")).unwrap();
+    drop(store);
+
+    let content = fs::read_to_string(&journal).unwrap();
+    fs::write(&journal, &content[..content.len() - 10]).unwrap();
+    assert_eq!(titles(&open_json(&path)), ["a"]);
+}
+
+#[test]
+fn append_after_torn_line_keeps_journal_readable() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("todos.json");
+    let journal = dir.path().join("todos.json.journal");
+
+    let mut store = open_json(&path);
+    store.add(todo("a")).unwrap();
+    drop(store);
+
+    // เหมือนโปรแกรมตายระหว่างเขียน entry ถัดไป
+    let mut content = fs::read_to_string(&journal).unwrap();
+    content.push_str(r#"{"seq":2,"at":"2026"#);
+    fs::write(&journal, content).unwrap();
+
+    let mut store = open_json(&path);
+    store.add(todo("b")).unwrap();
+    drop(store);
+
+    assert_eq!(titles(&open_json(&path)), ["a", "b"]);
+}
+
+#[test]
+fn append_after_missing_final_newline_keeps_entry() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("todos.json");
+    let journal = dir.path().join("todos.json.journal");
+
+    let mut store = open_json(&path);
+    store.add(todo("a")).unwrap();
+    drop(store);
+
+    let content = fs::read_to_string(&journal).unwrap();
+    fs::write(&journal, content.trim_end()).unwrap();
+
+    let mut store = open_json(&path);
+    store.add(todo("b")).unwrap();
+    drop(store);
+
+    assert_eq!(titles(&open_json(&path)), ["a", "b"]);
+}
+
+#[test]
+fn corrupt_journal_is_an_error() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("todos.json");
+    fs::write(dir.path().join("todos.json.journal"), "not json\n").unwrap();
+
+    assert!(JournaledStore::open(JsonJournal::new(&path)).is_err());
+}
+
+#[test]
+fn reads_legacy_todo_array() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("todos.json");
+    fs::write(
+        &path,
+        r#"[{"id": 3, "title": "old", "completed": false, "created_at": "2024-01-01T00:00:00Z"}]"#,
+    )
+    .unwrap();
+
+    let mut store = open_json(&path);
+    assert_eq!(titles(&store), ["old"]);
+    assert_eq!(store.add(todo("new")).unwrap().id, 4);
+    assert!(matches!(store.undo().unwrap(), Some(Operation::Add { .. })));
+    assert_eq!(titles(&store), ["old"]);
+}
+
+#[test]
+fn sqlite_backend_round_trip() {
+    let dir = tempfile::tempdir().unwrap();
+    let path = dir.path().join("todos.db");
+    let open = || JournaledStore::open(SqliteJournal::open(&path).unwrap()).unwrap();
+
+    let mut store = open();
+    store.add(todo("a")).unwrap();
+    store.add(todo("b")).unwrap();
+    store.remove(1).unwrap();
+    drop(store);
+
+    let mut store = open();
+    assert_eq!(titles(&store), ["b"]);
+    store.undo().unwrap();
+    store.compact().unwrap();
+    store.update(2, Todo::complete).unwrap();
+    drop(store);
+
+    let mut store = open();
+    assert_eq!(titles(&store), ["a", "b"]);
+    assert!(store.get(2).unwrap().completed);
+    assert_eq!(store.journal_len(), 1);
+
+    // undo: Update แล้วตามด้วย Add b (Remove ถูก undo ไปก่อนหน้าแล้ว)
+    store.undo().unwrap();
+    store.undo().unwrap();
+    assert_eq!(titles(&store), ["a"]);
+}
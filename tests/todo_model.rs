@@ -0,0 +1,219 @@
+// ===========================================
+// Tests สำหรับ rust_tutorial::todo (model, due dates, query)
+// รัน: cargo test --test todo_model
+// ===========================================
+
+use chrono::NaiveDate;
+use rust_tutorial::todo::{DueDateError, Priority, Query, SortOrder, Status, Todo, parse_due};
+
+fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+    NaiveDate::from_ymd_opt(y, m, d).unwrap()
+}
+
+// วันพุธที่ 10 มกราคม 2024
+fn today() -> NaiveDate {
+    date(2024, 1, 10)
+}
+
+#[test]
+fn parse_due_keywords() {
+    assert_eq!(parse_due("today", today()), Ok(date(2024, 1, 10)));
+    assert_eq!(parse_due("Tomorrow", today()), Ok(date(2024, 1, 11)));
+    assert_eq!(parse_due(" yesterday ", today()), Ok(date(2024, 1, 9)));
+    assert_eq!(parse_due("next week", today()), Ok(date(2024, 1, 17)));
+}
+
+#[test]
+fn parse_due_relative() {
+    assert_eq!(parse_due("in 3 days", today()), Ok(date(2024, 1, 13)));
+    assert_eq!(parse_due("in 1 day", today()), Ok(date(2024, 1, 11)));
+    assert_eq!(parse_due("in 2 weeks", today()), Ok(date(2024, 1, 24)));
+    assert_eq!(parse_due("+3d", today()), Ok(date(2024, 1, 13)));
+    assert_eq!(parse_due("+10d", today()), Ok(date(2024, 1, 20)));
+    assert_eq!(parse_due("+1w", today()), Ok(date(2024, 1, 17)));
+}
+
+#[test]
+fn parse_due_weekdays() {
+    // วันเดียวกับวันนี้ = สัปดาห์หน้า
+    assert_eq!(parse_due("wednesday", today()), Ok(date(2024, 1, 17)));
+    assert_eq!(parse_due("friday", today()), Ok(date(2024, 1, 12)));
+    assert_eq!(parse_due("mon", today()), Ok(date(2024, 1, 15)));
+    assert_eq!(parse_due("next friday", today()), Ok(date(2024, 1, 19)));
+}
+
+#[test]
+fn parse_due_absolute() {
+    assert_eq!(parse_due("2024-02-29", today()), Ok(date(2024, 2, 29)));
+}
+
+#[test]
+fn parse_due_errors() {
+    for input in [
+        "",
+        "someday",
+        "in x days",
+        "in 3 months",
+        "+3",
+        "+d",
+        "+3ว",
+        "2023-02-29",
+    ] {
+        assert_eq!(
+            parse_due(input, today()),
+            Err(DueDateError::Unrecognised(input.to_string())),
+            "input: {:?}",
+            input
+        );
+    }
+    assert_eq!(
+        parse_due("in 999999999999 days", today()),
+        Err(DueDateError::OutOfRange("in 999999999999 days".to_string()))
+    );
+}
+
+#[test]
+fn priority_parse_and_order() {
+    assert_eq!("HIGH".parse(), Ok(Priority::High));
+    assert_eq!("m".parse(), Ok(Priority::Medium));
+    assert!("urgent".parse::<Priority>().is_err());
+    assert!(Priority::Low < Priority::Medium && Priority::Medium < Priority::High);
+}
+
+#[test]
+fn tags_are_normalised() {
+    let todo = Todo::new(1, "x".into())
+        .with_tag("#Work")
+        .with_tag("work")
+        .with_tag("home");
+
+    assert_eq!(todo.tags, vec!["work", "home"]);
+    assert!(todo.has_tag("WORK"));
+}
+
+#[test]
+fn overdue_ignores_completed() {
+    let mut todo = Todo::new(1, "x".into()).with_due(date(2024, 1, 9));
+    assert!(todo.is_overdue(today()));
+    assert!(!todo.is_overdue(date(2024, 1, 9)));
+
+    todo.complete();
+    assert!(!todo.is_overdue(today()));
+}
+
+#[test]
+fn old_json_without_new_fields_still_loads() {
+    let json = r#"{"id":1,"title":"Learn Rust","completed":false,
+        "created_at":"2024-01-01T00:00:00Z","completed_at":null}"#;
+    let todo: Todo = serde_json::from_str(json).unwrap();
+
+    assert_eq!(todo.priority, Priority::Medium);
+    assert_eq!(todo.due, None);
+    assert!(todo.tags.is_empty());
+}
+
+fn sample() -> Vec<Todo> {
+    let mut done = Todo::new(4, "Done".into())
+        .with_tag("work")
+        .with_due(date(2024, 1, 1));
+    done.complete();
+
+    vec![
+        Todo::new(1, "Write report".into())
+            .with_tag("work")
+            .with_priority(Priority::High)
+            .with_due(date(2024, 1, 12)),
+        Todo::new(2, "buy milk".into())
+            .with_tag("home")
+            .with_due(date(2024, 1, 8)),
+        Todo::new(3, "Answer email".into())
+            .with_tag("work")
+            .with_priority(Priority::Low),
+        done,
+        Todo::new(5, "Fix bug".into())
+            .with_tag("work")
+            .with_tag("urgent")
+            .with_priority(Priority::High)
+            .with_due(date(2024, 1, 5)),
+    ]
+}
+
+fn ids(todos: Vec<&Todo>) -> Vec<u32> {
+    todos.iter().map(|t| t.id).collect()
+}
+
+#[test]
+fn query_default_returns_all_by_id() {
+    let todos = sample();
+    assert_eq!(ids(Query::new().apply(&todos)), vec![1, 2, 3, 4, 5]);
+}
+
+#[test]
+fn query_by_tags() {
+    let todos = sample();
+    assert_eq!(
+        ids(Query::new().tag("work").apply(&todos)),
+        vec![1, 3, 4, 5]
+    );
+    assert_eq!(
+        ids(Query::new().tag("#WORK").tag("urgent").apply(&todos)),
+        vec![5]
+    );
+}
+
+#[test]
+fn query_by_status_and_overdue() {
+    let todos = sample();
+    assert_eq!(
+        ids(Query::new().status(Status::Completed).apply(&todos)),
+        vec![4]
+    );
+    assert_eq!(
+        ids(Query::new().status(Status::Pending).apply(&todos)),
+        vec![1, 2, 3, 5]
+    );
+    assert_eq!(ids(Query::new().overdue(today()).apply(&todos)), vec![2, 5]);
+}
+
+#[test]
+fn query_due_by_and_min_priority() {
+    let todos = sample();
+    assert_eq!(
+        ids(Query::new().due_by(date(2024, 1, 8)).apply(&todos)),
+        vec![2, 4, 5]
+    );
+    assert_eq!(
+        ids(Query::new().min_priority(Priority::Medium).apply(&todos)),
+        vec![1, 2, 4, 5]
+    );
+}
+
+#[test]
+fn query_sort_orders() {
+    let todos = sample();
+    assert_eq!(
+        ids(Query::new().sort_by(SortOrder::Due).apply(&todos)),
+        vec![4, 5, 2, 1, 3]
+    );
+    assert_eq!(
+        ids(Query::new().sort_by(SortOrder::Priority).apply(&todos)),
+        vec![5, 1, 4, 2, 3]
+    );
+    assert_eq!(
+        ids(Query::new().sort_by(SortOrder::Title).apply(&todos)),
+        vec![3, 2, 4, 5, 1]
+    );
+    assert_eq!(
+        ids(Query::new()
+            .sort_by(SortOrder::Due)
+            .reversed()
+            .apply(&todos)),
+        vec![3, 1, 2, 5, 4]
+    );
+}
+
+#[test]
+fn sort_order_from_str() {
+    assert_eq!("Due".parse(), Ok(SortOrder::Due));
+    assert!("size".parse::<SortOrder>().is_err());
+}
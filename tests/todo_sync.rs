@@ -0,0 +1,332 @@
+// ===========================================
+// Tests สำหรับ rust_tutorial::todo::SyncStore
+// รัน: cargo test --test todo_sync
+// ===========================================
+
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use rust_tutorial::todo::{Change, Resolution, SyncRequest, SyncStore, TodoFields};
+
+fn at(minutes: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 1, 10, 9, 0, 0).unwrap() + Duration::minutes(minutes)
+}
+
+fn fields(title: &str) -> TodoFields {
+    TodoFields {
+        title: title.to_string(),
+        completed: false,
+        priority: Default::default(),
+        due: None,
+        tags: Vec::new(),
+    }
+}
+
+fn edit(id: u32, base_version: u64, title: &str, minutes: i64) -> Change {
+    Change {
+        id: Some(id),
+        client_ref: None,
+        base_version,
+        updated_at: at(minutes),
+        deleted: false,
+        fields: Some(fields(title)),
+    }
+}
+
+fn create(client_ref: &str, title: &str, minutes: i64) -> Change {
+    Change {
+        id: None,
+        client_ref: Some(client_ref.to_string()),
+        base_version: 0,
+        updated_at: at(minutes),
+        deleted: false,
+        fields: Some(fields(title)),
+    }
+}
+
+fn titles(store: &SyncStore) -> Vec<String> {
+    store.todos().map(|t| t.todo.title.clone()).collect()
+}
+
+#[test]
+fn create_update_delete_bump_versions() {
+    let mut store = SyncStore::new();
+    let id = store.create(fields("a"), at(0)).todo.id;
+    assert_eq!((id, store.revision()), (1, 1));
+
+    let item = store.update(id, fields("b"), at(1)).unwrap();
+    assert_eq!((item.version, item.revision), (2, 2));
+
+    assert!(store.delete(id, at(2)));
+    assert!(store.get(id).is_none());
+    assert!(!store.delete(id, at(3)));
+    assert!(store.update(id, fields("c"), at(3)).is_none());
+
+    // tombstone ยังถูกส่งให้ client ที่ยังไม่เห็น
+    let changes = store.changes_since(0);
+    assert_eq!(changes.len(), 1);
+    assert!(changes[0].deleted);
+    assert_eq!(changes[0].version, 3);
+}
+
+#[test]
+fn ids_are_not_reused_after_delete() {
+    let mut store = SyncStore::new();
+    store.create(fields("a"), at(0));
+    let id = store.create(fields("b"), at(0)).todo.id;
+    store.delete(id, at(1));
+
+    assert_eq!(store.create(fields("c"), at(2)).todo.id, 3);
+}
+
+#[test]
+fn completion_time_comes_from_the_change() {
+    let done = |title| TodoFields {
+        completed: true,
+        ..fields(title)
+    };
+    let mut store = SyncStore::new();
+    let id = store.create(done("a"), at(0)).todo.id;
+    assert_eq!(store.get(id).unwrap().todo.completed_at, Some(at(0)));
+
+    // แก้อย่างอื่นทีหลัง เวลาที่ทำเสร็จไม่เปลี่ยน
+    store.update(id, done("b"), at(5));
+    assert_eq!(store.get(id).unwrap().todo.completed_at, Some(at(0)));
+
+    // ทำเสร็จผ่าน sync ใช้เวลาของ change (ทุกเครื่องได้ค่าเดียวกัน)
+    let other = store.create(fields("c"), at(1)).todo.id;
+    let mut change = edit(other, 1, "c", 7);
+    change.fields = Some(done("c"));
+    store.sync(
+        SyncRequest {
+            since: 0,
+            changes: vec![change],
+        },
+        at(60),
+    );
+    assert_eq!(store.get(other).unwrap().todo.completed_at, Some(at(7)));
+}
+
+#[test]
+fn sync_creates_items_and_maps_client_refs() {
+    let mut store = SyncStore::new();
+    let response = store.sync(
+        SyncRequest {
+            since: 0,
+            changes: vec![Change {
+                id: None,
+                client_ref: Some("laptop-1".to_string()),
+                base_version: 0,
+                updated_at: at(0),
+                deleted: false,
+                fields: Some(fields("from laptop")),
+            }],
+        },
+        at(5),
+    );
+
+    assert_eq!(response.created.len(), 1);
+    assert_eq!(response.created[0].client_ref, "laptop-1");
+    assert_eq!(response.changes[0].todo.id, response.created[0].id);
+    assert_eq!(response.revision, 1);
+    assert!(response.conflicts.is_empty());
+}
+
+#[test]
+fn retried_create_returns_the_same_id() {
+    let mut store = SyncStore::new();
+    let request = SyncRequest {
+        since: 0,
+        changes: vec![create("laptop-1", "from laptop", 0)],
+    };
+
+    let first = store.sync(request.clone(), at(5));
+    // response แรกหาย client ส่งซ้ำ
+    let retry = store.sync(request.clone(), at(6));
+
+    assert_eq!(titles(&store), ["from laptop"]);
+    assert_eq!(retry.created, first.created);
+    assert_eq!(retry.revision, first.revision);
+    assert_eq!(retry.changes, first.changes);
+
+    // store ที่โหลดกลับมาจาก JSON ยังจำ client_ref ได้
+    let json = serde_json::to_string(&store).unwrap();
+    let mut store: SyncStore = serde_json::from_str(&json).unwrap();
+    let retry = store.sync(request, at(7));
+    assert_eq!(titles(&store), ["from laptop"]);
+    assert_eq!(retry.created, first.created);
+}
+
+#[test]
+fn sync_returns_only_changes_since_revision() {
+    let mut store = SyncStore::new();
+    store.create(fields("a"), at(0));
+    store.create(fields("b"), at(0));
+
+    let response = store.sync(
+        SyncRequest {
+            since: 1,
+            changes: Vec::new(),
+        },
+        at(1),
+    );
+    assert_eq!(response.revision, 2);
+    assert_eq!(response.changes.len(), 1);
+    assert_eq!(response.changes[0].todo.title, "b");
+}
+
+#[test]
+fn non_conflicting_edit_is_applied() {
+    let mut store = SyncStore::new();
+    store.create(fields("a"), at(0));
+
+    let response = store.sync(
+        SyncRequest {
+            since: 1,
+            changes: vec![edit(1, 1, "a2", 1)],
+        },
+        at(2),
+    );
+
+    assert!(response.conflicts.is_empty());
+    assert_eq!(titles(&store), ["a2"]);
+    assert_eq!(store.get(1).unwrap().version, 2);
+}
+
+#[test]
+fn conflict_newer_client_wins() {
+    let mut store = SyncStore::new();
+    store.create(fields("a"), at(0));
+    store.update(1, fields("desk"), at(1)).unwrap();
+
+    // laptop แก้ต่อจาก version 1 แต่แก้หลัง desk
+    let response = store.sync(
+        SyncRequest {
+            since: 1,
+            changes: vec![edit(1, 1, "laptop", 2)],
+        },
+        at(3),
+    );
+
+    assert_eq!(response.conflicts.len(), 1);
+    assert_eq!(response.conflicts[0].resolution, Resolution::ClientWins);
+    assert_eq!(response.conflicts[0].server_version, Some(2));
+    assert_eq!(titles(&store), ["laptop"]);
+    assert_eq!(store.get(1).unwrap().version, 3);
+}
+
+#[test]
+fn conflict_newer_server_wins_and_is_returned() {
+    let mut store = SyncStore::new();
+    store.create(fields("a"), at(0));
+    store.update(1, fields("desk"), at(5)).unwrap();
+
+    // client เห็นถึง revision 2 แล้ว แต่ส่งการแก้ไขเก่าที่ค้างอยู่
+    let response = store.sync(
+        SyncRequest {
+            since: 2,
+            changes: vec![edit(1, 1, "laptop", 2)],
+        },
+        at(6),
+    );
+
+    assert_eq!(response.conflicts[0].resolution, Resolution::ServerWins);
+    assert_eq!(titles(&store), ["desk"]);
+    assert_eq!(response.changes.len(), 1);
+    assert_eq!(response.changes[0].todo.title, "desk");
+}
+
+#[test]
+fn future_client_clock_does_not_always_win() {
+    let mut store = SyncStore::new();
+    store.create(fields("a"), at(0));
+    store.update(1, fields("desk"), at(10)).unwrap();
+
+    // นาฬิกา client เร็วไป 1 วัน แต่ถูกตัดให้เท่ากับเวลาของ server (at(10))
+    let response = store.sync(
+        SyncRequest {
+            since: 0,
+            changes: vec![edit(1, 1, "laptop", 24 * 60)],
+        },
+        at(10),
+    );
+
+    assert_eq!(response.conflicts[0].resolution, Resolution::ServerWins);
+    assert_eq!(titles(&store), ["desk"]);
+}
+
+#[test]
+fn delete_through_sync() {
+    let mut store = SyncStore::new();
+    store.create(fields("a"), at(0));
+
+    let response = store.sync(
+        SyncRequest {
+            since: 1,
+            changes: vec![Change {
+                deleted: true,
+                fields: None,
+                ..edit(1, 1, "", 1)
+            }],
+        },
+        at(2),
+    );
+
+    assert!(response.conflicts.is_empty());
+    assert!(titles(&store).is_empty());
+    assert!(response.changes[0].deleted);
+}
+
+#[test]
+fn invalid_changes_are_rejected() {
+    let mut store = SyncStore::new();
+    store.create(fields("a"), at(0));
+
+    let response = store.sync(
+        SyncRequest {
+            since: 1,
+            changes: vec![
+                // ไม่พบ id
+                edit(42, 1, "x", 1),
+                // แก้ไขโดยไม่มี fields
+                Change {
+                    fields: None,
+                    ..edit(1, 1, "", 1)
+                },
+                // สร้างใหม่โดยไม่มี client_ref
+                Change {
+                    id: None,
+                    ..edit(0, 0, "new", 1)
+                },
+            ],
+        },
+        at(2),
+    );
+
+    assert_eq!(response.conflicts.len(), 3);
+    assert!(
+        response
+            .conflicts
+            .iter()
+            .all(|c| c.resolution == Resolution::Rejected)
+    );
+    assert_eq!(titles(&store), ["a"]);
+    assert_eq!(store.revision(), 1);
+}
+
+#[test]
+fn request_json_shape() {
+    let request: SyncRequest = serde_json::from_str(
+        r#"{"since": 3, "changes": [
+            {"client_ref": "c1", "updated_at": "2024-01-10T09:00:00Z",
+             "fields": {"title": "Buy milk", "tags": ["home"]}},
+            {"id": 2, "base_version": 4, "updated_at": "2024-01-10T09:00:00Z", "deleted": true}
+        ]}"#,
+    )
+    .unwrap();
+
+    assert_eq!(request.since, 3);
+    assert_eq!(
+        request.changes[0].fields.as_ref().unwrap().title,
+        "Buy milk"
+    );
+    assert!(request.changes[1].deleted);
+}
@@ -0,0 +1,244 @@
+// ===========================================
+// Tests สำหรับ rust_tutorial::examples (catalog, highlight) และ tutorial CLI
+// รัน: cargo test --test tutorial
+// ===========================================
+
+use rust_tutorial::examples::{self, Chapters, Example, Selection, parse_header, select};
+use std::fs;
+use std::io::Write;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+
+const PROJECT_DIR: &str = env!("CARGO_MANIFEST_DIR");
+
+fn catalog() -> Vec<Example> {
+    examples::load(&Path::new(PROJECT_DIR).join("examples")).unwrap()
+}
+
+fn names<'a>(selection: &Selection<'a>) -> Vec<&'a str> {
+    match selection {
+        Selection::Found(example) => vec![example.name.as_str()],
+        Selection::Ambiguous(matches) => matches.iter().map(|e| e.name.as_str()).collect(),
+        Selection::NotFound => Vec::new(),
+    }
+}
+
+fn tutorial(args: &[&str], stdin: &str) -> Output {
+    let mut child = Command::new(env!("CARGO_BIN_EXE_tutorial"))
+        .args(args)
+        .env("EXAMPLES_DIR", Path::new(PROJECT_DIR).join("examples"))
+        .env("BOOK_SRC_DIR", Path::new(PROJECT_DIR).join("docs/src"))
+        .env_remove("NO_COLOR")
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    child
+        .stdin
+        .take()
+        .unwrap()
+        .write_all(stdin.as_bytes())
+        .unwrap();
+    child.wait_with_output().unwrap()
+}
+
+fn stdout(output: &Output) -> String {
+    String::from_utf8_lossy(&output.stdout).into_owned()
+}
+
+// ตัด ANSI escape ออก (\x1b[...m)
+fn strip_ansi(text: &str) -> String {
+    let mut plain = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|&c| c == 'm');
+        } else {
+            plain.push(c);
+        }
+    }
+    plain
+}
+
+#[test]
+fn every_example_has_a_chapter_header() {
+    let catalog = catalog();
+    assert!(catalog.len() >= 11, "{:?}", catalog);
+
+    let find = |name: &str| catalog.iter().find(|e| e.name == name).unwrap();
+    assert_eq!(find("iterators").title, "Iterators & Closures");
+    assert_eq!(
+        find("iterators").chapters,
+        Chapters {
+            first: 13,
+            last: 13
+        }
+    );
+    assert_eq!(find("structs_enums").chapters.to_string(), "6-7");
+    assert!(
+        find("web_server")
+            .path
+            .ends_with("examples/web_server/main.rs")
+    );
+
+    // เรียงตามบท
+    assert_eq!(catalog[0].name, "hello_world");
+    assert!(catalog.windows(2).all(|w| w[0].chapters <= w[1].chapters));
+}
+
+#[test]
+fn parses_headers() {
+    assert_eq!(
+        parse_header("// =====\n// Async/Await - บทที่ 16\n// รัน: ...\n"),
+        Some((
+            "Async/Await".to_string(),
+            Chapters {
+                first: 16,
+                last: 16
+            }
+        ))
+    );
+    assert_eq!(
+        parse_header("// Structs & Enums - บทที่ 6-7\n").map(|(_, c)| c),
+        Some(Chapters { first: 6, last: 7 })
+    );
+    // header ต้องอยู่ใน comment ช่วงต้นไฟล์
+    assert_eq!(parse_header("fn main() {}\n// Late - บทที่ 2\n"), None);
+    assert_eq!(parse_header("// Backwards - บทที่ 7-6\n"), None);
+    assert_eq!(parse_header("// No chapter here\n"), None);
+}
+
+#[test]
+fn missing_header_is_an_error() {
+    let dir = tempfile::tempdir().unwrap();
+    fs::write(dir.path().join("bare.rs"), "fn main() {}\n").unwrap();
+    let error = examples::load(dir.path()).unwrap_err();
+    assert!(error.to_string().contains("bare.rs"), "{}", error);
+}
+
+#[test]
+fn selects_by_number_and_name() {
+    let catalog = catalog();
+    assert_eq!(names(&select(&catalog, "1")), ["hello_world"]);
+    assert_eq!(names(&select(&catalog, "0")), Vec::<&str>::new());
+    assert_eq!(names(&select(&catalog, "99")), Vec::<&str>::new());
+
+    assert_eq!(names(&select(&catalog, "iterators")), ["iterators"]);
+    assert_eq!(names(&select(&catalog, "Iter")), ["iterators"]);
+    assert_eq!(names(&select(&catalog, "web-server")), ["web_server"]);
+    // มีอยู่ในชื่อหรือหัวข้อ
+    assert_eq!(names(&select(&catalog, "closures")), ["iterators"]);
+    assert_eq!(names(&select(&catalog, "pointers")), ["smart_pointers"]);
+    // ตัวอักษรเรียงตามลำดับ
+    assert_eq!(names(&select(&catalog, "smrtptr")), ["smart_pointers"]);
+
+    assert_eq!(names(&select(&catalog, "xyzzy")), Vec::<&str>::new());
+}
+
+#[test]
+fn equally_good_matches_are_ambiguous() {
+    let catalog = catalog();
+    let selection = select(&catalog, "co");
+    assert!(matches!(selection, Selection::Ambiguous(_)));
+    assert_eq!(names(&selection), ["collections", "concurrency"]);
+}
+
+#[test]
+fn highlight_only_adds_colors() {
+    let source = "\
+#[derive(Debug)]
+struct Point<'a> { name: &'a str }
+
+fn main() {
+    // comment with \"quotes\"
+    let s = \"text // not a comment\";
+    let c = '\\n';
+    println!(\"{} {}\", s, 1.5);
+}
+";
+    let highlighted = examples::highlight(source);
+    assert_eq!(strip_ansi(&highlighted), source);
+
+    assert!(highlighted.contains("\x1b[90m// comment with \"quotes\"\x1b[0m"));
+    assert!(highlighted.contains("\x1b[32m\"text // not a comment\"\x1b[0m"));
+    assert!(highlighted.contains("\x1b[32m'\\n'\x1b[0m"));
+    assert!(highlighted.contains("\x1b[34mprintln!\x1b[0m"));
+    assert!(highlighted.contains("\x1b[1;35mfn\x1b[0m"));
+    assert!(highlighted.contains("\x1b[36mPoint\x1b[0m"));
+    assert!(highlighted.contains("\x1b[33m1.5\x1b[0m"));
+    assert!(highlighted.contains("\x1b[2m#[derive(Debug)]\x1b[0m"));
+
+    // สีไม่คร่อมบรรทัด
+    let comment = examples::highlight("/* a\nb */");
+    assert_eq!(comment, "\x1b[90m/* a\x1b[0m\n\x1b[90mb */\x1b[0m");
+}
+
+#[test]
+fn line_numbers_are_aligned() {
+    let source = (1..=10).map(|n| format!("line {}", n)).collect::<Vec<_>>();
+    let numbered = examples::with_line_numbers(&source.join("\n"), false);
+    let lines: Vec<&str> = numbered.lines().collect();
+    assert_eq!(lines[0], " 1 │ line 1");
+    assert_eq!(lines[9], "10 │ line 10");
+}
+
+#[test]
+fn cli_lists_examples_with_chapters() {
+    let output = tutorial(&["list"], "");
+    assert!(output.status.success());
+    let stdout = stdout(&output);
+    assert!(stdout.contains("  9  13       iterators"), "{}", stdout);
+    assert!(stdout.contains("Structs & Enums"));
+    assert!(stdout.contains("6-7"));
+}
+
+#[test]
+fn cli_shows_plain_source_with_book_chapter() {
+    let output = tutorial(&["show", "iter", "--no-color"], "");
+    assert!(output.status.success());
+    let stdout = stdout(&output);
+    assert!(!stdout.contains('\x1b'));
+    assert!(stdout.contains("📄 examples/iterators.rs - Iterators & Closures (บทที่ 13)"));
+    assert!(stdout.contains("ch13-iterators-closures"), "{}", stdout);
+    assert!(stdout.contains(" 2 │ // Iterators & Closures - บทที่ 13"));
+}
+
+#[test]
+fn cli_runs_an_example_after_its_source() {
+    let output = tutorial(&["run", "hello"], "");
+    assert!(output.status.success(), "{:?}", output);
+    let stdout = stdout(&output);
+    let source = stdout.find("fn main()").unwrap();
+    let output_start = stdout.find("▶️  Output:").unwrap();
+    assert!(source < output_start);
+    assert!(stdout.contains("🏁 Done (cargo run --example hello_world)"));
+
+    let only_output = self::stdout(&tutorial(&["1", "--no-source"], ""));
+    assert!(!only_output.contains("fn main()"));
+    assert!(only_output.contains("▶️  Output:"));
+}
+
+#[test]
+fn cli_menu_keeps_going_after_a_bad_choice() {
+    let output = tutorial(&[], "xyzzy\nco\nl\nq\n");
+    assert!(output.status.success());
+    let stdout = stdout(&output);
+    assert!(stdout.contains("❌ No example matches 'xyzzy'"));
+    assert!(stdout.contains("❌ 'co' matches more than one example: collections, concurrency"));
+    // รายการแสดงตอนเริ่มและตอนพิมพ์ l
+    assert_eq!(stdout.matches("smart_pointers").count(), 2);
+}
+
+#[test]
+fn cli_errors() {
+    let output = tutorial(&["run", "xyzzy"], "");
+    assert_eq!(output.status.code(), Some(1));
+    assert!(String::from_utf8_lossy(&output.stderr).contains("No example matches 'xyzzy'"));
+
+    for args in [&["run"][..], &["--verbose"], &["show", "a", "b"]] {
+        let output = tutorial(args, "");
+        assert_eq!(output.status.code(), Some(2), "{:?}", args);
+        assert!(String::from_utf8_lossy(&output.stderr).contains("Usage: tutorial"));
+    }
+}
@@ -0,0 +1,130 @@
+// ===========================================
+// Tests สำหรับ rust_tutorial::users (UserName, Email, Age, User และ serde)
+// รัน: cargo test --test users
+// ===========================================
+
+use rust_tutorial::users::{Age, Email, User, UserError, UserName};
+
+fn reason(email: &str) -> &'static str {
+    match Email::new(email) {
+        Err(UserError::InvalidEmail { reason, .. }) => reason,
+        other => panic!("expected InvalidEmail for {:?}, got {:?}", email, other),
+    }
+}
+
+#[test]
+fn user_name_rules() {
+    assert_eq!(UserName::new("  สมชาย ").unwrap().as_str(), "สมชาย");
+    assert_eq!(UserName::new(" \t"), Err(UserError::EmptyName));
+    assert_eq!(
+        UserName::new("ก".repeat(51)),
+        Err(UserError::NameTooLong { len: 51, max: 50 })
+    );
+    // 50 ตัวอักษรไทย (150 byte) ยังผ่าน
+    assert!(UserName::new("ก".repeat(50)).is_ok());
+    assert_eq!(
+        UserName::new("bad\u{7}name"),
+        Err(UserError::NameControlCharacter)
+    );
+}
+
+#[test]
+fn email_accepts_common_addresses() {
+    let email = Email::new(" Somchai.J+news@Mail.Example.CO.TH ").unwrap();
+    assert_eq!(email.as_str(), "Somchai.J+news@mail.example.co.th");
+    assert_eq!(email.local_part(), "Somchai.J+news");
+    assert_eq!(email.domain(), "mail.example.co.th");
+    assert!(Email::new("a@b-c.io").is_ok());
+}
+
+#[test]
+fn email_rejects_with_reason() {
+    assert_eq!(reason("somchai.example.com"), "missing '@'");
+    assert_eq!(
+        reason("@example.com"),
+        "the part before '@' must be 1-64 characters"
+    );
+    assert_eq!(
+        reason("a b@example.com"),
+        "unsupported character before '@'"
+    );
+    assert_eq!(reason(".a@example.com"), "misplaced '.' before '@'");
+    assert_eq!(reason("a..b@example.com"), "misplaced '.' before '@'");
+    assert_eq!(
+        reason("a@localhost"),
+        "domain needs a dot, e.g. example.com"
+    );
+    assert_eq!(reason("a@-example.com"), "invalid domain");
+    assert_eq!(reason("a@example..com"), "invalid domain");
+    assert_eq!(reason("a@example.c0m"), "invalid top-level domain");
+    assert_eq!(
+        reason(&format!("{}@example.com", "a".repeat(65))),
+        "the part before '@' must be 1-64 characters"
+    );
+    assert_eq!(reason(&format!("a@{}.com", "b".repeat(260))), "too long");
+}
+
+#[test]
+fn age_range() {
+    assert_eq!(Age::new(30).unwrap().get(), 30);
+    assert!(Age::new(18).unwrap().is_adult());
+    assert!(!Age::new(17).unwrap().is_adult());
+    assert_eq!(Age::new(150).unwrap().get(), 150);
+    assert_eq!(
+        Age::new(151),
+        Err(UserError::AgeOutOfRange { age: 151, max: 150 })
+    );
+    assert_eq!(
+        Age::new(-1),
+        Err(UserError::AgeOutOfRange { age: -1, max: 150 })
+    );
+}
+
+#[test]
+fn parse_via_from_str() {
+    let email: Email = "somchai@example.com".parse().unwrap();
+    assert_eq!(email.to_string(), "somchai@example.com");
+    assert!("x".parse::<Email>().is_err());
+    assert!("".parse::<UserName>().is_err());
+}
+
+#[test]
+fn user_serializes_as_plain_json() {
+    let user = User::new(
+        UserName::new("สมชาย").unwrap(),
+        Email::new("somchai@example.com").unwrap(),
+    );
+    assert_eq!(
+        serde_json::to_string(&user).unwrap(),
+        r#"{"name":"สมชาย","email":"somchai@example.com"}"#
+    );
+
+    let user = user.with_age(Age::new(30).unwrap());
+    let json = serde_json::to_string(&user).unwrap();
+    assert_eq!(
+        json,
+        r#"{"name":"สมชาย","email":"somchai@example.com","age":30}"#
+    );
+    assert_eq!(serde_json::from_str::<User>(&json).unwrap(), user);
+}
+
+#[test]
+fn deserialize_goes_through_validation() {
+    let user: User = serde_json::from_str(r#"{"name":" Bob ","email":"Bob@EXAMPLE.com"}"#).unwrap();
+    assert_eq!(user.name.as_str(), "Bob");
+    assert_eq!(user.email.as_str(), "Bob@example.com");
+    assert_eq!(user.age, None);
+
+    let error = |json: &str| serde_json::from_str::<User>(json).unwrap_err().to_string();
+    assert!(
+        error(r#"{"name":"Bob","email":"bob"}"#).contains("'bob' is not a valid email"),
+        "{}",
+        error(r#"{"name":"Bob","email":"bob"}"#)
+    );
+    assert!(error(r#"{"name":"","email":"bob@example.com"}"#).contains("name must not be empty"));
+    assert!(
+        error(r#"{"name":"Bob","email":"bob@example.com","age":-3}"#)
+            .contains("age -3 is out of range")
+    );
+    assert!(error(r#"{"name":"Bob"}"#).contains("missing field `email`"));
+}